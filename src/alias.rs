@@ -1,11 +1,486 @@
 use crate::config::AliasesConfig;
+use regex::Regex;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+use std::path::Path;
+use thiserror::Error;
 use tracing::{debug, info};
 
+/// Result of matching a transcription against the command set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandMatch {
+    /// Transcription named a command: output its alias value
+    Matched(String),
+    /// No command matched: nothing should be inserted
+    Unrecognized,
+}
+
+/// Match `text` exactly against alias triggers for command mode
+///
+/// Case, surrounding whitespace and trailing punctuation (which Whisper adds to
+/// short utterances) are ignored; the similarity threshold and `enabled` flag are not
+/// used, so near-misses are rejected instead of inserted.
+#[must_use]
+pub fn match_command(text: &str, config: &AliasesConfig) -> CommandMatch {
+    let spoken = normalize_command(text);
+    if spoken.is_empty() {
+        return CommandMatch::Unrecognized;
+    }
+    config
+        .entries
+        .iter()
+        .find(|(trigger, _)| normalize_command(trigger) == spoken)
+        .map_or(CommandMatch::Unrecognized, |(trigger, output)| {
+            info!(trigger = %trigger, "command matched");
+            CommandMatch::Matched(expand_escapes(output))
+        })
+}
+
+/// Lowercase, collapse whitespace and drop trailing punctuation
+fn normalize_command(text: &str) -> String {
+    text.trim()
+        .trim_end_matches(|c: char| c.is_ascii_punctuation())
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Errors reading an alias import file
+#[derive(Debug, Error)]
+pub enum AliasFileError {
+    /// File could not be read
+    #[error("failed to read alias file")]
+    Io(#[from] std::io::Error),
+
+    /// Quoted field is missing its closing quote
+    #[error("line {line}: unterminated quoted field")]
+    UnterminatedQuote {
+        /// 1-based line number
+        line: usize,
+    },
+
+    /// Unexpected text after a closing quote
+    #[error("line {line}: unexpected text after closing quote")]
+    TextAfterQuote {
+        /// 1-based line number
+        line: usize,
+    },
+
+    /// Line doesn't have exactly two fields
+    #[error("line {line}: expected 2 fields (trigger, replacement), found {found}")]
+    FieldCount {
+        /// 1-based line number
+        line: usize,
+        /// Number of fields found
+        found: usize,
+    },
+
+    /// Trigger field is empty
+    #[error("line {line}: empty trigger")]
+    EmptyTrigger {
+        /// 1-based line number
+        line: usize,
+    },
+}
+
+/// `[aliases.patterns]` entry that isn't a valid regex
+#[derive(Debug, Error)]
+#[error("invalid regex {pattern:?}")]
+pub struct AliasPatternError {
+    /// Pattern as written in the config
+    pub pattern: String,
+    /// Why it doesn't compile
+    #[source]
+    pub source: regex::Error,
+}
+
+/// Compiled `[aliases.patterns]` entry
+#[derive(Debug, Clone)]
+pub struct AliasPattern {
+    /// Pattern searched for in the transcription
+    pub regex: Regex,
+    /// Replacement; `$1` or `${name}` insert capture groups
+    pub replacement: String,
+}
+
+/// One `trigger,replacement` line from an alias import file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedAlias {
+    /// Spoken trigger phrase
+    pub trigger: String,
+    /// Output text
+    pub replacement: String,
+}
+
+/// Parse `trigger,replacement` pairs from CSV (or TSV with `delimiter = '\t'`)
+///
+/// Blank lines and lines starting with `#` are skipped. Fields may be wrapped in
+/// double quotes to include the delimiter; `""` inside quotes is a literal quote.
+/// Unquoted fields are trimmed.
+///
+/// # Errors
+/// Returns error naming the first malformed line
+pub fn parse_alias_file(
+    contents: &str,
+    delimiter: char,
+) -> Result<Vec<ImportedAlias>, AliasFileError> {
+    let mut pairs = Vec::new();
+    for (index, raw) in contents.lines().enumerate() {
+        let line = index + 1;
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let fields = split_fields(trimmed, delimiter, line)?;
+        let [trigger, replacement] =
+            <[String; 2]>::try_from(fields).map_err(|fields| AliasFileError::FieldCount {
+                line,
+                found: fields.len(),
+            })?;
+        if trigger.is_empty() {
+            return Err(AliasFileError::EmptyTrigger { line });
+        }
+        pairs.push(ImportedAlias {
+            trigger,
+            replacement,
+        });
+    }
+    Ok(pairs)
+}
+
+/// Split one line into fields, honoring double-quoted fields
+fn split_fields(
+    line: &str,
+    delimiter: char,
+    line_no: usize,
+) -> Result<Vec<String>, AliasFileError> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        // Skip leading whitespace (but not a tab delimiter)
+        while chars
+            .peek()
+            .is_some_and(|&c| c != delimiter && c.is_whitespace())
+        {
+            chars.next();
+        }
+
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err(AliasFileError::UnterminatedQuote { line: line_no }),
+                }
+            }
+            while chars
+                .peek()
+                .is_some_and(|&c| c != delimiter && c.is_whitespace())
+            {
+                chars.next();
+            }
+            if chars.peek().is_some_and(|&c| c != delimiter) {
+                return Err(AliasFileError::TextAfterQuote { line: line_no });
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == delimiter {
+                    break;
+                }
+                field.push(c);
+                chars.next();
+            }
+            field.truncate(field.trim_end().len());
+        }
+        fields.push(field);
+
+        // Consume delimiter; end of line finishes the record
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
+
+/// Read an alias file, using tab as delimiter for `.tsv` files and comma otherwise
+///
+/// # Errors
+/// Returns error if the file can't be read or has a malformed line
+pub fn load_alias_file(path: &Path) -> Result<Vec<ImportedAlias>, AliasFileError> {
+    let contents = std::fs::read_to_string(path)?;
+    let is_tsv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("tsv"));
+    parse_alias_file(&contents, if is_tsv { '\t' } else { ',' })
+}
+
+/// Merge imported aliases into `config.entries`, keeping existing (inline) entries on conflict
+///
+/// Returns the number of aliases added.
+pub fn merge_imported(config: &mut AliasesConfig, imported: Vec<ImportedAlias>) -> usize {
+    let mut added = 0;
+    for alias in imported {
+        if let Entry::Vacant(slot) = config.entries.entry(alias.trigger) {
+            slot.insert(alias.replacement);
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Casing pattern of a spoken phrase, as carried over by `preserve_case`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasePattern {
+    /// every letter lowercase (`run tests`)
+    Lower,
+    /// first letter uppercase, the rest lowercase (`Run tests`)
+    Title,
+    /// every letter uppercase, at least two letters (`RUN TESTS`)
+    Upper,
+    /// anything else, including text without letters: replacement kept as written
+    Mixed,
+}
+
+/// Detect the casing pattern of `text` from its cased letters (pure)
+#[must_use]
+pub fn case_pattern(text: &str) -> CasePattern {
+    let mut letters = text
+        .chars()
+        .filter(|c| c.is_lowercase() || c.is_uppercase());
+    let Some(first) = letters.next() else {
+        return CasePattern::Mixed;
+    };
+    let rest: Vec<char> = letters.collect();
+    let rest_lower = rest.iter().all(|c| c.is_lowercase());
+    if first.is_lowercase() && rest_lower {
+        CasePattern::Lower
+    } else if first.is_uppercase() && !rest.is_empty() && rest.iter().all(|c| c.is_uppercase()) {
+        CasePattern::Upper
+    } else if first.is_uppercase() && rest_lower {
+        CasePattern::Title
+    } else {
+        CasePattern::Mixed
+    }
+}
+
+/// Rewrite `replacement` in `pattern`'s casing (pure)
+///
+/// Title only uppercases the first letter, so replacements with their own inner
+/// capitals (`GitHub`) keep them at the start of a sentence.
+#[must_use]
+pub fn apply_case(pattern: CasePattern, replacement: &str) -> String {
+    match pattern {
+        CasePattern::Lower => replacement.to_lowercase(),
+        CasePattern::Upper => replacement.to_uppercase(),
+        CasePattern::Mixed => replacement.to_owned(),
+        CasePattern::Title => {
+            let Some(pos) = replacement.find(char::is_alphabetic) else {
+                return replacement.to_owned();
+            };
+            let (head, tail) = replacement.split_at(pos);
+            let mut chars = tail.chars();
+            let first = chars.next().map(char::to_uppercase).into_iter().flatten();
+            head.chars().chain(first).chain(chars).collect()
+        }
+    }
+}
+
+/// Interpret `\n`, `\t` and `\\` in an alias value (pure)
+///
+/// Lets values written as TOML literal strings or in an import file insert
+/// newlines and tabs. Any other backslash is kept as written.
+#[must_use]
+pub fn expand_escapes(value: &str) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            expanded.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('n') => expanded.push('\n'),
+            Some('t') => expanded.push('\t'),
+            Some('\\') => expanded.push('\\'),
+            _ => {
+                expanded.push('\\');
+                continue;
+            }
+        }
+        chars.next();
+    }
+    expanded
+}
+
+/// Value of the trigger in `entries` most similar to `input`, if at least `threshold`
+///
+/// Similarity is Jaro-Winkler (0.0-1.0), so small recognition errors
+/// ("cubernetes" for "kubernetes") still match. Case is ignored only with
+/// `case_insensitive`.
+#[must_use]
+pub fn best_match<'a, S: BuildHasher>(
+    input: &str,
+    entries: &'a HashMap<String, String, S>,
+    threshold: f64,
+    case_insensitive: bool,
+) -> Option<&'a str> {
+    let normalize = |s: &str| {
+        if case_insensitive {
+            s.to_lowercase()
+        } else {
+            s.to_owned()
+        }
+    };
+    let normalized_input = normalize(input);
+    let mut best: Option<(&str, f64)> = None;
+
+    for (trigger, output) in entries {
+        let similarity = strsim::jaro_winkler(&normalized_input, &normalize(trigger));
+
+        debug!(
+            trigger = trigger,
+            similarity = %similarity,
+            threshold = %threshold,
+            "alias match check"
+        );
+
+        if similarity >= threshold && best.map_or(true, |(_, score)| similarity > score) {
+            best = Some((output.as_str(), similarity));
+        }
+    }
+
+    best.map(|(output, similarity)| {
+        debug!(output = output, similarity = %similarity, "best alias match");
+        output
+    })
+}
+
+/// Whether `c` belongs to a word, for trigger boundaries
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '\''
+}
+
+/// Byte length of `trigger` matched at the start of `text`, if it matches (pure)
+fn match_prefix(text: &str, trigger: &str, case_insensitive: bool) -> Option<usize> {
+    let mut chars = text.char_indices();
+    for expected in trigger.chars() {
+        let (_, c) = chars.next()?;
+        let same =
+            c == expected || (case_insensitive && c.to_lowercase().eq(expected.to_lowercase()));
+        if !same {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(end, _)| end))
+}
+
+/// Alias `value` as inserted for the `spoken` text (escapes expanded, casing applied)
+fn alias_output(spoken: &str, value: &str, preserve_case: bool) -> String {
+    let output = expand_escapes(value);
+    if preserve_case {
+        apply_case(case_pattern(spoken), &output)
+    } else {
+        output
+    }
+}
+
+/// Replace triggers that appear as whole words inside `text` (pure)
+///
+/// A trigger only matches where it isn't part of a longer word, so `cd` leaves
+/// "second" alone. Longer triggers win where several match at the same place, and
+/// replaced text is never matched again. With `case_insensitive`, "Dot Com"
+/// matches the trigger `dot com`.
+#[must_use]
+pub fn replace_triggers(text: &str, config: &AliasesConfig) -> String {
+    let mut triggers: Vec<(&str, &str)> = config
+        .entries
+        .iter()
+        .filter(|(trigger, _)| !trigger.trim().is_empty())
+        .map(|(trigger, value)| (trigger.as_str(), value.as_str()))
+        .collect();
+    triggers.sort_by(|(a, _), (b, _)| b.chars().count().cmp(&a.chars().count()).then(a.cmp(b)));
+
+    let mut output = String::with_capacity(text.len());
+    let mut previous: Option<char> = None;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let found = triggers.iter().find_map(|&(trigger, value)| {
+            let starts_word = trigger.chars().next().is_some_and(is_word_char);
+            if starts_word && previous.is_some_and(is_word_char) {
+                return None;
+            }
+            let end = match_prefix(rest, trigger, config.case_insensitive)?;
+            let ends_word = trigger.chars().last().is_some_and(is_word_char);
+            let next = rest[end..].chars().next();
+            (!(ends_word && next.is_some_and(is_word_char))).then_some((end, value))
+        });
+        if let Some((end, value)) = found {
+            let spoken = &rest[..end];
+            output.push_str(&alias_output(spoken, value, config.preserve_case));
+            previous = spoken.chars().last();
+            rest = &rest[end..];
+        } else {
+            output.push(c);
+            previous = Some(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    output
+}
+
+/// Compile `[aliases.patterns]` (pattern -> replacement), in pattern order
+///
+/// Escapes in replacements are expanded like in alias values (see [`expand_escapes`]).
+///
+/// # Errors
+/// Returns error naming the first pattern that isn't a valid regex
+pub fn compile_patterns(
+    patterns: &BTreeMap<String, String>,
+) -> Result<Vec<AliasPattern>, AliasPatternError> {
+    patterns
+        .iter()
+        .map(|(pattern, replacement)| {
+            let regex = Regex::new(pattern).map_err(|source| AliasPatternError {
+                pattern: pattern.clone(),
+                source,
+            })?;
+            Ok(AliasPattern {
+                regex,
+                replacement: expand_escapes(replacement),
+            })
+        })
+        .collect()
+}
+
+/// Apply each pattern's substitution to `text` in turn (pure)
+#[must_use]
+pub fn apply_patterns(text: &str, patterns: &[AliasPattern]) -> String {
+    patterns.iter().fold(text.to_owned(), |text, pattern| {
+        let replaced = pattern
+            .regex
+            .replace_all(&text, pattern.replacement.as_str());
+        if replaced != text {
+            debug!(pattern = %pattern.regex, "alias pattern matched");
+        }
+        replaced.into_owned()
+    })
+}
+
 /// Apply alias matching to transcribed text
 ///
-/// Performs case-insensitive fuzzy matching against configured aliases.
-/// Returns the best matching alias value if similarity >= threshold,
-/// otherwise returns the original text.
+/// When the whole transcription fuzzily matches a trigger (see [`best_match`]), it
+/// is replaced by that alias. Otherwise triggers spoken inside the sentence are
+/// replaced where they stand as whole words (see [`replace_triggers`]). With
+/// `preserve_case`, a value takes the casing pattern of what was said (see
+/// [`case_pattern`]). Regex patterns run last, on the result (see [`apply_patterns`]).
 ///
 /// # Performance
 /// For typical usage (<10 aliases), string allocations are negligible as this
@@ -17,58 +492,200 @@ use tracing::{debug, info};
 /// * `config` - Alias configuration with entries and threshold
 ///
 /// # Returns
-/// Matched alias output or original text
+/// Text with aliases applied, or the original text
+#[must_use]
 pub fn apply_aliases(text: &str, config: &AliasesConfig) -> String {
-    // Return original if disabled or no aliases configured
-    if !config.enabled || config.entries.is_empty() {
+    if !config.enabled {
         return text.to_owned();
     }
+    let replaced = apply_literal_aliases(text, config);
+    apply_patterns(&replaced, &config.compiled_patterns)
+}
 
-    let normalized_text = text.to_lowercase();
-    let mut best_match: Option<(&str, f64)> = None;
-
-    // Find best matching alias
-    for (trigger, output) in &config.entries {
-        let normalized_trigger = trigger.to_lowercase();
-        let similarity = strsim::jaro_winkler(&normalized_text, &normalized_trigger);
-
-        debug!(
-            trigger = trigger,
-            similarity = %similarity,
-            threshold = %config.threshold,
-            "alias match check"
-        );
+/// Whole-utterance alias, or triggers replaced inside the sentence
+fn apply_literal_aliases(text: &str, config: &AliasesConfig) -> String {
+    // Return original if no aliases configured
+    if config.entries.is_empty() {
+        return text.to_owned();
+    }
 
-        if similarity >= config.threshold {
-            if let Some((_, best_score)) = best_match {
-                if similarity > best_score {
-                    best_match = Some((output.as_str(), similarity));
-                }
-            } else {
-                best_match = Some((output.as_str(), similarity));
-            }
-        }
+    if let Some(output) = best_match(
+        text,
+        &config.entries,
+        config.threshold,
+        config.case_insensitive,
+    ) {
+        info!(original = text, output = output, "alias matched");
+        return alias_output(text, output, config.preserve_case);
     }
 
-    // Return best match or original text
-    if let Some((output, score)) = best_match {
-        info!(
-            original = text,
-            output = output,
-            similarity = %score,
-            "alias matched"
-        );
-        output.to_owned()
-    } else {
+    let replaced = replace_triggers(text, config);
+    if replaced == text {
         debug!(text = text, "no alias match, using original");
-        text.to_owned()
+    } else {
+        info!(original = text, output = %replaced, "aliases replaced in text");
     }
+    replaced
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
+
+    fn command_set() -> AliasesConfig {
+        let mut config = AliasesConfig::default();
+        config
+            .entries
+            .insert("New Line".to_owned(), "\n".to_owned());
+        config
+            .entries
+            .insert("run tests".to_owned(), "make test".to_owned());
+        config
+    }
+
+    #[test]
+    fn test_match_command_exact() {
+        let config = command_set();
+        assert_eq!(
+            match_command("run tests", &config),
+            CommandMatch::Matched("make test".to_owned())
+        );
+        // Case, spacing and Whisper's trailing punctuation are ignored
+        assert_eq!(
+            match_command("  Run  Tests. ", &config),
+            CommandMatch::Matched("make test".to_owned())
+        );
+        assert_eq!(
+            match_command("new line!", &config),
+            CommandMatch::Matched("\n".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_match_command_rejects_non_matching() {
+        let config = command_set();
+        // Near-misses that fuzzy alias matching would accept
+        assert_eq!(
+            match_command("run test", &config),
+            CommandMatch::Unrecognized
+        );
+        assert_eq!(
+            match_command("please run tests", &config),
+            CommandMatch::Unrecognized
+        );
+        assert_eq!(match_command("", &config), CommandMatch::Unrecognized);
+        assert_eq!(match_command("...", &config), CommandMatch::Unrecognized);
+    }
+
+    #[test]
+    fn test_match_command_empty_set_rejects() {
+        assert_eq!(
+            match_command("run tests", &AliasesConfig::default()),
+            CommandMatch::Unrecognized
+        );
+    }
+
+    fn alias(trigger: &str, replacement: &str) -> ImportedAlias {
+        ImportedAlias {
+            trigger: trigger.to_owned(),
+            replacement: replacement.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_parse_alias_file_csv() {
+        let contents = "# trigger,replacement\n\nrun tests, make test\ngit status,git status -sb\n";
+        assert_eq!(
+            parse_alias_file(contents, ',').unwrap(),
+            vec![
+                alias("run tests", "make test"),
+                alias("git status", "git status -sb"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_alias_file_quoted_fields() {
+        let contents = r#""comma sign",","
+"say hi", "he said ""hi"", then left"
+"  padded  ",x
+"#;
+        assert_eq!(
+            parse_alias_file(contents, ',').unwrap(),
+            vec![
+                alias("comma sign", ","),
+                alias("say hi", r#"he said "hi", then left"#),
+                alias("  padded  ", "x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_alias_file_tsv() {
+        let contents = "dot com\t.com\nnew para\t\"a, b\"\n";
+        assert_eq!(
+            parse_alias_file(contents, '\t').unwrap(),
+            vec![alias("dot com", ".com"), alias("new para", "a, b")]
+        );
+    }
+
+    #[test]
+    fn test_parse_alias_file_errors_name_line() {
+        let err = parse_alias_file("a,b\nonly one field\n", ',').unwrap_err();
+        assert!(matches!(
+            err,
+            AliasFileError::FieldCount { line: 2, found: 1 }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected 2 fields (trigger, replacement), found 1"
+        );
+
+        assert!(matches!(
+            parse_alias_file("a,b,c", ','),
+            Err(AliasFileError::FieldCount { line: 1, found: 3 })
+        ));
+        assert!(matches!(
+            parse_alias_file("# ok\n\"open,value", ','),
+            Err(AliasFileError::UnterminatedQuote { line: 2 })
+        ));
+        assert!(matches!(
+            parse_alias_file("\"a\"b,c", ','),
+            Err(AliasFileError::TextAfterQuote { line: 1 })
+        ));
+        assert!(matches!(
+            parse_alias_file(" ,value", ','),
+            Err(AliasFileError::EmptyTrigger { line: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_merge_imported_inline_wins() {
+        let mut config = AliasesConfig::default();
+        config
+            .entries
+            .insert("run tests".to_owned(), "cargo test".to_owned());
+
+        let added = merge_imported(
+            &mut config,
+            vec![alias("run tests", "make test"), alias("dot com", ".com")],
+        );
+
+        assert_eq!(added, 1);
+        assert_eq!(config.entries["run tests"], "cargo test");
+        assert_eq!(config.entries["dot com"], ".com");
+    }
+
+    #[test]
+    fn test_load_alias_file_tsv_extension() {
+        let path = std::env::temp_dir().join("test_alias_import.tsv");
+        std::fs::write(&path, "dot com\t.com\n").unwrap();
+        assert_eq!(
+            load_alias_file(&path).unwrap(),
+            vec![alias("dot com", ".com")]
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
 
     #[test]
     fn test_disabled_returns_original() {
@@ -79,6 +696,11 @@ mod tests {
             enabled: false,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(apply_aliases("run tests", &config), "run tests");
@@ -90,6 +712,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries: HashMap::new(),
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(apply_aliases("run tests", &config), "run tests");
@@ -107,6 +734,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(
@@ -124,6 +756,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(apply_aliases("Run Tests", &config), "make test");
@@ -140,6 +777,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         // "runtests" should match "run tests" with high similarity
@@ -155,6 +797,11 @@ mod tests {
             enabled: true,
             threshold: 0.9, // High threshold
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         // "testing" should not match "run tests" at 0.9 threshold
@@ -172,6 +819,11 @@ mod tests {
             enabled: true,
             threshold: 0.5,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         // "run tests" should match "run tests" better than "run all tests"
@@ -192,6 +844,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(apply_aliases("run tests", &config), "make test");
@@ -208,6 +865,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(apply_aliases("hello world", &config), "hello world");
@@ -222,6 +884,11 @@ mod tests {
             enabled: true,
             threshold: 0.8,
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         assert_eq!(apply_aliases("", &config), "");
@@ -237,9 +904,290 @@ mod tests {
             enabled: true,
             threshold: 0.0, // Accept any match
             entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         };
 
         // Even very different strings should match at threshold 0.0
         assert_eq!(apply_aliases("completely different", &config), "output");
     }
+
+    #[test]
+    fn test_expand_escapes_newline_and_tab() {
+        assert_eq!(expand_escapes(r"\n"), "\n");
+        assert_eq!(expand_escapes(r"\t"), "\t");
+        assert_eq!(expand_escapes(r"line\nnext\tcol"), "line\nnext\tcol");
+    }
+
+    #[test]
+    fn test_expand_escapes_literal_backslash() {
+        assert_eq!(expand_escapes(r"\\"), "\\");
+        // An escaped backslash before n stays a backslash and an n
+        assert_eq!(expand_escapes(r"\\n"), "\\n");
+        assert_eq!(expand_escapes(r"C:\Users"), r"C:\Users");
+        assert_eq!(expand_escapes("trailing\\"), "trailing\\");
+    }
+
+    #[test]
+    fn test_expand_escapes_plain_text_unchanged() {
+        assert_eq!(expand_escapes("user@example.com"), "user@example.com");
+        assert_eq!(expand_escapes(""), "");
+    }
+
+    #[test]
+    fn test_alias_value_escapes_expanded() {
+        let config = AliasesConfig {
+            entries: HashMap::from([
+                ("new line".to_owned(), r"\n".to_owned()),
+                ("tab".to_owned(), r"\t".to_owned()),
+            ]),
+            ..AliasesConfig::default()
+        };
+        assert_eq!(apply_aliases("New line.", &config), "\n");
+        assert_eq!(apply_aliases("tab", &config), "\t");
+        assert_eq!(
+            match_command("New line.", &config),
+            CommandMatch::Matched("\n".to_owned())
+        );
+    }
+
+    fn kube_entries() -> HashMap<String, String> {
+        HashMap::from([
+            ("kubernetes".to_owned(), "Kubernetes".to_owned()),
+            ("grafana".to_owned(), "Grafana".to_owned()),
+        ])
+    }
+
+    #[test]
+    fn test_best_match_exact() {
+        assert_eq!(
+            best_match("Kubernetes", &kube_entries(), 0.8, true),
+            Some("Kubernetes")
+        );
+        // An exact match clears even the strictest threshold
+        assert_eq!(
+            best_match("grafana", &kube_entries(), 1.0, true),
+            Some("Grafana")
+        );
+    }
+
+    #[test]
+    fn test_best_match_case_sensitive() {
+        assert_eq!(best_match("GRAFANA", &kube_entries(), 1.0, false), None);
+        assert_eq!(
+            best_match("GRAFANA", &kube_entries(), 1.0, true),
+            Some("Grafana")
+        );
+        assert_eq!(
+            best_match("grafana", &kube_entries(), 1.0, false),
+            Some("Grafana")
+        );
+    }
+
+    #[test]
+    fn test_best_match_near_match_above_threshold() {
+        assert_eq!(
+            best_match("cubernetes", &kube_entries(), 0.85, true),
+            Some("Kubernetes")
+        );
+        assert_eq!(
+            best_match("grafanna", &kube_entries(), 0.85, true),
+            Some("Grafana")
+        );
+    }
+
+    #[test]
+    fn test_best_match_below_threshold_rejected() {
+        assert_eq!(best_match("cubernetes", &kube_entries(), 0.99, true), None);
+        assert_eq!(best_match("hello world", &kube_entries(), 0.8, true), None);
+        assert_eq!(best_match("kubernetes", &HashMap::new(), 0.0, true), None);
+    }
+
+    fn patterns(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|&(pattern, replacement)| (pattern.to_owned(), replacement.to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_patterns_capture_groups() {
+        let compiled = compile_patterns(&patterns(&[(
+            r"(?i)\bversion (\d+) point (\d+)\b",
+            "$1.$2",
+        )]))
+        .unwrap();
+        assert_eq!(
+            apply_patterns("Ship Version 1 point 2 today", &compiled),
+            "Ship 1.2 today"
+        );
+        assert_eq!(apply_patterns("no match here", &compiled), "no match here");
+    }
+
+    #[test]
+    fn test_apply_patterns_in_order_with_escapes() {
+        let compiled = compile_patterns(&patterns(&[
+            (r"\s*new paragraph\s*", r"\n\n"),
+            (r"(\d+) percent", "${1}%"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            apply_patterns("Up 5 percent new paragraph Done", &compiled),
+            "Up 5%\n\nDone"
+        );
+    }
+
+    #[test]
+    fn test_compile_patterns_reports_invalid_regex() {
+        let error = compile_patterns(&patterns(&[("ok", "fine"), ("(unclosed", "x")])).unwrap_err();
+        assert_eq!(error.pattern, "(unclosed");
+        assert_eq!(error.to_string(), r#"invalid regex "(unclosed""#);
+    }
+
+    #[test]
+    fn test_patterns_run_after_literal_aliases() {
+        let mut config = AliasesConfig {
+            entries: HashMap::from([("v two".to_owned(), "version 2".to_owned())]),
+            compiled_patterns: compile_patterns(&patterns(&[(r"version (\d+)", "v$1.0")])).unwrap(),
+            ..AliasesConfig::default()
+        };
+        assert_eq!(apply_aliases("v two", &config), "v2.0");
+
+        // Patterns apply without literal entries, but not when aliases are disabled
+        config.entries.clear();
+        assert_eq!(apply_aliases("version 3", &config), "v3.0");
+        config.enabled = false;
+        assert_eq!(apply_aliases("version 3", &config), "version 3");
+    }
+
+    fn sentence_config(entries: &[(&str, &str)]) -> AliasesConfig {
+        AliasesConfig {
+            entries: entries
+                .iter()
+                .map(|&(trigger, value)| (trigger.to_owned(), value.to_owned()))
+                .collect(),
+            ..AliasesConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_replace_triggers_respects_word_boundaries() {
+        let config = sentence_config(&[("cd", "change directory")]);
+        assert_eq!(
+            apply_aliases("The second cd worked", &config),
+            "The second change directory worked"
+        );
+        assert_eq!(replace_triggers("abcd cde", &config), "abcd cde");
+        assert_eq!(
+            replace_triggers("cd, then cd.", &config),
+            "change directory, then change directory."
+        );
+    }
+
+    #[test]
+    fn test_replace_triggers_case_folding() {
+        let mut config = sentence_config(&[("dot com", ".com")]);
+        assert_eq!(
+            apply_aliases("Visit example Dot Com today", &config),
+            "Visit example .com today"
+        );
+
+        config.case_insensitive = false;
+        assert_eq!(
+            replace_triggers("Visit example Dot Com today", &config),
+            "Visit example Dot Com today"
+        );
+        assert_eq!(
+            replace_triggers("Visit example dot com today", &config),
+            "Visit example .com today"
+        );
+    }
+
+    #[test]
+    fn test_replace_triggers_multiple_aliases() {
+        let config = sentence_config(&[
+            ("my email", "user@example.com"),
+            ("new line", r"\n"),
+            ("run tests", "make test"),
+            ("run", "cargo run"),
+        ]);
+        assert_eq!(
+            apply_aliases("Mail my email, new line and run tests", &config),
+            "Mail user@example.com, \n and make test"
+        );
+        // Replaced text isn't matched again
+        let config = sentence_config(&[("a", "a a"), ("b", "a")]);
+        assert_eq!(replace_triggers("b a", &config), "a a a");
+    }
+
+    #[test]
+    fn test_replace_triggers_non_word_edges() {
+        let config = sentence_config(&[("@team", "the whole team")]);
+        assert_eq!(
+            replace_triggers("ping @team now", &config),
+            "ping the whole team now"
+        );
+    }
+
+    #[test]
+    fn test_replace_triggers_preserve_case() {
+        let mut config = sentence_config(&[("gh", "github")]);
+        config.preserve_case = true;
+        assert_eq!(replace_triggers("Open GH now", &config), "Open GITHUB now");
+    }
+
+    #[test]
+    fn test_case_pattern() {
+        assert_eq!(case_pattern("run tests"), CasePattern::Lower);
+        assert_eq!(case_pattern("Run tests."), CasePattern::Title);
+        assert_eq!(case_pattern("RUN TESTS!"), CasePattern::Upper);
+        assert_eq!(case_pattern("run Tests"), CasePattern::Mixed);
+        assert_eq!(case_pattern("Run Tests"), CasePattern::Mixed);
+        assert_eq!(case_pattern("rUN"), CasePattern::Mixed);
+    }
+
+    #[test]
+    fn test_case_pattern_edge_cases() {
+        // Single capital letter reads as Title, not ALL-CAPS
+        assert_eq!(case_pattern("A"), CasePattern::Title);
+        assert_eq!(case_pattern("  ...  "), CasePattern::Mixed);
+        assert_eq!(case_pattern(""), CasePattern::Mixed);
+        // Digits and punctuation are ignored
+        assert_eq!(case_pattern("2 FA"), CasePattern::Upper);
+        assert_eq!(case_pattern("élan"), CasePattern::Lower);
+    }
+
+    #[test]
+    fn test_apply_case() {
+        assert_eq!(apply_case(CasePattern::Lower, "GitHub"), "github");
+        assert_eq!(apply_case(CasePattern::Upper, "GitHub"), "GITHUB");
+        assert_eq!(apply_case(CasePattern::Title, "make test"), "Make test");
+        // Title keeps inner capitals and skips leading non-letters
+        assert_eq!(apply_case(CasePattern::Title, "gitHub"), "GitHub");
+        assert_eq!(apply_case(CasePattern::Title, "\"@home\""), "\"@Home\"");
+        assert_eq!(apply_case(CasePattern::Title, "..."), "...");
+        assert_eq!(apply_case(CasePattern::Mixed, "GitHub"), "GitHub");
+    }
+
+    #[test]
+    fn test_preserve_case_applies_spoken_casing() {
+        let mut config = AliasesConfig {
+            preserve_case: true,
+            ..AliasesConfig::default()
+        };
+        config
+            .entries
+            .insert("dot com".to_owned(), "Example.com".to_owned());
+
+        assert_eq!(apply_aliases("dot com", &config), "example.com");
+        assert_eq!(apply_aliases("Dot com", &config), "Example.com");
+        assert_eq!(apply_aliases("DOT COM", &config), "EXAMPLE.COM");
+        assert_eq!(apply_aliases("dot Com", &config), "Example.com");
+
+        config.preserve_case = false;
+        assert_eq!(apply_aliases("DOT COM", &config), "Example.com");
+    }
 }
@@ -1,16 +1,24 @@
 use anyhow::{Context, Result};
-use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::traits::{DeviceTrait, StreamTrait};
+use cpal::{BufferSize, SupportedBufferSize};
 use hound::{WavSpec, WavWriter};
 use ringbuf::{
-    traits::{Consumer, Producer, Split},
-    HeapCons, HeapRb,
+    traits::{Consumer, Observer, Producer, RingBuffer, Split},
+    HeapCons, HeapProd, HeapRb,
 };
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
-use crate::config::AudioConfig;
+use super::device::select_input_device;
+use super::level::InputLevel;
+use super::resample::{choose_resampler, frames_duration, resample, Resampler};
+use super::stats::peak_level;
+use super::trim::{trim_silence, SilenceTrim};
+use crate::config::{AudioConfig, WavFormat};
 
 /// Trait for audio capture operations (enables testing via mocking)
 ///
@@ -44,11 +52,15 @@ trait StreamControl {
     fn play(&self) -> Result<()>;
     /// Pause audio stream (deactivate microphone)
     fn pause(&self) -> Result<()>;
+    /// Whether the stream still delivers audio (false after a fatal stream error)
+    fn is_healthy(&self) -> bool;
 }
 
 /// CPAL stream wrapper implementing `StreamControl`
 struct CpalStreamControl {
     stream: cpal::Stream,
+    /// Set by the stream's error callback when the device is gone
+    failed: Arc<AtomicBool>,
 }
 
 impl StreamControl for CpalStreamControl {
@@ -59,6 +71,84 @@ impl StreamControl for CpalStreamControl {
     fn pause(&self) -> Result<()> {
         self.stream.pause().context("failed to pause audio stream")
     }
+
+    fn is_healthy(&self) -> bool {
+        !self.failed.load(Ordering::Relaxed)
+    }
+}
+
+/// Sample format of an input stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StreamFormat {
+    /// Device sample rate in Hz
+    sample_rate: u32,
+    /// Number of audio channels
+    channels: u16,
+}
+
+/// Opened input device: stream, the ring buffer it fills, and its format
+struct DeviceInput {
+    control: Box<dyn StreamControl>,
+    consumer: HeapCons<f32>,
+    format: StreamFormat,
+}
+
+/// How often the drain thread empties the ring buffer
+///
+/// Far below the ring buffer's capacity (`max_recording_secs`), so it never fills up.
+const DRAIN_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Ring buffer consumer handed back by the drain thread, plus the samples it collected
+type Drained = (HeapCons<f32>, Vec<f32>);
+
+/// Background thread moving samples out of the ring buffer while recording
+/// (`[audio] continuous_drain`)
+///
+/// Recordings then grow in a `Vec` instead of being capped by the ring buffer.
+struct DrainThread {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<Drained>,
+}
+
+impl DrainThread {
+    /// Start draining `consumer` until [`finish`](Self::finish)
+    fn spawn(mut consumer: HeapCons<f32>) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let handle = std::thread::spawn(move || {
+            let mut samples = Vec::new();
+            while !stop_flag.load(Ordering::Acquire) {
+                samples.extend(consumer.pop_iter());
+                std::thread::sleep(DRAIN_INTERVAL);
+            }
+            // Samples pushed between the last pass and the stop request
+            samples.extend(consumer.pop_iter());
+            (consumer, samples)
+        });
+        Self { stop, handle }
+    }
+
+    /// Stop draining; returns the consumer and every sample in push order
+    ///
+    /// Call after the stream is paused so no samples arrive after the final pass.
+    ///
+    /// # Errors
+    /// Returns error if the drain thread panicked
+    fn finish(self) -> Result<Drained> {
+        self.stop.store(true, Ordering::Release);
+        self.handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("audio drain thread panicked"))
+    }
+}
+
+/// Time the last `stop_recording` spent on each step
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CaptureTimings {
+    /// Draining the ring buffer
+    pub drain: Duration,
+    /// Downmixing and resampling to 16kHz mono
+    pub resample: Duration,
 }
 
 /// Audio capture using CoreAudio/CPAL
@@ -70,10 +160,28 @@ pub struct AudioCapture {
     ring_buffer_consumer: HeapCons<f32>,
     /// Recording state flag
     is_recording: Arc<AtomicBool>,
-    /// Device sample rate in Hz
-    device_sample_rate: u32,
-    /// Number of audio channels
-    device_channels: u16,
+    /// Format of the samples in the ring buffer (only replaced together with it)
+    format: StreamFormat,
+    /// Step timings of the last stop (for `[telemetry] timing_debug`)
+    last_timings: CaptureTimings,
+    /// Drain the ring buffer on a background thread while recording
+    continuous_drain: bool,
+    /// Running drain thread, holding the ring buffer consumer until stop
+    drain_thread: Option<DrainThread>,
+    /// Pick linear or sinc resampling from the recording length
+    resampler_auto: bool,
+    /// Recordings at least this long use the sinc resampler (with `resampler_auto`)
+    resampler_threshold: Duration,
+    /// Strip leading and trailing silence after conversion (None = keep everything)
+    silence_trim: Option<SilenceTrim>,
+    /// Peak level recordings are scaled to after conversion (None = no normalization)
+    normalize_peak: Option<f32>,
+    /// Stream runs between recordings to fill the pre-roll (`[audio] preroll_ms`)
+    keep_stream_running: bool,
+    /// Settings the stream was opened with, reused when it's rebuilt
+    config: AudioConfig,
+    /// Smoothed input level while recording, updated by the audio callback
+    level: InputLevel,
 }
 
 impl AudioCapture {
@@ -81,86 +189,103 @@ impl AudioCapture {
     ///
     /// # Errors
     /// Returns error if default audio device is unavailable or stream creation fails
-    pub fn new(_config: &AudioConfig) -> Result<Self> {
-        info!("initializing audio capture");
-
-        // Get default input device
-        let host = cpal::default_host();
-        let device = host
-            .default_input_device()
-            .context("no input device available")?;
-
-        let device_desc = device
-            .description()
-            .map_or_else(|_| "unknown".to_owned(), |d| d.to_string());
-        info!("using input device: {}", device_desc);
-
-        // Get device config (use device default, will resample to 16kHz later)
-        let supported_config = device
-            .default_input_config()
-            .context("failed to get default input config")?;
+    pub fn new(config: &AudioConfig) -> Result<Self> {
+        Self::with_level(config, InputLevel::default())
+    }
 
-        let device_sample_rate = supported_config.sample_rate();
-        let device_channels = supported_config.channels();
+    /// Creates an audio capture reporting its input level to `level`
+    ///
+    /// Captures sharing a level (e.g. per-profile `[profiles.audio]`) drive one meter.
+    ///
+    /// # Errors
+    /// Returns error if default audio device is unavailable or stream creation fails
+    pub fn with_level(config: &AudioConfig, level: InputLevel) -> Result<Self> {
+        info!("initializing audio capture");
 
-        info!(
-            "device config: {} Hz, {} channels",
-            device_sample_rate, device_channels
-        );
+        let is_recording = Arc::new(AtomicBool::new(false));
+        let input = open_input(config, &is_recording, &level)?;
 
-        // Create ring buffer sized for max recording duration (30s at device sample rate)
-        // This ensures no samples are dropped during recording
-        let max_recording_secs = 30;
-        let ring_buffer_capacity =
-            (device_sample_rate as usize) * (device_channels as usize) * max_recording_secs;
-        info!(
-            "ring buffer capacity: {} samples ({} seconds at {} Hz)",
-            ring_buffer_capacity, max_recording_secs, device_sample_rate
-        );
-        let ring_buffer = HeapRb::<f32>::new(ring_buffer_capacity);
-        let (ring_buffer_producer, ring_buffer_consumer) = ring_buffer.split();
+        Ok(Self {
+            stream_control: Some(input.control),
+            ring_buffer_consumer: input.consumer,
+            is_recording,
+            format: input.format,
+            last_timings: CaptureTimings::default(),
+            continuous_drain: config.continuous_drain,
+            drain_thread: None,
+            resampler_auto: config.resampler_auto,
+            resampler_threshold: Duration::from_millis(config.resampler_threshold_ms),
+            silence_trim: SilenceTrim::from_config(config),
+            normalize_peak: config.normalize.then_some(config.target_peak),
+            keep_stream_running: config.preroll_ms > 0,
+            config: config.clone(),
+            level,
+        })
+    }
 
-        let is_recording = Arc::new(AtomicBool::new(false));
+    /// Whether the input stream is still alive (false once its device disappeared)
+    #[must_use]
+    pub fn is_stream_healthy(&self) -> bool {
+        self.stream_control
+            .as_ref()
+            .map_or(true, |control| control.is_healthy())
+    }
 
-        // Build input stream with callback
-        let is_recording_clone = Arc::clone(&is_recording);
-        let mut producer = ring_buffer_producer;
+    /// Smoothed RMS input level of the current recording (0.0 when not recording)
+    #[must_use]
+    #[allow(dead_code)] // Public API; the tray reads through `level_meter` without locking
+    pub fn current_level(&self) -> f32 {
+        self.level.get()
+    }
 
-        let stream_config = supported_config.into();
-        let stream = device
-            .build_input_stream(
-                &stream_config,
-                move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                    if is_recording_clone.load(Ordering::Relaxed) {
-                        // Lock-free push to ring buffer
-                        let pushed = producer.push_slice(data);
-                        if pushed < data.len() {
-                            warn!("ring buffer full, dropped {} samples", data.len() - pushed);
-                        }
-                    }
-                },
-                move |err| {
-                    warn!("audio stream error: {}", err);
-                },
-                None,
-            )
-            .context("failed to build input stream")?;
+    /// Handle for reading the input level without locking the capture (e.g. the tray meter)
+    #[must_use]
+    pub fn level_meter(&self) -> InputLevel {
+        self.level.clone()
+    }
 
-        // Wrap stream in controller
-        let stream_control = CpalStreamControl { stream };
+    /// Whether a recording is in progress
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.is_recording.load(Ordering::Relaxed)
+    }
 
-        // Start the stream and immediately pause it (mic inactive until hotkey pressed)
-        stream_control.play()?;
-        stream_control.pause()?;
-        info!("audio stream initialized (paused)");
+    /// Reopen the configured input device, or the current default (e.g. after a disconnect)
+    ///
+    /// The new device may run at a different rate or channel count; the next
+    /// recording is converted with the new format.
+    ///
+    /// # Errors
+    /// Returns error if the device can't be opened or a recording is in progress
+    pub fn rebuild_stream(&mut self) -> Result<()> {
+        if self.is_recording() {
+            anyhow::bail!("cannot switch input device while recording");
+        }
+        let input = open_input(&self.config, &self.is_recording, &self.level)?;
+        self.switch_input(input)
+    }
 
-        Ok(Self {
-            stream_control: Some(Box::new(stream_control)),
-            ring_buffer_consumer,
-            is_recording,
-            device_sample_rate,
-            device_channels,
-        })
+    /// Replace stream, ring buffer and format in one step
+    ///
+    /// Refused while recording: samples already captured must be converted
+    /// with the format of the device that produced them.
+    fn switch_input(&mut self, input: DeviceInput) -> Result<()> {
+        if self.is_recording.load(Ordering::Relaxed) {
+            anyhow::bail!("cannot switch input device while recording");
+        }
+        if input.format != self.format {
+            info!(
+                "input format changed: {} Hz, {} channels -> {} Hz, {} channels",
+                self.format.sample_rate,
+                self.format.channels,
+                input.format.sample_rate,
+                input.format.channels
+            );
+        }
+        self.stream_control = Some(input.control);
+        self.ring_buffer_consumer = input.consumer;
+        self.format = input.format;
+        Ok(())
     }
 
     /// Starts recording audio (public interface)
@@ -182,14 +307,28 @@ impl AudioCapture {
         let start = std::time::Instant::now();
         debug!("starting recording");
 
+        // A stop that failed early leaves its drain thread holding the consumer
+        self.reclaim_drain_thread();
+
         // Clear ring buffer
         self.ring_buffer_consumer.clear();
 
+        // Hand the consumer to the drain thread before any samples arrive
+        if self.continuous_drain {
+            let consumer =
+                std::mem::replace(&mut self.ring_buffer_consumer, placeholder_consumer());
+            self.drain_thread = Some(DrainThread::spawn(consumer));
+        }
+
         // Set recording flag BEFORE resuming stream to avoid race condition
         self.is_recording.store(true, Ordering::Relaxed);
 
-        // Resume audio stream (activate microphone)
-        if let Some(stream_control) = &self.stream_control {
+        // Resume audio stream (activate microphone); with pre-roll it never stopped
+        if let Some(stream_control) = self
+            .stream_control
+            .as_ref()
+            .filter(|_| !self.keep_stream_running)
+        {
             stream_control.play()?;
         }
 
@@ -217,25 +356,31 @@ impl AudioCapture {
         let start_total = std::time::Instant::now();
         debug!("stopping recording");
 
-        // Pause audio stream first (deactivate microphone)
-        if let Some(stream_control) = &self.stream_control {
-            stream_control.pause()?;
+        // Pause audio stream first (deactivate microphone); with pre-roll it keeps running
+        let paused = self
+            .stream_control
+            .as_ref()
+            .filter(|_| !self.keep_stream_running)
+            .map_or(Ok(()), |stream_control| stream_control.pause());
+        if let Err(e) = paused {
+            // Take the consumer back so the next recording isn't left with the placeholder
+            self.reclaim_drain_thread();
+            return Err(e);
         }
 
         // Clear recording flag after stream is paused
         self.is_recording.store(false, Ordering::Relaxed);
+        self.level.reset();
 
-        // Pause audio stream (deactivate microphone)
-        if let Some(stream_control) = &self.stream_control {
-            stream_control.pause()?;
-        }
-
-        // Drain ring buffer into Vec
+        // Drain ring buffer into Vec (or collect what the drain thread gathered)
         let start_drain = std::time::Instant::now();
-        let mut samples = Vec::new();
-        while let Some(sample) = self.ring_buffer_consumer.try_pop() {
-            samples.push(sample);
-        }
+        let samples = if let Some(drain_thread) = self.drain_thread.take() {
+            let (consumer, samples) = drain_thread.finish()?;
+            self.ring_buffer_consumer = consumer;
+            samples
+        } else {
+            self.ring_buffer_consumer.pop_iter().collect()
+        };
         let drain_duration = start_drain.elapsed();
 
         info!(
@@ -245,7 +390,24 @@ impl AudioCapture {
         );
 
         // Convert to 16kHz mono
-        let samples_16khz_mono = self.convert_to_16khz_mono(&samples);
+        let start_resample = std::time::Instant::now();
+        let mut samples_16khz_mono = self.convert_to_16khz_mono(&samples);
+        if let Some(trim) = &self.silence_trim {
+            let before = samples_16khz_mono.len();
+            samples_16khz_mono = trim_silence(&samples_16khz_mono, trim);
+            debug!(
+                before,
+                after = samples_16khz_mono.len(),
+                "trimmed leading/trailing silence"
+            );
+        }
+        if let Some(target_peak) = self.normalize_peak {
+            samples_16khz_mono = normalize_samples(&samples_16khz_mono, target_peak);
+        }
+        self.last_timings = CaptureTimings {
+            drain: drain_duration,
+            resample: start_resample.elapsed(),
+        };
 
         let total_duration = start_total.elapsed();
         info!(
@@ -256,20 +418,80 @@ impl AudioCapture {
         Ok(samples_16khz_mono)
     }
 
+    /// Stop a leftover drain thread and restore its consumer (its samples are discarded)
+    fn reclaim_drain_thread(&mut self) {
+        let Some(drain_thread) = self.drain_thread.take() else {
+            return;
+        };
+        match drain_thread.finish() {
+            Ok((consumer, samples)) => {
+                debug!(samples = samples.len(), "reclaimed audio drain thread");
+                self.ring_buffer_consumer = consumer;
+            }
+            Err(e) => warn!(error = %e, "failed to reclaim audio drain thread"),
+        }
+    }
+
+    /// Sample rate the current input device captures at
+    #[must_use]
+    pub const fn device_sample_rate(&self) -> u32 {
+        self.format.sample_rate
+    }
+
+    /// Drain and resample timings of the last `stop_recording`
+    #[must_use]
+    pub const fn last_timings(&self) -> CaptureTimings {
+        self.last_timings
+    }
+
+    /// Convert drained samples using the format of the stream that captured them
+    ///
+    /// The resampler is picked from the recording length (`[audio] resampler_auto`).
     fn convert_to_16khz_mono(&self, samples: &[f32]) -> Vec<f32> {
+        let frames = samples.len() / usize::from(self.format.channels.max(1));
+        let resampler = choose_resampler(
+            self.resampler_auto,
+            frames_duration(frames, self.format.sample_rate),
+            self.resampler_threshold,
+        );
+        Self::to_16khz_mono_with(
+            samples,
+            self.format.sample_rate,
+            self.format.channels,
+            resampler,
+        )
+    }
+
+    /// Downmix interleaved samples to mono and resample to 16kHz (Whisper input format)
+    ///
+    /// Uses the band-limited sinc resampler, so content above 8kHz is filtered out
+    /// instead of aliasing into the speech band.
+    #[must_use]
+    pub fn to_16khz_mono(samples: &[f32], sample_rate: u32, channels: u16) -> Vec<f32> {
+        Self::to_16khz_mono_with(samples, sample_rate, channels, Resampler::Sinc)
+    }
+
+    /// [`Self::to_16khz_mono`] with an explicit resampling algorithm
+    #[must_use]
+    pub fn to_16khz_mono_with(
+        samples: &[f32],
+        sample_rate: u32,
+        channels: u16,
+        resampler: Resampler,
+    ) -> Vec<f32> {
         let _span = tracing::debug_span!("convert_to_16khz_mono").entered();
         let start_total = std::time::Instant::now();
         let target_sample_rate = 16000;
 
         // Convert stereo to mono if needed
         let start_downmix = std::time::Instant::now();
-        let mono_samples = if self.device_channels == 1 {
+        let mono_samples = if channels == 1 {
             samples.to_vec()
         } else {
             // Average channels (simple downmix)
-            let channels_f64 = f64::from(self.device_channels);
+            let channels_f64 = f64::from(channels);
             samples
-                .chunks(self.device_channels as usize)
+                .chunks(channels as usize)
                 .map(|frame| {
                     let sum_f64: f64 = frame.iter().map(|&s| f64::from(s)).sum();
                     // f64 → f32: audio samples are stored as f32, precision sufficient
@@ -282,78 +504,31 @@ impl AudioCapture {
         };
         let downmix_duration = start_downmix.elapsed();
 
-        if self.device_channels > 1 {
+        if channels > 1 {
             debug!(
-                channels = self.device_channels,
+                channels = channels,
                 downmix_us = downmix_duration.as_micros(),
                 "stereo to mono conversion"
             );
         }
 
         // Resample if needed
-        if self.device_sample_rate == target_sample_rate {
+        if sample_rate == target_sample_rate {
             return mono_samples;
         }
 
-        // Simple linear interpolation resampling
-        // Algorithm requires f64 ↔ usize conversions for fractional index calculations
-        #[allow(
-            clippy::cast_possible_truncation,
-            clippy::cast_sign_loss,
-            clippy::cast_precision_loss
-        )]
-        let resampled = {
-            let start_resample = std::time::Instant::now();
-            let ratio = f64::from(self.device_sample_rate) / f64::from(target_sample_rate);
-
-            // Calculate output length - ratio is always positive for valid sample rates
-            let output_len_f64 = (mono_samples.len() as f64) / ratio;
-            let output_len = if output_len_f64.is_finite() && output_len_f64 >= 0.0 {
-                output_len_f64.ceil() as usize
-            } else {
-                mono_samples.len()
-            };
-
-            let mut resampled = Vec::with_capacity(output_len);
-            for i in 0..output_len {
-                // Calculate source index with linear interpolation
-                let src_idx_f64 = (i as f64) * ratio;
-
-                // Floor gives integer part, safe because src_idx >= 0
-                let src_idx_floor = if src_idx_f64 >= 0.0 && src_idx_f64 < (usize::MAX as f64) {
-                    src_idx_f64.floor() as usize
-                } else {
-                    0
-                };
-
-                let src_idx_ceil = (src_idx_floor + 1).min(mono_samples.len().saturating_sub(1));
-                let fract = src_idx_f64 - src_idx_f64.floor();
-
-                let sample = if src_idx_floor < mono_samples.len() {
-                    let s1 = f64::from(mono_samples[src_idx_floor]);
-                    let s2 = f64::from(mono_samples[src_idx_ceil]);
-                    // Use mul_add for better precision
-                    let interpolated = s1.mul_add(1.0 - fract, s2 * fract);
-                    interpolated as f32
-                } else {
-                    0.0_f32
-                };
-
-                resampled.push(sample);
-            }
-
-            let resample_duration = start_resample.elapsed();
-            info!(
-                device_rate = self.device_sample_rate,
-                target_rate = target_sample_rate,
-                input_samples = mono_samples.len(),
-                output_samples = resampled.len(),
-                resample_us = resample_duration.as_micros(),
-                "resampling completed"
-            );
-
-            resampled
-        };
+        let start_resample = std::time::Instant::now();
+        let output = resample(&mono_samples, sample_rate, target_sample_rate, resampler);
+        let resample_duration = start_resample.elapsed();
+        info!(
+            source_rate = sample_rate,
+            target_rate = target_sample_rate,
+            ?resampler,
+            input_samples = mono_samples.len(),
+            output_samples = output.len(),
+            resample_us = resample_duration.as_micros(),
+            "resampling completed"
+        );
 
         let total_duration = start_total.elapsed();
         debug!(
@@ -361,14 +536,14 @@ impl AudioCapture {
             "audio conversion complete"
         );
 
-        resampled
+        output
     }
 
     /// Save samples to WAV file for debugging
     ///
     /// # Errors
     /// Returns error if directory creation or file write fails
-    pub fn save_wav_debug(samples: &[f32], path: &Path) -> Result<()> {
+    pub fn save_wav_debug(samples: &[f32], path: &Path, format: WavFormat) -> Result<()> {
         debug!("saving WAV debug file: {:?}", path);
 
         // Create parent directory if needed
@@ -376,19 +551,15 @@ impl AudioCapture {
             std::fs::create_dir_all(parent).context("failed to create debug directory")?;
         }
 
-        let spec = WavSpec {
-            channels: 1,
-            sample_rate: 16000,
-            bits_per_sample: 32,
-            sample_format: hound::SampleFormat::Float,
-        };
-
+        let spec = wav_spec(format);
         let mut writer = WavWriter::create(path, spec).context("failed to create WAV file")?;
 
         for &sample in samples {
-            writer
-                .write_sample(sample)
-                .context("failed to write sample")?;
+            match format {
+                WavFormat::F32 => writer.write_sample(sample),
+                WavFormat::Pcm16 => writer.write_sample(to_pcm16(sample)),
+            }
+            .context("failed to write sample")?;
         }
 
         writer.finalize().context("failed to finalize WAV file")?;
@@ -402,6 +573,321 @@ impl AudioCapture {
     }
 }
 
+/// WAV header for 16kHz mono recordings in `format`
+const fn wav_spec(format: WavFormat) -> WavSpec {
+    let (bits_per_sample, sample_format) = match format {
+        WavFormat::F32 => (32, hound::SampleFormat::Float),
+        WavFormat::Pcm16 => (16, hound::SampleFormat::Int),
+    };
+    WavSpec {
+        channels: 1,
+        sample_rate: 16000,
+        bits_per_sample,
+        sample_format,
+    }
+}
+
+/// 16-bit PCM value of `sample`, clamped to full scale first (NaN becomes 0)
+fn to_pcm16(sample: f32) -> i16 {
+    // Clamped to [-1, 1], so the scaled value fits in i16
+    #[allow(clippy::cast_possible_truncation)]
+    let value = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)).round() as i16;
+    value
+}
+
+/// `samples` scaled so their peak reaches `target_peak` (pure)
+///
+/// The target is capped at full scale, so normalizing never clips. Silent
+/// buffers (peak 0) are returned unchanged.
+#[must_use]
+pub fn normalize_samples(samples: &[f32], target_peak: f32) -> Vec<f32> {
+    let peak = peak_level(samples);
+    if peak <= 0.0 {
+        return samples.to_vec();
+    }
+    let gain = f64::from(target_peak.clamp(0.0, 1.0)) / peak;
+    debug!(peak, gain, "normalizing recording");
+    // Scaled samples stay within [-1, 1], representable in f32
+    #[allow(clippy::cast_possible_truncation)]
+    samples
+        .iter()
+        .map(|&s| (f64::from(s) * gain) as f32)
+        .collect()
+}
+
+/// Ring buffer size above which startup warns (256 MB of f32 samples)
+const LARGE_RING_BUFFER_SAMPLES: usize = 64 * 1024 * 1024;
+
+/// Samples the ring buffer needs to hold `max_recording_secs` of `format` (at least one second)
+fn ring_buffer_capacity(format: StreamFormat, max_recording_secs: usize) -> usize {
+    (format.sample_rate as usize)
+        .saturating_mul(usize::from(format.channels.max(1)))
+        .saturating_mul(max_recording_secs.max(1))
+}
+
+/// Samples holding `preroll_ms` of `format` (whole frames, 0 = disabled)
+fn preroll_capacity(format: StreamFormat, preroll_ms: u64) -> usize {
+    let frames = u64::from(format.sample_rate).saturating_mul(preroll_ms) / 1000;
+    usize::try_from(frames)
+        .unwrap_or(usize::MAX)
+        .saturating_mul(usize::from(format.channels.max(1)))
+}
+
+/// Stand-in consumer while the drain thread owns the real one
+fn placeholder_consumer() -> HeapCons<f32> {
+    HeapRb::<f32>::new(1).split().1
+}
+
+/// Open the configured input device (or the default one) with a paused stream
+///
+/// # Errors
+/// Returns error if no input device is available or stream creation fails
+fn open_input(
+    config: &AudioConfig,
+    is_recording: &Arc<AtomicBool>,
+    level: &InputLevel,
+) -> Result<DeviceInput> {
+    // Configured device by name, falling back to the default input device
+    let host = cpal::default_host();
+    let device = select_input_device(&host, config.device_name.as_deref())?;
+
+    let device_desc = device
+        .description()
+        .map_or_else(|_| "unknown".to_owned(), |d| d.to_string());
+    info!("using input device: {}", device_desc);
+
+    // Get device config (use device default, will resample to 16kHz later)
+    let supported_config = device
+        .default_input_config()
+        .context("failed to get default input config")?;
+
+    let device_sample_rate = supported_config.sample_rate();
+    let device_channels = supported_config.channels();
+
+    info!(
+        "device config: {} Hz, {} channels",
+        device_sample_rate, device_channels
+    );
+
+    // Create ring buffer sized for max recording duration at device sample rate
+    // This ensures no samples are dropped during recording
+    let format = StreamFormat {
+        sample_rate: device_sample_rate,
+        channels: device_channels,
+    };
+    let preroll_capacity = preroll_capacity(format, config.preroll_ms);
+    // Pre-roll is moved into the ring buffer ahead of the recording
+    let ring_buffer_capacity =
+        ring_buffer_capacity(format, config.max_recording_secs).saturating_add(preroll_capacity);
+    info!(
+        "ring buffer capacity: {} samples ({} seconds at {} Hz)",
+        ring_buffer_capacity, config.max_recording_secs, device_sample_rate
+    );
+    if ring_buffer_capacity > LARGE_RING_BUFFER_SAMPLES {
+        warn!(
+            max_recording_secs = config.max_recording_secs,
+            megabytes = ring_buffer_capacity * std::mem::size_of::<f32>() / (1024 * 1024),
+            "⚠️  max_recording_secs allocates a very large ring buffer"
+        );
+    }
+
+    let buffer_size = choose_buffer_size(config.buffer_size, supported_config.buffer_size());
+    let fixed_buffer = matches!(buffer_size, BufferSize::Fixed(_));
+    let mut stream_config: cpal::StreamConfig = supported_config.into();
+    stream_config.buffer_size = buffer_size;
+
+    let built = build_stream(
+        &device,
+        &stream_config,
+        is_recording,
+        level,
+        ring_buffer_capacity,
+        preroll_capacity,
+    );
+    let InputStream {
+        stream,
+        consumer: ring_buffer_consumer,
+        failed,
+    } = match built {
+        Err(e) if fixed_buffer => {
+            // Supported range is only a hint; the device may still refuse the size
+            warn!(
+                error = %e,
+                requested = config.buffer_size,
+                "device rejected buffer size, falling back to device default"
+            );
+            stream_config.buffer_size = BufferSize::Default;
+            build_stream(
+                &device,
+                &stream_config,
+                is_recording,
+                level,
+                ring_buffer_capacity,
+                preroll_capacity,
+            )?
+        }
+        result => result?,
+    };
+    match stream_config.buffer_size {
+        BufferSize::Fixed(frames) => info!("audio buffer size: {} frames", frames),
+        BufferSize::Default => info!("audio buffer size: device default"),
+    }
+
+    // Wrap stream in controller
+    let stream_control = CpalStreamControl { stream, failed };
+
+    // Start the stream and immediately pause it (mic inactive until hotkey pressed),
+    // unless it has to keep filling the pre-roll
+    stream_control.play()?;
+    if preroll_capacity == 0 {
+        stream_control.pause()?;
+        info!("audio stream initialized (paused)");
+    } else {
+        info!(
+            preroll_ms = config.preroll_ms,
+            "audio stream initialized (running for pre-roll)"
+        );
+    }
+
+    Ok(DeviceInput {
+        control: Box::new(stream_control),
+        consumer: ring_buffer_consumer,
+        format: StreamFormat {
+            sample_rate: device_sample_rate,
+            channels: device_channels,
+        },
+    })
+}
+
+/// Choose the stream buffer size for `requested` frames (0 = device default)
+///
+/// Sizes outside the device's supported range fall back to the device default.
+fn choose_buffer_size(requested: usize, supported: &SupportedBufferSize) -> BufferSize {
+    let Ok(frames) = u32::try_from(requested) else {
+        warn!(requested, "buffer size too large, using device default");
+        return BufferSize::Default;
+    };
+    if frames == 0 {
+        return BufferSize::Default;
+    }
+    match *supported {
+        SupportedBufferSize::Range { min, max } if !(min..=max).contains(&frames) => {
+            warn!(
+                requested,
+                min, max, "buffer size not supported by device, using device default"
+            );
+            BufferSize::Default
+        }
+        // Unknown range: try it, `build_stream` errors are handled by the caller
+        SupportedBufferSize::Range { .. } | SupportedBufferSize::Unknown => {
+            BufferSize::Fixed(frames)
+        }
+    }
+}
+
+/// Input stream plus the consumer end of the ring buffer it fills
+struct InputStream {
+    stream: cpal::Stream,
+    consumer: HeapCons<f32>,
+    /// Set when the stream reports a fatal error
+    failed: Arc<AtomicBool>,
+}
+
+/// Whether `error` means the stream stopped for good and must be rebuilt
+const fn is_fatal_stream_error(error: &cpal::StreamError) -> bool {
+    matches!(
+        error,
+        cpal::StreamError::DeviceNotAvailable | cpal::StreamError::StreamInvalidated
+    )
+}
+
+/// Audio callback state: fills the ring buffer while recording, the pre-roll otherwise
+struct CaptureCallback {
+    producer: HeapProd<f32>,
+    is_recording: Arc<AtomicBool>,
+    /// Smoothed level of the audio being recorded
+    level: InputLevel,
+    /// Most recent audio from before the recording started (None = disabled)
+    preroll: Option<HeapRb<f32>>,
+}
+
+impl CaptureCallback {
+    fn new(
+        producer: HeapProd<f32>,
+        is_recording: &Arc<AtomicBool>,
+        level: &InputLevel,
+        preroll_capacity: usize,
+    ) -> Self {
+        Self {
+            producer,
+            is_recording: Arc::clone(is_recording),
+            level: level.clone(),
+            preroll: (preroll_capacity > 0).then(|| HeapRb::new(preroll_capacity)),
+        }
+    }
+
+    /// Handle one buffer of interleaved samples from the device
+    fn on_data(&mut self, data: &[f32]) {
+        if self.is_recording.load(Ordering::Relaxed) {
+            // First buffer of a recording: the retained pre-roll goes in front
+            if let Some(preroll) = self.preroll.as_mut().filter(|preroll| !preroll.is_empty()) {
+                let (head, tail) = preroll.as_slices();
+                self.producer.push_slice(head);
+                self.producer.push_slice(tail);
+                preroll.clear();
+            }
+            // Lock-free push to ring buffer
+            let pushed = self.producer.push_slice(data);
+            self.level.update(data);
+            if pushed < data.len() {
+                warn!("ring buffer full, dropped {} samples", data.len() - pushed);
+            }
+        } else if let Some(preroll) = &mut self.preroll {
+            // Whole frames in, whole frames evicted: channels stay aligned
+            preroll.push_slice_overwrite(data);
+        }
+    }
+}
+
+/// Build an input stream feeding a fresh ring buffer of `capacity` samples
+///
+/// With a non-zero `preroll_capacity`, the latest samples from before each
+/// recording are kept and prepended to it.
+fn build_stream(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    is_recording: &Arc<AtomicBool>,
+    level: &InputLevel,
+    capacity: usize,
+    preroll_capacity: usize,
+) -> Result<InputStream> {
+    let (producer, consumer) = HeapRb::<f32>::new(capacity).split();
+    let mut callback = CaptureCallback::new(producer, is_recording, level, preroll_capacity);
+    let failed = Arc::new(AtomicBool::new(false));
+    let error_flag = Arc::clone(&failed);
+
+    let stream = device
+        .build_input_stream(
+            config,
+            move |data: &[f32], _: &cpal::InputCallbackInfo| callback.on_data(data),
+            move |err| {
+                if is_fatal_stream_error(&err) {
+                    warn!("🎙️ Input device lost: {}", err);
+                    error_flag.store(true, Ordering::Relaxed);
+                } else {
+                    warn!("audio stream error: {}", err);
+                }
+            },
+            None,
+        )
+        .context("failed to build input stream")?;
+    Ok(InputStream {
+        stream,
+        consumer,
+        failed,
+    })
+}
+
 /// Implement trait for real `AudioCapture`
 impl AudioCaptureInterface for AudioCapture {
     fn start_recording(&mut self) -> Result<()> {
@@ -422,6 +908,7 @@ mod tests {
     struct MockStreamControl {
         play_count: Arc<AtomicBool>,
         pause_count: Arc<AtomicBool>,
+        failed: Arc<AtomicBool>,
     }
 
     impl StreamControl for MockStreamControl {
@@ -434,6 +921,10 @@ mod tests {
             self.pause_count.store(true, Ordering::Relaxed);
             Ok(())
         }
+
+        fn is_healthy(&self) -> bool {
+            !self.failed.load(Ordering::Relaxed)
+        }
     }
 
     // Mock AudioCapture for testing conversion logic
@@ -442,9 +933,297 @@ mod tests {
             stream_control: None,
             ring_buffer_consumer: HeapRb::<f32>::new(1024).split().1,
             is_recording: Arc::new(AtomicBool::new(false)),
-            device_sample_rate: sample_rate,
-            device_channels: channels,
+            format: StreamFormat {
+                sample_rate,
+                channels,
+            },
+            last_timings: CaptureTimings::default(),
+            continuous_drain: false,
+            drain_thread: None,
+            resampler_auto: false,
+            resampler_threshold: Duration::ZERO,
+            silence_trim: None,
+            normalize_peak: None,
+            keep_stream_running: false,
+            config: AudioConfig::default(),
+            level: InputLevel::default(),
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_capacity_from_device_format() {
+        use ringbuf::traits::Observer;
+
+        let format = |sample_rate, channels| StreamFormat {
+            sample_rate,
+            channels,
+        };
+        assert_eq!(ring_buffer_capacity(format(48000, 2), 30), 2_880_000);
+        assert_eq!(ring_buffer_capacity(format(44100, 1), 120), 5_292_000);
+        assert_eq!(ring_buffer_capacity(format(16000, 1), 0), 16000);
+
+        let capacity = ring_buffer_capacity(format(48000, 1), 60);
+        let (_, consumer) = HeapRb::<f32>::new(capacity).split();
+        assert_eq!(consumer.capacity().get(), 48000 * 60);
+    }
+
+    #[test]
+    fn test_preroll_capacity_whole_frames() {
+        let format = |sample_rate, channels| StreamFormat {
+            sample_rate,
+            channels,
+        };
+        assert_eq!(preroll_capacity(format(48000, 2), 300), 28800);
+        assert_eq!(preroll_capacity(format(44100, 1), 300), 13230);
+        assert_eq!(preroll_capacity(format(16000, 1), 0), 0);
+        assert_eq!(preroll_capacity(format(44100, 2), 1) % 2, 0);
+    }
+
+    /// Capture plus the device callback feeding its ring buffer
+    fn capture_with_callback(preroll_capacity: usize) -> (AudioCapture, CaptureCallback) {
+        let (producer, consumer) = HeapRb::<f32>::new(16000).split();
+        let mut capture = mock_audio_capture(16000, 1);
+        capture.ring_buffer_consumer = consumer;
+        let callback = CaptureCallback::new(
+            producer,
+            &capture.is_recording,
+            &capture.level,
+            preroll_capacity,
+        );
+        (capture, callback)
+    }
+
+    #[test]
+    fn test_preroll_prepended_to_recording() {
+        let (mut capture, mut callback) = capture_with_callback(4);
+        // Before the press: only the latest 4 samples are kept
+        callback.on_data(&[0.1, 0.2, 0.3]);
+        callback.on_data(&[0.4, 0.5, 0.6]);
+
+        capture.start_recording().unwrap();
+        callback.on_data(&[0.7, 0.8]);
+        callback.on_data(&[0.9]);
+        let samples = capture.stop_recording().unwrap();
+
+        assert_eq!(samples, vec![0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9]);
+    }
+
+    #[test]
+    fn test_preroll_refills_between_recordings() {
+        let (mut capture, mut callback) = capture_with_callback(2);
+        callback.on_data(&[0.1, 0.2]);
+        capture.start_recording().unwrap();
+        callback.on_data(&[0.3]);
+        assert_eq!(capture.stop_recording().unwrap(), vec![0.1, 0.2, 0.3]);
+
+        // Audio from the first recording isn't reused as pre-roll
+        callback.on_data(&[0.5]);
+        capture.start_recording().unwrap();
+        callback.on_data(&[0.6]);
+        assert_eq!(capture.stop_recording().unwrap(), vec![0.5, 0.6]);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    #[test]
+    fn test_callback_reports_recording_level() {
+        let (mut capture, mut callback) = capture_with_callback(0);
+        let sine: Vec<f32> = (0..320)
+            .map(|i| (2.0 * std::f32::consts::PI * 500.0 * i as f32 / 16000.0).sin() * 0.5)
+            .collect();
+
+        // Idle audio doesn't move the meter
+        callback.on_data(&sine);
+        assert!(capture.current_level().abs() < f32::EPSILON);
+
+        capture.start_recording().unwrap();
+        for _ in 0..20 {
+            callback.on_data(&sine);
         }
+        let level = capture.current_level();
+        assert!((0.32..=0.39).contains(&level), "level {level}");
+        assert!((capture.level_meter().get() - level).abs() < f32::EPSILON);
+
+        // Recording over: meter drops to silence
+        let _ = capture.stop_recording().unwrap();
+        assert!(capture.current_level().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_without_preroll_idle_audio_dropped() {
+        let (mut capture, mut callback) = capture_with_callback(0);
+        callback.on_data(&[0.1, 0.2]);
+        capture.start_recording().unwrap();
+        callback.on_data(&[0.3]);
+        assert_eq!(capture.stop_recording().unwrap(), vec![0.3]);
+    }
+
+    #[test]
+    fn test_preroll_keeps_stream_running() {
+        let play_called = Arc::new(AtomicBool::new(false));
+        let pause_called = Arc::new(AtomicBool::new(false));
+        let mut capture = mock_audio_capture(16000, 1);
+        capture.stream_control = Some(Box::new(MockStreamControl {
+            play_count: Arc::clone(&play_called),
+            pause_count: Arc::clone(&pause_called),
+            failed: Arc::new(AtomicBool::new(false)),
+        }));
+        capture.keep_stream_running = true;
+
+        capture.start_recording().unwrap();
+        let _ = capture.stop_recording().unwrap();
+        assert!(!play_called.load(Ordering::Relaxed));
+        assert!(!pause_called.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_ring_buffer_capacity_large_values() {
+        let format = StreamFormat {
+            sample_rate: 48000,
+            channels: 2,
+        };
+        // 30 minutes of 48kHz stereo crosses the warning size
+        assert!(ring_buffer_capacity(format, 1800) > LARGE_RING_BUFFER_SAMPLES);
+        assert!(ring_buffer_capacity(format, 600) < LARGE_RING_BUFFER_SAMPLES);
+        assert_eq!(ring_buffer_capacity(format, usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_resampler_auto_picks_by_recording_length() {
+        let mut capture = mock_audio_capture(48000, 1);
+        capture.resampler_auto = true;
+        capture.resampler_threshold = Duration::from_millis(500);
+
+        // 12 kHz tone: linear aliases it into the output, sinc filters it out
+        let tone = |frames: u16| -> Vec<f32> {
+            (0..frames)
+                .map(|i| if i % 4 < 2 { 0.5 } else { -0.5 })
+                .collect()
+        };
+        let peak = |samples: &[f32]| samples.iter().fold(0.0_f32, |m, s| m.max(s.abs()));
+
+        // 0.25 s: below the threshold
+        let aliased = capture.convert_to_16khz_mono(&tone(12000));
+        assert!(peak(&aliased) > 0.2);
+        // 1 s: at least the threshold (edges aside, the tone is gone)
+        let filtered = capture.convert_to_16khz_mono(&tone(48000));
+        assert!(peak(&filtered[100..filtered.len() - 100]) < 0.05);
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn tone_at(freq: f64, rate: u32, secs: f64) -> Vec<f32> {
+        let len = (f64::from(rate) * secs) as usize;
+        (0..len)
+            .map(|i| {
+                (2.0 * std::f64::consts::PI * freq * i as f64 / f64::from(rate)).sin() as f32 * 0.5
+            })
+            .collect()
+    }
+
+    /// Deterministic white noise in [-0.5, 0.5]
+    #[allow(clippy::cast_precision_loss)]
+    fn white_noise(len: usize) -> Vec<f32> {
+        let mut state: u32 = 0x1234_5678;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn mean_energy(samples: &[f32]) -> f64 {
+        samples.iter().map(|&s| f64::from(s).powi(2)).sum::<f64>() / samples.len() as f64
+    }
+
+    #[test]
+    fn test_convert_preserves_speech_band_energy() {
+        let capture = mock_audio_capture(48000, 1);
+        for freq in [300.0, 1000.0, 3000.0] {
+            let input = tone_at(freq, 48000, 0.5);
+            let sinc = capture.convert_to_16khz_mono(&input);
+            let linear = AudioCapture::to_16khz_mono_with(&input, 48000, 1, Resampler::Linear);
+            let sinc_ratio = mean_energy(&sinc) / mean_energy(&input);
+            let linear_ratio = mean_energy(&linear) / mean_energy(&input);
+            // Both keep speech-band tones; sinc at least as faithfully as linear
+            assert!((sinc_ratio - 1.0).abs() < 0.02, "{freq} Hz: {sinc_ratio}");
+            assert!((sinc_ratio - 1.0).abs() <= (linear_ratio - 1.0).abs() + 0.01);
+        }
+    }
+
+    #[test]
+    fn test_convert_rolls_off_above_target_nyquist() {
+        for rate in [44100, 48000] {
+            let capture = mock_audio_capture(rate, 1);
+            // 11 kHz can't be represented at 16 kHz: linear folds it to 5 kHz
+            let input = tone_at(11000.0, rate, 0.5);
+            let sinc = capture.convert_to_16khz_mono(&input);
+            let linear = AudioCapture::to_16khz_mono_with(&input, rate, 1, Resampler::Linear);
+            let input_energy = mean_energy(&input);
+            assert!(mean_energy(&sinc) / input_energy < 0.01, "{rate} Hz");
+            assert!(mean_energy(&linear) / input_energy > 0.1, "{rate} Hz");
+        }
+    }
+
+    #[test]
+    fn test_convert_noise_keeps_only_target_band() {
+        let capture = mock_audio_capture(48000, 1);
+        let input = white_noise(48000);
+        let input_energy = mean_energy(&input);
+        let sinc = mean_energy(&capture.convert_to_16khz_mono(&input)) / input_energy;
+        let linear = mean_energy(&AudioCapture::to_16khz_mono_with(
+            &input,
+            48000,
+            1,
+            Resampler::Linear,
+        )) / input_energy;
+        // 0-8 kHz is a third of the 0-24 kHz band; linear keeps aliased noise on top
+        assert!((sinc - 1.0 / 3.0).abs() < 0.05, "sinc energy ratio {sinc}");
+        assert!(linear > sinc + 0.1, "linear {linear} vs sinc {sinc}");
+    }
+
+    #[test]
+    fn test_choose_buffer_size_within_range() {
+        let supported = SupportedBufferSize::Range { min: 64, max: 4096 };
+        assert_eq!(choose_buffer_size(512, &supported), BufferSize::Fixed(512));
+        assert_eq!(choose_buffer_size(64, &supported), BufferSize::Fixed(64));
+        assert_eq!(
+            choose_buffer_size(4096, &supported),
+            BufferSize::Fixed(4096)
+        );
+    }
+
+    #[test]
+    fn test_choose_buffer_size_unsupported_falls_back() {
+        let supported = SupportedBufferSize::Range { min: 64, max: 4096 };
+        assert_eq!(choose_buffer_size(32, &supported), BufferSize::Default);
+        assert_eq!(choose_buffer_size(8192, &supported), BufferSize::Default);
+        assert_eq!(
+            choose_buffer_size(usize::MAX, &SupportedBufferSize::Unknown),
+            BufferSize::Default
+        );
+    }
+
+    #[test]
+    fn test_choose_buffer_size_zero_uses_device_default() {
+        let supported = SupportedBufferSize::Range { min: 64, max: 4096 };
+        assert_eq!(choose_buffer_size(0, &supported), BufferSize::Default);
+        assert_eq!(
+            choose_buffer_size(0, &SupportedBufferSize::Unknown),
+            BufferSize::Default
+        );
+    }
+
+    #[test]
+    fn test_choose_buffer_size_unknown_range_tries_fixed() {
+        assert_eq!(
+            choose_buffer_size(1024, &SupportedBufferSize::Unknown),
+            BufferSize::Fixed(1024)
+        );
     }
 
     #[test]
@@ -585,6 +1364,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normalize_boosts_quiet_buffer_to_target() {
+        let quiet: Vec<f32> = tone_at(440.0, 16000, 0.5)
+            .iter()
+            .map(|s| s * 0.05)
+            .collect();
+        let normalized = normalize_samples(&quiet, 0.9);
+        assert_eq!(normalized.len(), quiet.len());
+        let peak = peak_level(&normalized);
+        assert!((peak - 0.9).abs() < 1e-4, "peak {peak}");
+        // Uniform gain: the waveform shape is unchanged
+        let ratio = normalized[100] / quiet[100];
+        assert!((ratio - normalized[200] / quiet[200]).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_normalize_never_clips() {
+        let normalized = normalize_samples(&[0.1, -0.2, 0.05], 1.5);
+        assert!((peak_level(&normalized) - 1.0).abs() < 1e-6);
+        assert!(normalized.iter().all(|s| (-1.0..=1.0).contains(s)));
+    }
+
+    #[test]
+    fn test_normalize_silent_buffer_untouched() {
+        let silence = vec![0.0; 1600];
+        let normalized = normalize_samples(&silence, 0.9);
+        assert_eq!(normalized, silence);
+        assert!(normalized.iter().all(|s| s.is_finite()));
+        assert!(normalize_samples(&[], 0.9).is_empty());
+    }
+
+    #[test]
+    fn test_stop_recording_normalizes_when_enabled() {
+        let mut capture = mock_audio_capture(16000, 1);
+        capture.normalize_peak = Some(0.9);
+        let (mut producer, consumer) = HeapRb::<f32>::new(1024).split();
+        producer.push_slice(&[0.01, -0.02, 0.015]);
+        capture.ring_buffer_consumer = consumer;
+
+        let samples = capture.stop_recording().unwrap();
+        assert!((samples[1] + 0.9).abs() < 1e-6, "{samples:?}");
+        assert!((samples[0] - 0.45).abs() < 1e-6);
+    }
+
     #[test]
     fn test_wav_debug_spec() {
         use std::env;
@@ -597,7 +1420,7 @@ mod tests {
         // Clean up if exists
         let _ = fs::remove_file(&wav_path);
 
-        let result = AudioCapture::save_wav_debug(&samples, &wav_path);
+        let result = AudioCapture::save_wav_debug(&samples, &wav_path, WavFormat::F32);
         assert!(result.is_ok());
 
         // Verify file exists
@@ -620,6 +1443,51 @@ mod tests {
         let _ = fs::remove_file(wav_path);
     }
 
+    #[test]
+    fn test_wav_debug_pcm16_round_trip() {
+        let samples = vec![0.0, 0.5, -0.5, 1.0, -1.0, 1.7, -3.0, 0.25];
+        let wav_path = std::env::temp_dir().join("test_audio_pcm16.wav");
+        let _ = std::fs::remove_file(&wav_path);
+
+        AudioCapture::save_wav_debug(&samples, &wav_path, WavFormat::Pcm16).unwrap();
+
+        let mut reader = hound::WavReader::open(&wav_path).unwrap();
+        assert_eq!(
+            reader.spec(),
+            WavSpec {
+                channels: 1,
+                sample_rate: 16000,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            }
+        );
+        let read: Vec<f32> = reader
+            .samples::<i16>()
+            .map(|s| f32::from(s.unwrap()) / f32::from(i16::MAX))
+            .collect();
+        // Out-of-range samples are clamped to full scale
+        let expected = [0.0, 0.5, -0.5, 1.0, -1.0, 1.0, -1.0, 0.25];
+        assert_eq!(read.len(), expected.len());
+        for (got, want) in read.iter().zip(expected) {
+            assert!((got - want).abs() < 1e-4, "{got} != {want}");
+        }
+        // Half the data of the float format (44-byte header)
+        let size = std::fs::metadata(&wav_path).unwrap().len();
+        assert_eq!(size, 44 + 2 * samples.len() as u64);
+
+        let _ = std::fs::remove_file(wav_path);
+    }
+
+    #[test]
+    fn test_to_pcm16_clamps() {
+        assert_eq!(to_pcm16(1.0), i16::MAX);
+        assert_eq!(to_pcm16(2.5), i16::MAX);
+        assert_eq!(to_pcm16(-1.0), -i16::MAX);
+        assert_eq!(to_pcm16(-8.0), -i16::MAX);
+        assert_eq!(to_pcm16(0.0), 0);
+        assert_eq!(to_pcm16(f32::NAN), 0);
+    }
+
     #[test]
     fn test_save_wav_debug_empty_samples() {
         use std::env;
@@ -631,7 +1499,7 @@ mod tests {
 
         let _ = fs::remove_file(&wav_path);
 
-        let result = AudioCapture::save_wav_debug(&samples, &wav_path);
+        let result = AudioCapture::save_wav_debug(&samples, &wav_path, WavFormat::F32);
         assert!(result.is_ok());
         assert!(wav_path.exists());
 
@@ -650,7 +1518,7 @@ mod tests {
         // Ensure parent doesn't exist
         let _ = fs::remove_dir_all(temp_dir.join("test_audio_nested"));
 
-        let result = AudioCapture::save_wav_debug(&samples, &nested_path);
+        let result = AudioCapture::save_wav_debug(&samples, &nested_path, WavFormat::F32);
         assert!(result.is_ok());
         assert!(nested_path.exists());
 
@@ -696,6 +1564,19 @@ mod tests {
         let config = AudioConfig {
             buffer_size: 1024,
             sample_rate: 16000,
+            clipping_threshold: 0.01,
+            continuous_drain: false,
+            resampler_auto: false,
+            resampler_threshold_ms: 5000,
+            max_recording_secs: 30,
+            device_name: None,
+            preroll_ms: 0,
+            trim_silence: false,
+            silence_threshold: 0.01,
+            trim_attack_ms: 100,
+            trim_release_ms: 200,
+            normalize: false,
+            target_peak: 0.9,
         };
 
         let result = AudioCapture::new(&config);
@@ -705,8 +1586,8 @@ mod tests {
         );
 
         let capture = result.unwrap();
-        assert!(capture.device_sample_rate > 0);
-        assert!(capture.device_channels > 0);
+        assert!(capture.format.sample_rate > 0);
+        assert!(capture.format.channels > 0);
     }
 
     #[test]
@@ -715,6 +1596,19 @@ mod tests {
         let config = AudioConfig {
             buffer_size: 1024,
             sample_rate: 16000,
+            clipping_threshold: 0.01,
+            continuous_drain: false,
+            resampler_auto: false,
+            resampler_threshold_ms: 5000,
+            max_recording_secs: 30,
+            device_name: None,
+            preroll_ms: 0,
+            trim_silence: false,
+            silence_threshold: 0.01,
+            trim_attack_ms: 100,
+            trim_release_ms: 200,
+            normalize: false,
+            target_peak: 0.9,
         };
 
         let mut capture = AudioCapture::new(&config).unwrap();
@@ -743,6 +1637,19 @@ mod tests {
         let config = AudioConfig {
             buffer_size: 1024,
             sample_rate: 16000,
+            clipping_threshold: 0.01,
+            continuous_drain: false,
+            resampler_auto: false,
+            resampler_threshold_ms: 5000,
+            max_recording_secs: 30,
+            device_name: None,
+            preroll_ms: 0,
+            trim_silence: false,
+            silence_threshold: 0.01,
+            trim_attack_ms: 100,
+            trim_release_ms: 200,
+            normalize: false,
+            target_peak: 0.9,
         };
 
         let mut capture = AudioCapture::new(&config).unwrap();
@@ -761,6 +1668,19 @@ mod tests {
         let config = AudioConfig {
             buffer_size: 1024,
             sample_rate: 16000,
+            clipping_threshold: 0.01,
+            continuous_drain: false,
+            resampler_auto: false,
+            resampler_threshold_ms: 5000,
+            max_recording_secs: 30,
+            device_name: None,
+            preroll_ms: 0,
+            trim_silence: false,
+            silence_threshold: 0.01,
+            trim_attack_ms: 100,
+            trim_release_ms: 200,
+            normalize: false,
+            target_peak: 0.9,
         };
 
         let mut capture = AudioCapture::new(&config).unwrap();
@@ -789,6 +1709,7 @@ mod tests {
         let mock_stream = MockStreamControl {
             play_count: Arc::clone(&play_called),
             pause_count: Arc::clone(&pause_called),
+            failed: Arc::new(AtomicBool::new(false)),
         };
 
         let ring_buffer = HeapRb::<f32>::new(1024);
@@ -798,8 +1719,20 @@ mod tests {
             stream_control: Some(Box::new(mock_stream)),
             ring_buffer_consumer: consumer,
             is_recording: Arc::new(AtomicBool::new(false)),
-            device_sample_rate: 16000,
-            device_channels: 1,
+            format: StreamFormat {
+                sample_rate: 16000,
+                channels: 1,
+            },
+            last_timings: CaptureTimings::default(),
+            continuous_drain: false,
+            drain_thread: None,
+            resampler_auto: false,
+            resampler_threshold: Duration::ZERO,
+            silence_trim: None,
+            normalize_peak: None,
+            keep_stream_running: false,
+            config: AudioConfig::default(),
+            level: InputLevel::default(),
         };
 
         // Start recording should call play()
@@ -813,12 +1746,237 @@ mod tests {
         assert!(!capture.is_recording.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn test_stream_health_follows_error_flag() {
+        let failed = Arc::new(AtomicBool::new(false));
+        let mut capture = mock_audio_capture(16000, 1);
+        // No stream (never opened): nothing to recover
+        assert!(capture.is_stream_healthy());
+
+        capture.stream_control = Some(Box::new(MockStreamControl {
+            play_count: Arc::new(AtomicBool::new(false)),
+            pause_count: Arc::new(AtomicBool::new(false)),
+            failed: Arc::clone(&failed),
+        }));
+        assert!(capture.is_stream_healthy());
+
+        // Error callback reports the device gone
+        failed.store(true, Ordering::Relaxed);
+        assert!(!capture.is_stream_healthy());
+
+        // A replacement stream is healthy again
+        let (input, _) = mock_device_input(48000, 1);
+        capture.switch_input(input).unwrap();
+        assert!(capture.is_stream_healthy());
+    }
+
+    #[test]
+    fn test_rebuild_refused_while_recording() {
+        let mut capture = mock_audio_capture(16000, 1);
+        capture.start_recording().unwrap();
+        assert!(capture.is_recording());
+        assert!(capture.rebuild_stream().is_err());
+        capture.stop_recording().unwrap();
+        assert!(!capture.is_recording());
+    }
+
+    #[test]
+    fn test_fatal_stream_errors() {
+        assert!(is_fatal_stream_error(
+            &cpal::StreamError::DeviceNotAvailable
+        ));
+        assert!(is_fatal_stream_error(&cpal::StreamError::StreamInvalidated));
+        assert!(!is_fatal_stream_error(&cpal::StreamError::BufferUnderrun));
+    }
+
+    // Input as a reacquired device would deliver it, plus the producer feeding it
+    fn mock_device_input(sample_rate: u32, channels: u16) -> (DeviceInput, ringbuf::HeapProd<f32>) {
+        let (producer, consumer) = HeapRb::<f32>::new(sample_rate as usize * 2).split();
+        let input = DeviceInput {
+            control: Box::new(MockStreamControl {
+                play_count: Arc::new(AtomicBool::new(false)),
+                pause_count: Arc::new(AtomicBool::new(false)),
+                failed: Arc::new(AtomicBool::new(false)),
+            }),
+            consumer,
+            format: StreamFormat {
+                sample_rate,
+                channels,
+            },
+        };
+        (input, producer)
+    }
+
+    #[test]
+    fn test_device_switch_updates_conversion_rate() {
+        // 44.1kHz mono mic replaced by a 48kHz stereo one
+        let mut capture = mock_audio_capture(44100, 1);
+        let (input, mut producer) = mock_device_input(48000, 2);
+        capture.switch_input(input).unwrap();
+        assert_eq!(
+            capture.format,
+            StreamFormat {
+                sample_rate: 48000,
+                channels: 2
+            }
+        );
+
+        capture.start_recording().unwrap();
+        // 1 second of 48kHz stereo from the new device
+        assert_eq!(producer.push_slice(&vec![0.25_f32; 96000]), 96000);
+        let samples = capture.stop_recording().unwrap();
+
+        // Converted at 48kHz/2ch; the stale 44.1kHz mono format would yield ~34830
+        assert_eq!(samples.len(), 16000);
+        assert!((samples[0] - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_device_switch_back_to_original_rate() {
+        let mut capture = mock_audio_capture(48000, 1);
+        let (input, _) = mock_device_input(44100, 1);
+        capture.switch_input(input).unwrap();
+        let (input, mut producer) = mock_device_input(48000, 1);
+        capture.switch_input(input).unwrap();
+
+        capture.start_recording().unwrap();
+        producer.push_slice(&vec![0.1_f32; 4800]);
+        assert_eq!(capture.stop_recording().unwrap().len(), 1600);
+    }
+
+    #[test]
+    fn test_device_switch_refused_while_recording() {
+        let mut capture = mock_audio_capture(44100, 1);
+        capture.start_recording().unwrap();
+
+        let (input, _) = mock_device_input(48000, 2);
+        assert!(capture.switch_input(input).is_err());
+        // Samples in flight keep the format they were captured with
+        assert_eq!(
+            capture.format,
+            StreamFormat {
+                sample_rate: 44100,
+                channels: 1
+            }
+        );
+        capture.stop_recording().unwrap();
+    }
+
+    // Push all of `samples` in small chunks, waiting whenever the ring buffer is full
+    fn push_with_backpressure(producer: &mut ringbuf::HeapProd<f32>, samples: &[f32]) {
+        for chunk in samples.chunks(32) {
+            let mut pushed = 0;
+            while pushed < chunk.len() {
+                pushed += producer.push_slice(&chunk[pushed..]);
+                if pushed < chunk.len() {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_drain_thread_assembles_pushed_samples() {
+        // Ten times the ring buffer's capacity: only fits because it is drained meanwhile
+        let (mut producer, consumer) = HeapRb::<f32>::new(256).split();
+        let pushed: Vec<f32> = (0..2560_u16).map(f32::from).collect();
+
+        let drain = DrainThread::spawn(consumer);
+        push_with_backpressure(&mut producer, &pushed);
+        let (_, samples) = drain.finish().unwrap();
+
+        assert_eq!(samples, pushed);
+    }
+
+    #[test]
+    fn test_drain_thread_collects_tail_on_finish() {
+        let (mut producer, consumer) = HeapRb::<f32>::new(256).split();
+        let drain = DrainThread::spawn(consumer);
+        // Pushed right before stop, likely after the thread's last pass
+        producer.push_slice(&[0.1, 0.2, 0.3]);
+        let (mut consumer, samples) = drain.finish().unwrap();
+
+        assert_eq!(samples, vec![0.1, 0.2, 0.3]);
+        assert!(consumer.try_pop().is_none());
+    }
+
+    #[test]
+    fn test_continuous_drain_recording_lifecycle() {
+        let mut capture = mock_audio_capture(16000, 1);
+        capture.continuous_drain = true;
+        let (input, mut producer) = mock_device_input(16000, 1);
+        capture.switch_input(input).unwrap();
+
+        for round in 0..2_u8 {
+            capture.start_recording().unwrap();
+            assert!(capture.drain_thread.is_some());
+
+            let pushed = vec![f32::from(round) / 10.0; 48000];
+            push_with_backpressure(&mut producer, &pushed);
+            let samples = capture.stop_recording().unwrap();
+
+            // Drain thread stopped and the consumer handed back for the next recording
+            assert!(capture.drain_thread.is_none());
+            assert_eq!(samples, pushed);
+        }
+    }
+
+    #[test]
+    fn test_continuous_drain_recovers_after_failed_stop() {
+        struct PauseFails;
+
+        impl StreamControl for PauseFails {
+            fn play(&self) -> Result<()> {
+                Ok(())
+            }
+
+            fn pause(&self) -> Result<()> {
+                anyhow::bail!("pause failed")
+            }
+
+            fn is_healthy(&self) -> bool {
+                true
+            }
+        }
+
+        let mut capture = mock_audio_capture(16000, 1);
+        capture.continuous_drain = true;
+        let (input, mut producer) = mock_device_input(16000, 1);
+        capture.switch_input(input).unwrap();
+        let working = capture.stream_control.replace(Box::new(PauseFails));
+
+        capture.start_recording().unwrap();
+        push_with_backpressure(&mut producer, &[0.5; 1000]);
+        assert!(capture.stop_recording().is_err());
+        // Consumer taken back from the drain thread despite the early return
+        assert!(capture.drain_thread.is_none());
+
+        capture.stream_control = working;
+        capture.start_recording().unwrap();
+        let pushed = vec![0.25; 48000];
+        push_with_backpressure(&mut producer, &pushed);
+        assert_eq!(capture.stop_recording().unwrap(), pushed);
+    }
+
     #[test]
     #[ignore = "requires audio hardware"]
     fn test_stream_pause_resume() {
         let config = AudioConfig {
             buffer_size: 1024,
             sample_rate: 16000,
+            clipping_threshold: 0.01,
+            continuous_drain: false,
+            resampler_auto: false,
+            resampler_threshold_ms: 5000,
+            max_recording_secs: 30,
+            device_name: None,
+            preroll_ms: 0,
+            trim_silence: false,
+            silence_threshold: 0.01,
+            trim_attack_ms: 100,
+            trim_release_ms: 200,
+            normalize: false,
+            target_peak: 0.9,
         };
 
         let mut capture = AudioCapture::new(&config).unwrap();
@@ -917,7 +2075,7 @@ mod tests {
         let wav_path = nested_dir.join("nested").join("test.wav");
 
         // Should succeed by creating parent directories
-        let result = AudioCapture::save_wav_debug(&samples, &wav_path);
+        let result = AudioCapture::save_wav_debug(&samples, &wav_path, WavFormat::F32);
         assert!(result.is_ok());
         assert!(wav_path.exists());
 
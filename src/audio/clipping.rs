@@ -0,0 +1,84 @@
+/// Absolute level at or above which a sample counts as clipped
+///
+/// Slightly below full scale: converters often saturate just short of ±1.0.
+pub const CLIP_LEVEL: f32 = 0.99;
+
+/// Fraction of samples at or near full scale (0.0 for empty input)
+#[must_use]
+pub fn clipped_fraction(samples: &[f32]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let clipped = samples.iter().filter(|s| s.abs() >= CLIP_LEVEL).count();
+    // Precision loss only matters beyond 2^52 samples
+    #[allow(clippy::cast_precision_loss)]
+    let fraction = clipped as f64 / samples.len() as f64;
+    fraction
+}
+
+/// Whether the clipped fraction exceeds `threshold` (0 disables detection)
+#[must_use]
+pub fn is_clipping(samples: &[f32], threshold: f64) -> bool {
+    threshold > 0.0 && clipped_fraction(samples) > threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_buffer_not_clipped() {
+        assert!((clipped_fraction(&[]) - 0.0).abs() < f64::EPSILON);
+        assert!(!is_clipping(&[], 0.01));
+    }
+
+    #[test]
+    fn test_no_clipping() {
+        let samples: Vec<f32> = (0..1000_u16)
+            .map(|i| (f32::from(i) * 0.01).sin() * 0.5)
+            .collect();
+        assert!((clipped_fraction(&samples) - 0.0).abs() < f64::EPSILON);
+        assert!(!is_clipping(&samples, 0.01));
+    }
+
+    #[test]
+    fn test_known_clipping_proportion() {
+        // 25 of 100 samples at full scale, both polarities
+        let mut samples = vec![0.2_f32; 100];
+        for (i, sample) in samples.iter_mut().take(25).enumerate() {
+            *sample = if i % 2 == 0 { 1.0 } else { -1.0 };
+        }
+        assert!((clipped_fraction(&samples) - 0.25).abs() < 1e-9);
+        assert!(is_clipping(&samples, 0.1));
+        assert!(!is_clipping(&samples, 0.3));
+    }
+
+    #[test]
+    fn test_near_full_scale_counts_as_clipped() {
+        let samples = [0.995_f32, -0.99, 0.98, 0.0];
+        assert!((clipped_fraction(&samples) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_heavily_clipped() {
+        let samples = vec![1.0_f32; 500];
+        assert!((clipped_fraction(&samples) - 1.0).abs() < f64::EPSILON);
+        assert!(is_clipping(&samples, 0.01));
+    }
+
+    #[test]
+    fn test_threshold_zero_disables() {
+        let samples = vec![1.0_f32; 500];
+        assert!(!is_clipping(&samples, 0.0));
+    }
+
+    #[test]
+    fn test_fraction_at_threshold_not_clipping() {
+        // Exactly 1% clipped with a 1% threshold: not over it
+        let mut samples = vec![0.0_f32; 100];
+        samples[0] = 1.0;
+        assert!(!is_clipping(&samples, 0.01));
+        samples[1] = -1.0;
+        assert!(is_clipping(&samples, 0.01));
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+use cpal::traits::{DeviceTrait, HostTrait};
+use tracing::{info, warn};
+
+/// Display name of `device` (None if the backend can't report it)
+fn device_name(device: &cpal::Device) -> Option<String> {
+    device
+        .description()
+        .ok()
+        .map(|description| description.name().to_owned())
+}
+
+/// Index of the device matching `wanted` in `names` (pure)
+///
+/// Matching is case-insensitive. An exact name wins over a substring match,
+/// so `MacBook Pro Microphone` isn't shadowed by a longer name containing it;
+/// otherwise the first device containing `wanted` is picked.
+#[must_use]
+pub fn match_device(names: &[String], wanted: &str) -> Option<usize> {
+    let wanted = wanted.trim().to_lowercase();
+    if wanted.is_empty() {
+        return None;
+    }
+    let lowered: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+    lowered
+        .iter()
+        .position(|name| *name == wanted)
+        .or_else(|| lowered.iter().position(|name| name.contains(&wanted)))
+}
+
+/// Names of the available input devices
+///
+/// # Errors
+/// Returns error if the audio host can't enumerate devices
+pub fn list_input_devices() -> Result<Vec<String>> {
+    let devices = cpal::default_host()
+        .input_devices()
+        .context("failed to list input devices")?;
+    Ok(devices.filter_map(|device| device_name(&device)).collect())
+}
+
+/// Input device named like `wanted`, or the default input device
+///
+/// A configured name that matches no device falls back to the default with a warning.
+///
+/// # Errors
+/// Returns error if no input device is available
+pub fn select_input_device(host: &cpal::Host, wanted: Option<&str>) -> Result<cpal::Device> {
+    if let Some(wanted) = wanted {
+        let mut devices: Vec<(String, cpal::Device)> = host
+            .input_devices()
+            .context("failed to list input devices")?
+            .filter_map(|device| device_name(&device).map(|name| (name, device)))
+            .collect();
+        let names: Vec<String> = devices.iter().map(|(name, _)| name.clone()).collect();
+        if let Some(index) = match_device(&names, wanted) {
+            let (name, device) = devices.swap_remove(index);
+            info!("input device {:?} matches configured {:?}", name, wanted);
+            return Ok(device);
+        }
+        warn!(
+            device_name = wanted,
+            available = ?names,
+            "⚠️  Configured input device not found, using the default input device"
+        );
+    }
+    host.default_input_device()
+        .context("no input device available")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names() -> Vec<String> {
+        [
+            "MacBook Pro Microphone",
+            "Blue Yeti USB Microphone",
+            "MacBook Pro Microphone (Aggregate)",
+            "ZoomAudioDevice",
+        ]
+        .map(str::to_owned)
+        .to_vec()
+    }
+
+    #[test]
+    fn test_match_substring_case_insensitive() {
+        assert_eq!(match_device(&names(), "yeti"), Some(1));
+        assert_eq!(match_device(&names(), "BLUE YETI"), Some(1));
+        assert_eq!(match_device(&names(), "zoom"), Some(3));
+    }
+
+    #[test]
+    fn test_exact_match_preferred() {
+        assert_eq!(match_device(&names(), "macbook pro microphone"), Some(0));
+        assert_eq!(match_device(&names(), "Aggregate"), Some(2));
+    }
+
+    #[test]
+    fn test_first_substring_match_wins() {
+        assert_eq!(match_device(&names(), "microphone"), Some(0));
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert_eq!(match_device(&names(), "Scarlett"), None);
+        assert_eq!(match_device(&[], "Yeti"), None);
+        // Blank names never match everything
+        assert_eq!(match_device(&names(), "  "), None);
+    }
+
+    #[test]
+    fn test_match_trims_configured_name() {
+        assert_eq!(match_device(&names(), " Yeti "), Some(1));
+    }
+}
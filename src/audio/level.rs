@@ -0,0 +1,158 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// Fixed-point scale of the stored level (1.0 = full scale)
+const LEVEL_SCALE: f32 = 65_536.0;
+
+/// Smoothing applied when the level rises (fast, so speech shows immediately)
+const ATTACK: f32 = 0.6;
+
+/// Smoothing applied when the level falls (slow, so the meter doesn't flicker)
+const RELEASE: f32 = 0.15;
+
+/// Quietest level shown on the meter, in dBFS
+const METER_FLOOR_DB: f32 = -60.0;
+
+/// RMS of one callback buffer (0.0 for empty input, no allocation)
+#[must_use]
+pub fn buffer_rms(data: &[f32]) -> f32 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    let sum: f32 = data.iter().map(|s| s * s).sum();
+    // Callback buffers are a few thousand samples, exact in f32
+    #[allow(clippy::cast_precision_loss)]
+    let mean = sum / data.len() as f32;
+    mean.sqrt()
+}
+
+/// Next smoothed level after a buffer measuring `rms` (pure)
+#[must_use]
+pub fn smooth(previous: f32, rms: f32) -> f32 {
+    let factor = if rms > previous { ATTACK } else { RELEASE };
+    (rms - previous).mul_add(factor, previous)
+}
+
+/// Text meter of `width` cells for `level`, scaled in decibels (pure)
+#[must_use]
+pub fn meter_bar(level: f32, width: usize) -> String {
+    let db = 20.0 * level.max(f32::MIN_POSITIVE).log10();
+    let fraction = (1.0 - db / METER_FLOOR_DB).clamp(0.0, 1.0);
+    // Fraction is within [0, 1], so the product fits in the meter width
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let filled = (fraction * width as f32).round() as usize;
+    "▮".repeat(filled) + &"▯".repeat(width - filled)
+}
+
+/// Smoothed input level shared between the audio callback and readers
+///
+/// Stored as fixed-point in an atomic so the realtime callback never locks.
+#[derive(Debug, Clone, Default)]
+pub struct InputLevel(Arc<AtomicU32>);
+
+impl InputLevel {
+    /// Current smoothed RMS level (1.0 = full scale)
+    #[must_use]
+    pub fn get(&self) -> f32 {
+        // Stored values are at most LEVEL_SCALE, exact in f32
+        #[allow(clippy::cast_precision_loss)]
+        let level = self.0.load(Ordering::Relaxed) as f32 / LEVEL_SCALE;
+        level
+    }
+
+    /// Fold one callback buffer into the level
+    pub fn update(&self, data: &[f32]) {
+        let rms = buffer_rms(data);
+        if !rms.is_finite() {
+            return;
+        }
+        self.set(smooth(self.get(), rms));
+    }
+
+    /// Drop the level back to silence (recording ended)
+    pub fn reset(&self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+
+    fn set(&self, level: f32) {
+        // Clamped to [0, 1] first, so the scaled value fits in u32
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let fixed = (level.clamp(0.0, 1.0) * LEVEL_SCALE).round() as u32;
+        self.0.store(fixed, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn sine(amplitude: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 48000.0).sin() * amplitude)
+            .collect()
+    }
+
+    #[test]
+    fn test_buffer_rms() {
+        assert!(buffer_rms(&[]).abs() < f32::EPSILON);
+        assert!((buffer_rms(&[0.5, -0.5, 0.5, -0.5]) - 0.5).abs() < 1e-6);
+        // Sine RMS is amplitude / √2 (4800 samples = 44 whole periods)
+        let rms = buffer_rms(&sine(0.5, 4800));
+        assert!((rms - 0.5 / 2.0_f32.sqrt()).abs() < 0.01, "rms {rms}");
+    }
+
+    #[test]
+    fn test_sine_level_in_range() {
+        let level = InputLevel::default();
+        let buffer = sine(0.5, 512);
+        for _ in 0..30 {
+            level.update(&buffer);
+        }
+        let reported = level.get();
+        assert!((0.32..=0.39).contains(&reported), "level {reported}");
+    }
+
+    #[test]
+    fn test_level_rises_fast_and_decays_slowly() {
+        let level = InputLevel::default();
+        level.update(&sine(0.5, 512));
+        let after_attack = level.get();
+        assert!(after_attack > 0.2, "attack {after_attack}");
+
+        level.update(&[0.0; 512]);
+        let after_release = level.get();
+        assert!(after_release > after_attack * 0.8 && after_release < after_attack);
+    }
+
+    #[test]
+    fn test_reset_and_non_finite_input() {
+        let level = InputLevel::default();
+        level.update(&sine(0.5, 512));
+        level.update(&[f32::NAN; 4]);
+        assert!(level.get() > 0.0);
+        level.reset();
+        assert!(level.get().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_clones_share_level() {
+        let level = InputLevel::default();
+        let reader = level.clone();
+        level.update(&[1.0; 64]);
+        assert!(reader.get() > 0.5);
+    }
+
+    #[test]
+    fn test_meter_bar() {
+        assert_eq!(meter_bar(0.0, 5), "▯▯▯▯▯");
+        assert_eq!(meter_bar(1.0, 5), "▮▮▮▮▮");
+        // -30 dBFS sits halfway on a 60 dB meter
+        assert_eq!(meter_bar(10.0_f32.powf(-1.5), 4), "▮▮▯▯");
+        assert_eq!(meter_bar(2.0, 3), "▮▮▮");
+    }
+}
@@ -1,3 +1,13 @@
 mod capture;
+mod clipping;
+mod device;
+mod level;
+mod resample;
+mod stats;
+mod trim;
 
-pub use capture::AudioCapture;
+pub use capture::{AudioCapture, CaptureTimings};
+pub use clipping::{clipped_fraction, is_clipping};
+pub use device::list_input_devices;
+pub use level::{meter_bar, InputLevel};
+pub use stats::AudioStats;
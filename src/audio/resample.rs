@@ -0,0 +1,341 @@
+use std::f64::consts::PI;
+use std::time::Duration;
+
+/// Zero crossings of the sinc kernel on each side of the output sample
+const SINC_ZERO_CROSSINGS: f64 = 8.0;
+
+/// Kernel table entries per zero crossing (linearly interpolated between)
+const KERNEL_RESOLUTION: usize = 512;
+
+/// Resampling algorithm used after a recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resampler {
+    /// Linear interpolation: fastest, aliases when downsampling
+    Linear,
+    /// Windowed-sinc low-pass: band-limited, slower
+    Sinc,
+}
+
+/// Resampler for a recording of `recording` length (pure)
+///
+/// The band-limited sinc resampler is the default. With `auto` on, recordings
+/// shorter than `threshold` take the fast linear path instead, trading some
+/// aliasing for a few milliseconds of latency on quick phrases.
+#[must_use]
+pub fn choose_resampler(auto: bool, recording: Duration, threshold: Duration) -> Resampler {
+    if auto && recording < threshold {
+        Resampler::Linear
+    } else {
+        Resampler::Sinc
+    }
+}
+
+/// Length of `frames` frames at `sample_rate` (zero for a zero rate)
+#[must_use]
+// Precision loss only matters beyond 2^52 frames
+#[allow(clippy::cast_precision_loss)]
+pub fn frames_duration(frames: usize, sample_rate: u32) -> Duration {
+    if sample_rate == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(frames as f64 / f64::from(sample_rate))
+}
+
+/// Resample mono `samples` from `from_rate` to `to_rate` with `resampler`
+#[must_use]
+pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32, resampler: Resampler) -> Vec<f32> {
+    match resampler {
+        Resampler::Linear => resample_linear(samples, from_rate, to_rate),
+        Resampler::Sinc => resample_sinc(samples, from_rate, to_rate),
+    }
+}
+
+/// Output length for resampling `len` samples by `ratio` (source / target rate)
+// Algorithm requires f64 ↔ usize conversions for fractional index calculations
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn output_len(len: usize, ratio: f64) -> usize {
+    // Ratio is always positive for valid sample rates
+    let output_len_f64 = (len as f64) / ratio;
+    if output_len_f64.is_finite() && output_len_f64 >= 0.0 {
+        output_len_f64.ceil() as usize
+    } else {
+        len
+    }
+}
+
+/// Simple linear interpolation resampling
+// Algorithm requires f64 ↔ usize conversions for fractional index calculations
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    let ratio = f64::from(from_rate) / f64::from(to_rate);
+    let output_len = output_len(samples.len(), ratio);
+
+    let mut resampled = Vec::with_capacity(output_len);
+    for i in 0..output_len {
+        // Calculate source index with linear interpolation
+        let src_idx_f64 = (i as f64) * ratio;
+
+        // Floor gives integer part, safe because src_idx >= 0
+        let src_idx_floor = if src_idx_f64 >= 0.0 && src_idx_f64 < (usize::MAX as f64) {
+            src_idx_f64.floor() as usize
+        } else {
+            0
+        };
+
+        let src_idx_ceil = (src_idx_floor + 1).min(samples.len().saturating_sub(1));
+        let fract = src_idx_f64 - src_idx_f64.floor();
+
+        let sample = if src_idx_floor < samples.len() {
+            let s1 = f64::from(samples[src_idx_floor]);
+            let s2 = f64::from(samples[src_idx_ceil]);
+            // Use mul_add for better precision
+            let interpolated = s1.mul_add(1.0 - fract, s2 * fract);
+            interpolated as f32
+        } else {
+            0.0_f32
+        };
+
+        resampled.push(sample);
+    }
+    resampled
+}
+
+/// Normalized sinc, sin(πx) / (πx)
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-9 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+/// Blackman window over `[-half_width, half_width]` (zero outside)
+fn blackman(x: f64, half_width: f64) -> f64 {
+    if x.abs() >= half_width {
+        return 0.0;
+    }
+    let phase = PI * (x / half_width + 1.0);
+    0.08_f64.mul_add((2.0 * phase).cos(), 0.5_f64.mul_add(-phase.cos(), 0.42))
+}
+
+/// Right half of the Blackman-windowed sinc, sampled `KERNEL_RESOLUTION` times per zero crossing
+///
+/// Computed once per resample so the inner loop does no trigonometry.
+// Table indices are small, exact in f64
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn kernel_table() -> Vec<f64> {
+    let len = (SINC_ZERO_CROSSINGS as usize) * KERNEL_RESOLUTION + 2;
+    (0..len)
+        .map(|i| {
+            let x = i as f64 / KERNEL_RESOLUTION as f64;
+            sinc(x) * blackman(x, SINC_ZERO_CROSSINGS)
+        })
+        .collect()
+}
+
+/// Kernel value at `x` zero crossings from the center (symmetric, zero outside)
+// Table position is non-negative and bounded by the table length
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn kernel_at(table: &[f64], x: f64) -> f64 {
+    let position = x.abs() * KERNEL_RESOLUTION as f64;
+    let index = position as usize;
+    if index + 1 >= table.len() {
+        return 0.0;
+    }
+    let fract = position - index as f64;
+    table[index].mul_add(1.0 - fract, table[index + 1] * fract)
+}
+
+/// Band-limited resampling with a Blackman-windowed sinc kernel
+///
+/// When downsampling the cutoff drops to the target Nyquist frequency, so
+/// content above it is filtered out instead of folding back as aliasing.
+/// Kernel weights are normalized per output sample to keep unity DC gain, and
+/// the output is clamped to the input's range so filter ringing never pushes a
+/// sample past the loudest input sample (or full scale).
+// Algorithm requires f64 ↔ usize/isize conversions for fractional index calculations
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_wrap
+)]
+fn resample_sinc(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    let ratio = f64::from(from_rate) / f64::from(to_rate);
+    let output_len = output_len(samples.len(), ratio);
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    // Finite input range; ringing is clamped to it
+    let (lo, hi) = samples
+        .iter()
+        .filter(|s| s.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &s| {
+            (lo.min(f64::from(s)), hi.max(f64::from(s)))
+        });
+
+    // Cutoff relative to the source Nyquist frequency
+    let cutoff = (1.0 / ratio).min(1.0);
+    let half_width = SINC_ZERO_CROSSINGS / cutoff;
+    let last = samples.len() as isize - 1;
+    let table = kernel_table();
+
+    let mut resampled = Vec::with_capacity(output_len);
+    for i in 0..output_len {
+        let center = (i as f64) * ratio;
+        let first_tap = (center - half_width).ceil().max(0.0) as isize;
+        let last_tap = ((center + half_width).floor() as isize).min(last);
+
+        let mut acc = 0.0_f64;
+        let mut weight_sum = 0.0_f64;
+        for tap in first_tap..=last_tap {
+            // Scaling by the cutoff is left out: weights are normalized below
+            let weight = kernel_at(&table, cutoff * (center - tap as f64));
+            acc = f64::from(samples[tap as usize]).mul_add(weight, acc);
+            weight_sum += weight;
+        }
+
+        let sample = if weight_sum.abs() > f64::EPSILON {
+            acc / weight_sum
+        } else {
+            0.0
+        };
+        let sample = if lo <= hi {
+            sample.clamp(lo, hi)
+        } else {
+            sample
+        };
+        resampled.push(sample as f32);
+    }
+    resampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: Duration = Duration::from_secs(5);
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn sine(freq: f64, rate: u32, secs: f64) -> Vec<f32> {
+        let len = (f64::from(rate) * secs) as usize;
+        (0..len)
+            .map(|i| (2.0 * PI * freq * i as f64 / f64::from(rate)).sin() as f32 * 0.5)
+            .collect()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn rms(samples: &[f32]) -> f64 {
+        let sum: f64 = samples.iter().map(|&s| f64::from(s).powi(2)).sum();
+        (sum / samples.len() as f64).sqrt()
+    }
+
+    #[test]
+    fn test_auto_off_always_sinc() {
+        for secs in [0, 1, 5, 60, 600] {
+            assert_eq!(
+                choose_resampler(false, Duration::from_secs(secs), THRESHOLD),
+                Resampler::Sinc
+            );
+        }
+    }
+
+    #[test]
+    fn test_auto_short_recordings_use_linear() {
+        assert_eq!(
+            choose_resampler(true, Duration::ZERO, THRESHOLD),
+            Resampler::Linear
+        );
+        assert_eq!(
+            choose_resampler(true, Duration::from_millis(800), THRESHOLD),
+            Resampler::Linear
+        );
+        assert_eq!(
+            choose_resampler(true, Duration::from_millis(4999), THRESHOLD),
+            Resampler::Linear
+        );
+    }
+
+    #[test]
+    fn test_auto_long_recordings_use_sinc() {
+        // Threshold itself already counts as long
+        assert_eq!(
+            choose_resampler(true, THRESHOLD, THRESHOLD),
+            Resampler::Sinc
+        );
+        assert_eq!(
+            choose_resampler(true, Duration::from_secs(90), THRESHOLD),
+            Resampler::Sinc
+        );
+        // Zero threshold: always sinc
+        assert_eq!(
+            choose_resampler(true, Duration::ZERO, Duration::ZERO),
+            Resampler::Sinc
+        );
+    }
+
+    #[test]
+    fn test_frames_duration() {
+        assert_eq!(frames_duration(48000, 48000), Duration::from_secs(1));
+        assert_eq!(frames_duration(24000, 16000), Duration::from_millis(1500));
+        assert_eq!(frames_duration(100, 0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_sinc_output_length_matches_linear() {
+        let samples = sine(440.0, 48000, 0.25);
+        assert_eq!(
+            resample(&samples, 48000, 16000, Resampler::Sinc).len(),
+            resample(&samples, 48000, 16000, Resampler::Linear).len()
+        );
+        assert!(resample(&[], 48000, 16000, Resampler::Sinc).is_empty());
+    }
+
+    #[test]
+    fn test_sinc_keeps_passband_tone() {
+        let samples = sine(440.0, 48000, 0.5);
+        let out = resample(&samples, 48000, 16000, Resampler::Sinc);
+        // 440 Hz is well inside the 8 kHz passband: level unchanged
+        let ratio = rms(&out) / rms(&samples);
+        assert!((ratio - 1.0).abs() < 0.02, "passband ratio {ratio}");
+    }
+
+    #[test]
+    fn test_sinc_rejects_aliasing_tone() {
+        // 12 kHz is above the 8 kHz target Nyquist: linear folds it to 4 kHz
+        let samples = sine(12000.0, 48000, 0.5);
+        let sinc_out = resample(&samples, 48000, 16000, Resampler::Sinc);
+        let linear_out = resample(&samples, 48000, 16000, Resampler::Linear);
+        assert!(rms(&sinc_out) < 0.01, "sinc leaked {}", rms(&sinc_out));
+        assert!(rms(&linear_out) > 0.1);
+    }
+
+    #[test]
+    fn test_sinc_upsampling_preserves_dc() {
+        let samples = vec![0.25_f32; 800];
+        let out = resample(&samples, 8000, 16000, Resampler::Sinc);
+        assert_eq!(out.len(), 1600);
+        assert!(out.iter().all(|&s| (s - 0.25).abs() < 1e-4));
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use super::clipping::clipped_fraction;
+use super::resample::frames_duration;
+
+/// Sample rate of post-processed recordings (16kHz mono)
+const SAMPLE_RATE: u32 = 16000;
+
+/// Window over which silence is measured (20ms at 16kHz)
+const SILENCE_WINDOW: usize = 320;
+
+/// RMS level below which a window counts as silent (about -40 dBFS)
+pub const SILENCE_LEVEL: f64 = 0.01;
+
+/// Peak absolute sample level (0.0 for empty input)
+#[must_use]
+pub fn peak_level(samples: &[f32]) -> f64 {
+    samples
+        .iter()
+        .filter(|s| s.is_finite())
+        .fold(0.0_f64, |peak, &s| peak.max(f64::from(s.abs())))
+}
+
+/// Root-mean-square level (0.0 for empty input)
+#[must_use]
+pub fn rms_level(samples: &[f32]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f64 = samples.iter().map(|&s| f64::from(s).powi(2)).sum();
+    // Precision loss only matters beyond 2^52 samples
+    #[allow(clippy::cast_precision_loss)]
+    let mean = sum / samples.len() as f64;
+    mean.sqrt()
+}
+
+/// Fraction of 20ms windows whose RMS level is below [`SILENCE_LEVEL`] (0.0 for empty input)
+#[must_use]
+pub fn silence_fraction(samples: &[f32]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let windows = samples.chunks(SILENCE_WINDOW);
+    let total = windows.len();
+    let silent = windows
+        .filter(|window| rms_level(window) < SILENCE_LEVEL)
+        .count();
+    // Window counts are far below 2^52
+    #[allow(clippy::cast_precision_loss)]
+    let fraction = silent as f64 / total as f64;
+    fraction
+}
+
+/// Level statistics of one recording (`[telemetry] audio_stats_debug`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioStats {
+    /// Samples after conversion to 16kHz mono
+    pub sample_count: usize,
+    /// Recording length
+    pub duration: Duration,
+    /// Peak absolute level (1.0 = full scale)
+    pub peak: f64,
+    /// RMS level (1.0 = full scale)
+    pub rms: f64,
+    /// Fraction of samples at or near full scale
+    pub clipped_fraction: f64,
+    /// Fraction of 20ms windows below the silence level
+    pub silence_fraction: f64,
+    /// Sample rate the input device captured at
+    pub device_rate: u32,
+}
+
+impl AudioStats {
+    /// Aggregate stats over post-processed 16kHz mono `samples` (pure)
+    #[must_use]
+    pub fn compute(samples: &[f32], device_rate: u32) -> Self {
+        Self {
+            sample_count: samples.len(),
+            duration: frames_duration(samples.len(), SAMPLE_RATE),
+            peak: peak_level(samples),
+            rms: rms_level(samples),
+            clipped_fraction: clipped_fraction(samples),
+            silence_fraction: silence_fraction(samples),
+            device_rate,
+        }
+    }
+
+    /// One-line summary
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} samples, {:.2}s, peak {:.3}, rms {:.3}, clipped {:.1}%, silence {:.1}%, device {} Hz",
+            self.sample_count,
+            self.duration.as_secs_f64(),
+            self.peak,
+            self.rms,
+            self.clipped_fraction * 100.0,
+            self.silence_fraction * 100.0,
+            self.device_rate
+        )
+    }
+
+    /// Emit the stats as one structured log event
+    pub fn log(&self) {
+        tracing::info!(
+            sample_count = self.sample_count,
+            duration_ms = self.duration.as_millis(),
+            peak = format!("{:.3}", self.peak),
+            rms = format!("{:.3}", self.rms),
+            clipped_percent = format!("{:.1}", self.clipped_fraction * 100.0),
+            silence_percent = format!("{:.1}", self.silence_fraction * 100.0),
+            device_rate = self.device_rate,
+            "📊 Audio: {}",
+            self.summary()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn tone(amplitude: f32, secs: f64) -> Vec<f32> {
+        let len = (f64::from(SAMPLE_RATE) * secs) as usize;
+        (0..len)
+            .map(|i| {
+                let phase = 2.0 * std::f64::consts::PI * 440.0 * i as f64 / f64::from(SAMPLE_RATE);
+                phase.sin() as f32 * amplitude
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_buffer() {
+        let stats = AudioStats::compute(&[], 48000);
+        assert_eq!(stats.sample_count, 0);
+        assert_eq!(stats.duration, Duration::ZERO);
+        assert!(stats.peak.abs() < f64::EPSILON);
+        assert!(stats.rms.abs() < f64::EPSILON);
+        assert!(stats.silence_fraction.abs() < f64::EPSILON);
+        assert_eq!(stats.device_rate, 48000);
+    }
+
+    #[test]
+    fn test_silence() {
+        let stats = AudioStats::compute(&vec![0.0; 16000], 44100);
+        assert_eq!(stats.sample_count, 16000);
+        assert_eq!(stats.duration, Duration::from_secs(1));
+        assert!(stats.peak.abs() < f64::EPSILON);
+        assert!(stats.rms.abs() < f64::EPSILON);
+        assert!(stats.clipped_fraction.abs() < f64::EPSILON);
+        assert!((stats.silence_fraction - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tone() {
+        let stats = AudioStats::compute(&tone(0.5, 2.0), 16000);
+        assert_eq!(stats.duration, Duration::from_secs(2));
+        assert!((stats.peak - 0.5).abs() < 1e-3, "peak {}", stats.peak);
+        // Sine RMS is amplitude / √2
+        assert!(
+            (stats.rms - 0.5 / 2.0_f64.sqrt()).abs() < 1e-3,
+            "rms {}",
+            stats.rms
+        );
+        assert!(stats.clipped_fraction.abs() < f64::EPSILON);
+        assert!(stats.silence_fraction.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_clipped() {
+        // Full-scale square wave: every sample clipped
+        let samples: Vec<f32> = (0..1600)
+            .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+            .collect();
+        let stats = AudioStats::compute(&samples, 48000);
+        assert!((stats.peak - 1.0).abs() < f64::EPSILON);
+        assert!((stats.rms - 1.0).abs() < 1e-9);
+        assert!((stats.clipped_fraction - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_half_silent_recording() {
+        let mut samples = vec![0.0; 8000];
+        samples.extend(tone(0.3, 0.5));
+        let stats = AudioStats::compute(&samples, 16000);
+        assert!((stats.silence_fraction - 0.5).abs() < 0.01);
+        assert!((stats.peak - 0.3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_non_finite_samples_ignored_for_peak() {
+        assert!((peak_level(&[0.2, f32::NAN, -0.4, f32::INFINITY]) - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_summary() {
+        let stats = AudioStats::compute(&vec![0.0; 16000], 48000);
+        assert_eq!(
+            stats.summary(),
+            "16000 samples, 1.00s, peak 0.000, rms 0.000, clipped 0.0%, silence 100.0%, device 48000 Hz"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use super::stats::rms_level;
+use crate::config::AudioConfig;
+
+/// Sample rate of post-processed recordings (16kHz mono)
+const SAMPLE_RATE: u32 = 16000;
+
+/// Window over which the level is measured (10ms at 16kHz)
+const WINDOW: usize = 160;
+
+/// Leading/trailing silence trimming (`[audio] trim_silence`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SilenceTrim {
+    /// RMS level a window must reach to count as speech
+    pub threshold: f32,
+    /// Audio kept before the first speech window (soft word onsets)
+    pub attack: Duration,
+    /// Audio kept after the last speech window (trailing consonants)
+    pub release: Duration,
+}
+
+impl SilenceTrim {
+    /// Trimming settings from `config` (None = disabled)
+    #[must_use]
+    pub fn from_config(config: &AudioConfig) -> Option<Self> {
+        config.trim_silence.then(|| Self {
+            threshold: config.silence_threshold,
+            attack: Duration::from_millis(config.trim_attack_ms),
+            release: Duration::from_millis(config.trim_release_ms),
+        })
+    }
+}
+
+/// Samples in `duration` at 16kHz
+// Durations are user-configured milliseconds, far below usize::MAX samples
+#[allow(clippy::cast_possible_truncation)]
+const fn samples_in(duration: Duration) -> usize {
+    (duration.as_millis() * SAMPLE_RATE as u128 / 1000) as usize
+}
+
+/// Range of `samples` from the first to the last speech window, padded by attack/release (pure)
+///
+/// Only the head and tail are considered: everything between the first and last
+/// window above the threshold is kept, so pauses between words survive.
+/// None if no window reaches the threshold.
+#[must_use]
+pub fn speech_bounds(samples: &[f32], trim: &SilenceTrim) -> Option<Range<usize>> {
+    let threshold = f64::from(trim.threshold);
+    let loud = |window: &[f32]| rms_level(window) >= threshold;
+    let first = samples.chunks(WINDOW).position(loud)?;
+    let last = samples.chunks(WINDOW).rposition(loud)?;
+
+    let start = (first * WINDOW).saturating_sub(samples_in(trim.attack));
+    let end = ((last + 1) * WINDOW)
+        .saturating_add(samples_in(trim.release))
+        .min(samples.len());
+    Some(start..end)
+}
+
+/// `samples` without leading and trailing silence (empty if it's all silence)
+#[must_use]
+pub fn trim_silence(samples: &[f32], trim: &SilenceTrim) -> Vec<f32> {
+    speech_bounds(samples, trim).map_or_else(Vec::new, |range| samples[range].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIM: SilenceTrim = SilenceTrim {
+        threshold: 0.02,
+        attack: Duration::ZERO,
+        release: Duration::ZERO,
+    };
+
+    /// Constant-level stand-in for speech (RMS = level)
+    fn speech(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| if i % 2 == 0 { 0.3 } else { -0.3 })
+            .collect()
+    }
+
+    fn padded(head: usize, body: &[f32], tail: usize) -> Vec<f32> {
+        let mut samples = vec![0.0; head];
+        samples.extend_from_slice(body);
+        samples.extend(std::iter::repeat(0.0).take(tail));
+        samples
+    }
+
+    #[test]
+    fn test_trims_head_and_tail() {
+        // 0.5s silence, 1s speech, 0.8s silence
+        let samples = padded(8000, &speech(16000), 12800);
+        let trimmed = trim_silence(&samples, &TRIM);
+        assert_eq!(trimmed.len(), 16000);
+        assert_eq!(speech_bounds(&samples, &TRIM), Some(8000..24000));
+    }
+
+    #[test]
+    fn test_keeps_interior_pauses() {
+        // Word, 1s pause, word
+        let mut body = speech(3200);
+        body.extend(vec![0.0; 16000]);
+        body.extend(speech(3200));
+        let samples = padded(4800, &body, 4800);
+        let trimmed = trim_silence(&samples, &TRIM);
+        assert_eq!(trimmed, body);
+    }
+
+    #[test]
+    fn test_attack_and_release_padding() {
+        let trim = SilenceTrim {
+            attack: Duration::from_millis(100),
+            release: Duration::from_millis(200),
+            ..TRIM
+        };
+        let samples = padded(8000, &speech(16000), 8000);
+        // 100ms = 1600 samples before, 200ms = 3200 after
+        assert_eq!(speech_bounds(&samples, &trim), Some(6400..27200));
+    }
+
+    #[test]
+    fn test_padding_clamped_to_buffer() {
+        let trim = SilenceTrim {
+            attack: Duration::from_secs(5),
+            release: Duration::from_secs(5),
+            ..TRIM
+        };
+        let samples = padded(800, &speech(1600), 800);
+        assert_eq!(trim_silence(&samples, &trim).len(), samples.len());
+    }
+
+    #[test]
+    fn test_no_silence_unchanged() {
+        let samples = speech(4800);
+        assert_eq!(trim_silence(&samples, &TRIM), samples);
+    }
+
+    #[test]
+    fn test_all_silence_is_empty() {
+        assert!(trim_silence(&vec![0.001; 16000], &TRIM).is_empty());
+        assert!(trim_silence(&[], &TRIM).is_empty());
+    }
+
+    #[test]
+    fn test_quiet_noise_below_threshold_trimmed() {
+        let mut samples = vec![0.005; 4800];
+        samples.extend(speech(4800));
+        samples.extend(vec![-0.005; 4800]);
+        assert_eq!(trim_silence(&samples, &TRIM).len(), 4800);
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = AudioConfig::default();
+        assert_eq!(SilenceTrim::from_config(&config), None);
+
+        let config = AudioConfig {
+            trim_silence: true,
+            silence_threshold: 0.05,
+            trim_attack_ms: 50,
+            trim_release_ms: 300,
+            ..AudioConfig::default()
+        };
+        assert_eq!(
+            SilenceTrim::from_config(&config),
+            Some(SilenceTrim {
+                threshold: 0.05,
+                attack: Duration::from_millis(50),
+                release: Duration::from_millis(300),
+            })
+        );
+    }
+}
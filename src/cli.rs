@@ -0,0 +1,702 @@
+use crate::config::{
+    Config, HistoryConfig, HotkeyMode, ModelType, ProfileMode, StatsConfig, TranscriptionProfile,
+};
+use crate::history::{self, HistoryEntry, HistoryQuery};
+use crate::transcription::batch;
+use anyhow::{bail, Context, Result};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Command-line options
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // Independent command-line flags
+pub struct CliArgs {
+    /// Suppress decorative startup output (`--quiet` / `-q`)
+    pub quiet: bool,
+    /// Search history and exit (`--search <text>`, `--since`/`--until <YYYY-MM-DD>`)
+    pub search: Option<HistoryQuery>,
+    /// Log transcriptions instead of inserting them (`--dry-run`)
+    pub dry_run: bool,
+    /// Print per-profile usage statistics and exit (`--stats`)
+    pub stats: bool,
+    /// Transcribe every audio file in a directory and exit (`--transcribe-dir <dir>`)
+    pub transcribe_dir: Option<PathBuf>,
+    /// Output directory for `--transcribe-dir` (`--out <dir>`, default: the input directory)
+    pub out: Option<PathBuf>,
+    /// Also write a `.srt` subtitle file per input (`--srt`)
+    pub srt: bool,
+    /// Print the available input devices and exit (`--list-devices`)
+    pub list_devices: bool,
+    /// Load and validate the config, print a summary and exit (`--check-config`)
+    pub check_config: bool,
+    /// Model for every profile (`--model <type>`)
+    pub model: Option<ModelType>,
+    /// Hotkey for the first profile (`--hotkey <Modifier+...+Key>`)
+    pub hotkey: Option<HotkeyOverride>,
+    /// Inference threads for every profile (`--threads <n>`)
+    pub threads: Option<usize>,
+    /// Beam size for every profile (`--beam-size <n>`)
+    pub beam_size: Option<usize>,
+    /// Language for every profile (`--language <code>`, `auto` = detect)
+    pub language: Option<String>,
+    /// Preload models at startup (`--preload` / `--no-preload`)
+    pub preload: Option<bool>,
+}
+
+/// Hotkey given on the command line, e.g. `Control+Option+Z`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotkeyOverride {
+    /// Modifier keys (everything before the last `+`)
+    pub modifiers: Vec<String>,
+    /// Main key
+    pub key: String,
+}
+
+impl HotkeyOverride {
+    /// Parse `Modifier+...+Key` (pure)
+    ///
+    /// # Errors
+    /// Returns error if the key or a modifier is empty
+    pub fn parse(combo: &str) -> Result<Self> {
+        let mut parts: Vec<String> = combo
+            .split('+')
+            .map(|part| part.trim().to_owned())
+            .collect();
+        if parts.iter().any(String::is_empty) {
+            bail!("invalid hotkey {combo:?}: expected e.g. \"Control+Option+Z\"");
+        }
+        let key = parts.pop().unwrap_or_default();
+        Ok(Self {
+            modifiers: parts,
+            key,
+        })
+    }
+}
+
+/// Parse a positive count for `option`
+fn parse_count(option: &str, value: &str) -> Result<usize> {
+    match value.parse() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => bail!("{option} expects a positive number, got {value:?}"),
+    }
+}
+
+impl CliArgs {
+    /// Parse arguments (excluding program name)
+    ///
+    /// The `-psn_*` argument macOS passes to apps launched from Finder and
+    /// positional arguments are ignored.
+    ///
+    /// # Errors
+    /// Returns error if an option is unknown, missing its value, or a date is invalid
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            let mut value = || {
+                args.next()
+                    .map(|v| v.as_ref().to_owned())
+                    .with_context(|| format!("{arg} requires a value"))
+            };
+            match arg {
+                "--quiet" | "-q" => cli.quiet = true,
+                "--dry-run" => cli.dry_run = true,
+                "--stats" => cli.stats = true,
+                "--srt" => cli.srt = true,
+                "--list-devices" => cli.list_devices = true,
+                "--check-config" => cli.check_config = true,
+                "--transcribe-dir" => cli.transcribe_dir = Some(PathBuf::from(value()?)),
+                "--out" => cli.out = Some(PathBuf::from(value()?)),
+                "--model" => {
+                    cli.model = Some(ModelType::from_str(&value()?).map_err(anyhow::Error::msg)?);
+                }
+                "--hotkey" => cli.hotkey = Some(HotkeyOverride::parse(&value()?)?),
+                "--threads" => cli.threads = Some(parse_count(arg, &value()?)?),
+                "--beam-size" => cli.beam_size = Some(parse_count(arg, &value()?)?),
+                "--language" => cli.language = Some(value()?),
+                "--preload" => cli.preload = Some(true),
+                "--no-preload" => cli.preload = Some(false),
+                "--search" => {
+                    cli.search.get_or_insert_with(HistoryQuery::default).text = Some(value()?);
+                }
+                "--since" => {
+                    cli.search.get_or_insert_with(HistoryQuery::default).since =
+                        Some(history::parse_date(&value()?)?);
+                }
+                "--until" => {
+                    // Inclusive on the command line: include the whole `--until` day
+                    cli.search.get_or_insert_with(HistoryQuery::default).until =
+                        Some(history::parse_date(&value()?)? + 86_400);
+                }
+                other if other.starts_with("-psn_") || !other.starts_with('-') => {
+                    tracing::debug!(arg = other, "ignoring unknown argument");
+                }
+                // A typo like `--dry-rn` must not run the app for real
+                other => bail!("unknown option {other:?}"),
+            }
+        }
+        Ok(cli)
+    }
+
+    /// Parse the current process arguments
+    ///
+    /// # Errors
+    /// Returns error if arguments are invalid
+    pub fn from_env() -> Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+}
+
+/// Apply command-line overrides on top of the loaded config
+///
+/// Precedence is command line > config file > defaults. Model, threads, beam
+/// size, language and preload apply to every profile; the hotkey only to the
+/// first profile, since profiles need distinct hotkeys.
+pub fn apply_overrides(config: &mut Config, args: &CliArgs) {
+    for profile in &mut config.profiles {
+        if let Some(model) = args.model {
+            profile.model_type = model;
+        }
+        if let Some(threads) = args.threads {
+            profile.threads = threads;
+        }
+        if let Some(beam_size) = args.beam_size {
+            profile.beam_size = beam_size;
+        }
+        if let Some(language) = &args.language {
+            profile.language = (language != "auto").then(|| language.clone());
+        }
+        if let Some(preload) = args.preload {
+            profile.preload = preload;
+        }
+    }
+    if let (Some(hotkey), Some(profile)) = (&args.hotkey, config.profiles.first_mut()) {
+        profile.hotkey.modifiers.clone_from(&hotkey.modifiers);
+        profile.hotkey.key.clone_from(&hotkey.key);
+    }
+}
+
+/// One summary line for `profile` (pure)
+fn describe_profile(profile: &TranscriptionProfile) -> String {
+    let mode = match profile.hotkey.mode {
+        HotkeyMode::Hold => "hold",
+        HotkeyMode::Toggle => "toggle",
+    };
+    let model = profile
+        .custom_path
+        .as_ref()
+        .map_or_else(|| profile.model_type.as_str().to_owned(), Clone::clone);
+    let mut line = format!(
+        "- {}: {} ({mode}), model {model}, language {}, {} threads, beam {}, {}",
+        profile.name(),
+        profile.hotkey.combo(),
+        profile.language.as_deref().unwrap_or("auto"),
+        profile.threads,
+        profile.beam_size,
+        if profile.preload { "preloaded" } else { "lazy" }
+    );
+    if profile.mode == ProfileMode::Command {
+        line.push_str(", command mode");
+    }
+    if let Some(target) = profile.translation_target() {
+        line.push_str(" → ");
+        line.push_str(target);
+    }
+    line
+}
+
+/// Human-readable summary of the profiles in `config` (pure)
+#[must_use]
+pub fn describe_config(config: &Config) -> String {
+    let count = config.profiles.len();
+    let mut summary = format!("{count} profile{}:\n", if count == 1 { "" } else { "s" });
+    for profile in &config.profiles {
+        summary.push_str(&describe_profile(profile));
+        summary.push('\n');
+    }
+    summary
+}
+
+/// Print a summary of the loaded (and validated) config to stdout
+///
+/// # Errors
+/// Returns error if writing to stdout fails
+pub fn run_check_config(config: &Config) -> Result<()> {
+    let mut out = std::io::stdout().lock();
+    writeln!(out, "✓ Config OK")?;
+    write!(out, "{}", describe_config(config))?;
+    Ok(())
+}
+
+/// Print history entries matching `query` to stdout
+///
+/// # Errors
+/// Returns error if the history file can't be read
+pub fn run_search(config: &HistoryConfig, query: &HistoryQuery) -> Result<()> {
+    let path = crate::config::Config::expand_path(&config.path)?;
+    let entries = history::load(&path)?;
+    if entries.is_empty() && !config.enabled {
+        let _ = writeln!(
+            std::io::stderr(),
+            "History is empty - enable it with [history] enabled = true"
+        );
+    }
+    let matches = history::search(&entries, query);
+    write_search_results(&mut std::io::stdout(), &matches).context("failed to print results")
+}
+
+/// Print the per-profile usage breakdown to stdout
+///
+/// # Errors
+/// Returns error if the stats file can't be read
+pub fn run_stats(config: &StatsConfig) -> Result<()> {
+    let path = crate::config::Config::expand_path(&config.path)?;
+    let stats = crate::stats::load(&path)?;
+    if stats.profiles.is_empty() && !config.enabled {
+        let _ = writeln!(
+            std::io::stderr(),
+            "No stats recorded - enable them with [stats] enabled = true"
+        );
+    }
+    write!(std::io::stdout(), "{}", stats.report()).context("failed to print stats")
+}
+
+/// Print the input device names usable as `[audio] device_name`
+///
+/// # Errors
+/// Returns error if the devices can't be listed
+pub fn run_list_devices() -> Result<()> {
+    let names = crate::audio::list_input_devices()?;
+    if names.is_empty() {
+        let _ = writeln!(std::io::stderr(), "No input devices found");
+    }
+    let mut out = std::io::stdout();
+    for name in names {
+        writeln!(out, "{name}").context("failed to print devices")?;
+    }
+    Ok(())
+}
+
+/// Transcribe every supported audio file in `dir` with the first profile, then print a summary
+///
+/// Writes `<name>.txt` (and `<name>.srt` with `srt`) into `out` or `dir`.
+/// A file that fails is reported and skipped; the others are still transcribed.
+///
+/// # Errors
+/// Returns error if the directory can't be read, the model can't be loaded, or any file failed
+pub fn run_transcribe_dir(
+    config: &Config,
+    dir: &Path,
+    out: Option<&Path>,
+    srt: bool,
+) -> Result<()> {
+    let out_dir = out.unwrap_or(dir);
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("failed to create {}", out_dir.display()))?;
+    let plan = batch::scan_dir(dir, out_dir, srt)?;
+    let mut stdout = std::io::stdout();
+    if plan.jobs.is_empty() {
+        let _ = writeln!(stdout, "No supported audio files in {}", dir.display());
+        return Ok(());
+    }
+
+    let profile = config
+        .profiles
+        .first()
+        .context("config has no profiles to transcribe with")?;
+    let model = crate::transcription::ensure_profile_model(profile, &config.download, false)?;
+    let engine = crate::transcription::TranscriptionEngine::for_profile(
+        profile,
+        &config.model,
+        &model.path,
+    )?;
+
+    let summary = batch::run_batch(
+        &plan,
+        |samples| {
+            let text = engine.transcribe(samples)?;
+            Ok(crate::alias::apply_aliases(&text, &config.aliases))
+        },
+        |index, total, job| {
+            let _ = writeln!(stdout, "[{index}/{total}] {}", job.input.display());
+        },
+    );
+    let _ = write!(stdout, "{}", summary.report());
+    if !summary.all_succeeded() {
+        anyhow::bail!("{} file(s) failed to transcribe", summary.failed.len());
+    }
+    Ok(())
+}
+
+/// Write one `timestamp [profile] text` line per entry
+fn write_search_results(out: &mut impl Write, entries: &[&HistoryEntry]) -> std::io::Result<()> {
+    for entry in entries {
+        writeln!(
+            out,
+            "{} [{}] {}",
+            history::format_timestamp(entry.timestamp),
+            entry.profile,
+            entry.text
+        )?;
+    }
+    Ok(())
+}
+
+/// Print a startup status line to stdout unless `quiet`
+///
+/// Only for decorative progress output - errors go to stderr and details to the tracing log.
+pub fn status_print(quiet: bool, msg: &str) {
+    // Closed stdout (e.g. under a supervisor) must not abort startup
+    let _ = write_status(&mut std::io::stdout(), quiet, msg);
+}
+
+/// Write `msg` as a line to `out` unless `quiet`
+fn write_status(out: &mut impl Write, quiet: bool, msg: &str) -> std::io::Result<()> {
+    if quiet {
+        return Ok(());
+    }
+    writeln!(out, "{msg}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_no_args() {
+        assert_eq!(
+            CliArgs::parse(Vec::<String>::new()).unwrap(),
+            CliArgs::default()
+        );
+    }
+
+    #[test]
+    fn test_parse_quiet() {
+        assert!(CliArgs::parse(["--quiet"]).unwrap().quiet);
+        assert!(CliArgs::parse(["-q"]).unwrap().quiet);
+    }
+
+    #[test]
+    fn test_parse_dry_run() {
+        let cli = CliArgs::parse(["--dry-run", "-q"]).unwrap();
+        assert!(cli.dry_run);
+        assert!(cli.quiet);
+        assert!(!CliArgs::parse(["-q"]).unwrap().dry_run);
+    }
+
+    #[test]
+    fn test_parse_stats() {
+        assert!(CliArgs::parse(["--stats"]).unwrap().stats);
+        assert!(!CliArgs::parse(["-q"]).unwrap().stats);
+    }
+
+    #[test]
+    fn test_parse_list_devices() {
+        assert!(CliArgs::parse(["--list-devices"]).unwrap().list_devices);
+        assert!(!CliArgs::parse(["--stats"]).unwrap().list_devices);
+    }
+
+    #[test]
+    fn test_parse_transcribe_dir() {
+        let cli =
+            CliArgs::parse(["--transcribe-dir", "memos/", "--out", "text/", "--srt"]).unwrap();
+        assert_eq!(cli.transcribe_dir, Some(PathBuf::from("memos/")));
+        assert_eq!(cli.out, Some(PathBuf::from("text/")));
+        assert!(cli.srt);
+
+        let cli = CliArgs::parse(["--transcribe-dir", "memos/"]).unwrap();
+        assert_eq!(cli.out, None);
+        assert!(!cli.srt);
+        assert!(CliArgs::parse(["--transcribe-dir"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_overrides() {
+        let cli = CliArgs::parse([
+            "--model",
+            "small.en",
+            "--hotkey",
+            "Control+Option+Z",
+            "--threads",
+            "8",
+            "--beam-size",
+            "5",
+            "--language",
+            "de",
+            "--no-preload",
+        ])
+        .unwrap();
+        assert_eq!(cli.model, Some(ModelType::SmallEn));
+        assert_eq!(
+            cli.hotkey,
+            Some(HotkeyOverride {
+                modifiers: vec!["Control".to_owned(), "Option".to_owned()],
+                key: "Z".to_owned(),
+            })
+        );
+        assert_eq!(cli.threads, Some(8));
+        assert_eq!(cli.beam_size, Some(5));
+        assert_eq!(cli.language.as_deref(), Some("de"));
+        assert_eq!(cli.preload, Some(false));
+
+        let cli = CliArgs::parse(["--language", "auto", "--preload"]).unwrap();
+        assert_eq!(cli.language.as_deref(), Some("auto"));
+        assert_eq!(cli.preload, Some(true));
+        assert_eq!(cli.model, None);
+    }
+
+    #[test]
+    fn test_parse_override_errors() {
+        assert!(CliArgs::parse(["--model", "huge"]).is_err());
+        assert!(CliArgs::parse(["--threads", "0"]).is_err());
+        assert!(CliArgs::parse(["--beam-size", "many"]).is_err());
+        assert!(CliArgs::parse(["--hotkey", "Control+"]).is_err());
+        assert!(CliArgs::parse(["--model"]).is_err());
+    }
+
+    #[test]
+    fn test_hotkey_override_parse() {
+        let hotkey = HotkeyOverride::parse("F13").unwrap();
+        assert!(hotkey.modifiers.is_empty());
+        assert_eq!(hotkey.key, "F13");
+        let hotkey = HotkeyOverride::parse(" Command + Shift + V ").unwrap();
+        assert_eq!(hotkey.modifiers, ["Command", "Shift"]);
+        assert_eq!(hotkey.key, "V");
+        assert!(HotkeyOverride::parse("").is_err());
+        assert!(HotkeyOverride::parse("Control++Z").is_err());
+    }
+
+    fn two_profiles() -> Config {
+        let mut config = Config::default();
+        let mut second = config.profiles[0].clone();
+        second.hotkey.key = "X".to_owned();
+        config.profiles.push(second);
+        config
+    }
+
+    #[test]
+    fn test_apply_overrides_none_keeps_config() {
+        let mut config = two_profiles();
+        apply_overrides(&mut config, &CliArgs::default());
+        let profile = &config.profiles[0];
+        assert_eq!(profile.model_type, ModelType::BaseEn);
+        assert_eq!((profile.threads, profile.beam_size), (4, 1));
+        assert_eq!(profile.language.as_deref(), Some("en"));
+        assert!(profile.preload);
+        assert_eq!(profile.hotkey.key, "Z");
+        assert_eq!(config.profiles[1].hotkey.key, "X");
+    }
+
+    #[test]
+    fn test_apply_model_override() {
+        let mut config = two_profiles();
+        let args = CliArgs {
+            model: Some(ModelType::SmallEn),
+            ..CliArgs::default()
+        };
+        apply_overrides(&mut config, &args);
+        assert!(config
+            .profiles
+            .iter()
+            .all(|profile| profile.model_type == ModelType::SmallEn));
+    }
+
+    #[test]
+    fn test_apply_hotkey_override_first_profile_only() {
+        let mut config = two_profiles();
+        let args = CliArgs {
+            hotkey: Some(HotkeyOverride::parse("Command+Shift+D").unwrap()),
+            ..CliArgs::default()
+        };
+        apply_overrides(&mut config, &args);
+        assert_eq!(config.profiles[0].hotkey.modifiers, ["Command", "Shift"]);
+        assert_eq!(config.profiles[0].hotkey.key, "D");
+        assert_eq!(config.profiles[1].hotkey.key, "X");
+    }
+
+    #[test]
+    fn test_apply_threads_and_beam_size_overrides() {
+        let mut config = two_profiles();
+        let args = CliArgs {
+            threads: Some(8),
+            beam_size: Some(5),
+            ..CliArgs::default()
+        };
+        apply_overrides(&mut config, &args);
+        for profile in &config.profiles {
+            assert_eq!(profile.threads, 8);
+            assert_eq!(profile.beam_size, 5);
+        }
+    }
+
+    #[test]
+    fn test_apply_language_override() {
+        let mut config = two_profiles();
+        let args = CliArgs {
+            language: Some("nl".to_owned()),
+            ..CliArgs::default()
+        };
+        apply_overrides(&mut config, &args);
+        assert_eq!(config.profiles[1].language.as_deref(), Some("nl"));
+
+        let args = CliArgs {
+            language: Some("auto".to_owned()),
+            ..CliArgs::default()
+        };
+        apply_overrides(&mut config, &args);
+        assert!(config
+            .profiles
+            .iter()
+            .all(|profile| profile.language.is_none()));
+    }
+
+    #[test]
+    fn test_apply_preload_override() {
+        let mut config = two_profiles();
+        assert!(config.profiles[0].preload);
+        let args = CliArgs {
+            preload: Some(false),
+            ..CliArgs::default()
+        };
+        apply_overrides(&mut config, &args);
+        assert!(config.profiles.iter().all(|profile| !profile.preload));
+    }
+
+    #[test]
+    fn test_parse_check_config() {
+        assert!(CliArgs::parse(["--check-config"]).unwrap().check_config);
+        assert!(!CliArgs::parse(["--stats"]).unwrap().check_config);
+    }
+
+    #[test]
+    fn test_describe_config_default() {
+        assert_eq!(
+            describe_config(&Config::default()),
+            "1 profile:\n\
+             - base.en: Control+Option+Z (hold), model base.en, language en, 4 threads, beam 1, preloaded\n"
+        );
+    }
+
+    #[test]
+    fn test_describe_config_multiple_profiles() {
+        let mut config = two_profiles();
+        config.profiles[0].name = Some("Dictation".to_owned());
+        let notes = &mut config.profiles[1];
+        notes.name = Some("Notes".to_owned());
+        notes.hotkey = crate::config::HotkeyConfig {
+            modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
+            key: "N".to_owned(),
+            mode: HotkeyMode::Toggle,
+        };
+        notes.model_type = ModelType::SmallEn;
+        notes.language = None;
+        notes.beam_size = 5;
+        notes.preload = false;
+        notes.translate_to = Some("de".to_owned());
+        config.profiles.push(TranscriptionProfile {
+            name: Some("Commands".to_owned()),
+            custom_path: Some("~/models/tuned.bin".to_owned()),
+            mode: ProfileMode::Command,
+            hotkey: crate::config::HotkeyConfig {
+                modifiers: Vec::new(),
+                key: "F13".to_owned(),
+                mode: HotkeyMode::Hold,
+            },
+            ..config.profiles[0].clone()
+        });
+
+        let summary = describe_config(&config);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "3 profiles:",
+                "- Dictation: Control+Option+Z (hold), model base.en, language en, 4 threads, beam 1, preloaded",
+                "- Notes: Command+Shift+N (toggle), model small.en, language auto, 4 threads, beam 5, lazy → de",
+                "- Commands: F13 (hold), model ~/models/tuned.bin, language en, 4 threads, beam 1, preloaded, command mode",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_process_serial_number() {
+        let cli = CliArgs::parse(["-psn_0_12345", "--quiet"]).unwrap();
+        assert!(cli.quiet);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_option() {
+        let err = CliArgs::parse(["--dry-rn"]).unwrap_err();
+        assert!(err.to_string().contains("--dry-rn"));
+        assert!(CliArgs::parse(["-x"]).is_err());
+        assert!(CliArgs::parse(["--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_search() {
+        let cli = CliArgs::parse(["--search", "budget"]).unwrap();
+        assert_eq!(
+            cli.search,
+            Some(HistoryQuery {
+                text: Some("budget".to_owned()),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_search_date_range() {
+        let cli = CliArgs::parse(["--since", "2025-01-10", "--until", "2025-01-15"]).unwrap();
+        let query = cli.search.unwrap();
+        assert_eq!(query.text, None);
+        assert_eq!(
+            query.since,
+            Some(history::parse_date("2025-01-10").unwrap())
+        );
+        // --until day is included
+        assert_eq!(
+            query.until,
+            Some(history::parse_date("2025-01-16").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_search_errors() {
+        assert!(CliArgs::parse(["--search"]).is_err());
+        assert!(CliArgs::parse(["--since", "last week"]).is_err());
+    }
+
+    #[test]
+    fn test_write_search_results() {
+        let entry = HistoryEntry {
+            timestamp: 0,
+            profile: "default".to_owned(),
+            text: "hello".to_owned(),
+        };
+        let mut out = Vec::new();
+        write_search_results(&mut out, &[&entry]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1970-01-01 00:00 UTC [default] hello\n"
+        );
+    }
+
+    #[test]
+    fn test_write_status_quiet_suppresses() {
+        let mut out = Vec::new();
+        write_status(&mut out, true, "✓ Config loaded").unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_write_status_prints_when_not_quiet() {
+        let mut out = Vec::new();
+        write_status(&mut out, false, "✓ Config loaded").unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "✓ Config loaded\n");
+    }
+}
@@ -1,9 +1,12 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
 
+pub use crate::input::strategy::InsertionMethod;
+pub use crate::transcription::queue::OverflowPolicy;
+
 /// Whisper model type variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ModelType {
@@ -31,6 +34,36 @@ pub enum ModelType {
     LargeV2,
     /// Large model v3 (multilingual)
     LargeV3,
+    /// Tiny model (multilingual), 5-bit quantized
+    TinyQ5_1,
+    /// Tiny model (English-only), 5-bit quantized
+    TinyEnQ5_1,
+    /// Tiny model (multilingual), 8-bit quantized
+    TinyQ8_0,
+    /// Base model (multilingual), 5-bit quantized
+    BaseQ5_1,
+    /// Base model (English-only), 5-bit quantized
+    BaseEnQ5_1,
+    /// Base model (multilingual), 8-bit quantized
+    BaseQ8_0,
+    /// Small model (multilingual), 5-bit quantized
+    SmallQ5_1,
+    /// Small model (English-only), 5-bit quantized
+    SmallEnQ5_1,
+    /// Small model (multilingual), 8-bit quantized
+    SmallQ8_0,
+    /// Medium model (multilingual), 5-bit quantized
+    MediumQ5_0,
+    /// Medium model (English-only), 5-bit quantized
+    MediumEnQ5_0,
+    /// Medium model (multilingual), 8-bit quantized
+    MediumQ8_0,
+    /// Large model v2 (multilingual), 5-bit quantized
+    LargeV2Q5_0,
+    /// Large model v2 (multilingual), 8-bit quantized
+    LargeV2Q8_0,
+    /// Large model v3 (multilingual), 5-bit quantized
+    LargeV3Q5_0,
 }
 
 // Custom serde to serialize as "base.en" instead of "BaseEn"
@@ -70,26 +103,65 @@ impl ModelType {
             Self::LargeV1 => "large-v1",
             Self::LargeV2 => "large-v2",
             Self::LargeV3 => "large-v3",
+            Self::TinyQ5_1 => "tiny-q5_1",
+            Self::TinyEnQ5_1 => "tiny.en-q5_1",
+            Self::TinyQ8_0 => "tiny-q8_0",
+            Self::BaseQ5_1 => "base-q5_1",
+            Self::BaseEnQ5_1 => "base.en-q5_1",
+            Self::BaseQ8_0 => "base-q8_0",
+            Self::SmallQ5_1 => "small-q5_1",
+            Self::SmallEnQ5_1 => "small.en-q5_1",
+            Self::SmallQ8_0 => "small-q8_0",
+            Self::MediumQ5_0 => "medium-q5_0",
+            Self::MediumEnQ5_0 => "medium.en-q5_0",
+            Self::MediumQ8_0 => "medium-q8_0",
+            Self::LargeV2Q5_0 => "large-v2-q5_0",
+            Self::LargeV2Q8_0 => "large-v2-q8_0",
+            Self::LargeV3Q5_0 => "large-v3-q5_0",
         }
     }
 
+    /// Every model type, full-precision models first
+    #[must_use]
+    pub const fn variants() -> &'static [Self] {
+        &[
+            Self::Tiny,
+            Self::TinyEn,
+            Self::Base,
+            Self::BaseEn,
+            Self::Small,
+            Self::SmallEn,
+            Self::Medium,
+            Self::MediumEn,
+            Self::Large,
+            Self::LargeV1,
+            Self::LargeV2,
+            Self::LargeV3,
+            Self::TinyQ5_1,
+            Self::TinyEnQ5_1,
+            Self::TinyQ8_0,
+            Self::BaseQ5_1,
+            Self::BaseEnQ5_1,
+            Self::BaseQ8_0,
+            Self::SmallQ5_1,
+            Self::SmallEnQ5_1,
+            Self::SmallQ8_0,
+            Self::MediumQ5_0,
+            Self::MediumEnQ5_0,
+            Self::MediumQ8_0,
+            Self::LargeV2Q5_0,
+            Self::LargeV2Q8_0,
+            Self::LargeV3Q5_0,
+        ]
+    }
+
     /// Parse model type from string (e.g., "base.en" -> `BaseEn`)
-    fn from_str(s: &str) -> Result<Self, String> {
-        match s {
-            "tiny" => Ok(Self::Tiny),
-            "tiny.en" => Ok(Self::TinyEn),
-            "base" => Ok(Self::Base),
-            "base.en" => Ok(Self::BaseEn),
-            "small" => Ok(Self::Small),
-            "small.en" => Ok(Self::SmallEn),
-            "medium" => Ok(Self::Medium),
-            "medium.en" => Ok(Self::MediumEn),
-            "large" => Ok(Self::Large),
-            "large-v1" => Ok(Self::LargeV1),
-            "large-v2" => Ok(Self::LargeV2),
-            "large-v3" => Ok(Self::LargeV3),
-            _ => Err(format!("unknown model type: {s}")),
-        }
+    pub(crate) fn from_str(s: &str) -> Result<Self, String> {
+        Self::variants()
+            .iter()
+            .find(|model| model.as_str() == s)
+            .copied()
+            .ok_or_else(|| format!("unknown model type: {s}"))
     }
 
     /// Get model name for `HuggingFace` download (same as `as_str`)
@@ -118,11 +190,25 @@ fn is_default_hotkey(val: &HotkeyConfig) -> bool {
         && val.modifiers[0] == "Control"
         && val.modifiers[1] == "Option"
         && val.key == "Z"
+        && val.mode == HotkeyMode::Hold
 }
 
 fn is_default_audio(val: &AudioConfig) -> bool {
     val.buffer_size == AudioConfig::default().buffer_size
         && val.sample_rate == AudioConfig::default().sample_rate
+        && (val.clipping_threshold - default_clipping_threshold()).abs() < f64::EPSILON
+        && !val.continuous_drain
+        && !val.resampler_auto
+        && val.resampler_threshold_ms == default_resampler_threshold_ms()
+        && val.max_recording_secs == default_max_recording_secs()
+        && val.device_name.is_none()
+        && val.preroll_ms == 0
+        && !val.trim_silence
+        && (val.silence_threshold - default_silence_threshold()).abs() < f32::EPSILON
+        && val.trim_attack_ms == default_trim_attack_ms()
+        && val.trim_release_ms == default_trim_release_ms()
+        && !val.normalize
+        && (val.target_peak - default_target_peak()).abs() < f32::EPSILON
 }
 
 fn is_default_model(val: &ModelConfig) -> bool {
@@ -131,10 +217,21 @@ fn is_default_model(val: &ModelConfig) -> bool {
         && val.threads == 4
         && val.beam_size == 1
         && val.language.as_deref() == Some("en")
+        && val.custom_path.is_none()
+        && val.max_loaded_models == 0
+        && (val.no_speech_threshold - default_no_speech_threshold()).abs() < f32::EPSILON
+        && val.use_gpu == default_use_gpu()
+        && val.warmup
 }
 
 fn is_default_telemetry(val: &TelemetryConfig) -> bool {
-    val.enabled && val.log_path == "~/.whisper-hotkey/crash.log"
+    val.enabled
+        && val.log_path == "~/.whisper-hotkey/crash.log"
+        && !val.timing_debug
+        && !val.audio_stats_debug
+        && val.log_max_mb == default_log_max_mb()
+        && val.log_keep_files == default_log_keep_files()
+        && val.format == LogFormat::Text
 }
 
 fn is_default_recording(val: &RecordingConfig) -> bool {
@@ -142,12 +239,106 @@ fn is_default_recording(val: &RecordingConfig) -> bool {
     val.enabled == default.enabled
         && val.retention_days == default.retention_days
         && val.max_count == default.max_count
+        && val.max_total_mb == default.max_total_mb
         && val.cleanup_interval_hours == default.cleanup_interval_hours
+        && val.use_trash == default.use_trash
+        && val.degraded_behavior == default.degraded_behavior
+        && val.queue_press_during_processing == default.queue_press_during_processing
+        && val.cancel_on_escape == default.cancel_on_escape
+        && val.cancel_key == default.cancel_key
+        && val.cancel_modifiers == default.cancel_modifiers
+        && val.wav_format == default.wav_format
 }
 
 #[allow(clippy::float_cmp)]
 fn is_default_aliases(val: &AliasesConfig) -> bool {
-    val.enabled && val.threshold == 0.8 && val.entries.is_empty()
+    val.enabled
+        && val.threshold == 0.8
+        && val.entries.is_empty()
+        && val.import_path.is_none()
+        && !val.preserve_case
+        && val.case_insensitive
+        && val.patterns.is_empty()
+}
+
+fn is_default_output(val: &OutputConfig) -> bool {
+    !val.insert_original && val.combine_template == OutputConfig::default().combine_template
+}
+
+fn is_default_download(val: &DownloadConfig) -> bool {
+    let default = DownloadConfig::default();
+    val.connect_timeout_secs == default.connect_timeout_secs
+        && val.read_timeout_secs == default.read_timeout_secs
+        && val.deadline_secs == default.deadline_secs
+        && val.bootstrap == default.bootstrap
+        && val.bootstrap_model == default.bootstrap_model
+        && val.model_base_url.is_none()
+}
+
+fn is_default_insertion(val: &InsertionConfig) -> bool {
+    val.min_gap_ms == default_min_gap_ms()
+        && val.methods == default_insertion_methods()
+        && !val.verify
+        && val.dedupe_window_ms == 0
+        && !val.prime_insertion
+        && val.revoke_after_failures == default_revoke_after_failures()
+        && val.cursor_placeholder.is_none()
+        && val.restore_clipboard
+        && !val.append_space
+        && !val.append_newline
+        && val.chunk_size == default_chunk_size()
+        && val.chunk_delay_ms == default_chunk_delay_ms()
+}
+
+const fn is_default_app(val: &AppConfig) -> bool {
+    !val.show_dock_icon && !val.defer_permissions && !val.quiet && !val.notifications
+}
+
+const fn is_default_formatting(val: &FormattingConfig) -> bool {
+    val.locale.is_none()
+}
+
+fn is_default_queue(val: &QueueConfig) -> bool {
+    !val.enabled
+        && val.max_depth == default_queue_max_depth()
+        && val.overflow == OverflowPolicy::default()
+}
+
+const fn is_default_translation(val: &TranslationConfig) -> bool {
+    val.command.is_none()
+}
+
+fn is_default_history(val: &HistoryConfig) -> bool {
+    !val.enabled
+        && val.path == default_history_path()
+        && val.max_entries == default_history_max_entries()
+}
+
+const fn is_default_tray(val: &TrayConfig) -> bool {
+    !val.animate_recording
+}
+
+const fn is_default_latency(val: &LatencyConfig) -> bool {
+    val.max_inference_ms == 0
+        && val.recover_inference_ms == 0
+        && val.window == default_latency_window()
+}
+
+fn is_default_stats(val: &StatsConfig) -> bool {
+    let default = StatsConfig::default();
+    val.enabled == default.enabled && val.path == default.path
+}
+
+fn is_default_webhook(val: &WebhookConfig) -> bool {
+    *val == WebhookConfig::default()
+}
+
+fn is_default_quick_pick(val: &QuickPickConfig) -> bool {
+    *val == QuickPickConfig::default()
+}
+
+fn is_default_auto_punctuation(val: &AutoPunctuationConfig) -> bool {
+    *val == AutoPunctuationConfig::default()
 }
 
 fn is_default_profiles(val: &[TranscriptionProfile]) -> bool {
@@ -162,10 +353,18 @@ fn is_default_profiles(val: &[TranscriptionProfile]) -> bool {
         && profile.threads == 4
         && profile.beam_size == 1
         && profile.language.as_deref() == Some("en")
+        && profile.custom_path.is_none()
+        && profile.mode == ProfileMode::Dictation
+        && !profile.preserve_whitespace
+        && profile.fallback_model.is_none()
+        && profile.discard_modifier.is_none()
+        && !profile.flash_attn
+        && profile.audio.is_none()
 }
 
 /// Transcription profile combining hotkey and model configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[allow(clippy::struct_excessive_bools)] // Independent TOML on/off options
 pub struct TranscriptionProfile {
     /// Optional explicit profile name (auto-generated if multiple profiles share same `model_type`)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -187,6 +386,42 @@ pub struct TranscriptionProfile {
     /// Language code (None = auto-detect)
     #[serde(default = "default_language")]
     pub language: Option<String>,
+    /// Select all and delete before inserting (replaces focused field's contents)
+    #[serde(default)]
+    pub clear_before_insert: bool,
+    /// Strip markdown wrapping (code fences, backticks, emphasis) around transcriptions
+    #[serde(default)]
+    pub strip_markdown: bool,
+    /// Translate output to this language ("en" = Whisper native, others via `[translation] command`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translate_to: Option<String>,
+    /// Translate speech in any language to English with Whisper (same as `translate_to = "en"`)
+    #[serde(default)]
+    pub translate: bool,
+    /// Text Whisper is primed with to bias it toward domain vocabulary and spelling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_prompt: Option<String>,
+    /// Custom ggml model file (overrides `model_type` path, never downloaded)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_path: Option<String>,
+    /// Free-text dictation or strict command matching against aliases
+    #[serde(default)]
+    pub mode: ProfileMode,
+    /// Keep leading/trailing whitespace of the transcription (e.g. code indentation)
+    #[serde(default)]
+    pub preserve_whitespace: bool,
+    /// Faster model to switch to while inference is too slow (see `[latency]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_model: Option<ModelType>,
+    /// Modifier that discards the recording when held at hotkey release (e.g. "Shift")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discard_modifier: Option<String>,
+    /// Use flash attention for inference (faster on supported builds)
+    #[serde(default)]
+    pub flash_attn: bool,
+    /// Audio capture settings for this profile (None = global `[audio]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<AudioConfig>,
 }
 
 impl TranscriptionProfile {
@@ -198,13 +433,31 @@ impl TranscriptionProfile {
             .unwrap_or_else(|| self.model_type.as_str())
     }
 
-    /// Get model path for this profile
+    /// Language the output is translated to (`translate = true` means English)
+    #[must_use]
+    pub fn translation_target(&self) -> Option<&str> {
+        self.translate_to
+            .as_deref()
+            .or_else(|| self.translate.then_some(crate::translation::NATIVE_TARGET))
+    }
+
+    /// Get model path for this profile (`custom_path` takes precedence over `model_type`)
     #[must_use]
     pub fn model_path(&self) -> String {
-        self.model_type.model_path()
+        self.custom_path
+            .clone()
+            .unwrap_or_else(|| self.model_type.model_path())
     }
 }
 
+/// Audio settings `profile` records with (its `[profiles.audio]`, else `global`)
+///
+/// A profile's table replaces `[audio]` as a whole rather than field by field.
+#[must_use]
+pub fn resolved_audio(profile: &TranscriptionProfile, global: &AudioConfig) -> AudioConfig {
+    profile.audio.clone().unwrap_or_else(|| global.clone())
+}
+
 /// Application configuration
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Config {
@@ -235,6 +488,123 @@ pub struct Config {
     /// Aliases configuration
     #[serde(default, skip_serializing_if = "is_default_aliases")]
     pub aliases: AliasesConfig,
+    /// Output formatting configuration
+    #[serde(default, skip_serializing_if = "is_default_output")]
+    pub output: OutputConfig,
+    /// Model download configuration
+    #[serde(default, skip_serializing_if = "is_default_download")]
+    pub download: DownloadConfig,
+    /// Text insertion configuration
+    #[serde(default, skip_serializing_if = "is_default_insertion")]
+    pub insertion: InsertionConfig,
+    /// Application behavior configuration
+    #[serde(default, skip_serializing_if = "is_default_app")]
+    pub app: AppConfig,
+    /// Locale-aware number/date formatting
+    #[serde(default, skip_serializing_if = "is_default_formatting")]
+    pub formatting: FormattingConfig,
+    /// Transcription queue configuration
+    #[serde(default, skip_serializing_if = "is_default_queue")]
+    pub queue: QueueConfig,
+    /// External translation command for profiles with `translate_to`
+    #[serde(default, skip_serializing_if = "is_default_translation")]
+    pub translation: TranslationConfig,
+    /// Transcription history (searchable with `--search`)
+    #[serde(default, skip_serializing_if = "is_default_history")]
+    pub history: HistoryConfig,
+    /// Menubar icon configuration
+    #[serde(default, skip_serializing_if = "is_default_tray")]
+    pub tray: TrayConfig,
+    /// Latency-based model fallback configuration
+    #[serde(default, skip_serializing_if = "is_default_latency")]
+    pub latency: LatencyConfig,
+    /// Per-profile usage statistics (printed with `--stats`)
+    #[serde(default, skip_serializing_if = "is_default_stats")]
+    pub stats: StatsConfig,
+    /// Completion webhook
+    #[serde(default, skip_serializing_if = "is_default_webhook")]
+    pub webhook: WebhookConfig,
+    /// Quick-pick hotkey choosing the profile for the next recording
+    #[serde(default, skip_serializing_if = "is_default_quick_pick")]
+    pub quick_pick: QuickPickConfig,
+    /// Punctuation inserted from the silence between transcribed segments
+    #[serde(default, skip_serializing_if = "is_default_auto_punctuation")]
+    pub auto_punctuation: AutoPunctuationConfig,
+    /// Output transforms per detected language
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub language_rules: Vec<LanguageRule>,
+    /// App version that last wrote the file (stamped by `save`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Layout version of the file (missing = 0), upgraded by [`Config::migrate`]
+    ///
+    /// Separate from `version`, which records the app build and may change
+    /// without the layout changing.
+    #[serde(default)]
+    pub schema_version: u32,
+}
+
+/// Version of this build, written into the config on save
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// One config layout upgrade, applied in place
+type Migration = fn(&mut Config);
+
+/// Migrations indexed by the config version they upgrade from (`MIGRATIONS[0]`: v0 → v1)
+const MIGRATIONS: &[Migration] = &[Config::migrate_v0_to_v1];
+
+/// Config layout version written by this build (one past the last migration)
+// A handful of migrations, far below u32::MAX
+#[allow(clippy::cast_possible_truncation)]
+pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// How the version recorded in a config file relates to this build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigVersion {
+    /// No version recorded: written by hand or before versions were stamped
+    Unversioned,
+    /// Written by an older version (migrations may apply)
+    Older(String),
+    /// Written by this version
+    Current,
+    /// Written by a newer version: unknown fields may be dropped on save
+    Newer(String),
+    /// Version string that isn't `major.minor.patch`
+    Unknown(String),
+}
+
+/// Parse `major.minor.patch`, ignoring any pre-release/build suffix
+fn parse_version(version: &str) -> Option<[u64; 3]> {
+    let core = version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = core.split('.').map(str::parse::<u64>);
+    let version = [
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    ];
+    parts.next().is_none().then_some(version)
+}
+
+/// Compare the version recorded in a config file with `current` (pure)
+#[must_use]
+pub fn compare_version(recorded: Option<&str>, current: &str) -> ConfigVersion {
+    let Some(recorded) = recorded else {
+        return ConfigVersion::Unversioned;
+    };
+    match (parse_version(recorded), parse_version(current)) {
+        (Some(written), Some(running)) if written < running => {
+            ConfigVersion::Older(recorded.to_owned())
+        }
+        (Some(written), Some(running)) if written > running => {
+            ConfigVersion::Newer(recorded.to_owned())
+        }
+        (Some(_), Some(_)) => ConfigVersion::Current,
+        _ => ConfigVersion::Unknown(recorded.to_owned()),
+    }
 }
 
 /// Hotkey configuration
@@ -246,6 +616,24 @@ pub struct HotkeyConfig {
     /// Main key (e.g., "V")
     #[allow(dead_code)] // Used in Phase 2
     pub key: String,
+    /// Record while held, or start/stop with separate presses
+    ///
+    /// `hotkey_mode` in TOML: profiles flatten this struct, and their `mode` is the [`ProfileMode`].
+    #[serde(default, rename = "hotkey_mode")]
+    pub mode: HotkeyMode,
+}
+
+impl HotkeyConfig {
+    /// Key combination as written on the command line, e.g. `Control+Option+Z`
+    #[must_use]
+    pub fn combo(&self) -> String {
+        self.modifiers
+            .iter()
+            .map(String::as_str)
+            .chain([self.key.as_str()])
+            .collect::<Vec<_>>()
+            .join("+")
+    }
 }
 
 impl Default for HotkeyConfig {
@@ -253,19 +641,98 @@ impl Default for HotkeyConfig {
         Self {
             modifiers: vec!["Control".to_owned(), "Option".to_owned()],
             key: "Z".to_owned(),
+            mode: HotkeyMode::Hold,
         }
     }
 }
 
+/// How the hotkey controls recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HotkeyMode {
+    /// Push-to-talk: record while the hotkey is held, transcribe on release
+    #[default]
+    Hold,
+    /// Press once to start recording, press again to stop and transcribe
+    Toggle,
+}
+
 /// Audio capture configuration
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)] // Independent TOML on/off options
 pub struct AudioConfig {
-    /// Ring buffer size in samples
-    #[allow(dead_code)] // Used in Phase 3
+    /// Audio callback buffer size in frames (0 = device default)
     pub buffer_size: usize,
     /// Sample rate in Hz
     #[allow(dead_code)] // Used in Phase 3
     pub sample_rate: u32,
+    /// Warn when more than this fraction of samples is clipped (0 = disabled)
+    #[serde(default = "default_clipping_threshold")]
+    pub clipping_threshold: f64,
+    /// Move samples out of the ring buffer on a background thread while recording
+    #[serde(default)]
+    pub continuous_drain: bool,
+    /// Resample short recordings with linear interpolation and long ones with sinc
+    #[serde(default)]
+    pub resampler_auto: bool,
+    /// Recording length (ms) from which `resampler_auto` switches to sinc
+    #[serde(default = "default_resampler_threshold_ms")]
+    pub resampler_threshold_ms: u64,
+    /// Longest recording (seconds) the capture ring buffer holds
+    #[serde(default = "default_max_recording_secs")]
+    pub max_recording_secs: usize,
+    /// Input device to record from, matched case-insensitively by name (None = system default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// Audio (ms) from just before the hotkey press prepended to each recording (0 = off)
+    #[serde(default)]
+    pub preroll_ms: u64,
+    /// Strip leading and trailing silence before transcription
+    #[serde(default)]
+    pub trim_silence: bool,
+    /// RMS level from which audio counts as speech when trimming
+    #[serde(default = "default_silence_threshold")]
+    pub silence_threshold: f32,
+    /// Audio (ms) kept before the first speech when trimming
+    #[serde(default = "default_trim_attack_ms")]
+    pub trim_attack_ms: u64,
+    /// Audio (ms) kept after the last speech when trimming
+    #[serde(default = "default_trim_release_ms")]
+    pub trim_release_ms: u64,
+    /// Scale each recording so its peak reaches `target_peak` before transcription
+    #[serde(default)]
+    pub normalize: bool,
+    /// Peak level (1.0 = full scale) recordings are scaled to when normalizing
+    #[serde(default = "default_target_peak")]
+    pub target_peak: f32,
+}
+
+const fn default_clipping_threshold() -> f64 {
+    0.01 // 1% of samples at full scale
+}
+
+const fn default_resampler_threshold_ms() -> u64 {
+    5000
+}
+
+const fn default_max_recording_secs() -> usize {
+    30
+}
+
+const fn default_silence_threshold() -> f32 {
+    0.01 // About -40 dBFS
+}
+
+const fn default_trim_attack_ms() -> u64 {
+    100
+}
+
+const fn default_trim_release_ms() -> u64 {
+    200
+}
+
+const fn default_target_peak() -> f32 {
+    0.9 // About -1 dBFS, headroom for the resampler's ringing
 }
 
 impl Default for AudioConfig {
@@ -273,6 +740,19 @@ impl Default for AudioConfig {
         Self {
             buffer_size: 1024,
             sample_rate: 16000,
+            clipping_threshold: default_clipping_threshold(),
+            continuous_drain: false,
+            resampler_auto: false,
+            resampler_threshold_ms: default_resampler_threshold_ms(),
+            max_recording_secs: default_max_recording_secs(),
+            device_name: None,
+            preroll_ms: 0,
+            trim_silence: false,
+            silence_threshold: default_silence_threshold(),
+            trim_attack_ms: default_trim_attack_ms(),
+            trim_release_ms: default_trim_release_ms(),
+            normalize: false,
+            target_peak: default_target_peak(),
         }
     }
 }
@@ -290,6 +770,16 @@ pub struct ModelConfig {
     pub beam_size: usize,
     /// Language code (None = auto-detect)
     pub language: Option<String>,
+    /// Custom ggml model file for profiles without their own `custom_path`
+    pub custom_path: Option<String>,
+    /// Models kept in memory at once; least recently used are evicted (0 = unlimited)
+    pub max_loaded_models: usize,
+    /// Segments Whisper rates more likely than this to be non-speech are dropped (1.0 = keep all)
+    pub no_speech_threshold: f32,
+    /// Run inference on the GPU (Metal) when available
+    pub use_gpu: bool,
+    /// Transcribe a short silent buffer after preloading, so the first real use is fast
+    pub warmup: bool,
 }
 
 // Helper struct for deserializing old config format
@@ -310,6 +800,28 @@ struct ModelConfigHelper {
     beam_size: usize,
     #[serde(default = "default_language")]
     language: Option<String>,
+    #[serde(default)]
+    custom_path: Option<String>,
+    #[serde(default)]
+    max_loaded_models: usize,
+    #[serde(default = "default_no_speech_threshold")]
+    no_speech_threshold: f32,
+    #[serde(default = "default_use_gpu")]
+    use_gpu: bool,
+    #[serde(default = "default_warmup")]
+    warmup: bool,
+}
+
+const fn default_no_speech_threshold() -> f32 {
+    crate::transcription::engine::DEFAULT_NO_SPEECH_THRESHOLD
+}
+
+const fn default_use_gpu() -> bool {
+    cfg!(all(target_arch = "aarch64", target_os = "macos")) // Metal on Apple Silicon
+}
+
+const fn default_warmup() -> bool {
+    true
 }
 
 const fn default_preload() -> bool {
@@ -338,6 +850,18 @@ fn default_profiles() -> Vec<TranscriptionProfile> {
         threads: default_threads(),
         beam_size: default_beam_size(),
         language: default_language(),
+        clear_before_insert: false,
+        strip_markdown: false,
+        translate_to: None,
+        custom_path: None,
+        mode: ProfileMode::Dictation,
+        preserve_whitespace: false,
+        fallback_model: None,
+        discard_modifier: None,
+        flash_attn: false,
+        translate: false,
+        initial_prompt: None,
+        audio: None,
     }]
 }
 
@@ -364,6 +888,11 @@ impl<'de> Deserialize<'de> for ModelConfig {
             threads: helper.threads,
             beam_size: helper.beam_size,
             language: helper.language,
+            custom_path: helper.custom_path,
+            max_loaded_models: helper.max_loaded_models,
+            no_speech_threshold: helper.no_speech_threshold,
+            use_gpu: helper.use_gpu,
+            warmup: helper.warmup,
         })
     }
 }
@@ -374,12 +903,35 @@ impl Serialize for ModelConfig {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("ModelConfig", 5)?;
+        let custom_threshold =
+            (self.no_speech_threshold - default_no_speech_threshold()).abs() >= f32::EPSILON;
+        let len = 5
+            + usize::from(self.custom_path.is_some())
+            + usize::from(self.max_loaded_models != 0)
+            + usize::from(custom_threshold)
+            + usize::from(self.use_gpu != default_use_gpu())
+            + usize::from(!self.warmup);
+        let mut state = serializer.serialize_struct("ModelConfig", len)?;
         state.serialize_field("model_type", &self.model_type)?;
         state.serialize_field("preload", &self.preload)?;
         state.serialize_field("threads", &self.threads)?;
         state.serialize_field("beam_size", &self.beam_size)?;
         state.serialize_field("language", &self.language)?;
+        if let Some(custom_path) = &self.custom_path {
+            state.serialize_field("custom_path", custom_path)?;
+        }
+        if self.max_loaded_models != 0 {
+            state.serialize_field("max_loaded_models", &self.max_loaded_models)?;
+        }
+        if custom_threshold {
+            state.serialize_field("no_speech_threshold", &self.no_speech_threshold)?;
+        }
+        if self.use_gpu != default_use_gpu() {
+            state.serialize_field("use_gpu", &self.use_gpu)?;
+        }
+        if !self.warmup {
+            state.serialize_field("warmup", &self.warmup)?;
+        }
         state.end()
     }
 }
@@ -392,6 +944,11 @@ impl Default for ModelConfig {
             threads: default_threads(),
             beam_size: default_beam_size(),
             language: default_language(),
+            custom_path: None,
+            max_loaded_models: 0,
+            no_speech_threshold: default_no_speech_threshold(),
+            use_gpu: default_use_gpu(),
+            warmup: default_warmup(),
         }
     }
 }
@@ -403,6 +960,40 @@ pub struct TelemetryConfig {
     pub enabled: bool,
     /// Path to log file
     pub log_path: String,
+    /// Log a per-stage timing breakdown for every transcription
+    #[serde(default)]
+    pub timing_debug: bool,
+    /// Log level statistics (peak, RMS, clipping, silence) for every recording
+    #[serde(default)]
+    pub audio_stats_debug: bool,
+    /// Roll the log file over once it exceeds N MB (0 = never)
+    #[serde(default = "default_log_max_mb")]
+    pub log_max_mb: u64,
+    /// Rolled-over log files to keep (`crash.log.1`, `crash.log.2`, ...)
+    #[serde(default = "default_log_keep_files")]
+    pub log_keep_files: u32,
+    /// Format of the log file lines
+    #[serde(default)]
+    pub format: LogFormat,
+}
+
+/// Format of the telemetry log file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per line (timestamp, level, target, fields)
+    Json,
+}
+
+const fn default_log_max_mb() -> u64 {
+    10
+}
+
+const fn default_log_keep_files() -> u32 {
+    3
 }
 
 impl Default for TelemetryConfig {
@@ -410,12 +1001,18 @@ impl Default for TelemetryConfig {
         Self {
             enabled: true,
             log_path: "~/.whisper-hotkey/crash.log".to_owned(),
+            timing_debug: false,
+            audio_stats_debug: false,
+            log_max_mb: default_log_max_mb(),
+            log_keep_files: default_log_keep_files(),
+            format: LogFormat::Text,
         }
     }
 }
 
 /// Recording configuration
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[allow(clippy::struct_excessive_bools)] // Independent TOML on/off options
 pub struct RecordingConfig {
     /// Enable debug recordings
     #[serde(default = "default_recording_enabled")]
@@ -426,9 +1023,68 @@ pub struct RecordingConfig {
     /// Keep only N most recent recordings (0 = unlimited)
     #[serde(default = "default_max_count")]
     pub max_count: usize,
+    /// Delete the oldest recordings once all of them take more than N MB (0 = unlimited)
+    #[serde(default)]
+    pub max_total_mb: u64,
     /// Hours between cleanup runs (0 = startup only)
     #[serde(default = "default_cleanup_interval_hours")]
     pub cleanup_interval_hours: u32,
+    /// Move cleaned-up recordings to `~/.Trash` instead of deleting them
+    #[serde(default)]
+    pub use_trash: bool,
+    /// What to do with a recording when no transcription engine is available
+    #[serde(default)]
+    pub degraded_behavior: DegradedBehavior,
+    /// Start a new recording when processing finishes if the hotkey was pressed meanwhile
+    #[serde(default)]
+    pub queue_press_during_processing: bool,
+    /// Escape (or `cancel_key`) while recording discards the audio without transcribing
+    #[serde(default = "default_cancel_on_escape")]
+    pub cancel_on_escape: bool,
+    /// Key that cancels a recording (same names as a profile's `key`)
+    #[serde(default = "default_cancel_key")]
+    pub cancel_key: String,
+    /// Modifiers held together with `cancel_key` (empty = the bare key)
+    #[serde(default)]
+    pub cancel_modifiers: Vec<String>,
+    /// Sample format of debug recordings
+    #[serde(default)]
+    pub wav_format: WavFormat,
+}
+
+/// Sample format of saved WAV recordings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WavFormat {
+    /// 32-bit float, the exact samples passed to Whisper
+    #[default]
+    F32,
+    /// 16-bit integer PCM, half the size
+    Pcm16,
+}
+
+/// How a profile turns transcriptions into output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileMode {
+    /// Insert the transcription (after aliases and formatting)
+    #[default]
+    Dictation,
+    /// Only act on exact alias matches; anything else is rejected
+    Command,
+}
+
+/// Behavior when transcription is unavailable (model not loaded or failed to load)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DegradedBehavior {
+    /// Save the recording as `untranscribed_{timestamp}.wav` for later transcription
+    Save,
+    /// Post a notification explaining that transcription is off
+    Notify,
+    /// Only log a warning
+    #[default]
+    Silent,
 }
 
 const fn default_recording_enabled() -> bool {
@@ -447,13 +1103,29 @@ const fn default_cleanup_interval_hours() -> u32 {
     1
 }
 
+const fn default_cancel_on_escape() -> bool {
+    true
+}
+
+fn default_cancel_key() -> String {
+    "Escape".to_owned()
+}
+
 impl Default for RecordingConfig {
     fn default() -> Self {
         Self {
             enabled: default_recording_enabled(),
             retention_days: default_retention_days(),
             max_count: default_max_count(),
+            max_total_mb: 0,
             cleanup_interval_hours: default_cleanup_interval_hours(),
+            use_trash: false,
+            degraded_behavior: DegradedBehavior::default(),
+            queue_press_during_processing: false,
+            cancel_on_escape: default_cancel_on_escape(),
+            cancel_key: default_cancel_key(),
+            cancel_modifiers: Vec::new(),
+            wav_format: WavFormat::default(),
         }
     }
 }
@@ -470,6 +1142,21 @@ pub struct AliasesConfig {
     /// Alias mappings (trigger phrase -> output text)
     #[serde(default)]
     pub entries: HashMap<String, String>,
+    /// CSV/TSV file of `trigger,replacement` pairs merged into `entries` at load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_path: Option<String>,
+    /// Match the replacement's casing to the spoken text (lower, Title, ALL-CAPS)
+    #[serde(default)]
+    pub preserve_case: bool,
+    /// Ignore case when finding triggers inside a sentence
+    #[serde(default = "default_case_insensitive")]
+    pub case_insensitive: bool,
+    /// Regex substitutions (pattern -> replacement) applied after literal aliases
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub patterns: BTreeMap<String, String>,
+    /// `patterns` compiled at load
+    #[serde(skip)]
+    pub compiled_patterns: Vec<crate::alias::AliasPattern>,
 }
 
 const fn default_aliases_enabled() -> bool {
@@ -480,160 +1167,722 @@ const fn default_aliases_threshold() -> f64 {
     0.8
 }
 
+const fn default_case_insensitive() -> bool {
+    true
+}
+
 impl Default for AliasesConfig {
     fn default() -> Self {
         Self {
             enabled: default_aliases_enabled(),
             threshold: default_aliases_threshold(),
             entries: HashMap::new(),
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: default_case_insensitive(),
+            patterns: BTreeMap::new(),
+            compiled_patterns: Vec::new(),
         }
     }
 }
 
-impl Default for Config {
+/// Output formatting configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OutputConfig {
+    /// Insert the original transcription together with its translation (`translate_to` via command)
+    #[serde(default)]
+    pub insert_original: bool,
+    /// Template for inserting two outputs together (`{primary}`, `{secondary}`; `{{`/`}}` escape braces)
+    #[serde(default = "default_combine_template")]
+    pub combine_template: String,
+}
+
+fn default_combine_template() -> String {
+    crate::output::DEFAULT_COMBINE_TEMPLATE.to_owned()
+}
+
+impl Default for OutputConfig {
     fn default() -> Self {
         Self {
-            profiles: default_profiles(),
-            hotkey: HotkeyConfig::default(),
-            audio: AudioConfig::default(),
-            model: ModelConfig::default(),
-            telemetry: TelemetryConfig::default(),
-            recording: RecordingConfig::default(),
-            aliases: AliasesConfig::default(),
+            insert_original: false,
+            combine_template: default_combine_template(),
         }
     }
 }
 
-impl Config {
-    /// Load config from ~/.whisper-hotkey/config.toml
-    ///
-    /// Automatically migrates from old path (~/.whisper-hotkey.toml) if found.
-    /// Creates default config if none exists.
-    ///
-    /// # Errors
-    /// Returns error if config is invalid TOML or path expansion fails
-    pub fn load() -> Result<Self> {
-        let config_path = Self::config_path()?;
-
-        // Migrate from old path if needed
-        if !config_path.exists() {
-            let old_path = Self::old_config_path()?;
-            if old_path.exists() {
-                // Ensure new directory exists before migration
-                if let Some(parent) = config_path.parent() {
-                    fs::create_dir_all(parent).context("failed to create config directory")?;
-                }
-                fs::copy(&old_path, &config_path)
-                    .context("failed to migrate config from old location")?;
-                fs::remove_file(&old_path)
-                    .context("failed to remove old config file after migration")?;
-                tracing::info!(
-                    "migrated config from {} to {} and removed old config file",
-                    old_path.display(),
-                    config_path.display()
-                );
-            }
-        }
+/// Model download configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[allow(clippy::struct_field_names)] // Unit suffix keeps TOML keys self-describing
+pub struct DownloadConfig {
+    /// Max seconds to establish a connection
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Max seconds to wait for data before treating the download as stalled
+    #[serde(default = "default_read_timeout_secs")]
+    pub read_timeout_secs: u64,
+    /// Max seconds for the whole download (0 = unlimited)
+    #[serde(default = "default_deadline_secs")]
+    pub deadline_secs: u64,
+    /// Start on `bootstrap_model` while a missing profile model downloads in the background
+    #[serde(default = "default_bootstrap")]
+    pub bootstrap: bool,
+    /// Small model used until the configured one is ready
+    #[serde(default = "default_bootstrap_model")]
+    pub bootstrap_model: ModelType,
+    /// Mirror serving `ggml-<model>.bin` files (None = Hugging Face)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_base_url: Option<String>,
+}
 
-        if !config_path.exists() {
-            Self::create_default(&config_path).context("failed to create default config")?;
-        }
+const fn default_connect_timeout_secs() -> u64 {
+    10
+}
 
-        let mut contents =
-            fs::read_to_string(&config_path).context("failed to read config file")?;
+const fn default_read_timeout_secs() -> u64 {
+    30
+}
 
-        // Migrate to sparse format if config has content (create backup first)
-        // Skip if backup already exists (already migrated) or file is empty
-        let backup_path = config_path.with_extension("toml.bak");
-        if !contents.trim().is_empty() && !backup_path.exists() {
-            Self::migrate_to_sparse(&config_path)
-                .context("failed to migrate config to sparse format")?;
-            // Re-read contents after migration
-            contents = fs::read_to_string(&config_path).context("failed to read config file")?;
-        }
+const fn default_deadline_secs() -> u64 {
+    3600
+}
 
-        let mut config: Self = toml::from_str(&contents).context("failed to parse config TOML")?;
+const fn default_bootstrap() -> bool {
+    true
+}
 
-        // Migrate from old [hotkey]/[model] format to [[profiles]]
-        // Check if profiles is empty/default AND old sections exist (non-default values)
-        let needs_migration = (config.profiles.is_empty() || is_default_profiles(&config.profiles))
-            && (!is_default_hotkey(&config.hotkey) || !is_default_model(&config.model));
+const fn default_bootstrap_model() -> ModelType {
+    ModelType::TinyEn
+}
 
-        if needs_migration {
-            tracing::info!("migrating config from old [hotkey]/[model] format to [[profiles]]");
-            config.migrate_to_profiles();
-            config.save().context("failed to save migrated config")?;
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout_secs: default_connect_timeout_secs(),
+            read_timeout_secs: default_read_timeout_secs(),
+            deadline_secs: default_deadline_secs(),
+            bootstrap: default_bootstrap(),
+            bootstrap_model: default_bootstrap_model(),
+            model_base_url: None,
         }
+    }
+}
 
-        // Check if [model] section had old fields (name/path) and save migrated version
-        let had_old_fields = contents
-            .split("[model]")
-            .nth(1)
-            .and_then(|model_section| model_section.split('[').next())
-            .is_some_and(|model_only| {
-                model_only.contains("name =") || model_only.contains("path =")
-            });
-        if had_old_fields {
-            tracing::info!("migrating config: removing deprecated 'name' and 'path' fields");
-            config.save().context("failed to save migrated config")?;
-        }
+/// Text insertion configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[allow(clippy::struct_excessive_bools)] // Independent TOML on/off options
+pub struct InsertionConfig {
+    /// Minimum milliseconds between consecutive insertions (0 = no pacing)
+    #[serde(default = "default_min_gap_ms")]
+    pub min_gap_ms: u64,
+    /// Insertion methods tried in order until one succeeds
+    #[serde(default = "default_insertion_methods")]
+    pub methods: Vec<InsertionMethod>,
+    /// Read the focused field back (Accessibility API) and fall back if text is missing
+    #[serde(default)]
+    pub verify: bool,
+    /// Skip an insertion identical to the previous one within this many milliseconds (0 = off)
+    #[serde(default)]
+    pub dedupe_window_ms: u64,
+    /// Post a no-op event before keystroke insertion (for apps dropping the first character)
+    #[serde(default)]
+    pub prime_insertion: bool,
+    /// Check accessibility permission after this many consecutive failed insertions (0 = off)
+    #[serde(default = "default_revoke_after_failures")]
+    pub revoke_after_failures: u32,
+    /// Marker in the output where the cursor is left after insertion (removed from the text)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_placeholder: Option<String>,
+    /// Put the previous clipboard text back after clipboard insertion
+    #[serde(default = "default_restore_clipboard")]
+    pub restore_clipboard: bool,
+    /// Type a space after the inserted text
+    #[serde(default)]
+    pub append_space: bool,
+    /// Type a newline after the inserted text
+    #[serde(default)]
+    pub append_newline: bool,
+    /// UTF-16 code units per keyboard event in keystroke insertion (0 = one event)
+    #[serde(default = "default_chunk_size")]
+    pub chunk_size: usize,
+    /// Milliseconds between the keyboard events of one keystroke insertion
+    #[serde(default = "default_chunk_delay_ms")]
+    pub chunk_delay_ms: u64,
+}
 
-        // Ensure unique profile names (auto-generate for duplicates)
-        config.ensure_unique_names();
+const fn default_min_gap_ms() -> u64 {
+    50
+}
 
-        // Ensure at least one profile exists
-        if config.profiles.is_empty() {
-            anyhow::bail!(
-                "config must contain at least one profile - add a [[profiles]] section to {}",
-                Self::config_path()?.display()
-            );
-        }
+const fn default_revoke_after_failures() -> u32 {
+    3
+}
 
-        // Validate hotkey conflicts
-        config.validate_hotkeys()?;
+const fn default_restore_clipboard() -> bool {
+    true
+}
 
-        Ok(config)
-    }
+const fn default_chunk_size() -> usize {
+    200
+}
 
-    fn config_path() -> Result<PathBuf> {
-        let home = std::env::var("HOME").context("HOME environment variable not set")?;
-        Ok(PathBuf::from(home).join(".whisper-hotkey/config.toml"))
-    }
+const fn default_chunk_delay_ms() -> u64 {
+    10
+}
 
-    fn old_config_path() -> Result<PathBuf> {
-        let home = std::env::var("HOME").context("HOME environment variable not set")?;
-        Ok(PathBuf::from(home).join(".whisper-hotkey.toml"))
-    }
+fn default_insertion_methods() -> Vec<InsertionMethod> {
+    vec![InsertionMethod::Keystroke]
+}
 
-    fn create_default(path: &PathBuf) -> Result<()> {
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent).context("failed to create config directory")?;
+impl Default for InsertionConfig {
+    fn default() -> Self {
+        Self {
+            min_gap_ms: default_min_gap_ms(),
+            methods: default_insertion_methods(),
+            verify: false,
+            dedupe_window_ms: 0,
+            prime_insertion: false,
+            revoke_after_failures: default_revoke_after_failures(),
+            cursor_placeholder: None,
+            restore_clipboard: default_restore_clipboard(),
+            append_space: false,
+            append_newline: false,
+            chunk_size: default_chunk_size(),
+            chunk_delay_ms: default_chunk_delay_ms(),
         }
-
-        // Create empty config file - all defaults come from code
-        fs::write(path, "").context("failed to write default config")?;
-        Ok(())
     }
+}
 
-    /// Save config to ~/.whisper-hotkey/config.toml
-    ///
-    /// # Errors
-    /// Returns error if TOML serialization fails or file write fails
-    pub fn save(&self) -> Result<()> {
-        let config_path = Self::config_path()?;
-
-        // Ensure parent directory exists
+/// Application behavior configuration
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)] // Independent TOML on/off options
+pub struct AppConfig {
+    /// Show a Dock icon (default: menubar only)
+    #[serde(default)]
+    pub show_dock_icon: bool,
+    /// Check accessibility/input monitoring on first insertion instead of at startup
+    #[serde(default)]
+    pub defer_permissions: bool,
+    /// Suppress decorative startup output on stdout (same as `--quiet`)
+    #[serde(default)]
+    pub quiet: bool,
+    /// Post a notification when a transcription is inserted or fails
+    #[serde(default)]
+    pub notifications: bool,
+}
+
+/// Locale-aware number/date formatting configuration
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct FormattingConfig {
+    /// Output locale (e.g. "en-US", "de-DE"); None = leave numbers/dates as transcribed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<crate::formatting::Locale>,
+}
+
+/// Transcription queue configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct QueueConfig {
+    /// Transcribe all profiles' recordings one at a time through a shared queue
+    /// (false = each recording is transcribed on its own thread)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Max recordings waiting for transcription (minimum 1)
+    #[serde(default = "default_queue_max_depth")]
+    pub max_depth: usize,
+    /// What to do with a new recording when the queue is full
+    #[serde(default)]
+    pub overflow: OverflowPolicy,
+}
+
+const fn default_queue_max_depth() -> usize {
+    3
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_depth: default_queue_max_depth(),
+            overflow: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// Transcription history configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HistoryConfig {
+    /// Append every inserted transcription to the history file
+    #[serde(default)]
+    pub enabled: bool,
+    /// History file (JSONL, one transcription per line)
+    #[serde(default = "default_history_path")]
+    pub path: String,
+    /// Keep only the newest N transcriptions (0 = unlimited)
+    #[serde(default = "default_history_max_entries")]
+    pub max_entries: usize,
+}
+
+fn default_history_path() -> String {
+    "~/.whisper-hotkey/history.jsonl".to_owned()
+}
+
+const fn default_history_max_entries() -> usize {
+    1000
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_history_path(),
+            max_entries: default_history_max_entries(),
+        }
+    }
+}
+
+/// Menubar icon configuration
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct TrayConfig {
+    /// Blink the recording icon while recording
+    #[serde(default)]
+    pub animate_recording: bool,
+}
+
+/// Per-profile usage statistics configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StatsConfig {
+    /// Count invocations, audio time and characters per profile
+    #[serde(default)]
+    pub enabled: bool,
+    /// Stats file (JSON)
+    #[serde(default = "default_stats_path")]
+    pub path: String,
+}
+
+fn default_stats_path() -> String {
+    "~/.whisper-hotkey/stats.json".to_owned()
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_stats_path(),
+        }
+    }
+}
+
+/// Completion webhook configuration
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct WebhookConfig {
+    /// POST each completed transcription to `url`
+    #[serde(default)]
+    pub enabled: bool,
+    /// Endpoint receiving the JSON payload
+    #[serde(default)]
+    pub url: String,
+    /// Include the transcribed text (off: only profile, duration and time are sent)
+    #[serde(default)]
+    pub include_text: bool,
+    /// Max time for the whole request, in seconds
+    #[serde(default = "default_webhook_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+const fn default_webhook_timeout_secs() -> u64 {
+    5
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            include_text: false,
+            timeout_secs: default_webhook_timeout_secs(),
+        }
+    }
+}
+
+/// Quick-pick configuration: one hotkey opens a numbered profile list
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct QuickPickConfig {
+    /// Register the quick-pick hotkey
+    #[serde(default)]
+    pub enabled: bool,
+    /// Modifier keys of the quick-pick hotkey
+    #[serde(default = "default_quick_pick_modifiers")]
+    pub modifiers: Vec<String>,
+    /// Main key of the quick-pick hotkey
+    #[serde(default = "default_quick_pick_key")]
+    pub key: String,
+    /// How long the list waits for a digit, in milliseconds
+    #[serde(default = "default_quick_pick_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_quick_pick_modifiers() -> Vec<String> {
+    vec!["Control".to_owned(), "Option".to_owned()]
+}
+
+fn default_quick_pick_key() -> String {
+    "P".to_owned()
+}
+
+const fn default_quick_pick_timeout_ms() -> u64 {
+    3000
+}
+
+impl Default for QuickPickConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            modifiers: default_quick_pick_modifiers(),
+            key: default_quick_pick_key(),
+            timeout_ms: default_quick_pick_timeout_ms(),
+        }
+    }
+}
+
+/// Punctuation from pauses: silence between segments maps to a space, comma or period
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct AutoPunctuationConfig {
+    /// Insert punctuation at pauses between segments
+    #[serde(default)]
+    pub enabled: bool,
+    /// Shortest pause, in milliseconds, that becomes a comma (shorter = space)
+    #[serde(default = "default_comma_gap_ms")]
+    pub comma_gap_ms: u64,
+    /// Shortest pause, in milliseconds, that ends the sentence
+    #[serde(default = "default_period_gap_ms")]
+    pub period_gap_ms: u64,
+    /// Text inserted for a comma-length pause
+    #[serde(default = "default_comma_mark")]
+    pub comma: String,
+    /// Text inserted for a period-length pause (next segment is capitalized)
+    #[serde(default = "default_period_mark")]
+    pub period: String,
+}
+
+const fn default_comma_gap_ms() -> u64 {
+    300
+}
+
+const fn default_period_gap_ms() -> u64 {
+    800
+}
+
+fn default_comma_mark() -> String {
+    ",".to_owned()
+}
+
+fn default_period_mark() -> String {
+    ".".to_owned()
+}
+
+impl Default for AutoPunctuationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            comma_gap_ms: default_comma_gap_ms(),
+            period_gap_ms: default_period_gap_ms(),
+            comma: default_comma_mark(),
+            period: default_period_mark(),
+        }
+    }
+}
+
+/// Output transforms for transcriptions in one language (`[[language_rules]]`)
+///
+/// Unset fields keep the profile's (or `[formatting]`/`[aliases]`) setting.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct LanguageRule {
+    /// Whisper language code, as configured or auto-detected (e.g. "ja")
+    pub language: String,
+    /// Number/date formatting locale for this language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<crate::formatting::Locale>,
+    /// Leave numbers and dates as transcribed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disable_formatting: bool,
+    /// Apply `[aliases]` replacements
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<bool>,
+    /// Strip markdown wrapping around transcriptions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strip_markdown: Option<bool>,
+}
+
+/// Latency-based model fallback configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LatencyConfig {
+    /// Switch profiles to their `fallback_model` above this average inference time (0 = off)
+    #[serde(default)]
+    pub max_inference_ms: u64,
+    /// Switch back below this average on the fallback (0 = half of `max_inference_ms`)
+    #[serde(default)]
+    pub recover_inference_ms: u64,
+    /// Number of recent transcriptions averaged
+    #[serde(default = "default_latency_window")]
+    pub window: usize,
+}
+
+const fn default_latency_window() -> usize {
+    5
+}
+
+impl Default for LatencyConfig {
+    fn default() -> Self {
+        Self {
+            max_inference_ms: 0,
+            recover_inference_ms: 0,
+            window: default_latency_window(),
+        }
+    }
+}
+
+/// External translation hook configuration
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct TranslationConfig {
+    /// Command template (`{lang}` = target language); text is passed on stdin
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+/// Environment variable overriding the config file path
+pub const CONFIG_PATH_ENV: &str = "WHISPER_HOTKEY_CONFIG";
+
+/// Environment variable overriding every profile's model
+pub const MODEL_ENV: &str = "WHISPER_HOTKEY_MODEL";
+
+/// Value of environment variable `name`, if set and not blank
+fn env_value(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Config path from an override (`~/` expanded) or the default under `home` (pure)
+fn resolve_config_path(override_path: Option<&str>, home: Option<&str>) -> Result<PathBuf> {
+    let home = || home.context("HOME environment variable not set");
+    match override_path {
+        Some(path) => match path.strip_prefix("~/") {
+            Some(stripped) => Ok(PathBuf::from(home()?).join(stripped)),
+            None => Ok(PathBuf::from(path)),
+        },
+        None => Ok(PathBuf::from(home()?).join(".whisper-hotkey/config.toml")),
+    }
+}
+
+/// Model named by `WHISPER_HOTKEY_MODEL`, if set (pure)
+fn parse_model_override(value: Option<&str>) -> Result<Option<ModelType>> {
+    value
+        .map(|name| {
+            ModelType::from_str(name.trim())
+                .map_err(|e| anyhow::anyhow!("invalid {MODEL_ENV}: {e}"))
+        })
+        .transpose()
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            profiles: default_profiles(),
+            hotkey: HotkeyConfig::default(),
+            audio: AudioConfig::default(),
+            model: ModelConfig::default(),
+            telemetry: TelemetryConfig::default(),
+            recording: RecordingConfig::default(),
+            aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
+        }
+    }
+}
+
+impl Config {
+    /// Load config from ~/.whisper-hotkey/config.toml
+    ///
+    /// Automatically migrates from old path (~/.whisper-hotkey.toml) if found.
+    /// Creates default config if none exists.
+    ///
+    /// # Errors
+    /// Returns error if config is invalid TOML or path expansion fails
+    pub fn load() -> Result<Self> {
+        let config_path = Self::config_path()?;
+
+        // Migrate from old path if needed (not for an explicitly chosen path)
+        if !config_path.exists() && env_value(CONFIG_PATH_ENV).is_none() {
+            let old_path = Self::old_config_path()?;
+            if old_path.exists() {
+                // Ensure new directory exists before migration
+                if let Some(parent) = config_path.parent() {
+                    fs::create_dir_all(parent).context("failed to create config directory")?;
+                }
+                fs::copy(&old_path, &config_path)
+                    .context("failed to migrate config from old location")?;
+                fs::remove_file(&old_path)
+                    .context("failed to remove old config file after migration")?;
+                tracing::info!(
+                    "migrated config from {} to {} and removed old config file",
+                    old_path.display(),
+                    config_path.display()
+                );
+            }
+        }
+
+        if !config_path.exists() {
+            Self::create_default(&config_path).context("failed to create default config")?;
+        }
+
+        let mut contents =
+            fs::read_to_string(&config_path).context("failed to read config file")?;
+
+        // Migrate to sparse format if config has content (create backup first)
+        // Skip if backup already exists (already migrated) or file is empty
+        let backup_path = config_path.with_extension("toml.bak");
+        if !contents.trim().is_empty() && !backup_path.exists() {
+            Self::migrate_to_sparse(&config_path)
+                .context("failed to migrate config to sparse format")?;
+            // Re-read contents after migration
+            contents = fs::read_to_string(&config_path).context("failed to read config file")?;
+        }
+
+        let mut config: Self = toml::from_str(&contents).context("failed to parse config TOML")?;
+        config.check_version();
+
+        // Upgrade older layouts; an empty file is all defaults and stays empty
+        if config.upgrade() && !contents.trim().is_empty() {
+            config.save().context("failed to save migrated config")?;
+        }
+
+        // `WHISPER_HOTKEY_MODEL` overrides every profile's model (not saved)
+        if let Some(model) = parse_model_override(env_value(MODEL_ENV).as_deref())? {
+            for profile in &mut config.profiles {
+                profile.model_type = model;
+            }
+        }
+
+        // Ensure unique profile names (auto-generate for duplicates)
+        config.ensure_unique_names();
+        config.apply_model_custom_path();
+
+        // Ensure at least one profile exists
+        if config.profiles.is_empty() {
+            anyhow::bail!(
+                "config must contain at least one profile - add a [[profiles]] section to {}",
+                Self::config_path()?.display()
+            );
+        }
+
+        // Validate hotkey conflicts
+        config.validate_hotkeys()?;
+        config.validate_translation()?;
+        config.validate_custom_models()?;
+        config.import_aliases()?;
+        config.compile_alias_patterns()?;
+
+        if let Some(base_url) = &config.download.model_base_url {
+            crate::transcription::download::validate_base_url(base_url)
+                .context("invalid [download] model_base_url")?;
+        }
+        crate::output::validate_combine_template(&config.output.combine_template)
+            .context("invalid [output] section")?;
+
+        Ok(config)
+    }
+
+    /// Config file path: `WHISPER_HOTKEY_CONFIG`, else `~/.whisper-hotkey/config.toml`
+    fn config_path() -> Result<PathBuf> {
+        resolve_config_path(
+            env_value(CONFIG_PATH_ENV).as_deref(),
+            std::env::var("HOME").ok().as_deref(),
+        )
+    }
+
+    fn old_config_path() -> Result<PathBuf> {
+        let home = std::env::var("HOME").context("HOME environment variable not set")?;
+        Ok(PathBuf::from(home).join(".whisper-hotkey.toml"))
+    }
+
+    fn create_default(path: &PathBuf) -> Result<()> {
+        // Ensure parent directory exists
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("failed to create config directory")?;
+        }
+
+        // Create empty config file - all defaults come from code
+        fs::write(path, "").context("failed to write default config")?;
+        Ok(())
+    }
+
+    /// Save config to ~/.whisper-hotkey/config.toml
+    ///
+    /// # Errors
+    /// Returns error if TOML serialization fails or file write fails
+    pub fn save(&self) -> Result<()> {
+        let config_path = Self::config_path()?;
+
+        // Ensure parent directory exists
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent).context("failed to create config directory")?;
         }
 
-        let contents =
-            toml::to_string_pretty(self).context("failed to serialize config to TOML")?;
-        fs::write(&config_path, contents).context("failed to write config file")?;
+        fs::write(&config_path, self.to_toml()?).context("failed to write config file")?;
         Ok(())
     }
 
+    /// Serialize for writing to disk, stamped with this build's version
+    ///
+    /// # Errors
+    /// Returns error if TOML serialization fails
+    pub fn to_toml(&self) -> Result<String> {
+        let stamped = Self {
+            version: Some(APP_VERSION.to_owned()),
+            schema_version: self.schema_version.max(SCHEMA_VERSION),
+            ..self.clone()
+        };
+        toml::to_string_pretty(&stamped).context("failed to serialize config to TOML")
+    }
+
+    /// Log how the file's recorded version relates to this build
+    ///
+    /// A newer or unreadable version only warns: the file is still loaded, and
+    /// fields this build doesn't know are ignored.
+    fn check_version(&self) {
+        match compare_version(self.version.as_deref(), APP_VERSION) {
+            ConfigVersion::Newer(version) => tracing::warn!(
+                config_version = %version,
+                app_version = APP_VERSION,
+                "⚠️ Config was written by a newer version; unknown settings are ignored"
+            ),
+            ConfigVersion::Unknown(version) => tracing::warn!(
+                config_version = %version,
+                "⚠️ Config has an unrecognized version, loading anyway"
+            ),
+            ConfigVersion::Older(version) => tracing::info!(
+                config_version = %version,
+                app_version = APP_VERSION,
+                "config written by an older version"
+            ),
+            ConfigVersion::Unversioned | ConfigVersion::Current => {}
+        }
+    }
+
     /// Migrate existing config to sparse format (removes default values)
     /// Creates backup as config.toml.bak before migration
     ///
@@ -653,12 +1902,12 @@ impl Config {
         fs::copy(config_path, &backup_path).context("failed to create config backup")?;
         tracing::info!("created config backup at {}", backup_path.display());
 
-        // Load config (parses with defaults)
-        let config: Self = toml::from_str(&contents).context("failed to parse config TOML")?;
+        // Load config (parses with defaults), upgraded before it's stamped with the current version
+        let mut config: Self = toml::from_str(&contents).context("failed to parse config TOML")?;
+        config.upgrade();
 
         // Save (will skip default values due to skip_serializing_if)
-        let sparse_contents =
-            toml::to_string_pretty(&config).context("failed to serialize config to TOML")?;
+        let sparse_contents = config.to_toml()?;
         fs::write(config_path, sparse_contents).context("failed to write migrated config")?;
 
         tracing::info!("migrated config to sparse format");
@@ -687,9 +1936,57 @@ impl Config {
         }
     }
 
-    /// Migrate from old [hotkey]/[model] format to [[profiles]]
-    /// Converts single hotkey + model config into a single profile
-    fn migrate_to_profiles(&mut self) {
+    /// Run the migrations from config version `from` up to `to`, one version at a time
+    pub fn migrate(&mut self, from: u32, to: u32) {
+        for step in from..to {
+            if let Some(migration) = usize::try_from(step).ok().and_then(|i| MIGRATIONS.get(i)) {
+                migration(self);
+            }
+            self.schema_version = step + 1;
+        }
+    }
+
+    /// Migrate an older config to [`SCHEMA_VERSION`], returning whether anything ran
+    ///
+    /// A config from a newer build is left as is.
+    fn upgrade(&mut self) -> bool {
+        let from = self.schema_version;
+        if from >= SCHEMA_VERSION {
+            if from > SCHEMA_VERSION {
+                tracing::warn!(
+                    schema_version = from,
+                    supported = SCHEMA_VERSION,
+                    "⚠️ Config layout is newer than this build supports"
+                );
+            }
+            return false;
+        }
+        tracing::info!(
+            "migrating config from version {} to {}",
+            from,
+            SCHEMA_VERSION
+        );
+        self.migrate(from, SCHEMA_VERSION);
+        true
+    }
+
+    /// v0 → v1: move the old top-level [hotkey]/[model] into [[profiles]]
+    ///
+    /// Deprecated `[model]` `name`/`path` fields are already dropped when parsing;
+    /// saving the migrated config removes them from the file.
+    fn migrate_v0_to_v1(&mut self) {
+        // Only when profiles are empty/default AND old sections exist (non-default values)
+        let legacy = (self.profiles.is_empty() || is_default_profiles(&self.profiles))
+            && (!is_default_hotkey(&self.hotkey) || !is_default_model(&self.model));
+        if legacy {
+            tracing::info!("migrating config from old [hotkey]/[model] format to [[profiles]]");
+            self.migrate_to_profiles();
+        }
+    }
+
+    /// Migrate from old [hotkey]/[model] format to [[profiles]]
+    /// Converts single hotkey + model config into a single profile
+    fn migrate_to_profiles(&mut self) {
         // Only migrate if profiles is empty or using defaults
         if !self.profiles.is_empty() && !is_default_profiles(&self.profiles) {
             return;
@@ -704,6 +2001,18 @@ impl Config {
             threads: self.model.threads,
             beam_size: self.model.beam_size,
             language: self.model.language.clone(),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: self.model.custom_path.clone(),
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
     }
 
@@ -733,11 +2042,96 @@ impl Config {
         }
     }
 
+    /// Merge aliases from `[aliases] import_path` (inline entries win on conflict)
+    ///
+    /// # Errors
+    /// Returns error if the file can't be read or has a malformed line
+    fn import_aliases(&mut self) -> Result<()> {
+        let Some(import_path) = &self.aliases.import_path else {
+            return Ok(());
+        };
+        let path = Self::expand_path(import_path)?;
+        let imported = crate::alias::load_alias_file(&path)
+            .with_context(|| format!("failed to import aliases from {}", path.display()))?;
+        let added = crate::alias::merge_imported(&mut self.aliases, imported);
+        tracing::info!(path = %path.display(), added, "imported aliases");
+        Ok(())
+    }
+
+    /// Compile `[aliases.patterns]`, so an invalid regex stops startup
+    ///
+    /// # Errors
+    /// Returns error naming the first pattern that isn't a valid regex
+    fn compile_alias_patterns(&mut self) -> Result<()> {
+        self.aliases.compiled_patterns = crate::alias::compile_patterns(&self.aliases.patterns)
+            .context("invalid [aliases.patterns]")?;
+        Ok(())
+    }
+
+    /// Use `[model] custom_path` for profiles that don't set their own
+    fn apply_model_custom_path(&mut self) {
+        let Some(custom_path) = &self.model.custom_path else {
+            return;
+        };
+        for profile in &mut self.profiles {
+            if profile.custom_path.is_none() {
+                profile.custom_path = Some(custom_path.clone());
+            }
+        }
+    }
+
+    /// Validate that custom model files exist
+    ///
+    /// # Errors
+    /// Returns error if a profile's `custom_path` doesn't point to a file
+    fn validate_custom_models(&self) -> Result<()> {
+        for profile in &self.profiles {
+            if let Some(custom_path) = &profile.custom_path {
+                let path = Self::expand_path(custom_path)?;
+                if !path.is_file() {
+                    anyhow::bail!(
+                        "custom model not found for profile {}: {}",
+                        profile.name(),
+                        path.display()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate that profiles translating via the hook have a usable command
+    ///
+    /// # Errors
+    /// Returns error if a profile's `translate_to` needs a missing or invalid command,
+    /// or names another language than English while `translate = true`
+    fn validate_translation(&self) -> Result<()> {
+        for profile in &self.profiles {
+            if profile.translate
+                && profile.translation_target().is_some_and(|target| {
+                    !target.eq_ignore_ascii_case(crate::translation::NATIVE_TARGET)
+                })
+            {
+                anyhow::bail!(
+                    "profile {} sets translate = true (English) and translate_to = {:?}; use one of them",
+                    profile.name(),
+                    profile.translate_to.as_deref().unwrap_or_default()
+                );
+            }
+            crate::translation::HookCommand::for_profile(
+                profile.translate_to.as_deref(),
+                self.translation.command.as_deref(),
+            )
+            .with_context(|| format!("invalid translation for profile {}", profile.name()))?;
+        }
+        Ok(())
+    }
+
     /// Validate no duplicate hotkeys across profiles
     ///
     /// # Errors
     /// Returns error if duplicate hotkeys are found
-    fn validate_hotkeys(&self) -> Result<()> {
+    pub(crate) fn validate_hotkeys(&self) -> Result<()> {
         use std::collections::HashSet;
 
         let mut seen = HashSet::new();
@@ -888,6 +2282,64 @@ log_path = "/tmp/test.log"
         assert!(!config.telemetry.enabled);
     }
 
+    #[test]
+    fn test_quantized_model_types_parse_and_serialize() {
+        let quantized = [
+            (ModelType::TinyQ5_1, "tiny-q5_1"),
+            (ModelType::TinyEnQ5_1, "tiny.en-q5_1"),
+            (ModelType::TinyQ8_0, "tiny-q8_0"),
+            (ModelType::BaseQ5_1, "base-q5_1"),
+            (ModelType::BaseEnQ5_1, "base.en-q5_1"),
+            (ModelType::BaseQ8_0, "base-q8_0"),
+            (ModelType::SmallQ5_1, "small-q5_1"),
+            (ModelType::SmallEnQ5_1, "small.en-q5_1"),
+            (ModelType::SmallQ8_0, "small-q8_0"),
+            (ModelType::MediumQ5_0, "medium-q5_0"),
+            (ModelType::MediumEnQ5_0, "medium.en-q5_0"),
+            (ModelType::MediumQ8_0, "medium-q8_0"),
+            (ModelType::LargeV2Q5_0, "large-v2-q5_0"),
+            (ModelType::LargeV2Q8_0, "large-v2-q8_0"),
+            (ModelType::LargeV3Q5_0, "large-v3-q5_0"),
+        ];
+        for (model_type, name) in quantized {
+            assert!(ModelType::variants().contains(&model_type));
+            assert_eq!(ModelType::from_str(name), Ok(model_type));
+            assert_eq!(model_type.as_str(), name);
+            assert_eq!(
+                model_type.model_path(),
+                format!("~/.whisper-hotkey/models/ggml-{name}.bin")
+            );
+
+            let toml = format!(
+                "[[profiles]]\nmodel_type = \"{name}\"\nmodifiers = [\"Control\"]\nkey = \"K\"\n"
+            );
+            let config: Config = toml::from_str(&toml).unwrap();
+            assert_eq!(config.profiles[0].model_type, model_type);
+            let serialized = toml::to_string(&config).unwrap();
+            assert!(serialized.contains(&format!("model_type = \"{name}\"")));
+            let reparsed: Config = toml::from_str(&serialized).unwrap();
+            assert_eq!(reparsed.profiles[0].model_type, model_type);
+        }
+    }
+
+    #[test]
+    fn test_model_type_variants_round_trip() {
+        for &model_type in ModelType::variants() {
+            assert_eq!(ModelType::from_str(model_type.as_str()), Ok(model_type));
+        }
+        assert!(ModelType::from_str("base.en-q4_0").is_err());
+    }
+
+    #[test]
+    fn test_quantized_model_config_migration() {
+        let toml = r#"
+[model]
+name = "small.en-q5_1"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.model.model_type, ModelType::SmallEnQ5_1);
+    }
+
     #[test]
     fn test_model_config_migration() {
         // Test with model_type (new format)
@@ -1098,10 +2550,24 @@ log_path = "/test/log.txt"
             hotkey: HotkeyConfig {
                 modifiers: vec!["Command".to_owned()],
                 key: "V".to_owned(),
+                mode: HotkeyMode::Hold,
             },
             audio: AudioConfig {
                 buffer_size: 2048,
                 sample_rate: 16000,
+                clipping_threshold: 0.01,
+                continuous_drain: false,
+                resampler_auto: false,
+                resampler_threshold_ms: 5000,
+                max_recording_secs: 30,
+                device_name: None,
+                preroll_ms: 0,
+                trim_silence: false,
+                silence_threshold: 0.01,
+                trim_attack_ms: 100,
+                trim_release_ms: 200,
+                normalize: false,
+                target_peak: 0.9,
             },
             model: ModelConfig {
                 model_type: ModelType::Base,
@@ -1109,13 +2575,40 @@ log_path = "/test/log.txt"
                 threads: 4,
                 beam_size: 5,
                 language: None,
+                custom_path: None,
+                max_loaded_models: 0,
+                no_speech_threshold: 0.6,
+                use_gpu: true,
+                warmup: true,
             },
             telemetry: TelemetryConfig {
                 enabled: true,
                 log_path: "~/.whisper-hotkey/crash.log".to_owned(),
+                timing_debug: false,
+                audio_stats_debug: false,
+                log_max_mb: 10,
+                log_keep_files: 3,
+                format: LogFormat::Text,
             },
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         let serialized = toml::to_string(&config).unwrap();
@@ -1136,10 +2629,24 @@ log_path = "/test/log.txt"
             hotkey: HotkeyConfig {
                 modifiers: vec!["Command".to_owned()],
                 key: "V".to_owned(),
+                mode: HotkeyMode::Hold,
             },
             audio: AudioConfig {
                 buffer_size: 2048,
                 sample_rate: 16000,
+                clipping_threshold: 0.01,
+                continuous_drain: false,
+                resampler_auto: false,
+                resampler_threshold_ms: 5000,
+                max_recording_secs: 30,
+                device_name: None,
+                preroll_ms: 0,
+                trim_silence: false,
+                silence_threshold: 0.01,
+                trim_attack_ms: 100,
+                trim_release_ms: 200,
+                normalize: false,
+                target_peak: 0.9,
             },
             model: ModelConfig {
                 model_type: ModelType::Base,
@@ -1147,13 +2654,40 @@ log_path = "/test/log.txt"
                 threads: 8,
                 beam_size: 10,
                 language: Some("pl".to_owned()),
+                custom_path: None,
+                max_loaded_models: 0,
+                no_speech_threshold: 0.6,
+                use_gpu: true,
+                warmup: true,
             },
             telemetry: TelemetryConfig {
                 enabled: false,
                 log_path: "/tmp/log.txt".to_owned(),
+                timing_debug: false,
+                audio_stats_debug: false,
+                log_max_mb: 10,
+                log_keep_files: 3,
+                format: LogFormat::Text,
             },
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         let serialized = toml::to_string(&original).unwrap();
@@ -1176,6 +2710,44 @@ log_path = "/test/log.txt"
             .contains(".whisper-hotkey/config.toml"));
     }
 
+    #[test]
+    fn test_resolve_config_path_env_override() {
+        assert_eq!(
+            resolve_config_path(Some("/tmp/ci/config.toml"), Some("/Users/me")).unwrap(),
+            PathBuf::from("/tmp/ci/config.toml")
+        );
+        assert_eq!(
+            resolve_config_path(Some("~/alt.toml"), Some("/Users/me")).unwrap(),
+            PathBuf::from("/Users/me/alt.toml")
+        );
+        // An absolute override doesn't need HOME
+        assert_eq!(
+            resolve_config_path(Some("/etc/whisper.toml"), None).unwrap(),
+            PathBuf::from("/etc/whisper.toml")
+        );
+    }
+
+    #[test]
+    fn test_resolve_config_path_home_fallback() {
+        assert_eq!(
+            resolve_config_path(None, Some("/Users/me")).unwrap(),
+            PathBuf::from("/Users/me/.whisper-hotkey/config.toml")
+        );
+        assert!(resolve_config_path(None, None).is_err());
+        assert!(resolve_config_path(Some("~/alt.toml"), None).is_err());
+    }
+
+    #[test]
+    fn test_parse_model_override() {
+        assert_eq!(parse_model_override(None).unwrap(), None);
+        assert_eq!(
+            parse_model_override(Some("small.en")).unwrap(),
+            Some(ModelType::SmallEn)
+        );
+        let error = parse_model_override(Some("huge")).unwrap_err();
+        assert!(error.to_string().contains("WHISPER_HOTKEY_MODEL"));
+    }
+
     #[test]
     fn test_get_config_path() {
         let path = Config::get_config_path().unwrap();
@@ -1288,211 +2860,1566 @@ log_path = "/tmp/test.log"
             env::remove_var("HOME");
         }
 
-        // Cleanup
-        let _ = fs::remove_dir_all(&test_home);
+        // Cleanup
+        let _ = fs::remove_dir_all(&test_home);
+    }
+
+    #[test]
+    fn test_load_creates_default_when_no_config_exists() {
+        let _guard = HOME_TEST_LOCK.lock().unwrap();
+
+        let temp_base = env::temp_dir();
+        let test_home = temp_base.join(format!(
+            "whisper_test_no_config_{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        ));
+        fs::create_dir_all(&test_home).unwrap();
+
+        let original_home = env::var("HOME").ok();
+        env::set_var("HOME", test_home.to_str().unwrap());
+
+        // No config exists - should create default
+        let config = Config::load().unwrap();
+
+        // Verify new config was created at correct path
+        let config_path = test_home.join(".whisper-hotkey/config.toml");
+        assert!(config_path.exists());
+
+        // Verify default values
+        assert_eq!(config.hotkey.modifiers, vec!["Control", "Option"]);
+        assert_eq!(config.hotkey.key, "Z");
+        assert_eq!(config.audio.buffer_size, 1024);
+        assert_eq!(config.audio.sample_rate, 16000);
+        assert_eq!(config.model.model_type, ModelType::Small);
+        assert!(config.telemetry.enabled);
+
+        // Restore HOME
+        if let Some(home) = original_home {
+            env::set_var("HOME", home);
+        } else {
+            env::remove_var("HOME");
+        }
+
+        let _ = fs::remove_dir_all(&test_home);
+    }
+
+    #[test]
+    fn test_load_when_new_config_already_exists() {
+        let _guard = HOME_TEST_LOCK.lock().unwrap();
+
+        let temp_base = env::temp_dir();
+        let test_home = temp_base.join(format!(
+            "whisper_test_existing_{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        ));
+        fs::create_dir_all(&test_home).unwrap();
+
+        let original_home = env::var("HOME").ok();
+        env::set_var("HOME", test_home.to_str().unwrap());
+
+        // Create new config at correct path
+        let new_config_path = test_home.join(".whisper-hotkey/config.toml");
+        fs::create_dir_all(new_config_path.parent().unwrap()).unwrap();
+        let existing_config = r#"[hotkey]
+modifiers = ["Control", "Shift"]
+key = "X"
+
+[audio]
+buffer_size = 4096
+sample_rate = 16000
+
+[model]
+name = "large"
+path = "/custom/path.bin"
+preload = false
+
+[telemetry]
+enabled = false
+log_path = "/custom/log.txt"
+"#;
+        fs::write(&new_config_path, existing_config).unwrap();
+
+        // Create old config too - should not migrate since new exists
+        let old_config_path = test_home.join(".whisper-hotkey.toml");
+        fs::write(&old_config_path, "ignored").unwrap();
+
+        // Load - should use new config, ignore old
+        let config = Config::load().unwrap();
+
+        // Verify loaded from new config, not defaults
+        assert_eq!(config.hotkey.key, "X");
+        assert_eq!(config.audio.buffer_size, 4096);
+        assert_eq!(config.model.model_type, ModelType::Large);
+        assert!(!config.telemetry.enabled);
+
+        // Old config should still exist (not migrated)
+        assert!(old_config_path.exists());
+
+        // Restore HOME
+        if let Some(home) = original_home {
+            env::set_var("HOME", home);
+        } else {
+            env::remove_var("HOME");
+        }
+
+        let _ = fs::remove_dir_all(&test_home);
+    }
+
+    #[test]
+    fn test_save_creates_config_file() {
+        let _guard = HOME_TEST_LOCK.lock().unwrap();
+
+        let temp_base = env::temp_dir();
+        let test_dir = temp_base.join(format!(
+            "whisper_test_save_{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        ));
+        fs::create_dir_all(&test_dir).unwrap();
+
+        let original_home = env::var("HOME").ok();
+        env::set_var("HOME", test_dir.to_str().unwrap());
+
+        let config = Config {
+            profiles: default_profiles(),
+            hotkey: HotkeyConfig {
+                modifiers: vec!["Command".to_owned()],
+                key: "T".to_owned(),
+                mode: HotkeyMode::Hold,
+            },
+            audio: AudioConfig {
+                buffer_size: 2048,
+                sample_rate: 16000,
+                clipping_threshold: 0.01,
+                continuous_drain: false,
+                resampler_auto: false,
+                resampler_threshold_ms: 5000,
+                max_recording_secs: 30,
+                device_name: None,
+                preroll_ms: 0,
+                trim_silence: false,
+                silence_threshold: 0.01,
+                trim_attack_ms: 100,
+                trim_release_ms: 200,
+                normalize: false,
+                target_peak: 0.9,
+            },
+            model: ModelConfig {
+                model_type: ModelType::Base,
+                preload: true,
+                threads: 4,
+                beam_size: 5,
+                language: Some("en".to_owned()),
+                custom_path: None,
+                max_loaded_models: 0,
+                no_speech_threshold: 0.6,
+                use_gpu: true,
+                warmup: true,
+            },
+            telemetry: TelemetryConfig {
+                enabled: true,
+                log_path: "/test/log.txt".to_owned(),
+                timing_debug: false,
+                audio_stats_debug: false,
+                log_max_mb: 10,
+                log_keep_files: 3,
+                format: LogFormat::Text,
+            },
+            recording: RecordingConfig::default(),
+            aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
+        };
+
+        config.save().unwrap();
+
+        let config_path = test_dir.join(".whisper-hotkey/config.toml");
+        assert!(config_path.exists());
+
+        let contents = fs::read_to_string(&config_path).unwrap();
+        let loaded: Config = toml::from_str(&contents).unwrap();
+        assert_eq!(loaded.hotkey.key, "T");
+        assert_eq!(loaded.audio.buffer_size, 2048);
+
+        // Restore HOME
+        if let Some(home) = original_home {
+            env::set_var("HOME", home);
+        } else {
+            env::remove_var("HOME");
+        }
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_parse_config_with_language() {
+        let toml = r#"
+[hotkey]
+modifiers = ["Control"]
+key = "M"
+
+[audio]
+buffer_size = 512
+sample_rate = 16000
+
+[model]
+name = "tiny"
+path = "/tmp/tiny.bin"
+preload = true
+language = "en"
+
+[telemetry]
+enabled = true
+log_path = "/tmp/crash.log"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.model.language, Some("en".to_owned()));
+    }
+
+    #[test]
+    fn test_recording_config_defaults() {
+        let config = RecordingConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.retention_days, 7);
+        assert_eq!(config.max_count, 100);
+        assert_eq!(config.cleanup_interval_hours, 1);
+        assert_eq!(config.degraded_behavior, DegradedBehavior::Silent);
+    }
+
+    #[test]
+    fn test_output_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.output.insert_original);
+        assert_eq!(config.output.combine_template, "{primary}\n{secondary}");
+        assert!(is_default_output(&config.output));
+    }
+
+    #[test]
+    fn test_parse_output_combine_template() {
+        let toml = r#"
+[output]
+insert_original = true
+combine_template = "{primary} ({secondary})"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.output.insert_original);
+        assert_eq!(config.output.combine_template, "{primary} ({secondary})");
+
+        let serialized = toml::to_string(&config).unwrap();
+        assert!(serialized.contains("combine_template"));
+    }
+
+    #[test]
+    fn test_download_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.download.connect_timeout_secs, 10);
+        assert_eq!(config.download.read_timeout_secs, 30);
+        assert_eq!(config.download.deadline_secs, 3600);
+        assert!(config.download.bootstrap);
+        assert_eq!(config.download.bootstrap_model, ModelType::TinyEn);
+        assert!(is_default_download(&config.download));
+    }
+
+    #[test]
+    fn test_parse_download_bootstrap() {
+        let toml = r#"
+[download]
+bootstrap_model = "base.en"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.download.bootstrap);
+        assert_eq!(config.download.bootstrap_model, ModelType::BaseEn);
+        assert!(!is_default_download(&config.download));
+
+        let config: Config = toml::from_str("[download]\nbootstrap = false\n").unwrap();
+        assert!(!config.download.bootstrap);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("bootstrap = false"));
+    }
+
+    #[test]
+    fn test_parse_download_config() {
+        let toml = r"
+[download]
+connect_timeout_secs = 3
+deadline_secs = 0
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.download.connect_timeout_secs, 3);
+        assert_eq!(config.download.read_timeout_secs, 30);
+        assert_eq!(config.download.deadline_secs, 0);
+    }
+
+    #[test]
+    fn test_parse_download_model_base_url() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.download.model_base_url.is_none());
+
+        let toml = r#"
+[download]
+model_base_url = "https://mirror.example.com/whisper"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.download.model_base_url.as_deref(),
+            Some("https://mirror.example.com/whisper")
+        );
+        assert!(!is_default_download(&config.download));
+    }
+
+    #[test]
+    fn test_insertion_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.insertion.min_gap_ms, 50);
+        assert_eq!(config.insertion.methods, vec![InsertionMethod::Keystroke]);
+        assert!(!config.insertion.verify);
+        assert_eq!(config.insertion.dedupe_window_ms, 0);
+        assert!(!config.insertion.prime_insertion);
+        assert_eq!(config.insertion.revoke_after_failures, 3);
+        assert!(is_default_insertion(&config.insertion));
+    }
+
+    #[test]
+    fn test_parse_insertion_dedupe_window() {
+        let toml = r"
+[insertion]
+dedupe_window_ms = 1500
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.insertion.dedupe_window_ms, 1500);
+        assert!(!is_default_insertion(&config.insertion));
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("dedupe_window_ms = 1500"));
+    }
+
+    #[test]
+    fn test_parse_insertion_prime() {
+        let toml = r"
+[insertion]
+prime_insertion = true
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.insertion.prime_insertion);
+        assert!(!is_default_insertion(&config.insertion));
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("prime_insertion = true"));
+    }
+
+    #[test]
+    fn test_parse_insertion_restore_clipboard() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.insertion.restore_clipboard);
+
+        let toml = r"
+[insertion]
+restore_clipboard = false
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(!config.insertion.restore_clipboard);
+        assert!(!is_default_insertion(&config.insertion));
+    }
+
+    #[test]
+    fn test_parse_insertion_append_suffix() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.insertion.append_space);
+        assert!(!config.insertion.append_newline);
+
+        let toml = r"
+[insertion]
+append_space = true
+append_newline = true
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.insertion.append_space);
+        assert!(config.insertion.append_newline);
+        assert!(!is_default_insertion(&config.insertion));
+    }
+
+    #[test]
+    fn test_parse_insertion_chunking() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.insertion.chunk_size, 200);
+        assert_eq!(config.insertion.chunk_delay_ms, 10);
+
+        let toml = r"
+[insertion]
+chunk_size = 50
+chunk_delay_ms = 25
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.insertion.chunk_size, 50);
+        assert_eq!(config.insertion.chunk_delay_ms, 25);
+        assert!(!is_default_insertion(&config.insertion));
+    }
+
+    #[test]
+    fn test_parse_insertion_revoke_after_failures() {
+        let config: Config = toml::from_str("[insertion]\nrevoke_after_failures = 0\n").unwrap();
+        assert_eq!(config.insertion.revoke_after_failures, 0);
+        assert!(!is_default_insertion(&config.insertion));
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("revoke_after_failures = 0"));
+    }
+
+    #[test]
+    fn test_parse_insertion_min_gap() {
+        let toml = r"
+[insertion]
+min_gap_ms = 0
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.insertion.min_gap_ms, 0);
+        assert!(!is_default_insertion(&config.insertion));
+    }
+
+    #[test]
+    fn test_parse_insertion_methods() {
+        let toml = r#"
+[insertion]
+methods = ["keystroke", "clipboard"]
+verify = true
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.insertion.methods,
+            vec![InsertionMethod::Keystroke, InsertionMethod::Clipboard]
+        );
+        assert!(config.insertion.verify);
+        assert_eq!(config.insertion.min_gap_ms, 50);
+        assert!(!is_default_insertion(&config.insertion));
+    }
+
+    #[test]
+    fn test_app_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.app.show_dock_icon);
+        assert!(!config.app.defer_permissions);
+        assert!(!config.app.quiet);
+        assert!(is_default_app(&config.app));
+    }
+
+    #[test]
+    fn test_parse_app_quiet() {
+        let toml = r"
+[app]
+quiet = true
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.app.quiet);
+        assert!(!is_default_app(&config.app));
+    }
+
+    #[test]
+    fn test_parse_app_show_dock_icon() {
+        let toml = r"
+[app]
+show_dock_icon = true
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.app.show_dock_icon);
+
+        let serialized = toml::to_string(&config).unwrap();
+        assert!(serialized.contains("show_dock_icon = true"));
+    }
+
+    #[test]
+    fn test_parse_app_notifications() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.app.notifications);
+
+        let toml = r"
+[app]
+notifications = true
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.app.notifications);
+        assert!(!is_default_app(&config.app));
+        assert!(config.to_toml().unwrap().contains("notifications = true"));
+    }
+
+    #[test]
+    fn test_parse_app_defer_permissions() {
+        let toml = r"
+[app]
+defer_permissions = true
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.app.defer_permissions);
+        assert!(!config.app.show_dock_icon);
+    }
+
+    #[test]
+    fn test_formatting_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.formatting.locale.is_none());
+        assert!(is_default_formatting(&config.formatting));
+    }
+
+    #[test]
+    fn test_parse_formatting_locale() {
+        let toml = r#"
+[formatting]
+locale = "de_DE"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.formatting.locale,
+            Some(crate::formatting::Locale::DeDe)
+        );
+
+        let serialized = toml::to_string(&config).unwrap();
+        assert!(serialized.contains("locale = \"de-DE\""));
+    }
+
+    #[test]
+    fn test_parse_formatting_invalid_locale() {
+        let toml = r#"
+[formatting]
+locale = "xx-YY"
+"#;
+        let err = toml::from_str::<Config>(toml).unwrap_err();
+        assert!(err.to_string().contains("unsupported locale"));
+    }
+
+    #[test]
+    fn test_queue_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.queue.enabled);
+        assert_eq!(config.queue.max_depth, 3);
+        assert_eq!(config.queue.overflow, OverflowPolicy::DropOldest);
+        assert!(is_default_queue(&config.queue));
+    }
+
+    #[test]
+    fn test_parse_queue_config() {
+        let toml = r#"
+[queue]
+enabled = true
+max_depth = 1
+overflow = "reject_new"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.queue.enabled);
+        assert_eq!(config.queue.max_depth, 1);
+        assert_eq!(config.queue.overflow, OverflowPolicy::RejectNew);
+        assert!(!is_default_queue(&config.queue));
+    }
+
+    #[test]
+    fn test_parse_translate_to_with_command() {
+        let toml = r#"
+[translation]
+command = "trans -b :{lang}"
+
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+translate_to = "de"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.profiles[0].translate_to.as_deref(), Some("de"));
+        assert_eq!(
+            config.translation.command.as_deref(),
+            Some("trans -b :{lang}")
+        );
+        assert!(!is_default_translation(&config.translation));
+        assert!(config.validate_translation().is_ok());
+    }
+
+    #[test]
+    fn test_validate_translation_requires_command_for_hook() {
+        let toml = r#"
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+translate_to = "de"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(is_default_translation(&config.translation));
+        let err = config.validate_translation().unwrap_err();
+        assert!(format!("{err:#}").contains("[translation] command"));
+    }
+
+    #[test]
+    fn test_validate_translation_english_is_native() {
+        let toml = r#"
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+translate_to = "en"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.validate_translation().is_ok());
+    }
+
+    #[test]
+    fn test_parse_initial_prompt() {
+        let toml = r#"
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+initial_prompt = "Kubernetes, kubectl, Grafana, Prometheus."
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.profiles[0].initial_prompt.as_deref(),
+            Some("Kubernetes, kubectl, Grafana, Prometheus.")
+        );
+        assert_eq!(Config::default().profiles[0].initial_prompt, None);
+    }
+
+    #[test]
+    fn test_parse_translate_flag() {
+        let toml = r#"
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+translate = true
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.profiles[0].translate);
+        assert_eq!(config.profiles[0].translation_target(), Some("en"));
+        assert!(config.validate_translation().is_ok());
+
+        let toml = r#"
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(!config.profiles[0].translate);
+        assert_eq!(config.profiles[0].translation_target(), None);
+    }
+
+    #[test]
+    fn test_validate_translate_flag_conflicts_with_other_language() {
+        let toml = r#"
+[translation]
+command = "trans -b :{lang}"
+
+[[profiles]]
+model_type = "small"
+modifiers = ["Command", "Shift"]
+key = "T"
+translate = true
+translate_to = "de"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let err = config.validate_translation().unwrap_err();
+        assert!(err.to_string().contains("use one of them"));
+
+        // Both asking for English is fine
+        let toml = toml.replace("\"de\"", "\"en\"");
+        let config: Config = toml::from_str(&toml).unwrap();
+        assert!(config.validate_translation().is_ok());
+    }
+
+    #[test]
+    fn test_history_config_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.history.enabled);
+        assert_eq!(config.history.path, "~/.whisper-hotkey/history.jsonl");
+        assert_eq!(config.history.max_entries, 1000);
+        assert!(is_default_history(&config.history));
+    }
+
+    #[test]
+    fn test_parse_history() {
+        let toml = r"
+[history]
+enabled = true
+max_entries = 50
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.history.enabled);
+        assert_eq!(config.history.max_entries, 50);
+        assert_eq!(config.history.path, "~/.whisper-hotkey/history.jsonl");
+        assert!(!is_default_history(&config.history));
+    }
+
+    #[test]
+    fn test_profile_custom_path_overrides_model_type() {
+        let toml = r#"
+[[profiles]]
+model_type = "small"
+modifiers = ["Control"]
+key = "D"
+custom_path = "~/models/ggml-medical.bin"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.profiles[0].model_path(), "~/models/ggml-medical.bin");
+
+        let mut profile = config.profiles[0].clone();
+        profile.custom_path = None;
+        assert_eq!(profile.model_path(), ModelType::Small.model_path());
+    }
+
+    #[test]
+    fn test_model_custom_path_applies_to_profiles() {
+        let toml = r#"
+[model]
+custom_path = "/models/ggml-domain.bin"
+
+[[profiles]]
+model_type = "small"
+modifiers = ["Control"]
+key = "D"
+
+[[profiles]]
+model_type = "tiny"
+modifiers = ["Control"]
+key = "T"
+custom_path = "/models/ggml-other.bin"
+"#;
+        let mut config: Config = toml::from_str(toml).unwrap();
+        assert!(!is_default_model(&config.model));
+        config.apply_model_custom_path();
+        assert_eq!(config.profiles[0].model_path(), "/models/ggml-domain.bin");
+        // Profile's own custom_path wins
+        assert_eq!(config.profiles[1].model_path(), "/models/ggml-other.bin");
+    }
+
+    #[test]
+    fn test_validate_custom_models_missing_file() {
+        let mut config = Config::default();
+        config.profiles[0].custom_path = Some("/nonexistent/ggml-custom.bin".to_owned());
+        let err = config.validate_custom_models().unwrap_err();
+        assert!(err.to_string().contains("custom model not found"));
+
+        config.profiles[0].custom_path = None;
+        assert!(config.validate_custom_models().is_ok());
+    }
+
+    #[test]
+    fn test_parse_tray_config() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.tray.animate_recording);
+        assert!(is_default_tray(&config.tray));
+
+        let config: Config = toml::from_str("[tray]\nanimate_recording = true\n").unwrap();
+        assert!(config.tray.animate_recording);
+        assert!(!is_default_tray(&config.tray));
+    }
+
+    #[test]
+    fn test_parse_profile_mode() {
+        let toml = r#"
+[[profiles]]
+model_type = "base.en"
+modifiers = ["Control"]
+key = "D"
+
+[[profiles]]
+model_type = "base.en"
+modifiers = ["Control"]
+key = "C"
+mode = "command"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.profiles[0].mode, ProfileMode::Dictation);
+        assert_eq!(config.profiles[1].mode, ProfileMode::Command);
+
+        let invalid = toml.replace("\"command\"", "\"shell\"");
+        assert!(toml::from_str::<Config>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_parse_preserve_whitespace() {
+        let toml = r#"
+[[profiles]]
+model_type = "base.en"
+modifiers = ["Control"]
+key = "D"
+preserve_whitespace = true
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.profiles[0].preserve_whitespace);
+        assert!(!default_profiles()[0].preserve_whitespace);
+    }
+
+    #[test]
+    fn test_parse_hotkey_mode() {
+        let toml = r#"
+[[profiles]]
+model_type = "base.en"
+modifiers = ["Control"]
+key = "D"
+mode = "command"
+hotkey_mode = "toggle"
+
+[[profiles]]
+model_type = "small"
+modifiers = ["Control"]
+key = "S"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.profiles[0].hotkey.mode, HotkeyMode::Toggle);
+        assert_eq!(config.profiles[0].mode, ProfileMode::Command);
+        assert_eq!(config.profiles[1].hotkey.mode, HotkeyMode::Hold);
+
+        let reparsed: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(reparsed.profiles[0].hotkey.mode, HotkeyMode::Toggle);
+
+        let invalid = "[[profiles]]\nmodel_type = \"base\"\nmodifiers = []\nkey = \"D\"\nhotkey_mode = \"tap\"\n";
+        assert!(toml::from_str::<Config>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_parse_flash_attn() {
+        let toml = r#"
+[[profiles]]
+model_type = "base.en"
+modifiers = ["Control"]
+key = "D"
+flash_attn = true
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.profiles[0].flash_attn);
+        assert!(!default_profiles()[0].flash_attn);
+
+        let reparsed: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert!(reparsed.profiles[0].flash_attn);
+    }
+
+    #[test]
+    fn test_parse_profile_audio() {
+        let toml = r#"
+[audio]
+buffer_size = 1024
+sample_rate = 16000
+
+[[profiles]]
+name = "desk"
+model_type = "base.en"
+modifiers = ["Control"]
+key = "D"
+
+[profiles.audio]
+buffer_size = 2048
+sample_rate = 16000
+device_name = "Yeti"
+
+[[profiles]]
+name = "laptop"
+model_type = "base.en"
+modifiers = ["Control"]
+key = "L"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let desk = config.profiles[0].audio.as_ref().unwrap();
+        assert_eq!(desk.buffer_size, 2048);
+        assert_eq!(desk.device_name.as_deref(), Some("Yeti"));
+        // Unset fields take their defaults, not the global [audio] values
+        assert!((desk.clipping_threshold - default_clipping_threshold()).abs() < f64::EPSILON);
+        assert!(config.profiles[1].audio.is_none());
+
+        let serialized = config.to_toml().unwrap();
+        assert!(serialized.contains("[profiles.audio]"));
+        let reparsed: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(reparsed.profiles[0].audio, config.profiles[0].audio);
+        assert!(reparsed.profiles[1].audio.is_none());
+    }
+
+    #[test]
+    fn test_resolved_audio() {
+        let global = AudioConfig {
+            device_name: Some("MacBook Pro Microphone".to_owned()),
+            ..AudioConfig::default()
+        };
+        let mut profile = default_profiles().remove(0);
+        assert_eq!(resolved_audio(&profile, &global), global);
+
+        let own = AudioConfig {
+            buffer_size: 512,
+            device_name: Some("Yeti".to_owned()),
+            ..AudioConfig::default()
+        };
+        profile.audio = Some(own.clone());
+        assert_eq!(resolved_audio(&profile, &global), own);
+    }
+
+    #[test]
+    fn test_parse_latency_config() {
+        let toml = r#"
+[latency]
+max_inference_ms = 2500
+window = 3
+
+[[profiles]]
+model_type = "medium.en"
+fallback_model = "base.en"
+modifiers = ["Control"]
+key = "D"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.latency.max_inference_ms, 2500);
+        assert_eq!(config.latency.recover_inference_ms, 0);
+        assert_eq!(config.latency.window, 3);
+        assert_eq!(config.profiles[0].fallback_model, Some(ModelType::BaseEn));
+
+        let config: Config = toml::from_str("").unwrap();
+        assert!(is_default_latency(&config.latency));
+    }
+
+    #[test]
+    fn test_parse_queue_press_during_processing() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.recording.queue_press_during_processing);
+
+        let toml = "[recording]\nqueue_press_during_processing = true\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.recording.queue_press_during_processing);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("queue_press_during_processing = true"));
+    }
+
+    #[test]
+    fn test_parse_cancel_on_escape() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.recording.cancel_on_escape);
+        assert!(is_default_recording(&config.recording));
+
+        let toml = "[recording]\ncancel_on_escape = false\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(!config.recording.cancel_on_escape);
+        assert!(!is_default_recording(&config.recording));
+    }
+
+    #[test]
+    fn test_parse_cancel_key() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.recording.cancel_key, "Escape");
+        assert!(config.recording.cancel_modifiers.is_empty());
+
+        let toml = "[recording]\ncancel_key = \"Backquote\"\ncancel_modifiers = [\"Control\"]\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.recording.cancel_key, "Backquote");
+        assert_eq!(config.recording.cancel_modifiers, ["Control"]);
+        assert!(!is_default_recording(&config.recording));
+    }
+
+    #[test]
+    fn test_parse_max_total_mb() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.recording.max_total_mb, 0);
+
+        let config: Config = toml::from_str("[recording]\nmax_total_mb = 500\n").unwrap();
+        assert_eq!(config.recording.max_total_mb, 500);
+        assert!(!is_default_recording(&config.recording));
+    }
+
+    #[test]
+    fn test_parse_use_trash() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.recording.use_trash);
+
+        let config: Config = toml::from_str("[recording]\nuse_trash = true\n").unwrap();
+        assert!(config.recording.use_trash);
+        assert!(!is_default_recording(&config.recording));
+    }
+
+    #[test]
+    fn test_parse_wav_format() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.recording.wav_format, WavFormat::F32);
+
+        let toml = "[recording]\nwav_format = \"pcm16\"\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.recording.wav_format, WavFormat::Pcm16);
+        assert!(!is_default_recording(&config.recording));
+
+        assert!(toml::from_str::<Config>("[recording]\nwav_format = \"mp3\"\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_max_loaded_models() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.model.max_loaded_models, 0);
+
+        let toml = "[model]\nmax_loaded_models = 2\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.model.max_loaded_models, 2);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("max_loaded_models = 2"));
+    }
+
+    #[test]
+    fn test_parse_use_gpu() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(
+            config.model.use_gpu,
+            cfg!(all(target_arch = "aarch64", target_os = "macos"))
+        );
+        assert!(!toml::to_string(&config).unwrap().contains("use_gpu"));
+
+        let toml = format!("[model]\nuse_gpu = {}\n", !config.model.use_gpu);
+        let config: Config = toml::from_str(&toml).unwrap();
+        assert!(!is_default_model(&config.model));
+        assert!(toml::to_string(&config).unwrap().contains("use_gpu"));
+    }
+
+    #[test]
+    fn test_parse_warmup() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.model.warmup);
+
+        let toml = "[model]\nwarmup = false\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(!config.model.warmup);
+        assert!(!is_default_model(&config.model));
+        assert!(toml::to_string(&config).unwrap().contains("warmup = false"));
+    }
+
+    #[test]
+    fn test_alias_patterns_compiled() {
+        let toml = r#"
+[aliases.patterns]
+'(?i)version (\d+) point (\d+)' = "$1.$2"
+"#;
+        let mut config: Config = toml::from_str(toml).unwrap();
+        assert!(!is_default_aliases(&config.aliases));
+        assert!(config.aliases.compiled_patterns.is_empty());
+
+        config.compile_alias_patterns().unwrap();
+        assert_eq!(config.aliases.compiled_patterns.len(), 1);
+        assert_eq!(
+            crate::alias::apply_aliases("Version 2 point 1", &config.aliases),
+            "2.1"
+        );
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("[aliases.patterns]"));
+    }
+
+    #[test]
+    fn test_alias_patterns_invalid_regex_error() {
+        let toml = r#"
+[aliases.patterns]
+"[a-" = "x"
+"#;
+        let mut config: Config = toml::from_str(toml).unwrap();
+        let error = config.compile_alias_patterns().unwrap_err();
+        let message = format!("{error:#}");
+        assert!(message.starts_with("invalid [aliases.patterns]: invalid regex \"[a-\""));
+        assert!(message.contains("unclosed character class"), "{message}");
+    }
+
+    #[test]
+    fn test_parse_no_speech_threshold() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!((config.model.no_speech_threshold - 0.6).abs() < f32::EPSILON);
+        assert!(!toml::to_string(&config)
+            .unwrap()
+            .contains("no_speech_threshold"));
+
+        let toml = "[model]
+no_speech_threshold = 0.8
+";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!((config.model.no_speech_threshold - 0.8).abs() < f32::EPSILON);
+        assert!(!is_default_model(&config.model));
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("no_speech_threshold = 0.8"));
+    }
+
+    #[test]
+    fn test_profile_discard_modifier() {
+        let toml = r#"
+[[profiles]]
+model_type = "base.en"
+modifiers = ["Command", "Option"]
+key = "V"
+discard_modifier = "Shift"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.profiles[0].discard_modifier.as_deref(),
+            Some("Shift")
+        );
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("discard_modifier = \"Shift\""));
+    }
+
+    #[test]
+    fn test_parse_clipping_threshold() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!((config.audio.clipping_threshold - 0.01).abs() < f64::EPSILON);
+
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\nclipping_threshold = 0.05\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!((config.audio.clipping_threshold - 0.05).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_max_recording_secs() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.audio.max_recording_secs, 30);
+        assert!(!toml::to_string(&config).unwrap().contains("[audio]"));
+
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\nmax_recording_secs = 300\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.audio.max_recording_secs, 300);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("max_recording_secs = 300"));
     }
 
     #[test]
-    fn test_load_creates_default_when_no_config_exists() {
-        let _guard = HOME_TEST_LOCK.lock().unwrap();
+    fn test_parse_device_name() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.audio.device_name, None);
 
-        let temp_base = env::temp_dir();
-        let test_home = temp_base.join(format!(
-            "whisper_test_no_config_{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs()
-        ));
-        fs::create_dir_all(&test_home).unwrap();
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\ndevice_name = \"Yeti\"\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.audio.device_name.as_deref(), Some("Yeti"));
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("device_name = \"Yeti\""));
+    }
 
-        let original_home = env::var("HOME").ok();
-        env::set_var("HOME", test_home.to_str().unwrap());
+    #[test]
+    fn test_parse_preroll_ms() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.audio.preroll_ms, 0);
 
-        // No config exists - should create default
-        let config = Config::load().unwrap();
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\npreroll_ms = 300\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.audio.preroll_ms, 300);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("preroll_ms = 300"));
+    }
 
-        // Verify new config was created at correct path
-        let config_path = test_home.join(".whisper-hotkey/config.toml");
-        assert!(config_path.exists());
+    #[test]
+    fn test_parse_trim_silence() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.audio.trim_silence);
+        assert!((config.audio.silence_threshold - 0.01).abs() < f32::EPSILON);
+        assert_eq!(config.audio.trim_attack_ms, 100);
+        assert_eq!(config.audio.trim_release_ms, 200);
+
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\ntrim_silence = true\nsilence_threshold = 0.03\ntrim_release_ms = 400\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.audio.trim_silence);
+        assert!((config.audio.silence_threshold - 0.03).abs() < f32::EPSILON);
+        assert_eq!(config.audio.trim_attack_ms, 100);
+        assert_eq!(config.audio.trim_release_ms, 400);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("trim_silence = true"));
+    }
 
-        // Verify default values
-        assert_eq!(config.hotkey.modifiers, vec!["Control", "Option"]);
-        assert_eq!(config.hotkey.key, "Z");
-        assert_eq!(config.audio.buffer_size, 1024);
-        assert_eq!(config.audio.sample_rate, 16000);
-        assert_eq!(config.model.model_type, ModelType::Small);
-        assert!(config.telemetry.enabled);
+    #[test]
+    fn test_parse_normalize() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.audio.normalize);
+        assert!((config.audio.target_peak - 0.9).abs() < f32::EPSILON);
 
-        // Restore HOME
-        if let Some(home) = original_home {
-            env::set_var("HOME", home);
-        } else {
-            env::remove_var("HOME");
-        }
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\nnormalize = true\ntarget_peak = 0.7\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.audio.normalize);
+        assert!((config.audio.target_peak - 0.7).abs() < f32::EPSILON);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("normalize = true"));
+    }
 
-        let _ = fs::remove_dir_all(&test_home);
+    #[test]
+    fn test_parse_continuous_drain() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.audio.continuous_drain);
+
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\ncontinuous_drain = true\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.audio.continuous_drain);
+        assert!(!is_default_audio(&config.audio));
     }
 
     #[test]
-    fn test_load_when_new_config_already_exists() {
-        let _guard = HOME_TEST_LOCK.lock().unwrap();
+    fn test_parse_language_rules() {
+        let toml = r#"
+[[language_rules]]
+language = "ja"
+disable_formatting = true
+aliases = false
+
+[[language_rules]]
+language = "de"
+locale = "de-DE"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.language_rules.len(), 2);
+        assert_eq!(config.language_rules[0].language, "ja");
+        assert!(config.language_rules[0].disable_formatting);
+        assert_eq!(config.language_rules[0].aliases, Some(false));
+        assert_eq!(config.language_rules[0].strip_markdown, None);
+        assert_eq!(
+            config.language_rules[1].locale,
+            Some(crate::formatting::Locale::DeDe)
+        );
 
-        let temp_base = env::temp_dir();
-        let test_home = temp_base.join(format!(
-            "whisper_test_existing_{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs()
-        ));
-        fs::create_dir_all(&test_home).unwrap();
+        let serialized = toml::to_string(&config).unwrap();
+        assert!(serialized.contains("[[language_rules]]"));
+        let reparsed: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(reparsed.language_rules, config.language_rules);
+    }
 
-        let original_home = env::var("HOME").ok();
-        env::set_var("HOME", test_home.to_str().unwrap());
+    #[test]
+    fn test_parse_stats() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.stats.enabled);
+        assert_eq!(config.stats.path, "~/.whisper-hotkey/stats.json");
+        assert!(!toml::to_string(&config).unwrap().contains("[stats]"));
+
+        let config: Config = toml::from_str("[stats]\nenabled = true\n").unwrap();
+        assert!(config.stats.enabled);
+        assert!(toml::to_string(&config).unwrap().contains("[stats]"));
+    }
 
-        // Create new config at correct path
-        let new_config_path = test_home.join(".whisper-hotkey/config.toml");
-        fs::create_dir_all(new_config_path.parent().unwrap()).unwrap();
-        let existing_config = r#"[hotkey]
-modifiers = ["Control", "Shift"]
-key = "X"
+    #[test]
+    fn test_parse_webhook() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.webhook, WebhookConfig::default());
+        assert!(!config.webhook.include_text);
+        assert!(!toml::to_string(&config).unwrap().contains("[webhook]"));
+
+        let config: Config = toml::from_str(
+            "[webhook]\nenabled = true\nurl = \"http://localhost:8123/hook\"\ninclude_text = true\n",
+        )
+        .unwrap();
+        assert!(config.webhook.enabled);
+        assert_eq!(config.webhook.url, "http://localhost:8123/hook");
+        assert!(config.webhook.include_text);
+        assert_eq!(config.webhook.timeout_secs, 5);
+        assert!(toml::to_string(&config).unwrap().contains("[webhook]"));
+    }
 
-[audio]
-buffer_size = 4096
-sample_rate = 16000
+    #[test]
+    fn test_parse_timing_debug() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.telemetry.timing_debug);
+        assert!(!toml::to_string(&config).unwrap().contains("[telemetry]"));
+
+        let config: Config = toml::from_str(
+            "[telemetry]\nenabled = true\nlog_path = \"~/.whisper-hotkey/crash.log\"\ntiming_debug = true\n",
+        )
+        .unwrap();
+        assert!(config.telemetry.timing_debug);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("timing_debug = true"));
+    }
 
-[model]
-name = "large"
-path = "/custom/path.bin"
-preload = false
+    #[test]
+    fn test_parse_log_rotation() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.telemetry.log_max_mb, 10);
+        assert_eq!(config.telemetry.log_keep_files, 3);
+
+        let config: Config = toml::from_str(
+            "[telemetry]\nenabled = true\nlog_path = \"~/.whisper-hotkey/crash.log\"\nlog_max_mb = 0\nlog_keep_files = 5\n",
+        )
+        .unwrap();
+        assert_eq!(config.telemetry.log_max_mb, 0);
+        assert_eq!(config.telemetry.log_keep_files, 5);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("log_keep_files = 5"));
+    }
 
-[telemetry]
-enabled = false
-log_path = "/custom/log.txt"
-"#;
-        fs::write(&new_config_path, existing_config).unwrap();
+    #[test]
+    fn test_parse_log_format() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.telemetry.format, LogFormat::Text);
+
+        let config: Config = toml::from_str(
+            "[telemetry]\nenabled = true\nlog_path = \"~/.whisper-hotkey/crash.log\"\nformat = \"json\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.telemetry.format, LogFormat::Json);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("format = \"json\""));
+
+        let result: Result<Config, _> =
+            toml::from_str("[telemetry]\nenabled = true\nlog_path = \"x\"\nformat = \"xml\"\n");
+        assert!(result.is_err());
+    }
 
-        // Create old config too - should not migrate since new exists
-        let old_config_path = test_home.join(".whisper-hotkey.toml");
-        fs::write(&old_config_path, "ignored").unwrap();
+    #[test]
+    fn test_parse_audio_stats_debug() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.telemetry.audio_stats_debug);
+
+        let config: Config = toml::from_str(
+            "[telemetry]\nenabled = true\nlog_path = \"~/.whisper-hotkey/crash.log\"\naudio_stats_debug = true\n",
+        )
+        .unwrap();
+        assert!(config.telemetry.audio_stats_debug);
+        assert!(!config.telemetry.timing_debug);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("audio_stats_debug = true"));
+    }
 
-        // Load - should use new config, ignore old
-        let config = Config::load().unwrap();
+    #[test]
+    fn test_parse_quick_pick() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.quick_pick, QuickPickConfig::default());
+        assert!(!toml::to_string(&config).unwrap().contains("[quick_pick]"));
+
+        let config: Config =
+            toml::from_str("[quick_pick]\nenabled = true\nkey = \"Q\"\ntimeout_ms = 5000\n")
+                .unwrap();
+        assert!(config.quick_pick.enabled);
+        assert_eq!(config.quick_pick.key, "Q");
+        assert_eq!(config.quick_pick.modifiers, vec!["Control", "Option"]);
+        assert_eq!(config.quick_pick.timeout_ms, 5000);
+        assert!(toml::to_string(&config).unwrap().contains("[quick_pick]"));
+    }
 
-        // Verify loaded from new config, not defaults
-        assert_eq!(config.hotkey.key, "X");
-        assert_eq!(config.audio.buffer_size, 4096);
-        assert_eq!(config.model.model_type, ModelType::Large);
-        assert!(!config.telemetry.enabled);
+    #[test]
+    fn test_parse_auto_punctuation() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.auto_punctuation, AutoPunctuationConfig::default());
+        assert!(!config.auto_punctuation.enabled);
+        assert!(!toml::to_string(&config)
+            .unwrap()
+            .contains("[auto_punctuation]"));
+
+        let toml = "[auto_punctuation]\nenabled = true\nperiod_gap_ms = 1200\nperiod = \"!\"\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.auto_punctuation.enabled);
+        assert_eq!(config.auto_punctuation.comma_gap_ms, 300);
+        assert_eq!(config.auto_punctuation.period_gap_ms, 1200);
+        assert_eq!(config.auto_punctuation.comma, ",");
+        assert_eq!(config.auto_punctuation.period, "!");
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("[auto_punctuation]"));
+    }
 
-        // Old config should still exist (not migrated)
-        assert!(old_config_path.exists());
+    #[test]
+    fn test_parse_aliases_preserve_case() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.aliases.preserve_case);
+
+        let config: Config = toml::from_str("[aliases]\npreserve_case = true\n").unwrap();
+        assert!(config.aliases.preserve_case);
+        assert!(toml::to_string(&config)
+            .unwrap()
+            .contains("preserve_case = true"));
+    }
 
-        // Restore HOME
-        if let Some(home) = original_home {
-            env::set_var("HOME", home);
-        } else {
-            env::remove_var("HOME");
-        }
+    #[test]
+    fn test_parse_resampler_auto() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.audio.resampler_auto);
+        assert_eq!(config.audio.resampler_threshold_ms, 5000);
 
-        let _ = fs::remove_dir_all(&test_home);
+        let toml = "[audio]\nbuffer_size = 1024\nsample_rate = 16000\nresampler_auto = true\nresampler_threshold_ms = 2000\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.audio.resampler_auto);
+        assert_eq!(config.audio.resampler_threshold_ms, 2000);
+        assert!(!is_default_audio(&config.audio));
     }
 
     #[test]
-    fn test_save_creates_config_file() {
-        let _guard = HOME_TEST_LOCK.lock().unwrap();
+    fn test_save_stamps_app_version() {
+        let config = Config::default();
+        assert!(config.version.is_none());
+        let toml = config.to_toml().unwrap();
+        assert!(toml.contains(&format!("version = \"{APP_VERSION}\"")));
+
+        // Stamp replaces whatever version the file was loaded with
+        let config: Config = toml::from_str("version = \"0.0.1\"\n").unwrap();
+        assert_eq!(config.version.as_deref(), Some("0.0.1"));
+        let reloaded: Config = toml::from_str(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(reloaded.version.as_deref(), Some(APP_VERSION));
+    }
 
-        let temp_base = env::temp_dir();
-        let test_dir = temp_base.join(format!(
-            "whisper_test_save_{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs()
-        ));
-        fs::create_dir_all(&test_dir).unwrap();
+    #[test]
+    fn test_schema_version_missing_is_v0() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.schema_version, 0);
+        assert_eq!(Config::default().schema_version, SCHEMA_VERSION);
+        assert_eq!(SCHEMA_VERSION, 1);
+    }
 
-        let original_home = env::var("HOME").ok();
-        env::set_var("HOME", test_dir.to_str().unwrap());
+    #[test]
+    fn test_migrate_v0_to_v1_moves_legacy_sections() {
+        let mut config: Config = toml::from_str(
+            "[hotkey]\nmodifiers = [\"Command\"]\nkey = \"M\"\n\n\
+             [model]\nname = \"tiny\"\npath = \"/tmp/tiny.bin\"\nthreads = 8\n\n\
+             [audio]\nbuffer_size = 512\nsample_rate = 16000\n",
+        )
+        .unwrap();
+        config.migrate(0, 1);
+
+        assert_eq!(config.schema_version, 1);
+        assert_eq!(config.profiles.len(), 1);
+        assert_eq!(config.profiles[0].hotkey.key, "M");
+        assert_eq!(config.profiles[0].model_type, ModelType::Tiny);
+        assert_eq!(config.profiles[0].threads, 8);
+        // Unrelated sections are preserved
+        assert_eq!(config.audio.buffer_size, 512);
 
-        let config = Config {
-            profiles: default_profiles(),
-            hotkey: HotkeyConfig {
-                modifiers: vec!["Command".to_owned()],
-                key: "T".to_owned(),
-            },
-            audio: AudioConfig {
-                buffer_size: 2048,
-                sample_rate: 16000,
-            },
-            model: ModelConfig {
-                model_type: ModelType::Base,
-                preload: true,
-                threads: 4,
-                beam_size: 5,
-                language: Some("en".to_owned()),
-            },
-            telemetry: TelemetryConfig {
-                enabled: true,
-                log_path: "/test/log.txt".to_owned(),
-            },
-            recording: RecordingConfig::default(),
-            aliases: AliasesConfig::default(),
-        };
+        let toml = config.to_toml().unwrap();
+        assert!(toml.contains("schema_version = 1"));
+        assert!(!toml.contains("path = \"/tmp/tiny.bin\""));
+    }
 
-        config.save().unwrap();
+    #[test]
+    fn test_migrate_v0_to_v1_keeps_profiles() {
+        let mut config: Config = toml::from_str(
+            "[[profiles]]\nname = \"Notes\"\nmodel_type = \"small.en\"\nmodifiers = [\"Command\"]\nkey = \"N\"\n\n\
+             [aliases]\nenabled = true\n",
+        )
+        .unwrap();
+        assert!(config.upgrade());
+
+        assert_eq!(config.schema_version, SCHEMA_VERSION);
+        assert_eq!(config.profiles.len(), 1);
+        assert_eq!(config.profiles[0].name(), "Notes");
+        assert_eq!(config.profiles[0].model_type, ModelType::SmallEn);
+        assert!(config.aliases.enabled);
+        // Already current: nothing to do
+        assert!(!config.upgrade());
+    }
 
-        let config_path = test_dir.join(".whisper-hotkey/config.toml");
-        assert!(config_path.exists());
+    #[test]
+    fn test_upgrade_leaves_newer_schema() {
+        let mut config: Config = toml::from_str("schema_version = 7\n").unwrap();
+        assert!(!config.upgrade());
+        assert_eq!(config.schema_version, 7);
+        assert!(config.to_toml().unwrap().contains("schema_version = 7"));
+    }
 
-        let contents = fs::read_to_string(&config_path).unwrap();
-        let loaded: Config = toml::from_str(&contents).unwrap();
-        assert_eq!(loaded.hotkey.key, "T");
-        assert_eq!(loaded.audio.buffer_size, 2048);
+    #[test]
+    fn test_compare_version() {
+        assert_eq!(compare_version(None, "1.2.3"), ConfigVersion::Unversioned);
+        assert_eq!(
+            compare_version(Some("1.2.3"), "1.2.3"),
+            ConfigVersion::Current
+        );
+        assert_eq!(
+            compare_version(Some("1.1.9"), "1.2.3"),
+            ConfigVersion::Older("1.1.9".to_owned())
+        );
+        assert_eq!(
+            compare_version(Some("1.10.0"), "1.9.0"),
+            ConfigVersion::Newer("1.10.0".to_owned())
+        );
+        assert_eq!(
+            compare_version(Some("2.0.0"), "1.2.3"),
+            ConfigVersion::Newer("2.0.0".to_owned())
+        );
+    }
 
-        // Restore HOME
-        if let Some(home) = original_home {
-            env::set_var("HOME", home);
-        } else {
-            env::remove_var("HOME");
+    #[test]
+    fn test_compare_version_suffixes_and_garbage() {
+        // Pre-release/build suffixes and a leading `v` are ignored
+        assert_eq!(
+            compare_version(Some("v1.2.3-beta.1"), "1.2.3"),
+            ConfigVersion::Current
+        );
+        assert_eq!(
+            compare_version(Some("1.2.3+abc"), "1.2.3"),
+            ConfigVersion::Current
+        );
+        for garbage in ["", "1.2", "1.2.3.4", "latest"] {
+            assert_eq!(
+                compare_version(Some(garbage), "1.2.3"),
+                ConfigVersion::Unknown(garbage.to_owned())
+            );
         }
+    }
 
-        let _ = fs::remove_dir_all(&test_dir);
+    #[test]
+    fn test_newer_version_still_loads() {
+        let config: Config =
+            toml::from_str("version = \"99.0.0\"\n\n[future_section]\nenabled = true\n").unwrap();
+        assert_eq!(
+            compare_version(config.version.as_deref(), APP_VERSION),
+            ConfigVersion::Newer("99.0.0".to_owned())
+        );
+        config.check_version();
     }
 
     #[test]
-    fn test_parse_config_with_language() {
-        let toml = r#"
-[hotkey]
-modifiers = ["Control"]
-key = "M"
+    fn test_parse_cursor_placeholder() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.insertion.cursor_placeholder.is_none());
 
-[audio]
-buffer_size = 512
-sample_rate = 16000
+        let config: Config = toml::from_str("[insertion]\ncursor_placeholder = \"|\"\n").unwrap();
+        assert_eq!(config.insertion.cursor_placeholder.as_deref(), Some("|"));
+        assert!(!is_default_insertion(&config.insertion));
+    }
 
-[model]
-name = "tiny"
-path = "/tmp/tiny.bin"
-preload = true
-language = "en"
+    #[test]
+    fn test_parse_degraded_behavior() {
+        for (value, expected) in [
+            ("save", DegradedBehavior::Save),
+            ("notify", DegradedBehavior::Notify),
+            ("silent", DegradedBehavior::Silent),
+        ] {
+            let toml = format!("[recording]\ndegraded_behavior = \"{value}\"\n");
+            let config: Config = toml::from_str(&toml).unwrap();
+            assert_eq!(config.recording.degraded_behavior, expected);
+        }
+    }
 
-[telemetry]
-enabled = true
-log_path = "/tmp/crash.log"
-"#;
-        let config: Config = toml::from_str(toml).unwrap();
-        assert_eq!(config.model.language, Some("en".to_owned()));
+    #[test]
+    fn test_parse_degraded_behavior_invalid() {
+        let toml = "[recording]\ndegraded_behavior = \"shout\"\n";
+        let result: Result<Config, _> = toml::from_str(toml);
+        assert!(result.is_err());
     }
 
     #[test]
-    fn test_recording_config_defaults() {
-        let config = RecordingConfig::default();
-        assert!(config.enabled);
-        assert_eq!(config.retention_days, 7);
-        assert_eq!(config.max_count, 100);
-        assert_eq!(config.cleanup_interval_hours, 1);
+    fn test_degraded_behavior_non_default_serialized() {
+        let mut config = Config::default();
+        config.recording.degraded_behavior = DegradedBehavior::Save;
+        let serialized = toml::to_string(&config).unwrap();
+        assert!(serialized.contains("degraded_behavior = \"save\""));
     }
 
     #[test]
@@ -1804,6 +4731,18 @@ cleanup_interval_hours = 1
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         };
         assert_eq!(profile.name(), "custom-name");
     }
@@ -1818,6 +4757,18 @@ cleanup_interval_hours = 1
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         };
         assert_eq!(profile.name(), "small");
     }
@@ -1832,6 +4783,18 @@ cleanup_interval_hours = 1
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         };
         let path = profile.model_path();
         assert!(path.contains("base.en"));
@@ -1855,6 +4818,18 @@ cleanup_interval_hours = 1
                 threads: 4,
                 beam_size: 1,
                 language: Some("en".to_owned()),
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             },
             TranscriptionProfile {
                 name: None,
@@ -1862,11 +4837,24 @@ cleanup_interval_hours = 1
                 hotkey: HotkeyConfig {
                     modifiers: vec!["Shift".to_owned()],
                     key: "X".to_owned(),
+                    mode: HotkeyMode::Hold,
                 },
                 preload: true,
                 threads: 4,
                 beam_size: 1,
                 language: Some("en".to_owned()),
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             },
         ];
         assert!(!is_default_profiles(&profiles));
@@ -1882,6 +4870,18 @@ cleanup_interval_hours = 1
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
         assert!(!is_default_profiles(&profiles));
     }
@@ -1893,6 +4893,7 @@ cleanup_interval_hours = 1
             hotkey: HotkeyConfig {
                 modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
                 key: "V".to_owned(),
+                mode: HotkeyMode::Hold,
             },
             audio: AudioConfig::default(),
             model: ModelConfig {
@@ -1901,10 +4902,32 @@ cleanup_interval_hours = 1
                 threads: 8,
                 beam_size: 5,
                 language: Some("es".to_owned()),
+                custom_path: None,
+                max_loaded_models: 0,
+                no_speech_threshold: 0.6,
+                use_gpu: true,
+                warmup: true,
             },
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         config.migrate_to_profiles();
@@ -1927,15 +4950,29 @@ cleanup_interval_hours = 1
                 hotkey: HotkeyConfig {
                     modifiers: vec!["Alt".to_owned()],
                     key: "Q".to_owned(),
+                    mode: HotkeyMode::Hold,
                 },
                 preload: true,
                 threads: 2,
                 beam_size: 3,
                 language: Some("fr".to_owned()),
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             }],
             hotkey: HotkeyConfig {
                 modifiers: vec!["Command".to_owned()],
                 key: "V".to_owned(),
+                mode: HotkeyMode::Hold,
             },
             audio: AudioConfig::default(),
             model: ModelConfig {
@@ -1944,10 +4981,32 @@ cleanup_interval_hours = 1
                 threads: 8,
                 beam_size: 5,
                 language: Some("es".to_owned()),
+                custom_path: None,
+                max_loaded_models: 0,
+                no_speech_threshold: 0.6,
+                use_gpu: true,
+                warmup: true,
             },
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         config.migrate_to_profiles();
@@ -1969,6 +5028,18 @@ cleanup_interval_hours = 1
                 threads: 4,
                 beam_size: 1,
                 language: Some("en".to_owned()),
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             }],
             hotkey: HotkeyConfig::default(),
             audio: AudioConfig::default(),
@@ -1976,6 +5047,23 @@ cleanup_interval_hours = 1
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         config.ensure_unique_names();
@@ -1994,11 +5082,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
                 TranscriptionProfile {
                     name: None,
@@ -2006,11 +5107,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned()],
                         key: "B".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
                 TranscriptionProfile {
                     name: None,
@@ -2018,19 +5132,27 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned()],
                         key: "C".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
             ],
-            hotkey: HotkeyConfig::default(),
-            audio: AudioConfig::default(),
-            model: ModelConfig::default(),
-            telemetry: TelemetryConfig::default(),
-            recording: RecordingConfig::default(),
-            aliases: AliasesConfig::default(),
+            ..Config::default()
         };
 
         config.ensure_unique_names();
@@ -2052,11 +5174,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
                 TranscriptionProfile {
                     name: None,
@@ -2064,11 +5199,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned()],
                         key: "B".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
             ],
             hotkey: HotkeyConfig::default(),
@@ -2077,6 +5225,23 @@ cleanup_interval_hours = 1
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         config.ensure_unique_names();
@@ -2097,11 +5262,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
                 TranscriptionProfile {
                     name: None,
@@ -2109,11 +5287,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned(), "Option".to_owned()],
                         key: "B".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
             ],
             hotkey: HotkeyConfig::default(),
@@ -2122,6 +5313,23 @@ cleanup_interval_hours = 1
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         assert!(config.validate_hotkeys().is_ok());
@@ -2137,11 +5345,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
                 TranscriptionProfile {
                     name: Some("profile-2".to_owned()),
@@ -2149,11 +5370,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
             ],
             hotkey: HotkeyConfig::default(),
@@ -2162,6 +5396,23 @@ cleanup_interval_hours = 1
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         let result = config.validate_hotkeys();
@@ -2181,11 +5432,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
                 TranscriptionProfile {
                     name: Some("profile-2".to_owned()),
@@ -2193,11 +5457,24 @@ cleanup_interval_hours = 1
                     hotkey: HotkeyConfig {
                         modifiers: vec!["Shift".to_owned(), "Command".to_owned()],
                         key: "A".to_owned(),
+                        mode: HotkeyMode::Hold,
                     },
                     preload: true,
                     threads: 4,
                     beam_size: 1,
                     language: Some("en".to_owned()),
+                    clear_before_insert: false,
+                    strip_markdown: false,
+                    translate_to: None,
+                    custom_path: None,
+                    mode: ProfileMode::Dictation,
+                    preserve_whitespace: false,
+                    fallback_model: None,
+                    discard_modifier: None,
+                    flash_attn: false,
+                    translate: false,
+                    initial_prompt: None,
+                    audio: None,
                 },
             ],
             hotkey: HotkeyConfig::default(),
@@ -2206,6 +5483,23 @@ cleanup_interval_hours = 1
             telemetry: TelemetryConfig::default(),
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: SCHEMA_VERSION,
         };
 
         let result = config.validate_hotkeys();
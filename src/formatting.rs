@@ -0,0 +1,545 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Order of day/month/year components in a numeric date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// 15/01/2025
+    Dmy,
+    /// 01/15/2025
+    Mdy,
+    /// 2025-01-15
+    Ymd,
+}
+
+/// Supported output locales
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Locale {
+    /// English (United States)
+    EnUs,
+    /// English (United Kingdom)
+    EnGb,
+    /// German (Germany)
+    DeDe,
+    /// French (France)
+    FrFr,
+    /// Spanish (Spain)
+    EsEs,
+    /// Polish (Poland)
+    PlPl,
+    /// Japanese (Japan)
+    JaJp,
+}
+
+/// Locale conventions for numbers and dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LocaleFormat {
+    decimal: char,
+    group: char,
+    /// Minimum integer digits above the first group before grouping applies
+    /// (2 = "1234" stays ungrouped, "12 345" is grouped)
+    min_grouping_digits: usize,
+    date_order: DateOrder,
+    date_separator: char,
+}
+
+impl Locale {
+    /// All supported locales with their canonical tags
+    const ALL: [(Self, &'static str); 7] = [
+        (Self::EnUs, "en-US"),
+        (Self::EnGb, "en-GB"),
+        (Self::DeDe, "de-DE"),
+        (Self::FrFr, "fr-FR"),
+        (Self::EsEs, "es-ES"),
+        (Self::PlPl, "pl-PL"),
+        (Self::JaJp, "ja-JP"),
+    ];
+
+    /// Parse a locale tag (`en-US`, `en_us`, `de-DE`; case-insensitive)
+    #[must_use]
+    pub fn parse(tag: &str) -> Option<Self> {
+        let normalized = tag.trim().replace('_', "-");
+        Self::ALL
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(&normalized))
+            .map(|(locale, _)| *locale)
+    }
+
+    /// Canonical locale tag (e.g. `en-US`)
+    #[must_use]
+    pub fn tag(self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(locale, _)| *locale == self)
+            .map_or("en-US", |(_, name)| name)
+    }
+
+    /// Order of date components for this locale
+    #[must_use]
+    pub const fn date_order(self) -> DateOrder {
+        self.format().date_order
+    }
+
+    const fn format(self) -> LocaleFormat {
+        match self {
+            Self::EnUs => LocaleFormat {
+                decimal: '.',
+                group: ',',
+                min_grouping_digits: 1,
+                date_order: DateOrder::Mdy,
+                date_separator: '/',
+            },
+            Self::EnGb => LocaleFormat {
+                decimal: '.',
+                group: ',',
+                min_grouping_digits: 1,
+                date_order: DateOrder::Dmy,
+                date_separator: '/',
+            },
+            Self::DeDe => LocaleFormat {
+                decimal: ',',
+                group: '.',
+                min_grouping_digits: 1,
+                date_order: DateOrder::Dmy,
+                date_separator: '.',
+            },
+            Self::FrFr => LocaleFormat {
+                decimal: ',',
+                // Narrow no-break space, so numbers never wrap across lines
+                group: '\u{202F}',
+                min_grouping_digits: 1,
+                date_order: DateOrder::Dmy,
+                date_separator: '/',
+            },
+            Self::EsEs => LocaleFormat {
+                decimal: ',',
+                group: '.',
+                min_grouping_digits: 2,
+                date_order: DateOrder::Dmy,
+                date_separator: '/',
+            },
+            Self::PlPl => LocaleFormat {
+                decimal: ',',
+                group: '\u{00A0}',
+                min_grouping_digits: 2,
+                date_order: DateOrder::Dmy,
+                date_separator: '.',
+            },
+            Self::JaJp => LocaleFormat {
+                decimal: '.',
+                group: ',',
+                min_grouping_digits: 1,
+                date_order: DateOrder::Ymd,
+                date_separator: '/',
+            },
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value).ok_or_else(|| {
+            let supported: Vec<&str> = Self::ALL.iter().map(|(_, name)| *name).collect();
+            format!(
+                "unsupported locale {value:?} (supported: {})",
+                supported.join(", ")
+            )
+        })
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.tag().to_owned()
+    }
+}
+
+/// Format a plain (en-style) number for a locale
+///
+/// Accepts `1234`, `1234.56` or grouped `1,234.56`. Returns `None` if `number`
+/// isn't in that shape. Ungrouped input is never grouped, so years ("2025")
+/// and codes ("90210") are left intact; grouped input is regrouped with the
+/// locale's separator (subject to its minimum grouping digits).
+#[must_use]
+pub fn format_number(number: &str, locale: Locale) -> Option<String> {
+    let format = locale.format();
+    let (integer, fraction) = match number.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (number, None),
+    };
+
+    if fraction.is_some_and(|f| f.is_empty() || !f.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+
+    let grouped_input = integer.contains(',');
+    let digits: String = if grouped_input {
+        // Valid grouping: 1-3 leading digits, then groups of exactly 3
+        let mut groups = integer.split(',');
+        let first = groups.next()?;
+        if first.is_empty() || first.len() > 3 || !first.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut digits = first.to_owned();
+        for group in groups {
+            if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            digits.push_str(group);
+        }
+        digits
+    } else {
+        if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        integer.to_owned()
+    };
+
+    let mut result = if grouped_input && digits.len() >= 3 + format.min_grouping_digits {
+        group_digits(&digits, format.group)
+    } else {
+        digits
+    };
+
+    if let Some(fraction) = fraction {
+        result.push(format.decimal);
+        result.push_str(fraction);
+    }
+
+    Some(result)
+}
+
+/// Insert `separator` between groups of three digits
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3 * separator.len_utf8());
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            result.push(separator);
+        }
+        result.push(ch);
+    }
+    result
+}
+
+/// Format a numeric date for a locale
+///
+/// Accepts ISO `2025-01-15` and unambiguous slash dates: `01/15/2025` (month
+/// first) or `15/01/2025` (day first). Returns `None` for anything else,
+/// invalid day/month values, or slash dates where either part could be the
+/// month (`03/04/2025`), since reordering those could silently swap them.
+#[must_use]
+pub fn format_date(date: &str, locale: Locale) -> Option<String> {
+    let Date { year, month, day } = parse_date(date)?;
+    let format = locale.format();
+    let sep = format.date_separator;
+
+    Some(match format.date_order {
+        DateOrder::Dmy => format!("{day:02}{sep}{month:02}{sep}{year:04}"),
+        DateOrder::Mdy => format!("{month:02}{sep}{day:02}{sep}{year:04}"),
+        DateOrder::Ymd => format!("{year:04}{sep}{month:02}{sep}{day:02}"),
+    })
+}
+
+/// Parse an all-digit date component of at most `max_len` digits
+fn parse_date_part(part: &str, max_len: usize) -> Option<u32> {
+    if part.is_empty() || part.len() > max_len || !part.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    part.parse().ok()
+}
+
+/// Calendar date parsed from a numeric token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Date {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+/// Parse an ISO or unambiguous slash date
+fn parse_date(date: &str) -> Option<Date> {
+    let (year, month, day) = if let [y, m, d] = date.split('-').collect::<Vec<_>>()[..] {
+        if y.len() != 4 {
+            return None;
+        }
+        (
+            parse_date_part(y, 4)?,
+            parse_date_part(m, 2)?,
+            parse_date_part(d, 2)?,
+        )
+    } else if let [a, b, y] = date.split('/').collect::<Vec<_>>()[..] {
+        if y.len() != 4 {
+            return None;
+        }
+        let (a, b) = (parse_date_part(a, 2)?, parse_date_part(b, 2)?);
+        // Only the part that can't be a month tells the order apart
+        let (month, day) = match (a > 12, b > 12) {
+            (true, false) => (b, a),
+            (false, true) => (a, b),
+            _ => return None,
+        };
+        (parse_date_part(y, 4)?, month, day)
+    } else {
+        return None;
+    };
+
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some(Date { year, month, day })
+}
+
+/// Characters that can appear inside a number or date token
+const fn is_token_char(ch: char) -> bool {
+    ch.is_ascii_digit() || matches!(ch, '.' | ',' | '/' | '-')
+}
+
+/// Reformat all numbers and dates in `text` for `locale`
+///
+/// Tokens that aren't valid numbers/dates (lists like "1,2,3", versions like
+/// "1.2.3") are left unchanged. Trailing punctuation is preserved.
+#[must_use]
+pub fn apply_locale(text: &str, locale: Locale) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        // Digits inside words ("mp3", "x86") are left alone
+        let preceded_by_word = rest[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(|c: char| !is_token_char(c)).unwrap_or(rest.len());
+        let mut token = &rest[..end];
+        // Peel off sentence punctuation ("costs 1,234.", "on 01/15/2025,")
+        token = token.trim_end_matches(['.', ',', '/', '-']);
+        // Ordinals/units glued to the number ("3rd", "10am") are left alone
+        let followed_by_word = rest[token.len()..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric);
+
+        let formatted = if preceded_by_word || followed_by_word {
+            None
+        } else {
+            format_date(token, locale).or_else(|| format_number(token, locale))
+        };
+        result.push_str(formatted.as_deref().unwrap_or(token));
+        rest = &rest[token.len()..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_parse() {
+        assert_eq!(Locale::parse("en-US"), Some(Locale::EnUs));
+        assert_eq!(Locale::parse("en_us"), Some(Locale::EnUs));
+        assert_eq!(Locale::parse(" DE-de "), Some(Locale::DeDe));
+        assert_eq!(Locale::parse("pl-PL"), Some(Locale::PlPl));
+        assert_eq!(Locale::parse("xx-YY"), None);
+        assert_eq!(Locale::parse(""), None);
+    }
+
+    #[test]
+    fn test_locale_try_from_error_lists_supported() {
+        let err = Locale::try_from("klingon".to_owned()).unwrap_err();
+        assert!(err.contains("klingon"));
+        assert!(err.contains("en-US"));
+    }
+
+    #[test]
+    fn test_locale_roundtrip_tag() {
+        for (locale, tag) in Locale::ALL {
+            assert_eq!(locale.tag(), tag);
+            assert_eq!(Locale::parse(tag), Some(locale));
+            assert_eq!(String::from(locale), tag);
+        }
+    }
+
+    #[test]
+    fn test_format_number_grouping_en_vs_de() {
+        assert_eq!(
+            format_number("1,234.56", Locale::EnUs).as_deref(),
+            Some("1,234.56")
+        );
+        assert_eq!(
+            format_number("1,234.56", Locale::DeDe).as_deref(),
+            Some("1.234,56")
+        );
+        assert_eq!(
+            format_number("1,234,567", Locale::DeDe).as_deref(),
+            Some("1.234.567")
+        );
+    }
+
+    #[test]
+    fn test_format_number_fr_narrow_space() {
+        assert_eq!(
+            format_number("12,345.6", Locale::FrFr).as_deref(),
+            Some("12\u{202F}345,6")
+        );
+    }
+
+    #[test]
+    fn test_format_number_decimal_only() {
+        assert_eq!(format_number("3.14", Locale::DeDe).as_deref(), Some("3,14"));
+        assert_eq!(format_number("3.14", Locale::EnGb).as_deref(), Some("3.14"));
+    }
+
+    #[test]
+    fn test_format_number_years_not_grouped() {
+        // Ungrouped 4-digit integers (years) stay ungrouped in every locale
+        for (locale, _) in Locale::ALL {
+            assert_eq!(format_number("2025", locale).as_deref(), Some("2025"));
+        }
+        // Ungrouped long numbers (codes, ids) are not grouped either
+        assert_eq!(
+            format_number("90210", Locale::DeDe).as_deref(),
+            Some("90210")
+        );
+    }
+
+    #[test]
+    fn test_format_number_min_grouping_digits() {
+        // Polish/Spanish don't group 4-digit numbers
+        assert_eq!(
+            format_number("1,234", Locale::PlPl).as_deref(),
+            Some("1234")
+        );
+        assert_eq!(
+            format_number("1,234", Locale::EsEs).as_deref(),
+            Some("1234")
+        );
+        assert_eq!(
+            format_number("12,345", Locale::PlPl).as_deref(),
+            Some("12\u{00A0}345")
+        );
+        assert_eq!(
+            format_number("12,345", Locale::EsEs).as_deref(),
+            Some("12.345")
+        );
+    }
+
+    #[test]
+    fn test_format_number_rejects_invalid_grouping() {
+        assert_eq!(format_number("1,2,3", Locale::DeDe), None);
+        assert_eq!(format_number("1234,567", Locale::DeDe), None);
+        assert_eq!(format_number("1.2.3", Locale::DeDe), None);
+        assert_eq!(format_number("1.", Locale::DeDe), None);
+        assert_eq!(format_number("", Locale::DeDe), None);
+    }
+
+    #[test]
+    fn test_format_date_orders() {
+        assert_eq!(
+            format_date("01/15/2025", Locale::EnUs).as_deref(),
+            Some("01/15/2025")
+        );
+        assert_eq!(
+            format_date("01/15/2025", Locale::EnGb).as_deref(),
+            Some("15/01/2025")
+        );
+        assert_eq!(
+            format_date("01/15/2025", Locale::DeDe).as_deref(),
+            Some("15.01.2025")
+        );
+        assert_eq!(
+            format_date("01/15/2025", Locale::JaJp).as_deref(),
+            Some("2025/01/15")
+        );
+    }
+
+    #[test]
+    fn test_format_date_iso_input() {
+        assert_eq!(
+            format_date("2025-01-15", Locale::EnUs).as_deref(),
+            Some("01/15/2025")
+        );
+        assert_eq!(
+            format_date("2025-1-5", Locale::PlPl).as_deref(),
+            Some("05.01.2025")
+        );
+    }
+
+    #[test]
+    fn test_format_date_day_first_when_unambiguous() {
+        assert_eq!(
+            format_date("15/01/2025", Locale::EnUs).as_deref(),
+            Some("01/15/2025")
+        );
+    }
+
+    #[test]
+    fn test_format_date_ambiguous_slash_date_unchanged() {
+        assert_eq!(format_date("03/04/2025", Locale::EnGb), None);
+        assert_eq!(format_date("03/04/2025", Locale::DeDe), None);
+        assert_eq!(format_date("12/12/2025", Locale::EnUs), None);
+        assert_eq!(
+            apply_locale("Due 03/04/2025.", Locale::EnGb),
+            "Due 03/04/2025."
+        );
+        // ISO dates carry their own order
+        assert_eq!(
+            format_date("2025-03-04", Locale::EnGb).as_deref(),
+            Some("04/03/2025")
+        );
+    }
+
+    #[test]
+    fn test_format_date_invalid() {
+        assert_eq!(format_date("13/13/2025", Locale::EnUs), None);
+        assert_eq!(format_date("2025-00-10", Locale::EnUs), None);
+        assert_eq!(format_date("1/2/25", Locale::EnUs), None);
+        assert_eq!(format_date("2025", Locale::EnUs), None);
+    }
+
+    #[test]
+    fn test_locale_date_order() {
+        assert_eq!(Locale::EnUs.date_order(), DateOrder::Mdy);
+        assert_eq!(Locale::DeDe.date_order(), DateOrder::Dmy);
+        assert_eq!(Locale::JaJp.date_order(), DateOrder::Ymd);
+    }
+
+    #[test]
+    fn test_apply_locale_sentence() {
+        assert_eq!(
+            apply_locale("It costs 1,234.56 euros, due 01/15/2025.", Locale::DeDe),
+            "It costs 1.234,56 euros, due 15.01.2025."
+        );
+    }
+
+    #[test]
+    fn test_apply_locale_keeps_years_and_trailing_punctuation() {
+        assert_eq!(
+            apply_locale("Back in 2025, we paid 3.5.", Locale::DeDe),
+            "Back in 2025, we paid 3,5."
+        );
+    }
+
+    #[test]
+    fn test_apply_locale_leaves_non_numbers() {
+        let text = "Version 1.2.3 of mp3 player, steps 1,2,3";
+        assert_eq!(apply_locale(text, Locale::DeDe), text);
+    }
+
+    #[test]
+    fn test_apply_locale_no_numbers() {
+        assert_eq!(apply_locale("hello world", Locale::FrFr), "hello world");
+        assert_eq!(apply_locale("", Locale::FrFr), "");
+    }
+}
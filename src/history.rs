@@ -0,0 +1,427 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::{Config, HistoryConfig};
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// One recorded transcription (a line in the history JSONL file)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HistoryEntry {
+    /// Unix timestamp (seconds, UTC)
+    pub timestamp: u64,
+    /// Profile that produced the transcription
+    pub profile: String,
+    /// Inserted text (after aliases/formatting)
+    pub text: String,
+}
+
+/// Filter for history search
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryQuery {
+    /// Case-insensitive substring (None or empty = match all)
+    pub text: Option<String>,
+    /// Earliest timestamp, inclusive
+    pub since: Option<u64>,
+    /// Latest timestamp, exclusive
+    pub until: Option<u64>,
+}
+
+impl HistoryQuery {
+    /// Whether `entry` matches every set criterion
+    #[must_use]
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        let text_ok = self.text.as_deref().map_or(true, |needle| {
+            needle.is_empty() || entry.text.to_lowercase().contains(&needle.to_lowercase())
+        });
+        let since_ok = self.since.map_or(true, |since| entry.timestamp >= since);
+        let until_ok = self.until.map_or(true, |until| entry.timestamp < until);
+        text_ok && since_ok && until_ok
+    }
+}
+
+/// Entries matching `query`, in file (chronological) order
+#[must_use]
+pub fn search<'a>(entries: &'a [HistoryEntry], query: &HistoryQuery) -> Vec<&'a HistoryEntry> {
+    entries
+        .iter()
+        .filter(|entry| query.matches(entry))
+        .collect()
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)] // month/day < 32; era math is signed
+const fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Calendar date (year, month, day) for days since 1970-01-01
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)] // month/day always fit in u32
+const fn civil_from_days(days: i64) -> CivilDate {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    CivilDate { year, month, day }
+}
+
+struct CivilDate {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+/// Parse `YYYY-MM-DD` as the Unix timestamp of that day's midnight (UTC)
+///
+/// # Errors
+/// Returns error if the date is malformed, out of range or before 1970
+pub fn parse_date(date: &str) -> Result<u64> {
+    let invalid = || anyhow::anyhow!("invalid date {date:?} (expected YYYY-MM-DD)");
+
+    let mut parts = date.trim().splitn(3, '-');
+    let year: i64 = parts
+        .next()
+        .and_then(|p| p.parse().ok())
+        .ok_or_else(invalid)?;
+    let month: u32 = parts
+        .next()
+        .and_then(|p| p.parse().ok())
+        .ok_or_else(invalid)?;
+    let day: u32 = parts
+        .next()
+        .and_then(|p| p.parse().ok())
+        .ok_or_else(invalid)?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+
+    // Reject dates that don't exist (e.g. Feb 30) by round-tripping
+    let days = days_from_civil(year, month, day);
+    let back = civil_from_days(days);
+    if (back.year, back.month, back.day) != (year, month, day) {
+        return Err(invalid());
+    }
+
+    u64::try_from(days)
+        .map(|days| days * SECS_PER_DAY)
+        .map_err(|_| invalid())
+}
+
+/// Format a Unix timestamp as `YYYY-MM-DD HH:MM UTC`
+#[must_use]
+#[allow(clippy::cast_possible_wrap)] // timestamps are far below i64::MAX days
+pub fn format_timestamp(timestamp: u64) -> String {
+    let date = civil_from_days((timestamp / SECS_PER_DAY) as i64);
+    let secs_of_day = timestamp % SECS_PER_DAY;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        date.year,
+        date.month,
+        date.day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60
+    )
+}
+
+/// Parse history JSONL contents, skipping malformed lines
+#[must_use]
+pub fn parse_entries(contents: &str) -> Vec<HistoryEntry> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                tracing::warn!(error = %e, "skipping malformed history line");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Load all entries from a history file (missing file = empty history)
+///
+/// # Errors
+/// Returns error if the file exists but can't be read
+pub fn load(path: &Path) -> Result<Vec<HistoryEntry>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(parse_entries(&contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e).with_context(|| format!("failed to read history file {}", path.display())),
+    }
+}
+
+/// Append-only transcription history, trimmed to the last `max_entries`
+pub struct HistoryLog {
+    path: PathBuf,
+    /// Maximum retained entries (0 = unlimited)
+    max_entries: usize,
+    /// Serializes appends from concurrent transcriptions
+    write_lock: Mutex<()>,
+}
+
+impl HistoryLog {
+    /// Create log writing to `path`
+    #[must_use]
+    pub const fn new(path: PathBuf, max_entries: usize) -> Self {
+        Self {
+            path,
+            max_entries,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Create log from `[history]` config (`None` when disabled)
+    ///
+    /// # Errors
+    /// Returns error if the history path can't be expanded
+    pub fn from_config(config: &HistoryConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let path = Config::expand_path(&config.path).context("failed to expand history path")?;
+        Ok(Some(Self::new(path, config.max_entries)))
+    }
+
+    /// Append a transcription with the current time
+    ///
+    /// # Errors
+    /// Returns error if the history file can't be written
+    pub fn record(&self, profile: &str, text: &str) -> Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.append(&HistoryEntry {
+            timestamp,
+            profile: profile.to_owned(),
+            text: text.to_owned(),
+        })
+    }
+
+    /// Append `entry`, then drop the oldest entries beyond `max_entries`
+    ///
+    /// # Errors
+    /// Returns error if the history file can't be written
+    pub fn append(&self, entry: &HistoryEntry) -> Result<()> {
+        let _guard = self
+            .write_lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).context("failed to create history directory")?;
+        }
+
+        let line = serde_json::to_string(entry).context("failed to serialize history entry")?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("failed to open history file {}", self.path.display()))?;
+        writeln!(file, "{line}").context("failed to append history entry")?;
+        drop(file);
+
+        self.trim()
+    }
+
+    /// Rewrite the file keeping only the newest `max_entries` lines
+    fn trim(&self) -> Result<()> {
+        if self.max_entries == 0 {
+            return Ok(());
+        }
+        let contents = fs::read_to_string(&self.path).context("failed to read history file")?;
+        let lines: Vec<&str> = contents.lines().filter(|l| !l.trim().is_empty()).collect();
+        if lines.len() <= self.max_entries {
+            return Ok(());
+        }
+
+        let kept = &lines[lines.len() - self.max_entries..];
+        let tmp_path = self.path.with_extension("jsonl.tmp");
+        fs::write(&tmp_path, format!("{}\n", kept.join("\n")))
+            .context("failed to write trimmed history")?;
+        fs::rename(&tmp_path, &self.path).context("failed to replace history file")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, text: &str) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            profile: "default".to_owned(),
+            text: text.to_owned(),
+        }
+    }
+
+    fn sample_entries() -> Vec<HistoryEntry> {
+        vec![
+            entry(
+                parse_date("2025-01-10").unwrap() + 3600,
+                "Budget meeting notes",
+            ),
+            entry(parse_date("2025-01-15").unwrap() + 60, "Call the dentist"),
+            entry(parse_date("2025-01-20").unwrap(), "Follow up on the budget"),
+        ]
+    }
+
+    fn texts<'a>(entries: &[&'a HistoryEntry]) -> Vec<&'a str> {
+        entries.iter().map(|e| e.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_substring_case_insensitive() {
+        let entries = sample_entries();
+        let query = HistoryQuery {
+            text: Some("BUDGET".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            texts(&search(&entries, &query)),
+            vec!["Budget meeting notes", "Follow up on the budget"]
+        );
+    }
+
+    #[test]
+    fn test_search_no_match() {
+        let entries = sample_entries();
+        let query = HistoryQuery {
+            text: Some("holiday".to_owned()),
+            ..Default::default()
+        };
+        assert!(search(&entries, &query).is_empty());
+    }
+
+    #[test]
+    fn test_search_empty_query_matches_all() {
+        let entries = sample_entries();
+        assert_eq!(search(&entries, &HistoryQuery::default()).len(), 3);
+        let query = HistoryQuery {
+            text: Some(String::new()),
+            ..Default::default()
+        };
+        assert_eq!(search(&entries, &query).len(), 3);
+    }
+
+    #[test]
+    fn test_search_date_range() {
+        let entries = sample_entries();
+        let query = HistoryQuery {
+            since: Some(parse_date("2025-01-15").unwrap()),
+            until: Some(parse_date("2025-01-20").unwrap()),
+            ..Default::default()
+        };
+        // since inclusive, until exclusive
+        assert_eq!(texts(&search(&entries, &query)), vec!["Call the dentist"]);
+
+        let query = HistoryQuery {
+            since: Some(parse_date("2025-01-11").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(search(&entries, &query).len(), 2);
+    }
+
+    #[test]
+    fn test_search_substring_and_date_range() {
+        let entries = sample_entries();
+        let query = HistoryQuery {
+            text: Some("budget".to_owned()),
+            since: Some(parse_date("2025-01-12").unwrap()),
+            until: None,
+        };
+        assert_eq!(
+            texts(&search(&entries, &query)),
+            vec!["Follow up on the budget"]
+        );
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("1970-01-01").unwrap(), 0);
+        assert_eq!(parse_date("1970-01-02").unwrap(), SECS_PER_DAY);
+        assert_eq!(parse_date("2024-02-29").unwrap(), 1_709_164_800);
+        assert_eq!(parse_date("2025-01-15").unwrap(), 1_736_899_200);
+    }
+
+    #[test]
+    fn test_parse_date_invalid() {
+        for date in [
+            "",
+            "2025",
+            "2025-13-01",
+            "2025-02-30",
+            "2023-02-29",
+            "1969-12-31",
+            "jan 5",
+        ] {
+            assert!(parse_date(date).is_err(), "accepted {date:?}");
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(
+            format_timestamp(1_736_899_200 + 13 * 3600 + 5 * 60 + 59),
+            "2025-01-15 13:05 UTC"
+        );
+    }
+
+    #[test]
+    fn test_parse_entries_skips_malformed() {
+        let contents = "{\"timestamp\":1,\"profile\":\"p\",\"text\":\"a\"}\nnot json\n\n{\"timestamp\":2,\"profile\":\"p\",\"text\":\"b\"}\n";
+        let entries = parse_entries(contents);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].text, "b");
+    }
+
+    #[test]
+    fn test_history_log_append_and_trim() {
+        let dir = std::env::temp_dir().join(format!("whisper-history-{}", std::process::id()));
+        let path = dir.join("history.jsonl");
+        let _ = fs::remove_dir_all(&dir);
+
+        let log = HistoryLog::new(path.clone(), 2);
+        for (i, text) in ["one", "two", "three"].iter().enumerate() {
+            log.append(&entry(i as u64, text)).unwrap();
+        }
+
+        let entries = load(&path).unwrap();
+        assert_eq!(
+            entries.iter().map(|e| e.text.as_str()).collect::<Vec<_>>(),
+            vec!["two", "three"]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let path = std::env::temp_dir().join("whisper-history-does-not-exist.jsonl");
+        assert!(load(&path).unwrap().is_empty());
+    }
+}
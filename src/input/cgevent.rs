@@ -1,5 +1,7 @@
-use core_graphics::event::{CGEvent, CGEventTapLocation};
+use crate::input::strategy::InsertionChain;
+use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode, KeyCode};
 use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, error, info};
 
@@ -23,6 +25,30 @@ pub fn generate_text_preview(text: &str) -> String {
     }
 }
 
+/// Default UTF-16 code units per keyboard event
+pub const DEFAULT_CHUNK_SIZE: usize = 200;
+
+/// Default pause between keyboard events of one insertion
+pub const DEFAULT_CHUNK_DELAY: Duration = Duration::from_millis(10);
+
+/// How keystroke insertion splits long text into separate keyboard events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chunking {
+    /// UTF-16 code units per event (0 = whole text in one event)
+    pub size: usize,
+    /// Pause between events
+    pub delay: Duration,
+}
+
+impl Default for Chunking {
+    fn default() -> Self {
+        Self {
+            size: DEFAULT_CHUNK_SIZE,
+            delay: DEFAULT_CHUNK_DELAY,
+        }
+    }
+}
+
 /// Text insertion errors
 #[derive(Debug, Error)]
 pub enum TextInsertionError {
@@ -37,29 +63,48 @@ pub enum TextInsertionError {
     /// Text is empty
     #[error("text is empty")]
     EmptyText,
+
+    /// Copying text to the clipboard failed
+    #[error("clipboard paste failed: {0}")]
+    Clipboard(String),
+
+    /// Text was sent but not found in the focused field
+    #[error("inserted text not found in focused field")]
+    NotVerified,
 }
 
 /// Inserts text at the current cursor position using `CGEvent` API
 ///
+/// Same as [`insert_text_chunked`] with the default chunking.
+///
+/// # Errors
+/// Returns error if `CGEvent` creation fails or text is empty.
+pub fn insert_text(text: &str) -> Result<(), TextInsertionError> {
+    insert_text_chunked(text, &Chunking::default())
+}
+
+/// Inserts text at the current cursor position, `chunking.size` UTF-16 units per event
+///
 /// # Errors
 /// Returns error if `CGEvent` creation fails or text is empty.
 ///
 /// # Implementation
-/// Uses `CGEventKeyboardSetUnicodeString` to simulate keyboard input.
+/// Uses `CGEventKeyboardSetUnicodeString` to simulate keyboard input, one
+/// event per chunk with `chunking.delay` between them.
 /// Requires Input Monitoring permission (verified at app startup).
 ///
 /// # Known Limitations
 /// - `event.post()` does not return errors - if insertion fails silently,
 ///   check System Settings → Privacy & Security → Input Monitoring
 /// - Some apps block `CGEvent` insertion (e.g., Terminal with secure input)
-/// - No clipboard fallback (by design)
+/// - No clipboard fallback here; see `InsertionChain` for ordered fallback methods
 ///
 /// # Permissions
 /// Input Monitoring permission is verified at startup via
 /// `check_input_monitoring_permission()`. If that check passed, this function
 /// should work. If insertions fail at runtime, the user may have revoked permission
 /// or the target app has secure input enabled.
-pub fn insert_text(text: &str) -> Result<(), TextInsertionError> {
+pub fn insert_text_chunked(text: &str, chunking: &Chunking) -> Result<(), TextInsertionError> {
     if text.is_empty() {
         error!("attempted to insert empty text");
         return Err(TextInsertionError::EmptyText);
@@ -83,29 +128,32 @@ pub fn insert_text(text: &str) -> Result<(), TextInsertionError> {
         })?;
     debug!("✓ CGEventSource created successfully");
 
-    // Create a keyboard event with dummy keycode (will be overridden by string)
-    debug!("creating keyboard CGEvent");
-    let event = CGEvent::new_keyboard_event(source, 0, true).map_err(|()| {
-        error!("FAILED: CGEvent creation - unexpected error after permission check passed");
-        TextInsertionError::EventCreation
-    })?;
-    debug!("✓ keyboard CGEvent created successfully");
-
-    // Set the text to insert
-    // Note: set_string_from_utf16_unchecked is not marked unsafe in the core-graphics crate.
-    // SAFETY: The UTF-16 slice passed to set_string_from_utf16_unchecked must be valid UTF-16
-    // (no unpaired surrogates). This is guaranteed because Rust's encode_utf16() on &str
-    // always produces valid UTF-16.
-    debug!("encoding text to UTF-16 for insertion");
-    let utf16: Vec<u16> = text.encode_utf16().collect();
-    event.set_string_from_utf16_unchecked(&utf16);
-    debug!(utf16_len = utf16.len(), "✓ text set on CGEvent");
-
-    // Post the event to the HID system
-    // NOTE: post() does not return a result. If this fails (e.g., target app has
-    // secure input enabled), the failure is silent. Permission was verified at startup.
-    debug!("posting CGEvent to HID system");
-    event.post(CGEventTapLocation::HID);
+    // Slower apps drop characters from one long event, so post the text in chunks
+    let chunks = chunk_utf16(text, chunking.size);
+    debug!(chunks = chunks.len(), "✓ text encoded to UTF-16");
+    for (index, chunk) in chunks.iter().enumerate() {
+        if index > 0 {
+            std::thread::sleep(chunking.delay);
+        }
+
+        // Create a keyboard event with dummy keycode (will be overridden by string)
+        let event = CGEvent::new_keyboard_event(source.clone(), 0, true).map_err(|()| {
+            error!("FAILED: CGEvent creation - unexpected error after permission check passed");
+            TextInsertionError::EventCreation
+        })?;
+
+        // Note: set_string_from_utf16_unchecked is not marked unsafe in the core-graphics crate.
+        // SAFETY: The UTF-16 slice passed to set_string_from_utf16_unchecked must be valid UTF-16
+        // (no unpaired surrogates). chunk_utf16 only splits between characters, so every
+        // chunk of Rust's always-valid encode_utf16() output is valid on its own.
+        event.set_string_from_utf16_unchecked(chunk);
+
+        // Post the event to the HID system
+        // NOTE: post() does not return a result. If this fails (e.g., target app has
+        // secure input enabled), the failure is silent. Permission was verified at startup.
+        event.post(CGEventTapLocation::HID);
+        debug!(chunk = index, utf16_len = chunk.len(), "✓ chunk posted");
+    }
 
     info!(
         text_len = text.len(),
@@ -119,15 +167,337 @@ pub fn insert_text(text: &str) -> Result<(), TextInsertionError> {
     Ok(())
 }
 
-/// Attempts to insert text, logging errors without panicking
+/// Single synthesized key event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyStroke {
+    /// macOS virtual keycode
+    pub keycode: CGKeyCode,
+    /// Key down (true) or key up (false)
+    pub key_down: bool,
+    /// Hold Command modifier
+    pub command: bool,
+}
+
+/// Key events that clear the focused field: Cmd+A (select all), then Delete
+#[must_use]
+pub const fn clear_field_sequence() -> [KeyStroke; 4] {
+    [
+        KeyStroke {
+            keycode: KeyCode::ANSI_A,
+            key_down: true,
+            command: true,
+        },
+        KeyStroke {
+            keycode: KeyCode::ANSI_A,
+            key_down: false,
+            command: true,
+        },
+        KeyStroke {
+            keycode: KeyCode::DELETE,
+            key_down: true,
+            command: false,
+        },
+        KeyStroke {
+            keycode: KeyCode::DELETE,
+            key_down: false,
+            command: false,
+        },
+    ]
+}
+
+/// Whether to clear the focused field before inserting `text`
+///
+/// Never clears for empty/whitespace-only transcriptions, so silence can't wipe a field.
+#[must_use]
+pub fn should_clear_before_insert(clear_before_insert: bool, text: &str) -> bool {
+    clear_before_insert && !text.trim().is_empty()
+}
+
+/// Post a sequence of key events to the HID system
+///
+/// # Errors
+/// Returns error if `CGEvent` source or event creation fails
+pub fn post_key_sequence(keys: &[KeyStroke]) -> Result<(), TextInsertionError> {
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|()| TextInsertionError::EventSourceCreation)?;
+
+    for key in keys {
+        let event = CGEvent::new_keyboard_event(source.clone(), key.keycode, key.key_down)
+            .map_err(|()| TextInsertionError::EventCreation)?;
+        if key.command {
+            event.set_flags(CGEventFlags::CGEventFlagCommand);
+        }
+        event.post(CGEventTapLocation::HID);
+    }
+
+    Ok(())
+}
+
+/// Pause after the priming events so the event source is ready for the text
+pub const PRIME_DELAY: Duration = Duration::from_millis(15);
+
+/// No-op warm-up posted before inserting text (`[insertion] prime_insertion`)
+///
+/// Works around apps dropping the first character typed from a brand-new event source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Priming {
+    /// Events that change nothing in the target app
+    pub keys: [KeyStroke; 2],
+    /// Wait before the real insertion
+    pub delay: Duration,
+}
+
+/// Key events with no effect on their own: a bare Shift press and release
+#[must_use]
+pub const fn prime_sequence() -> [KeyStroke; 2] {
+    [
+        KeyStroke {
+            keycode: KeyCode::SHIFT,
+            key_down: true,
+            command: false,
+        },
+        KeyStroke {
+            keycode: KeyCode::SHIFT,
+            key_down: false,
+            command: false,
+        },
+    ]
+}
+
+/// Priming to run before inserting `text` (None = insert directly)
+///
+/// Empty text is never inserted, so it isn't primed either.
+#[must_use]
+pub fn priming_for(prime_insertion: bool, text: &str) -> Option<Priming> {
+    (prime_insertion && !text.is_empty()).then_some(Priming {
+        keys: prime_sequence(),
+        delay: PRIME_DELAY,
+    })
+}
+
+/// Post the priming events, then give the event source time to settle
+///
+/// # Errors
+/// Returns error if `CGEvent` source or event creation fails
+pub fn prime(priming: &Priming) -> Result<(), TextInsertionError> {
+    debug!(delay_ms = priming.delay.as_millis(), "priming event source");
+    post_key_sequence(&priming.keys)?;
+    std::thread::sleep(priming.delay);
+    Ok(())
+}
+
+/// `text` followed by a space and/or a newline (pure)
+///
+/// With both set, the space comes before the newline. Empty text stays empty,
+/// so a blank transcription never types a lone separator.
+#[must_use]
+pub fn with_suffix(text: &str, space: bool, newline: bool) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push_str(text);
+    if space {
+        out.push(' ');
+    }
+    if newline {
+        out.push('\n');
+    }
+    out
+}
+
+/// Text to insert and how far to move the cursor back afterwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorPlacement {
+    /// Text with the placeholder removed
+    pub text: String,
+    /// Left-arrow presses from the end of the text to the placeholder position
+    pub left_moves: usize,
+}
+
+/// Whether `c` is part of the previous character on screen (no cursor stop before it)
+const fn joins_previous(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}' // combining diacritical marks
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{FE00}'..='\u{FE0F}' // variation selectors (emoji presentation)
+        | '\u{1F3FB}'..='\u{1F3FF}' // skin tone modifiers
+        | '\u{200D}') // zero-width joiner
+}
+
+/// Number of left-arrow presses needed to cross `text` (pure)
+///
+/// Counts characters as the cursor sees them: combining marks, variation
+/// selectors and ZWJ emoji sequences don't add a stop, and `\r\n` is one.
+#[must_use]
+pub fn cursor_stops(text: &str) -> usize {
+    let mut stops = 0;
+    let mut prev = None;
+    for c in text.chars() {
+        if !joins(prev, c) {
+            stops += 1;
+        }
+        prev = Some(c);
+    }
+    stops
+}
+
+/// Whether `c` belongs to the same on-screen character as `prev`
+fn joins(prev: Option<char>, c: char) -> bool {
+    joins_previous(c) || prev == Some('\u{200D}') || (prev == Some('\r') && c == '\n')
+}
+
+/// UTF-16 encoding of `text` split into chunks of at most `size` code units (pure)
+///
+/// Splits only between characters, so surrogate pairs are never separated, and
+/// keeps combining marks, ZWJ emoji sequences and `\r\n` with the character they
+/// belong to; such a cluster may push a chunk past `size`. `size` 0 puts the
+/// whole text in one chunk. Empty text gives no chunks.
+#[must_use]
+pub fn chunk_utf16(text: &str, size: usize) -> Vec<Vec<u16>> {
+    let mut chunks = Vec::new();
+    let mut current: Vec<u16> = Vec::new();
+    let mut prev = None;
+    let mut buf = [0_u16; 2];
+    for c in text.chars() {
+        let units = c.encode_utf16(&mut buf);
+        let full = size > 0 && current.len() + units.len() > size;
+        if full && !current.is_empty() && !joins(prev, c) {
+            chunks.push(std::mem::take(&mut current));
+        }
+        current.extend_from_slice(units);
+        prev = Some(c);
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Remove the first `placeholder` from `text` and count the moves back to it (pure)
+///
+/// Without a placeholder (or with an empty one) the text is unchanged and the
+/// cursor stays at the end. Later occurrences are inserted literally.
+#[must_use]
+pub fn place_cursor(text: &str, placeholder: Option<&str>) -> CursorPlacement {
+    let found = placeholder
+        .filter(|p| !p.is_empty())
+        .and_then(|p| text.find(p).map(|pos| (pos, p.len())));
+    let Some((pos, len)) = found else {
+        return CursorPlacement {
+            text: text.to_owned(),
+            left_moves: 0,
+        };
+    };
+    let tail = &text[pos + len..];
+    CursorPlacement {
+        text: format!("{}{tail}", &text[..pos]),
+        left_moves: cursor_stops(tail),
+    }
+}
+
+/// Key events pressing Left Arrow `moves` times
+#[must_use]
+pub fn left_arrow_sequence(moves: usize) -> Vec<KeyStroke> {
+    [true, false]
+        .into_iter()
+        .map(|key_down| KeyStroke {
+            keycode: KeyCode::LEFT_ARROW,
+            key_down,
+            command: false,
+        })
+        .cycle()
+        .take(moves * 2)
+        .collect()
+}
+
+/// Move the cursor `moves` characters left (no-op for zero)
 ///
-/// This is the primary interface for the hotkey manager.
-/// Errors are logged to telemetry but do not crash the app.
-pub fn insert_text_safe(text: &str) -> bool {
-    match insert_text(text) {
+/// # Errors
+/// Returns error if `CGEvent` source or event creation fails
+pub fn move_cursor_left(moves: usize) -> Result<(), TextInsertionError> {
+    if moves == 0 {
+        return Ok(());
+    }
+    debug!(moves, "moving cursor to placeholder");
+    post_key_sequence(&left_arrow_sequence(moves))
+}
+
+/// Clear the focused field (Cmd+A, Delete)
+///
+/// # Errors
+/// Returns error if key events cannot be created
+pub fn clear_focused_field() -> Result<(), TextInsertionError> {
+    debug!("clearing focused field (Cmd+A, Delete)");
+    post_key_sequence(&clear_field_sequence())
+}
+
+/// Insert text via `chain`, first clearing the focused field if requested and `text` is non-empty
+///
+/// If clearing fails, text is still inserted (appended instead of replacing).
+pub fn insert_text_replacing_safe(
+    text: &str,
+    clear_before_insert: bool,
+    chain: &InsertionChain,
+) -> bool {
+    if !ensure_permissions() {
+        return false;
+    }
+    if should_clear_before_insert(clear_before_insert, text) {
+        if let Err(e) = clear_focused_field() {
+            error!(error = %e, "failed to clear field before insertion");
+        }
+    }
+    chain.insert_safe(text)
+}
+
+/// Result of handing text to the insertion step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertionOutcome {
+    /// Text was inserted
+    Inserted,
+    /// Dry run: text was only logged
+    DryRun,
+    /// Insertion failed (details already logged)
+    Failed,
+}
+
+/// Insert text via `chain`, or in dry-run mode only log what would be inserted
+///
+/// Dry run skips permission checks, field clearing and every insertion backend.
+pub fn dispatch_insertion(
+    text: &str,
+    clear_before_insert: bool,
+    chain: &InsertionChain,
+    dry_run: bool,
+) -> InsertionOutcome {
+    if dry_run {
+        info!(
+            text_len = text.len(),
+            text_preview = %generate_text_preview(text),
+            clear_before_insert,
+            "🧪 Dry run - would insert"
+        );
+        debug!(text = %text, "🧪 Dry run - full text");
+        return InsertionOutcome::DryRun;
+    }
+
+    if insert_text_replacing_safe(text, clear_before_insert, chain) {
+        InsertionOutcome::Inserted
+    } else {
+        InsertionOutcome::Failed
+    }
+}
+
+/// Check insertion permissions (no-op once granted; runs the deferred check on first use)
+fn ensure_permissions() -> bool {
+    match crate::permissions::ensure_insertion_permissions() {
         Ok(()) => true,
         Err(e) => {
-            error!(error = %e, text_len = text.len(), "text insertion failed");
+            error!("insertion permissions missing: {e}");
             false
         }
     }
@@ -136,6 +506,81 @@ pub fn insert_text_safe(text: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::strategy::{InsertionMethod, MockInsertionBackend};
+    use std::sync::{Arc, Mutex};
+
+    /// Tracing writer collecting output in memory
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for CapturedLogs {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_dispatch_dry_run_skips_backends_and_logs_text() {
+        let mut backend = MockInsertionBackend::new();
+        backend
+            .expect_method()
+            .return_const(InsertionMethod::Keystroke);
+        backend.expect_insert().never();
+        backend.expect_verify().never();
+        let chain = InsertionChain::new(vec![Box::new(backend)], true);
+
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(logs.clone())
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .finish();
+        let text = format!("git commit -m fix {}", "x".repeat(60));
+        let outcome = tracing::subscriber::with_default(subscriber, || {
+            dispatch_insertion(&text, true, &chain, true)
+        });
+
+        assert_eq!(outcome, InsertionOutcome::DryRun);
+        let logs = logs.contents();
+        assert!(logs.contains("Dry run - would insert"));
+        // Preview at info level, full text at debug level
+        assert!(logs.contains(&generate_text_preview(&text)));
+        assert!(logs.contains(&text));
+    }
+
+    #[test]
+    fn test_dispatch_live_uses_chain() {
+        let mut backend = MockInsertionBackend::new();
+        backend
+            .expect_method()
+            .return_const(InsertionMethod::Keystroke);
+        backend.expect_insert().times(1).returning(|_| Ok(()));
+        let chain = InsertionChain::new(vec![Box::new(backend)], false);
+
+        assert_eq!(
+            dispatch_insertion("hello", false, &chain, false),
+            InsertionOutcome::Inserted
+        );
+    }
 
     #[test]
     fn test_generate_text_preview_short() {
@@ -193,12 +638,6 @@ mod tests {
         assert!(matches!(result, Err(TextInsertionError::EmptyText)));
     }
 
-    #[test]
-    fn test_insert_text_safe_empty_returns_false() {
-        let result = insert_text_safe("");
-        assert!(!result);
-    }
-
     #[test]
     #[ignore = "requires Accessibility permissions and active cursor"]
     fn test_insert_text_simple() {
@@ -240,13 +679,6 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[test]
-    #[ignore = "requires Accessibility permissions and active cursor"]
-    fn test_insert_text_safe_simple() {
-        let result = insert_text_safe("test");
-        assert!(result);
-    }
-
     #[test]
     #[ignore = "requires Accessibility permissions and active cursor"]
     fn test_multiple_insertions() {
@@ -273,10 +705,77 @@ mod tests {
     }
 
     #[test]
-    fn test_insert_text_safe_with_non_empty_text() {
-        // This will fail on systems without accessibility permissions
-        // but it tests the code path
-        let _ = insert_text_safe("test");
+    fn test_clear_field_sequence() {
+        let seq = clear_field_sequence();
+        assert_eq!(seq.len(), 4);
+
+        // Cmd+A down/up, then Delete down/up
+        assert_eq!(seq[0].keycode, KeyCode::ANSI_A);
+        assert!(seq[0].key_down && seq[0].command);
+        assert_eq!(seq[1].keycode, KeyCode::ANSI_A);
+        assert!(!seq[1].key_down && seq[1].command);
+        assert_eq!(seq[2].keycode, KeyCode::DELETE);
+        assert!(seq[2].key_down && !seq[2].command);
+        assert_eq!(seq[3].keycode, KeyCode::DELETE);
+        assert!(!seq[3].key_down && !seq[3].command);
+    }
+
+    #[test]
+    fn test_prime_sequence_is_bare_shift() {
+        let seq = prime_sequence();
+        assert_eq!(seq[0].keycode, KeyCode::SHIFT);
+        assert!(seq[0].key_down && !seq[0].command);
+        assert_eq!(seq[1].keycode, KeyCode::SHIFT);
+        assert!(!seq[1].key_down && !seq[1].command);
+    }
+
+    #[test]
+    fn test_priming_for_enabled() {
+        assert_eq!(
+            priming_for(true, "hello"),
+            Some(Priming {
+                keys: prime_sequence(),
+                delay: PRIME_DELAY,
+            })
+        );
+    }
+
+    #[test]
+    fn test_priming_skipped_when_disabled_or_empty() {
+        assert_eq!(priming_for(false, "hello"), None);
+        assert_eq!(priming_for(true, ""), None);
+    }
+
+    #[test]
+    fn test_should_clear_before_insert() {
+        assert!(should_clear_before_insert(true, "hello"));
+        assert!(!should_clear_before_insert(false, "hello"));
+    }
+
+    #[test]
+    fn test_should_clear_before_insert_skips_empty() {
+        assert!(!should_clear_before_insert(true, ""));
+        assert!(!should_clear_before_insert(true, "  \n\t"));
+    }
+
+    #[test]
+    fn test_insert_text_replacing_safe_empty_does_not_clear() {
+        // Empty text: no clear attempted, insertion rejected
+        assert!(!insert_text_replacing_safe(
+            "",
+            true,
+            &InsertionChain::default()
+        ));
+    }
+
+    #[test]
+    #[ignore = "requires Accessibility permissions and active cursor"]
+    fn test_insert_text_replacing() {
+        assert!(insert_text_replacing_safe(
+            "replaced",
+            true,
+            &InsertionChain::default()
+        ));
     }
 
     #[test]
@@ -320,4 +819,176 @@ mod tests {
         assert!(decoded.contains('\n'));
         assert!(decoded.contains('\t'));
     }
+
+    fn placement(text: &str, left_moves: usize) -> CursorPlacement {
+        CursorPlacement {
+            text: text.to_owned(),
+            left_moves,
+        }
+    }
+
+    fn joined(chunks: &[Vec<u16>]) -> String {
+        String::from_utf16(&chunks.concat()).unwrap()
+    }
+
+    fn chunk_strings(text: &str, size: usize) -> Vec<String> {
+        chunk_utf16(text, size)
+            .iter()
+            .map(|chunk| String::from_utf16(chunk).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_chunk_utf16_ascii() {
+        let text = "a".repeat(450);
+        let chunks = chunk_utf16(&text, 200);
+        let lens: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(lens, [200, 200, 50]);
+        assert_eq!(joined(&chunks), text);
+    }
+
+    #[test]
+    fn test_chunk_utf16_empty_and_disabled() {
+        assert!(chunk_utf16("", 200).is_empty());
+        assert!(chunk_utf16("", 0).is_empty());
+        let chunks = chunk_utf16(&"a".repeat(1500), 0);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 1500);
+    }
+
+    #[test]
+    fn test_chunk_utf16_keeps_surrogate_pairs() {
+        // Each emoji is a surrogate pair; odd sizes would split them
+        let text = "👋🌍👋🌍👋";
+        for size in 1..=5 {
+            let chunks = chunk_utf16(text, size);
+            assert_eq!(joined(&chunks), text);
+            for chunk in &chunks {
+                assert!(String::from_utf16(chunk).is_ok(), "size {size}");
+            }
+        }
+        // 'a' + pair fits in 3; the next pair starts a new chunk
+        let chunks = chunk_utf16("a👋👋", 3);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(String::from_utf16(&chunks[0]).unwrap(), "a👋");
+    }
+
+    #[test]
+    fn test_chunk_utf16_keeps_clusters_together() {
+        // Family emoji: four people joined by ZWJ, 11 code units
+        let family = "👨\u{200D}👩\u{200D}👧\u{200D}👦";
+        let chunks = chunk_utf16(family, 4);
+        assert_eq!(chunks.len(), 1);
+        // e + combining acute, and \r\n, stay in one chunk
+        let chunks = chunk_strings("ae\u{301}", 1);
+        assert_eq!(chunks, ["a", "e\u{301}"]);
+        let chunks = chunk_strings("ab\r\n", 2);
+        assert_eq!(chunks, ["ab", "\r\n"]);
+    }
+
+    #[test]
+    fn test_chunk_utf16_multibyte_text() {
+        let text = "Zażółć gęślą jaźń ".repeat(20);
+        let chunks = chunk_utf16(&text, 64);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 64));
+        assert_eq!(joined(&chunks), text);
+    }
+
+    #[test]
+    fn test_with_suffix_combinations() {
+        assert_eq!(with_suffix("hello", false, false), "hello");
+        assert_eq!(with_suffix("hello", true, false), "hello ");
+        assert_eq!(with_suffix("hello", false, true), "hello\n");
+        assert_eq!(with_suffix("hello", true, true), "hello \n");
+    }
+
+    #[test]
+    fn test_with_suffix_empty_text() {
+        for (space, newline) in [(false, false), (true, false), (false, true), (true, true)] {
+            assert_eq!(with_suffix("", space, newline), "");
+        }
+    }
+
+    #[test]
+    fn test_with_suffix_multibyte() {
+        assert_eq!(with_suffix("zażółć", true, false), "zażółć ");
+        assert_eq!(with_suffix("日本語", false, true).encode_utf16().count(), 4);
+    }
+
+    #[test]
+    fn test_place_cursor_positions() {
+        let p = Some("|");
+        assert_eq!(
+            place_cursor("def (|): pass", p),
+            placement("def (): pass", 7)
+        );
+        assert_eq!(place_cursor("|start", p), placement("start", 5));
+        assert_eq!(place_cursor("end|", p), placement("end", 0));
+        assert_eq!(place_cursor("|", p), placement("", 0));
+    }
+
+    #[test]
+    fn test_place_cursor_without_placeholder() {
+        assert_eq!(place_cursor("a | b", None), placement("a | b", 0));
+        assert_eq!(place_cursor("a | b", Some("")), placement("a | b", 0));
+        assert_eq!(
+            place_cursor("no marker", Some("|")),
+            placement("no marker", 0)
+        );
+    }
+
+    #[test]
+    fn test_place_cursor_first_occurrence_and_long_marker() {
+        // Only the first marker moves the cursor; later ones stay literal
+        assert_eq!(place_cursor("a|b|c", Some("|")), placement("ab|c", 3));
+        assert_eq!(
+            place_cursor("<p>{{cursor}}</p>", Some("{{cursor}}")),
+            placement("<p></p>", 4)
+        );
+    }
+
+    #[test]
+    fn test_place_cursor_multibyte() {
+        assert_eq!(
+            place_cursor("zażółć|gęślą", Some("|")),
+            placement("zażółćgęślą", 5)
+        );
+        assert_eq!(
+            place_cursor("日本|語です", Some("|")),
+            placement("日本語です", 3)
+        );
+        // Multi-byte placeholder
+        assert_eq!(place_cursor("a▮bc", Some("▮")), placement("abc", 2));
+    }
+
+    #[test]
+    fn test_cursor_stops_clusters() {
+        assert_eq!(cursor_stops(""), 0);
+        assert_eq!(cursor_stops("abc"), 3);
+        // e + combining acute accent is one character on screen
+        assert_eq!(cursor_stops("e\u{301}x"), 2);
+        // Emoji with skin tone, and a ZWJ family sequence
+        assert_eq!(cursor_stops("\u{1F44D}\u{1F3FD}"), 1);
+        assert_eq!(
+            cursor_stops("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!"),
+            2
+        );
+        // Heart with emoji presentation selector
+        assert_eq!(cursor_stops("\u{2764}\u{FE0F}"), 1);
+        assert_eq!(cursor_stops("a\r\nb"), 3);
+    }
+
+    #[test]
+    fn test_left_arrow_sequence() {
+        assert!(left_arrow_sequence(0).is_empty());
+        let seq = left_arrow_sequence(2);
+        assert_eq!(seq.len(), 4);
+        assert!(seq
+            .iter()
+            .all(|k| k.keycode == KeyCode::LEFT_ARROW && !k.command));
+        assert_eq!(
+            seq.iter().map(|k| k.key_down).collect::<Vec<_>>(),
+            vec![true, false, true, false]
+        );
+    }
 }
@@ -0,0 +1,166 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Text and completion time of the previous insertion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastInsertion {
+    /// Inserted text
+    pub text: String,
+    /// When it was inserted
+    pub at: Instant,
+}
+
+/// Whether inserting `text` at `now` repeats `last` within `window` (pure)
+///
+/// A zero `window` disables the check.
+#[must_use]
+pub fn is_duplicate(
+    last: Option<&LastInsertion>,
+    text: &str,
+    now: Instant,
+    window: Duration,
+) -> bool {
+    if window.is_zero() {
+        return false;
+    }
+    last.is_some_and(|last| last.text == text && now.saturating_duration_since(last.at) < window)
+}
+
+/// Suppresses an insertion identical to the previous one within a short window
+///
+/// Shared by all profiles, like the insertion pacer, so the same phrase
+/// triggered twice from different hotkeys is caught too.
+pub struct DuplicateGuard {
+    window: Duration,
+    last: Mutex<Option<LastInsertion>>,
+}
+
+impl DuplicateGuard {
+    /// Create guard suppressing repeats within `window` (zero = disabled)
+    #[must_use]
+    pub const fn new(window: Duration) -> Self {
+        Self {
+            window,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Whether `text` repeats the last remembered insertion within the window
+    #[must_use]
+    pub fn is_duplicate(&self, text: &str, now: Instant) -> bool {
+        let last = self
+            .last
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        is_duplicate(last.as_ref(), text, now, self.window)
+    }
+
+    /// Remember `text` as inserted at `now`
+    ///
+    /// Call only once the insertion succeeded, so a failed or skipped
+    /// insertion never suppresses the retry.
+    pub fn remember(&self, text: &str, now: Instant) {
+        if self.window.is_zero() {
+            return;
+        }
+        *self
+            .last
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(LastInsertion {
+            text: text.to_owned(),
+            at: now,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(2);
+
+    fn last(text: &str, at: Instant) -> LastInsertion {
+        LastInsertion {
+            text: text.to_owned(),
+            at,
+        }
+    }
+
+    #[test]
+    fn test_identical_within_window_is_duplicate() {
+        let t0 = Instant::now();
+        let prev = last("hello world", t0);
+        assert!(is_duplicate(
+            Some(&prev),
+            "hello world",
+            t0 + Duration::from_millis(500),
+            WINDOW
+        ));
+    }
+
+    #[test]
+    fn test_identical_after_window_is_allowed() {
+        let t0 = Instant::now();
+        let prev = last("hello world", t0);
+        assert!(!is_duplicate(
+            Some(&prev),
+            "hello world",
+            t0 + WINDOW,
+            WINDOW
+        ));
+        assert!(!is_duplicate(
+            Some(&prev),
+            "hello world",
+            t0 + Duration::from_secs(10),
+            WINDOW
+        ));
+    }
+
+    #[test]
+    fn test_different_text_is_allowed() {
+        let t0 = Instant::now();
+        let prev = last("hello world", t0);
+        assert!(!is_duplicate(
+            Some(&prev),
+            "hello there",
+            t0 + Duration::from_millis(100),
+            WINDOW
+        ));
+    }
+
+    #[test]
+    fn test_no_previous_or_zero_window_is_allowed() {
+        let t0 = Instant::now();
+        assert!(!is_duplicate(None, "hello", t0, WINDOW));
+        let prev = last("hello", t0);
+        assert!(!is_duplicate(Some(&prev), "hello", t0, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_guard_suppresses_repeat_and_keeps_anchor() {
+        let guard = DuplicateGuard::new(WINDOW);
+        let t0 = Instant::now();
+        assert!(!guard.is_duplicate("hello", t0));
+        guard.remember("hello", t0);
+        assert!(guard.is_duplicate("hello", t0 + Duration::from_millis(1500)));
+        // Window counts from the first (inserted) occurrence, not the suppressed one
+        assert!(!guard.is_duplicate("hello", t0 + Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn test_guard_failed_insertion_does_not_suppress_retry() {
+        let guard = DuplicateGuard::new(WINDOW);
+        let t0 = Instant::now();
+        assert!(!guard.is_duplicate("hello", t0));
+        // Insertion failed: nothing remembered, so the immediate retry goes through
+        assert!(!guard.is_duplicate("hello", t0 + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_guard_disabled() {
+        let guard = DuplicateGuard::new(Duration::ZERO);
+        let t0 = Instant::now();
+        guard.remember("hello", t0);
+        assert!(!guard.is_duplicate("hello", t0));
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+
+/// Modifier key that can be checked at hotkey release
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modifier {
+    /// Control key
+    Control,
+    /// Option (Alt) key
+    Option,
+    /// Command key
+    Command,
+    /// Shift key
+    Shift,
+}
+
+impl Modifier {
+    /// Parse a modifier name as used in `[[profiles]] modifiers`
+    ///
+    /// # Errors
+    /// Returns error for unknown modifier names
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "Control" | "Ctrl" => Ok(Self::Control),
+            "Option" | "Alt" => Ok(Self::Option),
+            "Command" | "Super" => Ok(Self::Command),
+            "Shift" => Ok(Self::Shift),
+            _ => Err(anyhow!("unknown modifier: {name}")),
+        }
+    }
+}
+
+/// What happens to a recording when the hotkey is released
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEnd {
+    /// Transcribe and insert (default)
+    Commit,
+    /// Drop the audio without transcribing
+    Discard,
+}
+
+/// Decide the end of a session from the modifiers held at release
+///
+/// Without a `discard` modifier every session commits.
+#[must_use]
+pub fn session_end(discard: Option<Modifier>, held: &[Modifier]) -> SessionEnd {
+    match discard {
+        Some(modifier) if held.contains(&modifier) => SessionEnd::Discard,
+        _ => SessionEnd::Commit,
+    }
+}
+
+/// Parse a profile's `discard_modifier`, rejecting one that is part of its hotkey
+///
+/// A hotkey modifier is still held at every release, so it would discard everything.
+///
+/// # Errors
+/// Returns error for unknown names or a modifier used by the hotkey itself
+pub fn parse_discard_modifier(
+    name: Option<&str>,
+    hotkey_modifiers: &[String],
+) -> Result<Option<Modifier>> {
+    let Some(name) = name else {
+        return Ok(None);
+    };
+    let modifier = Modifier::parse(name)?;
+    for hotkey_modifier in hotkey_modifiers {
+        if Modifier::parse(hotkey_modifier).ok() == Some(modifier) {
+            return Err(anyhow!(
+                "discard_modifier {name} is part of the hotkey and would discard every recording"
+            ));
+        }
+    }
+    Ok(Some(modifier))
+}
+
+/// Modifier keys currently held down
+#[cfg(target_os = "macos")]
+#[must_use]
+pub fn held_modifiers() -> Vec<Modifier> {
+    use core_graphics::event::{CGEvent, CGEventFlags};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    let Ok(source) = CGEventSource::new(CGEventSourceStateID::CombinedSessionState) else {
+        tracing::warn!("failed to read modifier state");
+        return Vec::new();
+    };
+    // A blank event carries the current modifier flags
+    let Ok(event) = CGEvent::new(source) else {
+        tracing::warn!("failed to read modifier state");
+        return Vec::new();
+    };
+    let flags = event.get_flags();
+    [
+        (CGEventFlags::CGEventFlagControl, Modifier::Control),
+        (CGEventFlags::CGEventFlagAlternate, Modifier::Option),
+        (CGEventFlags::CGEventFlagCommand, Modifier::Command),
+        (CGEventFlags::CGEventFlagShift, Modifier::Shift),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| flags.contains(*flag))
+    .map(|(_, modifier)| modifier)
+    .collect()
+}
+
+/// Modifier keys currently held down
+#[cfg(not(target_os = "macos"))]
+#[must_use]
+pub const fn held_modifiers() -> Vec<Modifier> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_gesture_always_commits() {
+        assert_eq!(session_end(None, &[]), SessionEnd::Commit);
+        assert_eq!(session_end(None, &[Modifier::Shift]), SessionEnd::Commit);
+    }
+
+    #[test]
+    fn test_discard_modifier_held() {
+        assert_eq!(
+            session_end(Some(Modifier::Shift), &[Modifier::Command, Modifier::Shift]),
+            SessionEnd::Discard
+        );
+    }
+
+    #[test]
+    fn test_discard_modifier_not_held() {
+        assert_eq!(
+            session_end(Some(Modifier::Shift), &[Modifier::Command]),
+            SessionEnd::Commit
+        );
+        assert_eq!(session_end(Some(Modifier::Shift), &[]), SessionEnd::Commit);
+    }
+
+    #[test]
+    fn test_parse_discard_modifier() {
+        let hotkey = vec!["Command".to_owned(), "Option".to_owned()];
+        assert_eq!(parse_discard_modifier(None, &hotkey).ok(), Some(None));
+        assert_eq!(
+            parse_discard_modifier(Some("Shift"), &hotkey).ok(),
+            Some(Some(Modifier::Shift))
+        );
+        assert_eq!(
+            parse_discard_modifier(Some("Ctrl"), &hotkey).ok(),
+            Some(Some(Modifier::Control))
+        );
+    }
+
+    #[test]
+    fn test_parse_discard_modifier_rejects_hotkey_modifier() {
+        let hotkey = vec!["Command".to_owned(), "Option".to_owned()];
+        // Alias of a hotkey modifier is still the same key
+        assert!(parse_discard_modifier(Some("Alt"), &hotkey).is_err());
+        assert!(parse_discard_modifier(Some("Hyper"), &hotkey).is_err());
+    }
+}
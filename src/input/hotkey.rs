@@ -3,14 +3,41 @@ use global_hotkey::{
     hotkey::{Code, HotKey, Modifiers},
     GlobalHotKeyEvent, GlobalHotKeyManager,
 };
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use tracing::{debug, info, warn};
 
 use crate::alias;
-use crate::audio::AudioCapture;
-use crate::config::{AliasesConfig, HotkeyConfig};
-use crate::input::cgevent;
-use crate::transcription::{ModelManager, TranscriptionEngine};
+use crate::audio::{self, AudioCapture, CaptureTimings};
+use crate::config::{
+    resolved_audio, AliasesConfig, AudioConfig, AutoPunctuationConfig, Config, DegradedBehavior,
+    HotkeyConfig, HotkeyMode, LanguageRule, ProfileMode, QuickPickConfig, RecordingConfig,
+    TranscriptionProfile, WavFormat,
+};
+use crate::formatting::Locale;
+use crate::history::HistoryLog;
+use crate::input::cgevent::{self, InsertionOutcome};
+use crate::input::dedupe::DuplicateGuard;
+use crate::input::gesture::{self, Modifier, SessionEnd};
+use crate::input::pacing::InsertionPacer;
+use crate::input::quick_pick::{self, QuickPick, Selection};
+use crate::input::strategy::InsertionChain;
+use crate::notify;
+use crate::output::TextPipeline;
+use crate::permissions::{self, AccessChange, AccessMonitor};
+use crate::stats::StatsLog;
+use crate::telemetry::{self, TimingRecord};
+use crate::transcription::bootstrap::{self, ModelBootstrap};
+use crate::transcription::latency::{LatencyFallback, ModelTier};
+use crate::transcription::queue::{Job, JobQueue};
+use crate::transcription::{file, punctuation, ModelManager, Transcript, TranscriptionEngine};
+use crate::translation::HookCommand;
+use crate::webhook::Webhook;
+
+/// Most recent transcription, shared with the tray menu (None = nothing transcribed yet)
+pub type LastTranscription = Arc<Mutex<Option<String>>>;
 
 /// Application state machine
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -21,22 +48,291 @@ pub enum AppState {
     Recording,
     /// Transcribing and inserting text
     Processing,
+    /// Waiting for a deferred model to load before transcribing
+    Loading,
+}
+
+/// Load progress of a profile's deferred (`preload = false`) model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelLoadState {
+    /// Not loaded yet, or the last attempt failed (retried on next press)
+    NotLoaded,
+    /// Load in progress
+    Loading,
+    /// Engine available
+    Ready,
 }
 
 /// Lazy loading configuration (model manager + model name)
 type LazyLoadConfig = (Arc<Mutex<ModelManager>>, String);
 
+/// Preloaded engine, or lazy loading configuration
+type EngineRoute = (Option<Arc<TranscriptionEngine>>, Option<LazyLoadConfig>);
+
+/// Profiles to load, plus whether each starts on the bootstrap model
+type BootstrapPlan = (Vec<TranscriptionProfile>, Vec<bool>);
+
+/// Run a deferred model load, tracking it in `load_state` and the tray state
+///
+/// The tray switches to `Loading` only while a transcription is waiting on the
+/// model (state `Processing`); a load started on press stays behind `Recording`.
+/// Once the model is `Ready`, `load` runs without any state changes.
+fn load_tracked<T>(
+    load_state: &Mutex<ModelLoadState>,
+    app_state: &Mutex<AppState>,
+    load: impl FnOnce() -> Option<T>,
+) -> Option<T> {
+    {
+        let mut load_state = load_state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if *load_state == ModelLoadState::Ready {
+            drop(load_state);
+            return load();
+        }
+        *load_state = ModelLoadState::Loading;
+    }
+    set_app_state_if(app_state, AppState::Processing, AppState::Loading);
+
+    let result = load();
+
+    *load_state
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = if result.is_some() {
+        ModelLoadState::Ready
+    } else {
+        ModelLoadState::NotLoaded
+    };
+    set_app_state_if(app_state, AppState::Loading, AppState::Processing);
+    result
+}
+
+/// Move `state` from `from` to `to`, leaving any other state untouched
+fn set_app_state_if(state: &Mutex<AppState>, from: AppState, to: AppState) {
+    let mut state = state
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if *state == from {
+        *state = to;
+    }
+}
+
 /// Global hotkey manager with state tracking
+#[allow(clippy::struct_excessive_bools)] // Independent per-profile behavior flags
 pub struct HotkeyManager {
     manager: Arc<GlobalHotKeyManager>,
     hotkey: HotKey,
     state: Arc<Mutex<AppState>>,
     audio: Arc<Mutex<AudioCapture>>,
     transcription: Option<Arc<TranscriptionEngine>>,
+    /// Push-to-talk, or start/stop with separate presses
+    mode: HotkeyMode,
     recording_enabled: bool,
-    aliases: Arc<AliasesConfig>,
+    /// What to do with recordings when no transcription engine is available
+    degraded_behavior: DegradedBehavior,
+    /// Sample format of debug recordings
+    wav_format: WavFormat,
+    /// Queue presses during Processing instead of ignoring them
+    queue_press_during_processing: bool,
+    /// Press queued while busy: recording starts once processing finishes
+    pending_start: Arc<AtomicBool>,
+    /// Modifier that discards the recording when held at release (None = always commit)
+    discard_modifier: Option<Modifier>,
+    /// Clipped-sample fraction that triggers a mic gain warning (0 = disabled)
+    clipping_threshold: f64,
+    /// Markdown stripping, translation, aliases and locale formatting
+    pipeline: TextPipeline,
+    /// Punctuation from pauses between segments (None = joined as transcribed)
+    auto_punctuation: Option<Arc<AutoPunctuationConfig>>,
     /// For lazy loading: model manager + model name
     lazy_load_config: Option<LazyLoadConfig>,
+    /// Progress of the lazily loaded model (Ready when preloaded)
+    model_load: Arc<Mutex<ModelLoadState>>,
+    /// Serializes insertions across profiles (unpaced unless set via `with_insertion_pacer`)
+    pacer: Arc<InsertionPacer>,
+    /// Last inserted text and time; skips immediate repeats (off unless set via `with_duplicate_guard`)
+    duplicate_guard: Arc<DuplicateGuard>,
+    /// Stops insertions when accessibility is revoked (off unless set via `with_access_monitor`)
+    access_monitor: Arc<AccessMonitor>,
+    /// Insertion methods tried in order (keystroke only unless set via `with_insertion_chain`)
+    insertion_chain: Arc<InsertionChain>,
+    /// Clear focused field (Cmd+A, Delete) before inserting
+    clear_before_insert: bool,
+    /// Shared bounded queue (None = transcribe on a dedicated thread per recording)
+    job_queue: Option<Arc<JobQueue>>,
+    /// History, usage stats and webhook fed by each completed transcription
+    sinks: CompletionSinks,
+    /// Log text instead of inserting it (`--dry-run`)
+    dry_run: bool,
+    /// Switches to a faster model while inference is slow (None = disabled)
+    latency_fallback: Option<Arc<LatencyFallback>>,
+    /// Small model used while the configured one downloads (None = model was available)
+    bootstrap: Option<Arc<ModelBootstrap>>,
+    /// Log a per-stage timing breakdown after each transcription
+    timing_debug: bool,
+    /// Log level statistics of each captured recording
+    audio_stats_debug: bool,
+    /// Presses are ignored while set (never paused unless shared via `with_pause_flag`)
+    paused: Arc<AtomicBool>,
+    /// Post a notification when a transcription is inserted or fails
+    notifications: bool,
+}
+
+/// Shared history log plus the profile name entries are recorded under
+#[derive(Clone)]
+struct HistorySink {
+    log: Arc<HistoryLog>,
+    profile: String,
+}
+
+impl HistorySink {
+    /// Append `text`, logging (not propagating) write failures
+    fn record(&self, text: &str) {
+        if let Err(e) = self.log.record(&self.profile, text) {
+            warn!(error = %e, "failed to record transcription history");
+        }
+    }
+}
+
+/// Shared stats log plus the profile name usage is counted under
+#[derive(Clone)]
+struct StatsSink {
+    log: Arc<StatsLog>,
+    profile: String,
+}
+
+impl StatsSink {
+    /// Count one transcription, logging (not propagating) write failures
+    fn record(&self, audio_secs: f64, chars: usize) {
+        if let Err(e) = self.log.record(&self.profile, audio_secs, chars) {
+            warn!(error = %e, "failed to record usage stats");
+        }
+    }
+}
+
+/// Shared webhook plus the profile name payloads are sent under
+#[derive(Clone)]
+struct WebhookSink {
+    webhook: Arc<Webhook>,
+    profile: String,
+}
+
+/// Everything a transcription worker needs to insert its text
+#[derive(Clone)]
+struct InsertionTarget {
+    pacer: Arc<InsertionPacer>,
+    duplicate_guard: Arc<DuplicateGuard>,
+    access: Arc<AccessMonitor>,
+    chain: Arc<InsertionChain>,
+    clear_before_insert: bool,
+    dry_run: bool,
+}
+
+impl InsertionTarget {
+    /// Insert `text` (paced, logging the outcome); None = skipped as a repeat or while revoked
+    fn insert(&self, text: &str, text_preview: &str) -> Option<InsertionOutcome> {
+        if self
+            .duplicate_guard
+            .is_duplicate(text, std::time::Instant::now())
+        {
+            debug!(text_len = text.len(), "skipping repeated insertion");
+            return None;
+        }
+        let (allowed, change) = self
+            .access
+            .before_insertion(permissions::is_accessibility_trusted);
+        if let Some(change) = change {
+            HotkeyManager::notify_access_change(change);
+        }
+        if !allowed {
+            warn!(
+                text_len = text.len(),
+                "⛔ Insertion skipped - accessibility permission revoked"
+            );
+            return None;
+        }
+        let outcome = self.pacer.run(|| {
+            cgevent::dispatch_insertion(text, self.clear_before_insert, &self.chain, self.dry_run)
+        });
+        match outcome {
+            InsertionOutcome::Inserted => {
+                info!(text_len = text.len(), "✅ Inserted {} chars", text.len());
+                self.duplicate_guard
+                    .remember(text, std::time::Instant::now());
+            }
+            InsertionOutcome::DryRun => return Some(outcome),
+            InsertionOutcome::Failed => warn!(
+                text_len = text.len(),
+                text_preview = %text_preview,
+                "❌ Text insertion failed - check permissions"
+            ),
+        }
+        let succeeded = outcome == InsertionOutcome::Inserted;
+        if let Some(change) = self
+            .access
+            .record(succeeded, permissions::is_accessibility_trusted)
+        {
+            HotkeyManager::notify_access_change(change);
+        }
+        Some(outcome)
+    }
+}
+
+/// Notification title and message for an insertion of `chars` characters (pure)
+///
+/// None for dry runs, which insert nothing.
+fn insertion_notice(outcome: InsertionOutcome, chars: usize) -> Option<(&'static str, String)> {
+    match outcome {
+        InsertionOutcome::Inserted => {
+            Some(("transcription complete", format!("Inserted {chars} chars")))
+        }
+        InsertionOutcome::Failed => Some((
+            "insertion failed",
+            "Text could not be inserted - check permissions".to_owned(),
+        )),
+        InsertionOutcome::DryRun => None,
+    }
+}
+
+/// Optional consumers of completed transcriptions
+#[derive(Clone, Default)]
+struct CompletionSinks {
+    /// Records inserted transcriptions (None = history disabled)
+    history: Option<HistorySink>,
+    /// Per-profile usage counters (None = disabled)
+    stats: Option<StatsSink>,
+    /// Posts inserted transcriptions to a URL (None = disabled)
+    webhook: Option<WebhookSink>,
+    /// Keeps the latest inserted transcription for the tray menu (None = not kept)
+    last: Option<LastTranscription>,
+}
+
+impl CompletionSinks {
+    /// Count one transcription of `sample_count` 16kHz samples, inserted or not
+    fn record_usage(&self, sample_count: usize, text: &str) {
+        // Same 16kHz sample rate as the capture log line
+        #[allow(clippy::cast_precision_loss)]
+        let audio_secs = sample_count as f64 / 16000.0;
+        if let Some(stats) = &self.stats {
+            stats.record(audio_secs, text.chars().count());
+        }
+    }
+
+    /// Feed one inserted transcription to history, the tray and the webhook
+    fn record_inserted(&self, sample_count: usize, text: &str) {
+        if let Some(history) = &self.history {
+            history.record(text);
+        }
+        if let Some(last) = &self.last {
+            *last
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(text.to_owned());
+        }
+        if let Some(sink) = &self.webhook {
+            let duration_ms = u64::try_from(sample_count / 16).unwrap_or(u64::MAX);
+            sink.webhook.notify(&sink.profile, text, duration_ms);
+        }
+    }
 }
 
 impl HotkeyManager {
@@ -49,32 +345,252 @@ impl HotkeyManager {
         config: &HotkeyConfig,
         audio: Arc<Mutex<AudioCapture>>,
         transcription: Option<Arc<TranscriptionEngine>>,
-        recording_enabled: bool,
+        recording: &RecordingConfig,
         aliases: Arc<AliasesConfig>,
         lazy_load_config: Option<LazyLoadConfig>,
     ) -> Result<Self> {
-        let modifiers = Self::parse_modifiers(&config.modifiers)?;
-        let code = Self::parse_key(&config.key)?;
-
-        let hotkey = HotKey::new(Some(modifiers), code);
+        let hotkey = Self::hotkey_for(config)?;
         manager
             .register(hotkey)
             .context("failed to register hotkey")?;
 
         info!("registered hotkey: {:?} + {}", config.modifiers, config.key);
 
+        let model_load = if transcription.is_some() {
+            ModelLoadState::Ready
+        } else {
+            ModelLoadState::NotLoaded
+        };
+
         Ok(Self {
             manager,
             hotkey,
             state: Arc::new(Mutex::new(AppState::Idle)),
             audio,
             transcription,
-            recording_enabled,
-            aliases,
+            mode: config.mode,
+            recording_enabled: recording.enabled,
+            degraded_behavior: recording.degraded_behavior,
+            wav_format: recording.wav_format,
+            queue_press_during_processing: recording.queue_press_during_processing,
+            pending_start: Arc::new(AtomicBool::new(false)),
+            discard_modifier: None,
+            clipping_threshold: 0.0,
+            pipeline: TextPipeline::new(aliases),
+            auto_punctuation: None,
+            model_load: Arc::new(Mutex::new(model_load)),
             lazy_load_config,
+            pacer: Arc::new(InsertionPacer::new(std::time::Duration::ZERO)),
+            duplicate_guard: Arc::new(DuplicateGuard::new(std::time::Duration::ZERO)),
+            access_monitor: Arc::new(AccessMonitor::new(0)),
+            insertion_chain: Arc::new(InsertionChain::default()),
+            clear_before_insert: false,
+            job_queue: None,
+            sinks: CompletionSinks::default(),
+            dry_run: false,
+            latency_fallback: None,
+            bootstrap: None,
+            timing_debug: false,
+            audio_stats_debug: false,
+            paused: Arc::new(AtomicBool::new(false)),
+            notifications: false,
         })
     }
 
+    /// Share the pause flag with other managers (tray "Pause")
+    #[must_use]
+    pub fn with_pause_flag(mut self, paused: Arc<AtomicBool>) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Post a notification when a transcription is inserted or fails (`[app] notifications`)
+    #[must_use]
+    pub const fn with_notifications(mut self, enabled: bool) -> Self {
+        self.notifications = enabled;
+        self
+    }
+
+    /// Share an insertion pacer with other managers (enforces gap between insertions)
+    #[must_use]
+    pub fn with_insertion_pacer(mut self, pacer: Arc<InsertionPacer>) -> Self {
+        self.pacer = pacer;
+        self
+    }
+
+    /// Share a duplicate guard with other managers (skips repeated insertions)
+    #[must_use]
+    pub fn with_duplicate_guard(mut self, duplicate_guard: Arc<DuplicateGuard>) -> Self {
+        self.duplicate_guard = duplicate_guard;
+        self
+    }
+
+    /// Share an accessibility revocation monitor with other managers
+    #[must_use]
+    pub fn with_access_monitor(mut self, access_monitor: Arc<AccessMonitor>) -> Self {
+        self.access_monitor = access_monitor;
+        self
+    }
+
+    /// Share an insertion chain (method order, verification, fallback)
+    #[must_use]
+    pub fn with_insertion_chain(mut self, insertion_chain: Arc<InsertionChain>) -> Self {
+        self.insertion_chain = insertion_chain;
+        self
+    }
+
+    /// Format numbers/dates in transcriptions for `locale`
+    #[must_use]
+    pub const fn with_locale(mut self, locale: Option<Locale>) -> Self {
+        self.pipeline.locale = locale;
+        self
+    }
+
+    /// Strip spurious markdown wrapping from transcriptions
+    #[must_use]
+    pub const fn with_strip_markdown(mut self, strip_markdown: bool) -> Self {
+        self.pipeline.strip_markdown = strip_markdown;
+        self
+    }
+
+    /// Insert free text (dictation) or only exact alias matches (command)
+    #[must_use]
+    pub const fn with_mode(mut self, mode: ProfileMode) -> Self {
+        self.pipeline.mode = mode;
+        self
+    }
+
+    /// Discard the recording instead of transcribing when `modifier` is held at release
+    #[must_use]
+    pub const fn with_discard_modifier(mut self, modifier: Option<Modifier>) -> Self {
+        self.discard_modifier = modifier;
+        self
+    }
+
+    /// Warn when more than `threshold` of a recording's samples are clipped
+    #[must_use]
+    pub const fn with_clipping_threshold(mut self, threshold: f64) -> Self {
+        self.clipping_threshold = threshold;
+        self
+    }
+
+    /// Adjust output per detected language (`[[language_rules]]`)
+    #[must_use]
+    pub fn with_language_rules(mut self, rules: Arc<[LanguageRule]>) -> Self {
+        self.pipeline.language_rules = rules;
+        self
+    }
+
+    /// Pipe transcriptions through an external translation command
+    #[must_use]
+    pub fn with_translation_hook(mut self, hook: Option<HookCommand>) -> Self {
+        self.pipeline.translation = hook;
+        self
+    }
+
+    /// Insert the original together with its translation, joined by `template`
+    #[must_use]
+    pub fn with_combine_template(mut self, template: Option<Arc<str>>) -> Self {
+        self.pipeline.combine_template = template;
+        self
+    }
+
+    /// Record inserted transcriptions under `profile` in a shared history log
+    #[must_use]
+    pub fn with_history(mut self, log: Option<Arc<HistoryLog>>, profile: &str) -> Self {
+        self.sinks.history = log.map(|log| HistorySink {
+            log,
+            profile: profile.to_owned(),
+        });
+        self
+    }
+
+    /// Count this profile's usage in a shared stats log
+    #[must_use]
+    pub fn with_stats(mut self, log: Option<Arc<StatsLog>>, profile: &str) -> Self {
+        self.sinks.stats = log.map(|log| StatsSink {
+            log,
+            profile: profile.to_owned(),
+        });
+        self
+    }
+
+    /// Post this profile's completed transcriptions to a shared webhook
+    #[must_use]
+    pub fn with_webhook(mut self, webhook: Option<Arc<Webhook>>, profile: &str) -> Self {
+        self.sinks.webhook = webhook.map(|webhook| WebhookSink {
+            webhook,
+            profile: profile.to_owned(),
+        });
+        self
+    }
+
+    /// Keep each non-empty transcription in a slot shared with the tray menu
+    #[must_use]
+    pub fn with_last_transcription(mut self, last: LastTranscription) -> Self {
+        self.sinks.last = Some(last);
+        self
+    }
+
+    /// Switch to a faster fallback model while inference stays slow
+    #[must_use]
+    pub fn with_latency_fallback(mut self, fallback: Option<LatencyFallback>) -> Self {
+        self.latency_fallback = fallback.map(Arc::new);
+        self
+    }
+
+    /// Transcribe with a bootstrap model until the configured model is ready
+    #[must_use]
+    pub fn with_bootstrap(mut self, bootstrap: Option<Arc<ModelBootstrap>>) -> Self {
+        self.bootstrap = bootstrap;
+        self
+    }
+
+    /// Log transcriptions instead of inserting them
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Log where each transcription's time went (`[telemetry] timing_debug`)
+    #[must_use]
+    pub const fn with_timing_debug(mut self, timing_debug: bool) -> Self {
+        self.timing_debug = timing_debug;
+        self
+    }
+
+    /// Log peak, RMS, clipping and silence of each recording (`[telemetry] audio_stats_debug`)
+    #[must_use]
+    pub const fn with_audio_stats_debug(mut self, audio_stats_debug: bool) -> Self {
+        self.audio_stats_debug = audio_stats_debug;
+        self
+    }
+
+    /// Submit transcriptions to a shared bounded queue (None = one thread per recording)
+    #[must_use]
+    pub fn with_job_queue(mut self, job_queue: Option<Arc<JobQueue>>) -> Self {
+        self.job_queue = job_queue;
+        self
+    }
+
+    /// Punctuate transcriptions from the pauses between segments
+    #[must_use]
+    pub fn with_auto_punctuation(
+        mut self,
+        auto_punctuation: Option<Arc<AutoPunctuationConfig>>,
+    ) -> Self {
+        self.auto_punctuation = auto_punctuation;
+        self
+    }
+
+    /// Replace the focused field's contents instead of inserting at the cursor
+    #[must_use]
+    pub const fn with_clear_before_insert(mut self, clear_before_insert: bool) -> Self {
+        self.clear_before_insert = clear_before_insert;
+        self
+    }
+
     /// Get shared state for external monitoring (e.g., UI updates)
     #[must_use]
     pub fn state_shared(&self) -> Arc<Mutex<AppState>> {
@@ -92,37 +608,86 @@ impl HotkeyManager {
                 info!("🎤 Hotkey pressed - recording started");
                 *state = AppState::Recording;
                 drop(state);
+                // A fresh press supersedes any stale queued start
+                self.pending_start.store(false, Ordering::SeqCst);
 
-                // Start audio recording with error recovery
-                let recording_result = self
-                    .audio
-                    .lock()
-                    .unwrap_or_else(std::sync::PoisonError::into_inner)
-                    .start_recording();
-
-                if let Err(e) = recording_result {
-                    warn!(error = %e, "❌ Failed to start recording");
-                    let mut state = self
-                        .state
-                        .lock()
-                        .unwrap_or_else(std::sync::PoisonError::into_inner);
-                    *state = AppState::Idle;
-                    // Continue running - this is a transient error, user can try again
+                if Self::start_capture(&self.state, &self.audio) {
+                    self.prefetch_model();
                 }
             }
+            AppState::Recording if self.mode == HotkeyMode::Toggle => {
+                info!("⏹️  Hotkey pressed again - processing audio");
+                *state = AppState::Processing;
+                drop(state);
+                self.finish_recording();
+            }
             AppState::Recording => {
                 drop(state);
                 debug!("hotkey pressed while recording (ignored)");
             }
-            AppState::Processing => {
+            AppState::Processing | AppState::Loading => {
                 drop(state);
-                debug!("hotkey pressed while processing (ignored)");
+                if self.queue_press_during_processing {
+                    self.pending_start.store(true, Ordering::SeqCst);
+                    info!("⏭️  Hotkey pressed while processing - recording queued");
+                } else {
+                    debug!("hotkey pressed while processing (ignored)");
+                }
             }
         }
     }
 
+    /// Start audio capture for a state already set to Recording
+    ///
+    /// Returns false (and recovers to Idle) if the recording could not start.
+    fn start_capture(state: &Mutex<AppState>, audio: &Mutex<AudioCapture>) -> bool {
+        let recording_result = audio
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .start_recording();
+
+        if let Err(e) = recording_result {
+            warn!(error = %e, "❌ Failed to start recording");
+            *state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = AppState::Idle;
+            // Continue running - this is a transient error, user can try again
+            return false;
+        }
+        true
+    }
+
+    /// Start a recording queued by a press during Processing, once back to Idle
+    ///
+    /// Polled from the main event loop: audio capture must start on the main thread,
+    /// so the transcription worker only returns the state to Idle.
+    pub fn start_queued_recording(&self) {
+        if !self.pending_start.load(Ordering::SeqCst) {
+            return;
+        }
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if *state != AppState::Idle || !self.pending_start.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        info!("🎤 Starting queued recording");
+        *state = AppState::Recording;
+        drop(state);
+
+        if Self::start_capture(&self.state, &self.audio) {
+            self.prefetch_model();
+        }
+    }
+
     /// Handle hotkey release event
     pub fn on_release(&self) {
+        if self.mode == HotkeyMode::Toggle {
+            // Presses alone start and stop toggle recordings
+            debug!("hotkey released in toggle mode (ignored)");
+            return;
+        }
         let mut state = self
             .state
             .lock()
@@ -132,57 +697,133 @@ impl HotkeyManager {
                 info!("⏹️  Hotkey released - processing audio");
                 *state = AppState::Processing;
                 drop(state);
-
-                // Stop audio recording and get samples
-                let stop_result = self
-                    .audio
-                    .lock()
-                    .unwrap_or_else(std::sync::PoisonError::into_inner)
-                    .stop_recording();
-
-                match stop_result {
-                    Ok(samples) => {
-                        // Duration calculation: usize → f64 for sample_count / sample_rate
-                        // Safe: even 1hr audio = 57.6M samples, well within f64 precision
-                        #[allow(clippy::cast_precision_loss)]
-                        let duration_secs = samples.len() as f64 / 16000.0;
-                        info!(
-                            sample_count = samples.len(),
-                            duration_secs = format!("{:.1}", duration_secs),
-                            "📼 Captured {:.1}s audio ({} samples)",
-                            duration_secs,
-                            samples.len()
-                        );
-
-                        if self.recording_enabled {
-                            Self::save_debug_wav(&samples);
-                        }
-                        self.process_transcription(samples);
-                    }
-                    Err(e) => {
-                        warn!(error = %e, "❌ Failed to stop recording: {}", e);
-                        let mut state = self
-                            .state
-                            .lock()
-                            .unwrap_or_else(std::sync::PoisonError::into_inner);
-                        *state = AppState::Idle;
-                        // Continue running - this is a transient error, user can try again
-                    }
-                }
+                self.finish_recording();
             }
             AppState::Idle => {
                 drop(state);
                 debug!("hotkey released while idle (ignored)");
             }
-            AppState::Processing => {
+            AppState::Processing | AppState::Loading => {
                 drop(state);
-                debug!("hotkey released while processing (ignored)");
+                // Key let go before processing finished: nothing left to record
+                if self.pending_start.swap(false, Ordering::SeqCst) {
+                    info!("⏭️  Queued recording cancelled (hotkey released while processing)");
+                } else {
+                    debug!("hotkey released while processing (ignored)");
+                }
+            }
+        }
+    }
+
+    /// Stop capture and transcribe, for a state already set to Processing
+    fn finish_recording(&self) {
+        // Stop audio recording and get samples
+        let (stop_result, capture_timings, device_rate) = {
+            let mut audio = self
+                .audio
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let result = audio.stop_recording();
+            (result, audio.last_timings(), audio.device_sample_rate())
+        };
+
+        // Checked right at release (or the stopping press), while the gesture's modifier is held
+        let end = gesture::session_end(self.discard_modifier, &gesture::held_modifiers());
+
+        match stop_result {
+            Ok(_) if end == SessionEnd::Discard => {
+                info!("🗑️  Recording discarded (discard modifier held at release)");
+                *self
+                    .state
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner) = AppState::Idle;
+            }
+            Ok(samples) if samples.is_empty() => {
+                // Nothing captured, or only silence with `[audio] trim_silence`
+                info!("🤫 No audio to transcribe, skipping");
+                *self
+                    .state
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner) = AppState::Idle;
+            }
+            Ok(samples) => {
+                // Duration calculation: usize → f64 for sample_count / sample_rate
+                // Safe: even 1hr audio = 57.6M samples, well within f64 precision
+                #[allow(clippy::cast_precision_loss)]
+                let duration_secs = samples.len() as f64 / 16000.0;
+                info!(
+                    sample_count = samples.len(),
+                    duration_secs = format!("{:.1}", duration_secs),
+                    "📼 Captured {:.1}s audio ({} samples)",
+                    duration_secs,
+                    samples.len()
+                );
+
+                if self.audio_stats_debug {
+                    audio::AudioStats::compute(&samples, device_rate).log();
+                }
+
+                if audio::is_clipping(&samples, self.clipping_threshold) {
+                    let clipped = audio::clipped_fraction(&samples) * 100.0;
+                    warn!(
+                        clipped_percent = format!("{clipped:.1}"),
+                        "📢 Input is clipping - lower your mic gain"
+                    );
+                    notify::notify(
+                        "input is clipping",
+                        "Lower your mic gain for better transcriptions",
+                    );
+                }
+
+                if self.recording_enabled {
+                    Self::save_debug_wav(&samples, self.wav_format);
+                }
+                self.process_transcription(samples, capture_timings);
+            }
+            Err(e) => {
+                warn!(error = %e, "❌ Failed to stop recording: {}", e);
+                let mut state = self
+                    .state
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                *state = AppState::Idle;
+                // Continue running - this is a transient error, user can try again
             }
         }
     }
 
+    /// Cancel an in-progress recording: stop capture and drop the audio
+    ///
+    /// Returns true if a recording was cancelled. Nothing is transcribed or inserted,
+    /// and the hotkey release that follows is ignored since the state is back to Idle.
+    pub fn cancel(&self) -> bool {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if *state != AppState::Recording {
+            drop(state);
+            debug!("cancel while not recording (ignored)");
+            return false;
+        }
+        *state = AppState::Idle;
+        drop(state);
+
+        // Stopping drains the ring buffer; the samples are discarded
+        let stop_result = self
+            .audio
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .stop_recording();
+        if let Err(e) = stop_result {
+            warn!(error = %e, "failed to stop cancelled recording");
+        }
+        info!("✖️  Recording cancelled");
+        true
+    }
+
     /// Save debug WAV file with error recovery
-    fn save_debug_wav(samples: &[f32]) {
+    fn save_debug_wav(samples: &[f32], format: WavFormat) {
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_else(|_| std::time::Duration::from_secs(0))
@@ -193,83 +834,283 @@ impl HotkeyManager {
             .join("debug")
             .join(format!("recording_{timestamp}.wav"));
 
-        if let Err(e) = AudioCapture::save_wav_debug(samples, &debug_path) {
+        if let Err(e) = AudioCapture::save_wav_debug(samples, &debug_path, format) {
             warn!(error = %e, path = ?debug_path, "failed to save debug WAV");
         } else {
             debug!(path = ?debug_path, "saved debug WAV");
         }
     }
 
-    /// Process transcription and text insertion in background thread
-    fn process_transcription(&self, samples: Vec<f32>) {
-        let engine = self.transcription.clone();
-        let lazy_load_config = self.lazy_load_config.clone();
-        let state_arc = Arc::clone(&self.state);
-        let aliases = Arc::clone(&self.aliases);
+    /// Directory for recordings saved while transcription is unavailable
+    fn untranscribed_dir() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_owned());
+        PathBuf::from(home)
+            .join(".whisper-hotkey")
+            .join("untranscribed")
+    }
 
-        // Set state to Processing if lazy loading needed (loading + transcription)
-        if engine.is_none() && lazy_load_config.is_some() {
-            *state_arc
-                .lock()
-                .unwrap_or_else(std::sync::PoisonError::into_inner) = AppState::Processing;
+    /// Unused `untranscribed_<millis>[_<n>].wav` path in `dir`
+    ///
+    /// Recordings can finish within the same millisecond of each other, so an
+    /// existing file bumps the counter instead of being overwritten.
+    fn untranscribed_path(dir: &Path) -> PathBuf {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_else(|_| std::time::Duration::from_secs(0))
+            .as_millis();
+        let mut path = dir.join(format!("untranscribed_{timestamp}.wav"));
+        let mut n = 1u32;
+        while path.exists() {
+            path = dir.join(format!("untranscribed_{timestamp}_{n}.wav"));
+            n += 1;
         }
+        path
+    }
 
-        std::thread::spawn(move || {
-            // Try lazy loading if needed (in background thread)
-            let engine = engine.or_else(|| {
-                if let Some((model_mgr, model_name)) = &lazy_load_config {
-                    info!("🔄 Lazy loading model: {}", model_name);
-                    let result = {
-                        let mut mgr = model_mgr
-                            .lock()
-                            .unwrap_or_else(std::sync::PoisonError::into_inner);
-                        mgr.get_or_load(model_name)
-                    };
-                    match result {
-                        Ok(engine) => {
-                            info!("✅ Model loaded: {}", model_name);
-                            Some(engine)
-                        }
-                        Err(e) => {
-                            warn!(error = %e, model = %model_name, "❌ Failed to lazy load model");
-                            None
-                        }
+    /// Handle a recording that can't be transcribed (no engine available)
+    ///
+    /// Returns the saved file path when `behavior` is `Save` and the write succeeded.
+    /// Saved recordings are always float, so a later transcription sees the original samples.
+    fn handle_degraded(behavior: DegradedBehavior, samples: &[f32], dir: &Path) -> Option<PathBuf> {
+        match behavior {
+            DegradedBehavior::Save => {
+                let path = Self::untranscribed_path(dir);
+                match AudioCapture::save_wav_debug(samples, &path, WavFormat::F32) {
+                    Ok(()) => {
+                        info!(path = ?path, "💾 Transcription unavailable - recording saved");
+                        Some(path)
+                    }
+                    Err(e) => {
+                        warn!(error = %e, path = ?path, "failed to save untranscribed recording");
+                        None
                     }
-                } else {
-                    None
                 }
-            });
-
-            if let Some(engine) = engine {
-                match engine.transcribe(&samples) {
-                    Ok(text) => {
-                        let text_preview: String = text.chars().take(50).collect();
-                        info!(
-                            text_len = text.len(),
-                            text_preview = %text_preview,
-                            "✨ Transcription: \"{}{}\"",
-                            text_preview,
-                            if text.len() > 50 { "..." } else { "" }
-                        );
-
-                        // Apply alias matching
-                        let final_text = alias::apply_aliases(&text, &aliases);
+            }
+            DegradedBehavior::Notify => {
+                warn!("⚠️  Transcription engine not available");
+                notify::notify(
+                    "transcription off",
+                    "Model not loaded - recording was discarded",
+                );
+                None
+            }
+            DegradedBehavior::Silent => {
+                warn!("⚠️  Transcription engine not available");
+                None
+            }
+        }
+    }
+
+    /// Post a macOS notification when accessibility permission is revoked or restored
+    fn notify_access_change(change: AccessChange) {
+        let (title, message) = match change {
+            AccessChange::Revoked => {
+                warn!(
+                    "⛔ Accessibility permission revoked - insertions paused until it is granted again"
+                );
+                (
+                    "accessibility revoked",
+                    "Text insertion paused - re-enable Whisper Hotkey in System Settings → Privacy & Security → Accessibility",
+                )
+            }
+            AccessChange::Restored => {
+                info!("✅ Accessibility permission restored");
+                ("accessibility restored", "Text insertion resumed")
+            }
+        };
+        notify::notify(title, message);
+    }
+
+    /// Post a macOS notification about a latency-driven model switch
+    fn notify_model_switch(tier: ModelTier) {
+        let message = match tier {
+            ModelTier::Fallback => "Transcription is slow - using the faster fallback model",
+            ModelTier::Primary => "Latency recovered - back to the configured model",
+        };
+        notify::notify("model switched", message);
+    }
+
+    /// Load a deferred model through the shared model manager
+    fn lazy_load(lazy_load_config: Option<&LazyLoadConfig>) -> Option<Arc<TranscriptionEngine>> {
+        let (model_mgr, model_name) = lazy_load_config?;
+        info!("🔄 Lazy loading model: {}", model_name);
+        let result = {
+            let mut mgr = model_mgr
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            mgr.get_or_load(model_name)
+        };
+        match result {
+            Ok(engine) => {
+                info!("✅ Model loaded: {}", model_name);
+                Some(engine)
+            }
+            Err(e) => {
+                warn!(error = %e, model = %model_name, "❌ Failed to lazy load model");
+                None
+            }
+        }
+    }
+
+    /// Bootstrap engine while the configured model is still downloading or loading
+    fn bootstrap_engine(&self) -> Option<Arc<TranscriptionEngine>> {
+        self.bootstrap
+            .as_ref()
+            .and_then(|bootstrap| bootstrap.active_engine())
+    }
+
+    /// Load a deferred model for a transcription, showing the loading state
+    fn load_on_demand(
+        lazy_load_config: Option<&LazyLoadConfig>,
+        model_load: &Mutex<ModelLoadState>,
+        state: &Mutex<AppState>,
+    ) -> Option<Arc<TranscriptionEngine>> {
+        let (model_mgr, model_name) = lazy_load_config?;
+        // Evicted by the model budget since last use: show loading again
+        if !model_mgr
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .is_loaded(model_name)
+        {
+            *model_load
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = ModelLoadState::NotLoaded;
+        }
+        load_tracked(model_load, state, || Self::lazy_load(lazy_load_config))
+    }
+
+    /// Start loading a deferred model while the user is still speaking
+    ///
+    /// Only the first press of a lazily loaded profile spawns a load; the
+    /// transcription worker picks up the engine (or waits for it) on release.
+    fn prefetch_model(&self) {
+        // While bootstrapping, the background download loads the model
+        if self.transcription.is_some() || self.bootstrap_engine().is_some() {
+            return;
+        }
+        let Some(lazy_load_config) = self.lazy_load_config.clone() else {
+            return;
+        };
+        if *self
+            .model_load
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            != ModelLoadState::NotLoaded
+        {
+            return;
+        }
+
+        let model_load = Arc::clone(&self.model_load);
+        let state = Arc::clone(&self.state);
+        std::thread::spawn(move || {
+            load_tracked(&model_load, &state, || {
+                Self::lazy_load(Some(&lazy_load_config))
+            });
+        });
+    }
+
+    /// Insertion settings handed to the transcription worker
+    fn insertion_target(&self) -> InsertionTarget {
+        InsertionTarget {
+            pacer: Arc::clone(&self.pacer),
+            duplicate_guard: Arc::clone(&self.duplicate_guard),
+            access: Arc::clone(&self.access_monitor),
+            chain: Arc::clone(&self.insertion_chain),
+            clear_before_insert: self.clear_before_insert,
+            dry_run: self.dry_run,
+        }
+    }
+
+    /// Process transcription and text insertion in background thread
+    fn process_transcription(&self, samples: Vec<f32>, capture: CaptureTimings) {
+        let engine = self
+            .transcription
+            .clone()
+            .or_else(|| self.bootstrap_engine());
+        let lazy_load_config = self.lazy_load_config.clone();
+        let model_load = Arc::clone(&self.model_load);
+        let state_arc = Arc::clone(&self.state);
+        let pipeline = self.pipeline.clone();
+        let auto_punctuation = self.auto_punctuation.clone();
+        let sinks = self.sinks.clone();
+        let latency_fallback = self.latency_fallback.clone();
+        let degraded_behavior = self.degraded_behavior;
+        let target = self.insertion_target();
+        let timing_debug = self.timing_debug;
+        let notifications = self.notifications;
+        // Queue time counts from here: the capture stages just finished
+        let mut stage_start = std::time::Instant::now();
+
+        let work = move || {
+            let mut timing = TimingRecord::from_capture(capture);
+            // Try lazy loading if needed (in background thread), showing the loading state
+            let engine = engine.or_else(|| {
+                Self::load_on_demand(lazy_load_config.as_ref(), &model_load, &state_arc)
+            });
+            timing.wait = telemetry::lap(&mut stage_start);
+
+            if let Some(engine) = engine {
+                let result = latency_fallback.as_ref().map_or_else(
+                    || engine.transcribe_detailed(&samples),
+                    |fallback| fallback.transcribe(&engine, &samples, Self::notify_model_switch),
+                );
+                timing.inference = telemetry::lap(&mut stage_start);
+                match result {
+                    Ok(Transcript {
+                        text,
+                        language,
+                        segments,
+                    }) => {
+                        telemetry::record_transcription(
+                            samples.len(),
+                            timing.inference.as_millis(),
+                        );
+                        let text = punctuation::apply(
+                            text,
+                            &segments,
+                            auto_punctuation.as_deref(),
+                            language.as_deref(),
+                        );
+                        let text_preview: String = text.chars().take(50).collect();
+                        info!(
+                            text_len = text.len(),
+                            text_preview = %text_preview,
+                            "✨ Transcription: \"{}{}\"",
+                            text_preview,
+                            if text.len() > 50 { "..." } else { "" }
+                        );
+
+                        // Strip markdown, translate, apply aliases and locale formatting
+                        // (command mode: exact alias match or nothing)
+                        let final_text = pipeline
+                            .for_language(language.as_deref())
+                            .output(text)
+                            .unwrap_or_else(|| {
+                                notify::notify("unrecognized command", "Nothing was inserted");
+                                String::new()
+                            });
+                        timing.formatting = telemetry::lap(&mut stage_start);
+
+                        sinks.record_usage(samples.len(), &final_text);
 
                         // Insert text at cursor, only if non-empty
                         if final_text.is_empty() {
                             info!("🔇 No speech detected (silence or noise)");
-                        } else if cgevent::insert_text_safe(&final_text) {
-                            info!(
-                                text_len = final_text.len(),
-                                "✅ Inserted {} chars",
-                                final_text.len()
-                            );
                         } else {
-                            warn!(
-                                text_len = final_text.len(),
-                                text_preview = %text_preview,
-                                "❌ Text insertion failed - check permissions"
-                            );
+                            let outcome = target.insert(&final_text, &text_preview);
+                            if outcome == Some(InsertionOutcome::Inserted) {
+                                sinks.record_inserted(samples.len(), &final_text);
+                            }
+                            let notice = outcome.and_then(|outcome| {
+                                insertion_notice(outcome, final_text.chars().count())
+                            });
+                            if let Some((title, message)) = notice.filter(|_| notifications) {
+                                notify::notify(title, &message);
+                            }
+                        }
+                        timing.insertion = telemetry::lap(&mut stage_start);
+                        if timing_debug {
+                            timing.log();
                         }
                     }
                     Err(e) => {
@@ -279,10 +1120,13 @@ impl HotkeyManager {
                             "❌ Transcription failed: {}",
                             e
                         );
+                        if notifications {
+                            notify::notify("transcription failed", &e.to_string());
+                        }
                     }
                 }
             } else {
-                warn!("⚠️  Transcription engine not available");
+                Self::handle_degraded(degraded_behavior, &samples, &Self::untranscribed_dir());
             }
 
             // Set state to Idle after processing (always recover)
@@ -290,6 +1134,31 @@ impl HotkeyManager {
                 .lock()
                 .unwrap_or_else(std::sync::PoisonError::into_inner) = AppState::Idle;
             info!("✓ Ready for next recording");
+        };
+
+        self.dispatch(work);
+    }
+
+    /// Run transcription work via the shared queue, or on its own thread if none
+    fn dispatch(&self, work: impl FnOnce() + Send + 'static) {
+        let Some(job_queue) = &self.job_queue else {
+            std::thread::spawn(work);
+            return;
+        };
+
+        let state = Arc::clone(&self.state);
+        job_queue.submit(Job {
+            run: Box::new(work),
+            discard: Box::new(move || {
+                // Dropped without transcription: recover so the hotkey works again
+                *state
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner) = AppState::Idle;
+                notify::notify(
+                    "recording dropped",
+                    "Transcription queue is full - a waiting recording was discarded",
+                );
+            }),
         });
     }
 
@@ -298,17 +1167,30 @@ impl HotkeyManager {
         if event.id != self.hotkey.id() {
             return;
         }
+        self.handle_key_state(event.state);
+    }
 
-        match event.state {
+    /// Act on a press or release routed to this profile (possibly from another hotkey)
+    fn handle_key_state(&self, state: global_hotkey::HotKeyState) {
+        if !accepts_event(state, self.paused.load(Ordering::SeqCst)) {
+            debug!("hotkey pressed while paused (ignored)");
+            return;
+        }
+
+        match state {
             global_hotkey::HotKeyState::Pressed => self.on_press(),
             global_hotkey::HotKeyState::Released => self.on_release(),
         }
     }
 
-    /// Get hotkey ID for this manager
-    #[must_use]
-    pub fn hotkey_id(&self) -> u32 {
-        self.hotkey.id()
+    /// Hotkey described by `config`, without registering it
+    ///
+    /// # Errors
+    /// Returns error if a modifier or the key is unknown
+    fn hotkey_for(config: &HotkeyConfig) -> Result<HotKey> {
+        let modifiers = Self::parse_modifiers(&config.modifiers)?;
+        let code = Self::parse_key(&config.key)?;
+        Ok(HotKey::new(Some(modifiers), code))
     }
 
     fn parse_modifiers(modifiers: &[String]) -> Result<Modifiers> {
@@ -325,6 +1207,10 @@ impl HotkeyManager {
         Ok(result)
     }
 
+    /// Key code for a config key name
+    ///
+    /// Accepts `A`-`Z`, `0`-`9`, `F1`-`F20`, `Space`, `Tab`, `Return`, `Escape`, and punctuation
+    /// by name (`Minus`, `Backquote`, ...) or as the character itself. Names are case-sensitive.
     fn parse_key(key: &str) -> Result<Code> {
         match key {
             "A" => Ok(Code::KeyA),
@@ -353,6 +1239,51 @@ impl HotkeyManager {
             "X" => Ok(Code::KeyX),
             "Y" => Ok(Code::KeyY),
             "Z" => Ok(Code::KeyZ),
+            "0" => Ok(Code::Digit0),
+            "1" => Ok(Code::Digit1),
+            "2" => Ok(Code::Digit2),
+            "3" => Ok(Code::Digit3),
+            "4" => Ok(Code::Digit4),
+            "5" => Ok(Code::Digit5),
+            "6" => Ok(Code::Digit6),
+            "7" => Ok(Code::Digit7),
+            "8" => Ok(Code::Digit8),
+            "9" => Ok(Code::Digit9),
+            "F1" => Ok(Code::F1),
+            "F2" => Ok(Code::F2),
+            "F3" => Ok(Code::F3),
+            "F4" => Ok(Code::F4),
+            "F5" => Ok(Code::F5),
+            "F6" => Ok(Code::F6),
+            "F7" => Ok(Code::F7),
+            "F8" => Ok(Code::F8),
+            "F9" => Ok(Code::F9),
+            "F10" => Ok(Code::F10),
+            "F11" => Ok(Code::F11),
+            "F12" => Ok(Code::F12),
+            "F13" => Ok(Code::F13),
+            "F14" => Ok(Code::F14),
+            "F15" => Ok(Code::F15),
+            "F16" => Ok(Code::F16),
+            "F17" => Ok(Code::F17),
+            "F18" => Ok(Code::F18),
+            "F19" => Ok(Code::F19),
+            "F20" => Ok(Code::F20),
+            "Space" => Ok(Code::Space),
+            "Tab" => Ok(Code::Tab),
+            "Escape" => Ok(Code::Escape),
+            "Return" | "Enter" => Ok(Code::Enter),
+            "Minus" | "-" => Ok(Code::Minus),
+            "Equal" | "=" => Ok(Code::Equal),
+            "Backquote" | "`" => Ok(Code::Backquote),
+            "BracketLeft" | "[" => Ok(Code::BracketLeft),
+            "BracketRight" | "]" => Ok(Code::BracketRight),
+            "Backslash" | "\\" => Ok(Code::Backslash),
+            "Semicolon" | ";" => Ok(Code::Semicolon),
+            "Quote" | "'" => Ok(Code::Quote),
+            "Comma" | "," => Ok(Code::Comma),
+            "Period" | "." => Ok(Code::Period),
+            "Slash" | "/" => Ok(Code::Slash),
             _ => Err(anyhow!("unsupported key: {key}")),
         }
     }
@@ -366,16 +1297,341 @@ impl Drop for HotkeyManager {
     }
 }
 
+/// Digit keys offered by the quick-pick list, in order
+const DIGIT_CODES: [Code; quick_pick::MAX_CHOICES] = [
+    Code::Digit1,
+    Code::Digit2,
+    Code::Digit3,
+    Code::Digit4,
+    Code::Digit5,
+    Code::Digit6,
+    Code::Digit7,
+    Code::Digit8,
+    Code::Digit9,
+];
+
+/// Held digit key recording with the profile it picked
+#[derive(Debug, Clone, Copy)]
+struct ActiveDigit {
+    /// Hotkey id of the digit key
+    id: u32,
+    /// Index of the chosen profile
+    profile: usize,
+}
+
+/// Quick-pick hotkey plus the digit keys grabbed while its list is open
+///
+/// Holding the chosen digit records with that profile, like holding its own hotkey.
+struct QuickPickHotkeys {
+    manager: Arc<GlobalHotKeyManager>,
+    trigger: HotKey,
+    /// Bare digit keys 1-9, registered only while the list is open
+    digits: Vec<HotKey>,
+    picker: Mutex<QuickPick>,
+    /// Recording in progress from a held digit key
+    active: Mutex<Option<ActiveDigit>>,
+    /// Numbered profile list shown when the list opens
+    menu: String,
+}
+
+impl QuickPickHotkeys {
+    /// Register the quick-pick hotkey (`None` when disabled)
+    ///
+    /// # Errors
+    /// Returns error if the hotkey is invalid or can't be registered
+    fn register(
+        config: &QuickPickConfig,
+        manager: &Arc<GlobalHotKeyManager>,
+        managers: &[(String, HotkeyManager)],
+    ) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let profile_names: Vec<&str> = managers.iter().map(|(name, _)| name.as_str()).collect();
+        let modifiers = HotkeyManager::parse_modifiers(&config.modifiers)?;
+        let trigger = HotKey::new(Some(modifiers), HotkeyManager::parse_key(&config.key)?);
+        manager
+            .register(trigger)
+            .context("failed to register quick-pick hotkey")?;
+        info!(
+            "registered quick-pick hotkey: {:?} + {}",
+            config.modifiers, config.key
+        );
+
+        Ok(Some(Self {
+            manager: Arc::clone(manager),
+            trigger,
+            digits: DIGIT_CODES
+                .iter()
+                .map(|&code| HotKey::new(None, code))
+                .collect(),
+            picker: Mutex::new(QuickPick::new(
+                profile_names.len(),
+                std::time::Duration::from_millis(config.timeout_ms),
+            )),
+            active: Mutex::new(None),
+            menu: quick_pick::menu_text(&profile_names),
+        }))
+    }
+
+    /// Handle a quick-pick or digit event; false if the event isn't ours
+    fn handle_event(&self, event: GlobalHotKeyEvent, managers: &[(String, HotkeyManager)]) -> bool {
+        if event.id == self.trigger.id() {
+            if event.state == global_hotkey::HotKeyState::Pressed {
+                self.open();
+            }
+            return true;
+        }
+        let Some(index) = self.digits.iter().position(|digit| digit.id() == event.id) else {
+            return false;
+        };
+
+        match event.state {
+            global_hotkey::HotKeyState::Pressed => self.on_digit(event.id, index, managers),
+            global_hotkey::HotKeyState::Released => {
+                let mut active = self
+                    .active
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                if let Some(digit) = active.filter(|digit| digit.id == event.id) {
+                    *active = None;
+                    drop(active);
+                    managers[digit.profile].1.on_release();
+                    self.release_digits();
+                }
+            }
+        }
+        true
+    }
+
+    /// Show the profile list and grab the digit keys
+    fn open(&self) {
+        self.picker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .open(std::time::Instant::now());
+        if let Err(e) = self.manager.register_all(&self.digits) {
+            // Already registered when reopening an open list
+            debug!(error = %e, "quick-pick digit keys not registered");
+        }
+        info!("🔢 Quick pick: {}", self.menu);
+        notify::notify("press a number", &self.menu);
+    }
+
+    /// Digit pressed: start recording with the chosen profile
+    fn on_digit(&self, id: u32, index: usize, managers: &[(String, HotkeyManager)]) {
+        let digit = u8::try_from(index + 1).unwrap_or(u8::MAX);
+        let selection = self
+            .picker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .select(digit, std::time::Instant::now());
+        match selection {
+            Selection::Profile(profile) => {
+                info!(profile = %managers[profile].0, "🔢 Quick pick selected profile {}", digit);
+                *self
+                    .active
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner) =
+                    Some(ActiveDigit { id, profile });
+                managers[profile].1.on_press();
+            }
+            Selection::Invalid => warn!("🔢 Quick pick: no profile {}", digit),
+            Selection::Expired => {
+                info!("🔢 Quick pick timed out");
+                self.release_digits();
+            }
+            Selection::NotOpen => {}
+        }
+    }
+
+    /// Close the list if it timed out
+    fn expire(&self) {
+        let expired = self
+            .picker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .expire(std::time::Instant::now());
+        if expired {
+            info!("🔢 Quick pick timed out");
+            self.release_digits();
+        }
+    }
+
+    /// Give the digit keys back to other apps
+    fn release_digits(&self) {
+        if let Err(e) = self.manager.unregister_all(&self.digits) {
+            warn!(error = %e, "failed to unregister quick-pick digit keys");
+        }
+    }
+}
+
+impl Drop for QuickPickHotkeys {
+    fn drop(&mut self) {
+        if let Err(e) = self.manager.unregister(self.trigger) {
+            tracing::error!("failed to unregister quick-pick hotkey: {}", e);
+        }
+        // Digit keys are only registered while the list is open
+        let _ = self.manager.unregister_all(&self.digits);
+    }
+}
+
+/// Cancel key (Escape by default), grabbed only while some profile is recording
+///
+/// Pressing it cancels the recording; the rest of the time the key goes to other apps.
+struct CancelKey {
+    manager: Arc<GlobalHotKeyManager>,
+    hotkey: HotKey,
+    /// Whether the key is currently registered
+    registered: AtomicBool,
+}
+
+impl CancelKey {
+    /// Hotkey for `[recording] cancel_key` and `cancel_modifiers`
+    ///
+    /// # Errors
+    /// Returns error if a modifier or the key is unknown
+    fn hotkey_for(config: &RecordingConfig) -> Result<HotKey> {
+        let modifiers = HotkeyManager::parse_modifiers(&config.cancel_modifiers)?;
+        let code = HotkeyManager::parse_key(&config.cancel_key)?;
+        Ok(HotKey::new(Some(modifiers), code))
+    }
+
+    /// Cancel key for `[recording] cancel_on_escape` (`None` when disabled)
+    ///
+    /// # Errors
+    /// Returns error if the configured key is invalid or is also a profile hotkey
+    fn new(
+        config: &RecordingConfig,
+        manager: &Arc<GlobalHotKeyManager>,
+        routes: &HashMap<u32, usize>,
+    ) -> Result<Option<Self>> {
+        if !config.cancel_on_escape {
+            return Ok(None);
+        }
+        let hotkey = Self::hotkey_for(config).context("invalid [recording] cancel_key")?;
+        if routes.contains_key(&hotkey.id()) {
+            return Err(anyhow!(
+                "[recording] cancel_key {:?} + {} is also a profile hotkey",
+                config.cancel_modifiers,
+                config.cancel_key
+            ));
+        }
+        Ok(Some(Self {
+            manager: Arc::clone(manager),
+            hotkey,
+            registered: AtomicBool::new(false),
+        }))
+    }
+
+    /// Whether `event` is a press of the cancel key
+    fn is_press(&self, event: GlobalHotKeyEvent) -> bool {
+        event.id == self.hotkey.id() && event.state == global_hotkey::HotKeyState::Pressed
+    }
+
+    /// Grab the cancel key while `recording`, give it back otherwise
+    fn sync(&self, recording: bool) {
+        if self.registered.load(Ordering::SeqCst) == recording {
+            return;
+        }
+        let result = if recording {
+            self.manager.register(self.hotkey)
+        } else {
+            self.manager.unregister(self.hotkey)
+        };
+        match result {
+            Ok(()) => self.registered.store(recording, Ordering::SeqCst),
+            Err(e) => warn!(error = %e, "failed to update cancel key"),
+        }
+    }
+}
+
+impl Drop for CancelKey {
+    fn drop(&mut self) {
+        self.sync(false);
+    }
+}
+
+/// Map from hotkey id to the index of the profile it triggers (pure)
+///
+/// `hotkeys` holds each profile's name and hotkey id, in profile order.
+///
+/// # Errors
+/// Returns error if two profiles use the same hotkey
+fn hotkey_routes(hotkeys: &[(String, u32)]) -> Result<HashMap<u32, usize>> {
+    let mut routes = HashMap::with_capacity(hotkeys.len());
+    for (index, (name, id)) in hotkeys.iter().enumerate() {
+        if let Some(previous) = routes.insert(*id, index) {
+            return Err(anyhow!(
+                "profiles {} and {name} use the same hotkey",
+                hotkeys[previous].0
+            ));
+        }
+    }
+    Ok(routes)
+}
+
+/// Whether a hotkey event in `state` is handled: presses are dropped while paused (pure)
+///
+/// Releases still go through, so a recording held down when pausing finishes normally.
+fn accepts_event(state: global_hotkey::HotKeyState, paused: bool) -> bool {
+    !paused || state == global_hotkey::HotKeyState::Released
+}
+
+/// Profile handling an event routed to profile `index` (pure)
+///
+/// The first profile's hotkey records with the `selected` profile; others keep their own.
+const fn redirect_primary(index: usize, selected: usize) -> usize {
+    if index == 0 {
+        selected
+    } else {
+        index
+    }
+}
+
+/// Whether the hotkey may move between profiles in these states (pure)
+///
+/// Both must be idle: switching away mid-recording loses the hotkey release, and
+/// switching onto a busy profile would press its hotkey while it's still working.
+fn can_switch_profile(current: AppState, target: AppState) -> bool {
+    current == AppState::Idle && target == AppState::Idle
+}
+
+/// Index of the profile triggered by the hotkey with `id` (None for other hotkeys)
+fn route_event(routes: &HashMap<u32, usize>, id: u32) -> Option<usize> {
+    routes.get(&id).copied()
+}
+
+/// Audio settings and the capture opened for them
+type ProfileCapture = (AudioConfig, Arc<Mutex<AudioCapture>>);
+
 /// Multi-profile hotkey manager supporting multiple models and hotkeys
 pub struct MultiHotkeyManager {
     /// Individual hotkey managers (one per profile)
     managers: Vec<(String, HotkeyManager)>,
+    /// Hotkey id to the index in `managers` of the profile it triggers
+    routes: HashMap<u32, usize>,
     /// Shared audio capture
     #[allow(dead_code)] // Held for lifetime management
     audio: Arc<Mutex<AudioCapture>>,
+    /// Captures opened for profiles with their own `[profiles.audio]`
+    profile_audio: Vec<Arc<Mutex<AudioCapture>>>,
     /// Model manager for lazy loading
-    #[allow(dead_code)] // Held for lifetime management
     model_manager: Arc<Mutex<ModelManager>>,
+    /// Aliases applied to file transcriptions
+    aliases: Arc<AliasesConfig>,
+    /// One hotkey choosing the profile for the next recording (None = disabled)
+    quick_pick: Option<QuickPickHotkeys>,
+    /// Key cancelling the current recording, Escape by default (None = disabled)
+    cancel_key: Option<CancelKey>,
+    /// A recording was cancelled since the last `take_cancelled`
+    cancelled: AtomicBool,
+    /// Latest transcription of any profile
+    last_transcription: LastTranscription,
+    /// Hotkeys paused from the tray, shared with every profile
+    paused: Arc<AtomicBool>,
+    /// Index in `managers` of the profile the first profile's hotkey records with
+    selected: AtomicUsize,
 }
 
 impl MultiHotkeyManager {
@@ -418,58 +1674,154 @@ impl MultiHotkeyManager {
         // No-op on non-macOS platforms
     }
 
-    /// Create multi-hotkey manager from profiles
+    /// Create multi-hotkey manager from the config's profiles
+    ///
+    /// With `dry_run`, transcriptions are logged instead of inserted.
     ///
     /// # Errors
     /// Returns error if hotkey registration fails or model preloading fails
-    pub fn new(
-        profiles: &[crate::config::TranscriptionProfile],
-        audio: Arc<Mutex<AudioCapture>>,
-        recording_enabled: bool,
-        aliases: &Arc<AliasesConfig>,
-    ) -> Result<Self> {
+    #[allow(clippy::too_many_lines)] // Flat wiring of every shared component into each profile
+    pub fn new(config: &Config, audio: Arc<Mutex<AudioCapture>>, dry_run: bool) -> Result<Self> {
+        let insertion = &config.insertion;
+        let queue = &config.queue;
+        let aliases = Arc::new(config.aliases.clone());
+        let auto_punctuation = config
+            .auto_punctuation
+            .enabled
+            .then(|| Arc::new(config.auto_punctuation.clone()));
+        let language_rules: Arc<[LanguageRule]> = config.language_rules.clone().into();
+
         // Create single shared GlobalHotKeyManager for all profiles
         // Pump event loop first to ensure NSApplication is ready
         Self::pump_event_loop();
         let global_manager =
             Arc::new(GlobalHotKeyManager::new().context("failed to create global hotkey manager")?);
 
+        let (profiles, bootstrapping) = Self::plan_bootstrap(config)?;
+
+        // Checked before registering, so a shared hotkey names both profiles
+        let hotkey_ids = profiles
+            .iter()
+            .map(|profile| {
+                let hotkey = HotkeyManager::hotkey_for(&profile.hotkey)
+                    .with_context(|| format!("invalid hotkey for profile: {}", profile.name()))?;
+                Ok((profile.name().to_owned(), hotkey.id()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let routes = hotkey_routes(&hotkey_ids)?;
+
         // Create model manager (preloads where profile.preload=true)
+        let max_loaded_models = config.model.max_loaded_models;
         let model_manager = Arc::new(Mutex::new(
-            ModelManager::new(profiles).context("failed to initialize model manager")?,
+            ModelManager::new(&profiles, &config.model)
+                .context("failed to initialize model manager")?,
         ));
 
+        // One pacer shared by all profiles so their insertions never interleave
+        let pacer = Arc::new(InsertionPacer::new(std::time::Duration::from_millis(
+            insertion.min_gap_ms,
+        )));
+
+        // Repeats are caught across profiles, like pacing
+        let duplicate_guard = Arc::new(DuplicateGuard::new(std::time::Duration::from_millis(
+            insertion.dedupe_window_ms,
+        )));
+
+        // Revocation is a process-wide condition, detected from all profiles' insertions
+        let access_monitor = Arc::new(AccessMonitor::new(insertion.revoke_after_failures));
+
+        // Method order/fallback shared by all profiles
+        let insertion_chain = Arc::new(InsertionChain::from_config(insertion));
+
+        // One history file shared by all profiles
+        let history = HistoryLog::from_config(&config.history)?.map(Arc::new);
+
+        // One stats file shared by all profiles, keyed by profile name
+        let stats = StatsLog::from_config(&config.stats)?.map(Arc::new);
+        let webhook = Webhook::from_config(&config.webhook)?.map(Arc::new);
+
+        // Opt-in queue shared by all profiles caps memory held by pending recordings
+        let job_queue = queue
+            .enabled
+            .then(|| Arc::new(JobQueue::new(queue.max_depth, queue.overflow)));
+
+        // Original + translation for translating profiles, when enabled
+        let combine_template: Option<Arc<str>> = config
+            .output
+            .insert_original
+            .then(|| config.output.combine_template.as_str().into());
+
+        // Whichever profile transcribed last, for the tray's copy action
+        let last_transcription = LastTranscription::default();
+        let paused = Arc::new(AtomicBool::new(false));
+
         let mut managers = Vec::new();
+        let mut profile_audio = Vec::new();
 
-        for profile in profiles {
+        for (profile, needs_bootstrap) in profiles.iter().zip(bootstrapping) {
             let model_name = profile.name().to_owned();
-
-            // Get engine if preloaded, None if lazy
-            let (engine, lazy_config) = if profile.preload {
-                let arc_engine = {
-                    let mut mgr = model_manager
-                        .lock()
-                        .unwrap_or_else(std::sync::PoisonError::into_inner);
-                    mgr.get_or_load(&model_name)
-                        .with_context(|| format!("failed to get preloaded model: {model_name}"))?
-                };
-                (Some(Arc::clone(&arc_engine)), None)
-            } else {
-                // For lazy loading, pass model manager + model name
-                (None, Some((Arc::clone(&model_manager), model_name.clone())))
-            };
+            let audio_config = resolved_audio(profile, &config.audio);
+            let capture = Self::capture_for(&audio_config, config, &audio, &mut profile_audio)
+                .with_context(|| format!("failed to open audio for profile: {model_name}"))?;
+            let (engine, lazy_config) =
+                Self::route_engine(profile, &model_manager, max_loaded_models)?;
+            let bootstrap = needs_bootstrap
+                .then(|| ModelBootstrap::start(profile, &config.download, &model_manager))
+                .transpose()
+                .with_context(|| format!("failed to bootstrap profile: {model_name}"))?;
 
             // Create hotkey manager for this profile with shared global manager
             let mgr = HotkeyManager::new(
                 Arc::clone(&global_manager),
                 &profile.hotkey,
-                Arc::clone(&audio),
+                capture,
                 engine,
-                recording_enabled,
-                Arc::clone(aliases),
+                &config.recording,
+                Arc::clone(&aliases),
                 lazy_config,
             )
-            .with_context(|| format!("failed to register hotkey for profile: {model_name}"))?;
+            .with_context(|| format!("failed to register hotkey for profile: {model_name}"))?
+            .with_insertion_pacer(Arc::clone(&pacer))
+            .with_duplicate_guard(Arc::clone(&duplicate_guard))
+            .with_access_monitor(Arc::clone(&access_monitor))
+            .with_insertion_chain(Arc::clone(&insertion_chain))
+            .with_clear_before_insert(profile.clear_before_insert)
+            .with_auto_punctuation(auto_punctuation.clone())
+            .with_locale(config.formatting.locale)
+            .with_strip_markdown(profile.strip_markdown)
+            .with_mode(profile.mode)
+            .with_clipping_threshold(audio_config.clipping_threshold)
+            .with_language_rules(Arc::clone(&language_rules))
+            .with_discard_modifier(
+                gesture::parse_discard_modifier(
+                    profile.discard_modifier.as_deref(),
+                    &profile.hotkey.modifiers,
+                )
+                .with_context(|| format!("invalid discard_modifier for profile: {model_name}"))?,
+            )
+            .with_translation_hook(
+                HookCommand::for_profile(
+                    profile.translate_to.as_deref(),
+                    config.translation.command.as_deref(),
+                )
+                .with_context(|| format!("invalid translation for profile: {model_name}"))?,
+            )
+            .with_combine_template(combine_template.clone())
+            .with_history(history.clone(), &model_name)
+            .with_stats(stats.clone(), &model_name)
+            .with_webhook(webhook.clone(), &model_name)
+            .with_last_transcription(Arc::clone(&last_transcription))
+            .with_pause_flag(Arc::clone(&paused))
+            .with_notifications(config.app.notifications)
+            .with_dry_run(dry_run)
+            .with_timing_debug(config.telemetry.timing_debug)
+            .with_audio_stats_debug(config.telemetry.audio_stats_debug)
+            .with_bootstrap(bootstrap)
+            .with_latency_fallback(
+                LatencyFallback::for_profile(profile, &config.latency, &config.model)
+                    .with_context(|| format!("invalid fallback model for profile: {model_name}"))?,
+            )
+            .with_job_queue(job_queue.clone());
 
             info!(
                 profile = %model_name,
@@ -481,75 +1833,344 @@ impl MultiHotkeyManager {
             managers.push((model_name, mgr));
         }
 
+        let quick_pick =
+            QuickPickHotkeys::register(&config.quick_pick, &global_manager, &managers)?;
+        let cancel_key = CancelKey::new(&config.recording, &global_manager, &routes)?;
+
         Ok(Self {
             managers,
+            routes,
             audio,
+            profile_audio: profile_audio
+                .into_iter()
+                .map(|(_, capture)| capture)
+                .collect(),
             model_manager,
+            aliases,
+            quick_pick,
+            cancel_key,
+            cancelled: AtomicBool::new(false),
+            last_transcription,
+            paused,
+            selected: AtomicUsize::new(0),
         })
     }
 
-    /// Handle hotkey event by dispatching only to the matching manager
-    pub fn handle_event(&self, event: GlobalHotKeyEvent) {
-        for (_, mgr) in &self.managers {
-            if event.id == mgr.hotkey_id() {
-                mgr.handle_event(event);
-                break; // Only one manager handles a given event
-            }
+    /// Capture a profile with `settings` records from
+    ///
+    /// Profiles on the global `[audio]` share `audio`; the others share one capture
+    /// per distinct setting, opened on first use and feeding the same level meter.
+    fn capture_for(
+        settings: &AudioConfig,
+        config: &Config,
+        audio: &Arc<Mutex<AudioCapture>>,
+        opened: &mut Vec<ProfileCapture>,
+    ) -> Result<Arc<Mutex<AudioCapture>>> {
+        if *settings == config.audio {
+            return Ok(Arc::clone(audio));
+        }
+        if let Some((_, capture)) = opened.iter().find(|(existing, _)| existing == settings) {
+            return Ok(Arc::clone(capture));
         }
+        let level = audio
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .level_meter();
+        #[allow(clippy::arc_with_non_send_sync)]
+        let capture = Arc::new(Mutex::new(AudioCapture::with_level(settings, level)?));
+        opened.push((settings.clone(), Arc::clone(&capture)));
+        Ok(capture)
     }
 
-    /// Get state for specific profile
-    #[must_use]
-    pub fn profile_state(&self, profile_name: &str) -> Option<Arc<Mutex<AppState>>> {
-        self.managers
-            .iter()
-            .find(|(name, _)| name == profile_name)
-            .map(|(_, mgr)| mgr.state_shared())
+    /// Input streams opened for profiles with their own `[profiles.audio]`
+    ///
+    /// The shared capture passed to [`Self::new`] isn't included.
+    pub fn profile_captures(&self) -> impl Iterator<Item = &Mutex<AudioCapture>> {
+        self.profile_audio.iter().map(AsRef::as_ref)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_modifiers_control() {
-        let result = HotkeyManager::parse_modifiers(&["Control".to_owned()]).unwrap();
-        assert_eq!(result, Modifiers::CONTROL);
+    /// Decide which profiles start on the bootstrap model
+    ///
+    /// Profiles whose model is still missing start on the bootstrap model; theirs is
+    /// loaded once the background download finishes, so it can't be preloaded.
+    fn plan_bootstrap(config: &Config) -> Result<BootstrapPlan> {
+        let mut profiles = config.profiles.clone();
+        let mut bootstrapping = Vec::with_capacity(profiles.len());
+        for profile in &mut profiles {
+            let needs_bootstrap = bootstrap::profile_needs_bootstrap(profile, &config.download)?;
+            profile.preload &= !needs_bootstrap;
+            bootstrapping.push(needs_bootstrap);
+        }
+        Ok((profiles, bootstrapping))
     }
 
-    #[test]
-    fn test_parse_modifiers_ctrl_alias() {
-        let result = HotkeyManager::parse_modifiers(&["Ctrl".to_owned()]).unwrap();
-        assert_eq!(result, Modifiers::CONTROL);
+    /// Preloaded engine, or the shared model manager for lazy loading
+    ///
+    /// With a model budget every profile goes through the manager, so preloaded
+    /// engines can be evicted too.
+    fn route_engine(
+        profile: &TranscriptionProfile,
+        model_manager: &Arc<Mutex<ModelManager>>,
+        max_loaded_models: usize,
+    ) -> Result<EngineRoute> {
+        let model_name = profile.name();
+        if profile.preload && max_loaded_models == 0 {
+            let engine = model_manager
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .get_or_load(model_name)
+                .with_context(|| format!("failed to get preloaded model: {model_name}"))?;
+            Ok((Some(engine), None))
+        } else {
+            // For lazy loading, pass model manager + model name
+            Ok((
+                None,
+                Some((Arc::clone(model_manager), model_name.to_owned())),
+            ))
+        }
     }
 
-    #[test]
-    fn test_parse_modifiers_option() {
-        let result = HotkeyManager::parse_modifiers(&["Option".to_owned()]).unwrap();
-        assert_eq!(result, Modifiers::ALT);
+    /// Start recordings queued while their profile was processing
+    pub fn start_queued_recordings(&self) {
+        for (_, mgr) in &self.managers {
+            mgr.start_queued_recording();
+        }
+        self.sync_cancel_key();
     }
 
-    #[test]
-    fn test_parse_modifiers_alt_alias() {
-        let result = HotkeyManager::parse_modifiers(&["Alt".to_owned()]).unwrap();
-        assert_eq!(result, Modifiers::ALT);
+    /// Close the quick-pick list once it timed out without a selection
+    pub fn expire_quick_pick(&self) {
+        if let Some(quick_pick) = &self.quick_pick {
+            quick_pick.expire();
+        }
     }
 
-    #[test]
-    fn test_parse_modifiers_command() {
-        let result = HotkeyManager::parse_modifiers(&["Command".to_owned()]).unwrap();
-        assert_eq!(result, Modifiers::SUPER);
+    /// Handle hotkey event by dispatching only to the matching manager
+    pub fn handle_event(&self, event: GlobalHotKeyEvent) {
+        self.dispatch_event(event);
+        self.sync_cancel_key();
     }
 
-    #[test]
-    fn test_parse_modifiers_super_alias() {
-        let result = HotkeyManager::parse_modifiers(&["Super".to_owned()]).unwrap();
-        assert_eq!(result, Modifiers::SUPER);
+    fn dispatch_event(&self, event: GlobalHotKeyEvent) {
+        if let Some(cancel_key) = &self.cancel_key {
+            if cancel_key.is_press(event) {
+                self.cancel_recordings();
+                return;
+            }
+        }
+        if let Some(quick_pick) = &self.quick_pick {
+            // Paused: the list doesn't open, but a quick-pick recording still ends on release
+            if accepts_event(event.state, self.is_paused())
+                && quick_pick.handle_event(event, &self.managers)
+            {
+                return;
+            }
+        }
+        // Only the profile registered for this hotkey handles it, except that the
+        // first profile's hotkey records with the profile picked in the tray
+        let Some(index) = route_event(&self.routes, event.id) else {
+            return;
+        };
+        let target = redirect_primary(index, self.selected.load(Ordering::SeqCst));
+        if target == index {
+            self.managers[index].1.handle_event(event);
+        } else {
+            self.managers[target].1.handle_key_state(event.state);
+        }
     }
 
-    #[test]
-    fn test_parse_modifiers_shift() {
+    /// Make the first profile's hotkey record with the profile called `name`
+    ///
+    /// The profile's model starts loading in the background if it isn't loaded yet.
+    ///
+    /// # Errors
+    /// Returns error if no profile is called `name`, or while the current or
+    /// target profile is busy
+    pub fn select_profile(&self, name: &str) -> Result<()> {
+        let index = self
+            .managers
+            .iter()
+            .position(|(profile, _)| profile == name)
+            .ok_or_else(|| anyhow!("no profile named {name}"))?;
+        let state_of = |mgr: &HotkeyManager| {
+            *mgr.state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+        };
+        let current = state_of(&self.managers[self.selected.load(Ordering::SeqCst)].1);
+        let target = state_of(&self.managers[index].1);
+        if !can_switch_profile(current, target) {
+            return Err(anyhow!(
+                "can't switch profile while busy (current: {current:?}, {name}: {target:?})"
+            ));
+        }
+        self.selected.store(index, Ordering::SeqCst);
+        self.managers[index].1.prefetch_model();
+        info!(profile = %name, "🎛️ Hotkey now records with profile {}", name);
+        Ok(())
+    }
+
+    /// Cancel every profile that is recording
+    fn cancel_recordings(&self) {
+        for (_, mgr) in &self.managers {
+            if mgr.cancel() {
+                self.cancelled.store(true, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Grab the cancel key only while some profile is recording
+    fn sync_cancel_key(&self) {
+        let Some(cancel_key) = &self.cancel_key else {
+            return;
+        };
+        let recording = self.managers.iter().any(|(_, mgr)| {
+            *mgr.state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                == AppState::Recording
+        });
+        cancel_key.sync(recording);
+    }
+
+    /// Whether a recording was cancelled since the last call (polled by the tray)
+    pub fn take_cancelled(&self) -> bool {
+        self.cancelled.swap(false, Ordering::SeqCst)
+    }
+
+    /// Transcribe an audio file with the first profile's model and copy the result to the clipboard
+    ///
+    /// Runs in a background thread; unsupported files and failures are logged.
+    pub fn transcribe_file(&self, path: PathBuf) {
+        let Some((model_name, _)) = self.managers.first() else {
+            warn!("no profiles configured, cannot transcribe file");
+            return;
+        };
+        let model_name = model_name.clone();
+        let model_manager = Arc::clone(&self.model_manager);
+        let aliases = Arc::clone(&self.aliases);
+
+        info!(path = %path.display(), profile = %model_name, "📂 Transcribing file");
+        std::thread::spawn(move || {
+            match Self::transcribe_file_blocking(&path, &model_manager, &model_name, &aliases) {
+                Ok(text) => info!(
+                    text_len = text.len(),
+                    "📋 Copied transcription of {} to clipboard",
+                    path.display()
+                ),
+                Err(e) => warn!(
+                    error = %e,
+                    path = %path.display(),
+                    "❌ File transcription failed: {:#}",
+                    e
+                ),
+            }
+        });
+    }
+
+    fn transcribe_file_blocking(
+        path: &Path,
+        model_manager: &Mutex<ModelManager>,
+        model_name: &str,
+        aliases: &AliasesConfig,
+    ) -> Result<String> {
+        let samples = file::load_audio_file(path)?;
+        let engine = model_manager
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get_or_load(model_name)?;
+        let text = engine.transcribe(&samples)?;
+        let text = alias::apply_aliases(&text, aliases);
+        file::copy_to_clipboard(&text)?;
+        Ok(text)
+    }
+
+    /// Get state for specific profile
+    #[must_use]
+    pub fn profile_state(&self, profile_name: &str) -> Option<Arc<Mutex<AppState>>> {
+        self.managers
+            .iter()
+            .find(|(name, _)| name == profile_name)
+            .map(|(_, mgr)| mgr.state_shared())
+    }
+
+    /// Whether hotkey presses are currently ignored
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume all hotkeys, returning whether they're now paused
+    pub fn toggle_pause(&self) -> bool {
+        let paused = !self.paused.fetch_xor(true, Ordering::SeqCst);
+        if paused {
+            info!("⏸️  Hotkeys paused");
+        } else {
+            info!("▶️  Hotkeys resumed");
+        }
+        paused
+    }
+
+    /// Slot holding the latest transcription of any profile (for the tray menu)
+    #[must_use]
+    pub fn last_transcription(&self) -> LastTranscription {
+        Arc::clone(&self.last_transcription)
+    }
+
+    /// Whether no profile is recording, transcribing or loading a model
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        self.managers.iter().all(|(_, mgr)| {
+            *mgr.state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                == AppState::Idle
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_modifiers_control() {
+        let result = HotkeyManager::parse_modifiers(&["Control".to_owned()]).unwrap();
+        assert_eq!(result, Modifiers::CONTROL);
+    }
+
+    #[test]
+    fn test_parse_modifiers_ctrl_alias() {
+        let result = HotkeyManager::parse_modifiers(&["Ctrl".to_owned()]).unwrap();
+        assert_eq!(result, Modifiers::CONTROL);
+    }
+
+    #[test]
+    fn test_parse_modifiers_option() {
+        let result = HotkeyManager::parse_modifiers(&["Option".to_owned()]).unwrap();
+        assert_eq!(result, Modifiers::ALT);
+    }
+
+    #[test]
+    fn test_parse_modifiers_alt_alias() {
+        let result = HotkeyManager::parse_modifiers(&["Alt".to_owned()]).unwrap();
+        assert_eq!(result, Modifiers::ALT);
+    }
+
+    #[test]
+    fn test_parse_modifiers_command() {
+        let result = HotkeyManager::parse_modifiers(&["Command".to_owned()]).unwrap();
+        assert_eq!(result, Modifiers::SUPER);
+    }
+
+    #[test]
+    fn test_parse_modifiers_super_alias() {
+        let result = HotkeyManager::parse_modifiers(&["Super".to_owned()]).unwrap();
+        assert_eq!(result, Modifiers::SUPER);
+    }
+
+    #[test]
+    fn test_parse_modifiers_shift() {
         let result = HotkeyManager::parse_modifiers(&["Shift".to_owned()]).unwrap();
         assert_eq!(result, Modifiers::SHIFT);
     }
@@ -599,15 +2220,176 @@ mod tests {
 
     #[test]
     fn test_parse_key_unsupported() {
-        let result = HotkeyManager::parse_key("F1");
+        let result = HotkeyManager::parse_key("F21");
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("unsupported key"));
+        assert_eq!(result.unwrap_err().to_string(), "unsupported key: F21");
+        assert!(HotkeyManager::parse_key("Hyper").is_err());
+        assert!(HotkeyManager::parse_key("10").is_err());
     }
 
     #[test]
     fn test_parse_key_lowercase() {
         let result = HotkeyManager::parse_key("a");
         assert!(result.is_err());
+        assert!(HotkeyManager::parse_key("f13").is_err());
+        assert!(HotkeyManager::parse_key("space").is_err());
+    }
+
+    #[test]
+    fn test_parse_key_digits() {
+        assert_eq!(HotkeyManager::parse_key("0").unwrap(), Code::Digit0);
+        assert_eq!(HotkeyManager::parse_key("5").unwrap(), Code::Digit5);
+        assert_eq!(HotkeyManager::parse_key("9").unwrap(), Code::Digit9);
+    }
+
+    #[test]
+    fn test_parse_key_function_keys() {
+        assert_eq!(HotkeyManager::parse_key("F1").unwrap(), Code::F1);
+        assert_eq!(HotkeyManager::parse_key("F12").unwrap(), Code::F12);
+        assert_eq!(HotkeyManager::parse_key("F13").unwrap(), Code::F13);
+        assert_eq!(HotkeyManager::parse_key("F20").unwrap(), Code::F20);
+    }
+
+    fn hotkey_config(modifiers: &[&str], key: &str) -> HotkeyConfig {
+        HotkeyConfig {
+            modifiers: modifiers.iter().map(|&m| m.to_owned()).collect(),
+            key: key.to_owned(),
+            mode: HotkeyMode::Hold,
+        }
+    }
+
+    #[test]
+    fn test_hotkey_for_ids_distinguish_profiles() {
+        let fast = HotkeyManager::hotkey_for(&hotkey_config(&["Control", "Option"], "Z")).unwrap();
+        let accurate =
+            HotkeyManager::hotkey_for(&hotkey_config(&["Command", "Shift"], "V")).unwrap();
+        let fast_again = HotkeyManager::hotkey_for(&hotkey_config(&["Ctrl", "Alt"], "Z")).unwrap();
+        assert_ne!(fast.id(), accurate.id());
+        // Aliased modifier names describe the same hotkey
+        assert_eq!(fast.id(), fast_again.id());
+        assert!(HotkeyManager::hotkey_for(&hotkey_config(&["Hyper"], "Z")).is_err());
+    }
+
+    #[test]
+    fn test_insertion_notice() {
+        assert_eq!(
+            insertion_notice(InsertionOutcome::Inserted, 240),
+            Some(("transcription complete", "Inserted 240 chars".to_owned()))
+        );
+        let (title, _) = insertion_notice(InsertionOutcome::Failed, 240).unwrap();
+        assert_eq!(title, "insertion failed");
+        assert_eq!(insertion_notice(InsertionOutcome::DryRun, 240), None);
+    }
+
+    #[test]
+    fn test_redirect_primary() {
+        // Nothing picked: every hotkey keeps its own profile
+        assert_eq!(redirect_primary(0, 0), 0);
+        assert_eq!(redirect_primary(2, 0), 2);
+        // Only the first profile's hotkey follows the tray selection
+        assert_eq!(redirect_primary(0, 2), 2);
+        assert_eq!(redirect_primary(1, 2), 1);
+    }
+
+    #[test]
+    fn test_can_switch_profile() {
+        assert!(can_switch_profile(AppState::Idle, AppState::Idle));
+        for busy in [AppState::Recording, AppState::Processing, AppState::Loading] {
+            assert!(!can_switch_profile(busy, AppState::Idle));
+            assert!(!can_switch_profile(AppState::Idle, busy));
+        }
+    }
+
+    #[test]
+    fn test_accepts_event_when_paused() {
+        use global_hotkey::HotKeyState::{Pressed, Released};
+
+        assert!(accepts_event(Pressed, false));
+        assert!(accepts_event(Released, false));
+        assert!(!accepts_event(Pressed, true));
+        assert!(accepts_event(Released, true));
+    }
+
+    #[test]
+    fn test_route_event_to_profile() {
+        let ids: Vec<(String, u32)> = [
+            ("base.en", hotkey_config(&["Control", "Option"], "Z")),
+            ("small", hotkey_config(&["Command", "Shift"], "V")),
+            ("medium", hotkey_config(&["Control"], "F13")),
+        ]
+        .into_iter()
+        .map(|(name, config)| {
+            let id = HotkeyManager::hotkey_for(&config).unwrap().id();
+            (name.to_owned(), id)
+        })
+        .collect();
+        let routes = hotkey_routes(&ids).unwrap();
+
+        assert_eq!(route_event(&routes, ids[0].1), Some(0));
+        assert_eq!(route_event(&routes, ids[1].1), Some(1));
+        assert_eq!(route_event(&routes, ids[2].1), Some(2));
+        // Escape (cancel key) isn't a profile hotkey
+        assert_eq!(
+            route_event(&routes, HotKey::new(None, Code::Escape).id()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cancel_key_defaults_to_bare_escape() {
+        let hotkey = CancelKey::hotkey_for(&RecordingConfig::default()).unwrap();
+        assert_eq!(hotkey.id(), HotKey::new(None, Code::Escape).id());
+    }
+
+    #[test]
+    fn test_cancel_key_from_config() {
+        let config = RecordingConfig {
+            cancel_key: "Backquote".to_owned(),
+            cancel_modifiers: vec!["Control".to_owned()],
+            ..RecordingConfig::default()
+        };
+        let hotkey = CancelKey::hotkey_for(&config).unwrap();
+        assert_eq!(
+            hotkey.id(),
+            HotKey::new(Some(Modifiers::CONTROL), Code::Backquote).id()
+        );
+
+        let invalid = RecordingConfig {
+            cancel_key: "Esc".to_owned(),
+            ..RecordingConfig::default()
+        };
+        assert!(CancelKey::hotkey_for(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_hotkey_routes_reject_shared_hotkey() {
+        let ids = vec![
+            ("base.en".to_owned(), 7),
+            ("small".to_owned(), 9),
+            ("medium".to_owned(), 7),
+        ];
+        let error = hotkey_routes(&ids).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "profiles base.en and medium use the same hotkey"
+        );
+        assert!(hotkey_routes(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_key_named_and_punctuation() {
+        assert_eq!(HotkeyManager::parse_key("Space").unwrap(), Code::Space);
+        assert_eq!(HotkeyManager::parse_key("Escape").unwrap(), Code::Escape);
+        assert_eq!(HotkeyManager::parse_key("Return").unwrap(), Code::Enter);
+        assert_eq!(HotkeyManager::parse_key("Minus").unwrap(), Code::Minus);
+        assert_eq!(HotkeyManager::parse_key("Equal").unwrap(), Code::Equal);
+        assert_eq!(
+            HotkeyManager::parse_key("Backquote").unwrap(),
+            Code::Backquote
+        );
+        assert_eq!(HotkeyManager::parse_key("`").unwrap(), Code::Backquote);
+        assert_eq!(HotkeyManager::parse_key("Slash").unwrap(), Code::Slash);
+        assert_eq!(HotkeyManager::parse_key("\\").unwrap(), Code::Backslash);
     }
 
     #[test]
@@ -671,6 +2453,61 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_handle_degraded_save_writes_untranscribed_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "whisper_degraded_test_{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let path = HotkeyManager::handle_degraded(DegradedBehavior::Save, &[0.1, 0.2], &dir)
+            .expect("save should return the written path");
+
+        assert!(path.exists());
+        assert_eq!(path.parent(), Some(dir.as_path()));
+        let filename = path.file_name().unwrap().to_string_lossy();
+        assert!(filename.starts_with("untranscribed_"));
+        assert!(filename.ends_with(".wav"));
+        // Must not look like a debug recording (those are subject to cleanup)
+        assert!(!filename.starts_with("recording_"));
+
+        // Back-to-back saves land in the same millisecond - neither may be overwritten
+        let second = HotkeyManager::handle_degraded(DegradedBehavior::Save, &[0.3], &dir)
+            .expect("second save should return the written path");
+        assert_ne!(second, path);
+        assert!(path.exists());
+        assert!(second.exists());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_handle_degraded_silent_writes_nothing() {
+        let dir = std::env::temp_dir().join(format!(
+            "whisper_degraded_silent_test_{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let result = HotkeyManager::handle_degraded(DegradedBehavior::Silent, &[0.1], &dir);
+        assert!(result.is_none());
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_untranscribed_dir() {
+        let dir = HotkeyManager::untranscribed_dir();
+        assert!(dir
+            .to_string_lossy()
+            .ends_with(".whisper-hotkey/untranscribed"));
+    }
+
     #[test]
     #[ignore = "requires audio hardware and global hotkey registration"]
     fn test_hotkey_manager_creation() {
@@ -689,6 +2526,66 @@ mod tests {
         // Skip for now as it's integration-level testing
     }
 
+    #[test]
+    fn test_load_tracked_shows_loading_then_ready() {
+        let load_state = Mutex::new(ModelLoadState::NotLoaded);
+        let app_state = Mutex::new(AppState::Processing);
+
+        let result = load_tracked(&load_state, &app_state, || {
+            // Mock loader: observe the state while the model "loads"
+            assert_eq!(*load_state.lock().unwrap(), ModelLoadState::Loading);
+            assert_eq!(*app_state.lock().unwrap(), AppState::Loading);
+            Some("engine")
+        });
+
+        assert_eq!(result, Some("engine"));
+        assert_eq!(*load_state.lock().unwrap(), ModelLoadState::Ready);
+        assert_eq!(*app_state.lock().unwrap(), AppState::Processing);
+    }
+
+    #[test]
+    fn test_load_tracked_failure_allows_retry() {
+        let load_state = Mutex::new(ModelLoadState::NotLoaded);
+        let app_state = Mutex::new(AppState::Processing);
+
+        let result: Option<()> = load_tracked(&load_state, &app_state, || None);
+
+        assert_eq!(result, None);
+        assert_eq!(*load_state.lock().unwrap(), ModelLoadState::NotLoaded);
+        assert_eq!(*app_state.lock().unwrap(), AppState::Processing);
+    }
+
+    #[test]
+    fn test_load_tracked_during_recording_keeps_recording_state() {
+        // Prefetch on press: the tray keeps showing Recording while the model loads
+        let load_state = Mutex::new(ModelLoadState::NotLoaded);
+        let app_state = Mutex::new(AppState::Recording);
+
+        load_tracked(&load_state, &app_state, || {
+            assert_eq!(*app_state.lock().unwrap(), AppState::Recording);
+            Some(())
+        });
+
+        assert_eq!(*load_state.lock().unwrap(), ModelLoadState::Ready);
+        assert_eq!(*app_state.lock().unwrap(), AppState::Recording);
+    }
+
+    #[test]
+    fn test_load_tracked_ready_skips_loading_state() {
+        let load_state = Mutex::new(ModelLoadState::Ready);
+        let app_state = Mutex::new(AppState::Processing);
+        let mut calls = 0;
+
+        load_tracked(&load_state, &app_state, || {
+            calls += 1;
+            assert_eq!(*app_state.lock().unwrap(), AppState::Processing);
+            Some(())
+        });
+
+        assert_eq!(calls, 1);
+        assert_eq!(*load_state.lock().unwrap(), ModelLoadState::Ready);
+    }
+
     // Phase 2: Mock-based state machine tests
     mod mock_tests {
         use super::*;
@@ -727,6 +2624,13 @@ mod tests {
             state: Arc<Mutex<AppState>>,
             audio: Arc<Mutex<MockAudioCapture>>,
             transcription: Option<Arc<MockTranscriptionEngine>>,
+            mode: HotkeyMode,
+            queue_press_during_processing: bool,
+            pending_start: AtomicBool,
+            discard_modifier: Option<Modifier>,
+            /// Stands in for `gesture::held_modifiers()` at release
+            held_at_release: Vec<Modifier>,
+            paused: AtomicBool,
         }
 
         impl TestHotkeyManager {
@@ -738,6 +2642,46 @@ mod tests {
                     state: Arc::new(Mutex::new(AppState::Idle)),
                     audio: Arc::new(Mutex::new(audio)),
                     transcription: transcription.map(Arc::new),
+                    mode: HotkeyMode::Hold,
+                    queue_press_during_processing: false,
+                    pending_start: AtomicBool::new(false),
+                    discard_modifier: None,
+                    held_at_release: Vec::new(),
+                    paused: AtomicBool::new(false),
+                }
+            }
+
+            /// Mirrors `HotkeyManager::handle_event` for an event of this hotkey
+            fn handle_event(&self, state: global_hotkey::HotKeyState) {
+                if !accepts_event(state, self.paused.load(Ordering::SeqCst)) {
+                    return;
+                }
+                match state {
+                    global_hotkey::HotKeyState::Pressed => self.on_press(),
+                    global_hotkey::HotKeyState::Released => self.on_release(),
+                }
+            }
+
+            fn with_discard_gesture(mut self, discard: Modifier, held: Vec<Modifier>) -> Self {
+                self.discard_modifier = Some(discard);
+                self.held_at_release = held;
+                self
+            }
+
+            const fn with_queue_press(mut self) -> Self {
+                self.queue_press_during_processing = true;
+                self
+            }
+
+            const fn with_toggle_mode(mut self) -> Self {
+                self.mode = HotkeyMode::Toggle;
+                self
+            }
+
+            fn start_capture(&self) {
+                let recording_result = self.audio.lock().unwrap().start_recording();
+                if let Err(_e) = recording_result {
+                    *self.state.lock().unwrap() = AppState::Idle;
                 }
             }
 
@@ -747,63 +2691,221 @@ mod tests {
                     AppState::Idle => {
                         *state = AppState::Recording;
                         drop(state);
-
-                        let recording_result = self.audio.lock().unwrap().start_recording();
-                        if let Err(_e) = recording_result {
-                            let mut state = self.state.lock().unwrap();
-                            *state = AppState::Idle;
-                        }
+                        self.pending_start.store(false, Ordering::SeqCst);
+                        self.start_capture();
+                    }
+                    AppState::Recording if self.mode == HotkeyMode::Toggle => {
+                        *state = AppState::Processing;
+                        drop(state);
+                        self.finish_recording();
                     }
-                    AppState::Recording | AppState::Processing => {
+                    AppState::Recording => {
+                        drop(state);
+                    }
+                    AppState::Processing | AppState::Loading => {
                         drop(state);
+                        if self.queue_press_during_processing {
+                            self.pending_start.store(true, Ordering::SeqCst);
+                        }
                     }
                 }
             }
 
+            /// Mirrors `HotkeyManager::start_queued_recording` (polled by the event loop)
+            fn start_queued_recording(&self) {
+                let mut state = self.state.lock().unwrap();
+                if *state == AppState::Idle && self.pending_start.swap(false, Ordering::SeqCst) {
+                    *state = AppState::Recording;
+                    drop(state);
+                    self.start_capture();
+                }
+            }
+
+            /// Worker thread finishing: back to Idle, then the next event loop tick
+            fn finish_processing(&self) {
+                *self.state.lock().unwrap() = AppState::Idle;
+                self.start_queued_recording();
+            }
+
             /// Process hotkey release event.
             ///
             /// NOTE: This is a simplified synchronous test harness that processes
             /// transcription inline (unlike the real `HotkeyManager` which spawns a thread).
             /// This doesn't validate threading behavior or race conditions.
             fn on_release(&self) {
+                if self.mode == HotkeyMode::Toggle {
+                    return;
+                }
                 let mut state = self.state.lock().unwrap();
                 match *state {
                     AppState::Recording => {
                         *state = AppState::Processing;
                         drop(state);
+                        self.finish_recording();
+                    }
+                    AppState::Idle => {
+                        drop(state);
+                    }
+                    AppState::Processing | AppState::Loading => {
+                        drop(state);
+                        self.pending_start.store(false, Ordering::SeqCst);
+                    }
+                }
+            }
 
-                        let stop_result = self.audio.lock().unwrap().stop_recording();
-                        match stop_result {
-                            Ok(samples) => {
-                                if let Some(engine) = &self.transcription {
-                                    match engine.transcribe(&samples) {
-                                        Ok(text) => {
-                                            if !text.is_empty() {
-                                                // Would call cgevent::insert_text_safe here
-                                                // but we don't mock that in these tests
-                                            }
-                                        }
-                                        Err(_e) => {}
+            /// Mirrors `HotkeyManager::finish_recording` (state already Processing)
+            fn finish_recording(&self) {
+                let stop_result = self.audio.lock().unwrap().stop_recording();
+                let end = gesture::session_end(self.discard_modifier, &self.held_at_release);
+                match stop_result {
+                    Ok(_) if end == SessionEnd::Discard => {
+                        *self.state.lock().unwrap() = AppState::Idle;
+                    }
+                    Ok(samples) => {
+                        if let Some(engine) = &self.transcription {
+                            match engine.transcribe(&samples) {
+                                Ok(text) => {
+                                    if !text.is_empty() {
+                                        // Would call cgevent::dispatch_insertion here
+                                        // but we don't mock that in these tests
                                     }
                                 }
-                                *self.state.lock().unwrap() = AppState::Idle;
-                            }
-                            Err(_e) => {
-                                *self.state.lock().unwrap() = AppState::Idle;
+                                Err(_e) => {}
                             }
                         }
+                        self.finish_processing();
                     }
-                    AppState::Idle | AppState::Processing => {
-                        drop(state);
+                    Err(_e) => {
+                        *self.state.lock().unwrap() = AppState::Idle;
                     }
                 }
             }
 
+            /// Mirrors `HotkeyManager::cancel` (Escape while recording)
+            fn cancel(&self) -> bool {
+                let mut state = self.state.lock().unwrap();
+                if *state != AppState::Recording {
+                    return false;
+                }
+                *state = AppState::Idle;
+                drop(state);
+                let _ = self.audio.lock().unwrap().stop_recording();
+                true
+            }
+
             fn get_state(&self) -> AppState {
                 *self.state.lock().unwrap()
             }
         }
 
+        #[test]
+        fn test_toggle_press_starts_and_second_press_stops() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Ok(vec![0.1; 1600]));
+            let mut mock_transcription = MockTranscriptionEngine::new();
+            mock_transcription
+                .expect_transcribe()
+                .times(1)
+                .returning(|_| Ok("hello".to_owned()));
+
+            let manager =
+                TestHotkeyManager::new(mock_audio, Some(mock_transcription)).with_toggle_mode();
+
+            manager.on_press();
+            assert_eq!(manager.get_state(), AppState::Recording);
+            manager.on_press();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_toggle_release_ignored() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+            mock_audio.expect_stop_recording().times(0);
+
+            let manager = TestHotkeyManager::new(mock_audio, None).with_toggle_mode();
+
+            manager.on_press();
+            manager.on_release();
+            assert_eq!(manager.get_state(), AppState::Recording);
+            // Release while idle is a no-op too
+            *manager.state.lock().unwrap() = AppState::Idle;
+            manager.on_release();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_toggle_full_cycle_twice() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(2)
+                .returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(2)
+                .returning(|| Ok(vec![0.1; 160]));
+
+            let manager = TestHotkeyManager::new(mock_audio, None).with_toggle_mode();
+
+            for _ in 0..2 {
+                manager.on_press();
+                manager.on_release();
+                assert_eq!(manager.get_state(), AppState::Recording);
+                manager.on_press();
+                manager.on_release();
+                assert_eq!(manager.get_state(), AppState::Idle);
+            }
+        }
+
+        #[test]
+        fn test_toggle_press_while_processing_queues_without_release_cancel() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+
+            let manager = TestHotkeyManager::new(mock_audio, None)
+                .with_toggle_mode()
+                .with_queue_press();
+            *manager.state.lock().unwrap() = AppState::Processing;
+
+            manager.on_press();
+            manager.on_release();
+            manager.finish_processing();
+            assert_eq!(manager.get_state(), AppState::Recording);
+        }
+
+        #[test]
+        fn test_toggle_stop_failure_recovers_to_idle() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Err(anyhow::anyhow!("device gone")));
+
+            let manager = TestHotkeyManager::new(mock_audio, None).with_toggle_mode();
+
+            manager.on_press();
+            manager.on_press();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
         #[test]
         fn test_on_press_from_idle_starts_recording() {
             let mut mock_audio = MockAudioCapture::new();
@@ -819,6 +2921,41 @@ mod tests {
             assert_eq!(manager.get_state(), AppState::Recording);
         }
 
+        #[test]
+        fn test_press_ignored_while_paused() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_start_recording().times(0);
+
+            let manager = TestHotkeyManager::new(mock_audio, None);
+            manager.paused.store(true, Ordering::SeqCst);
+            manager.handle_event(global_hotkey::HotKeyState::Pressed);
+            assert_eq!(manager.get_state(), AppState::Idle);
+            manager.handle_event(global_hotkey::HotKeyState::Released);
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_recording_finishes_after_pause() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Ok(vec![0.0; 16000]));
+
+            let manager = TestHotkeyManager::new(mock_audio, None);
+            manager.handle_event(global_hotkey::HotKeyState::Pressed);
+            assert_eq!(manager.get_state(), AppState::Recording);
+
+            // Paused mid-recording: the release still ends it
+            manager.paused.store(true, Ordering::SeqCst);
+            manager.handle_event(global_hotkey::HotKeyState::Released);
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
         #[test]
         fn test_on_press_from_recording_ignored() {
             let mut mock_audio = MockAudioCapture::new();
@@ -845,6 +2982,77 @@ mod tests {
             assert_eq!(manager.get_state(), AppState::Processing);
         }
 
+        #[test]
+        fn test_press_during_processing_queued_starts_after() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+            let manager = TestHotkeyManager::new(mock_audio, None).with_queue_press();
+            *manager.state.lock().unwrap() = AppState::Processing;
+
+            manager.on_press();
+            assert_eq!(manager.get_state(), AppState::Processing);
+
+            manager.finish_processing();
+            assert_eq!(manager.get_state(), AppState::Recording);
+            assert!(!manager.pending_start.load(Ordering::SeqCst));
+        }
+
+        #[test]
+        fn test_press_during_processing_without_queue_goes_idle() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_start_recording().never();
+            let manager = TestHotkeyManager::new(mock_audio, None);
+            *manager.state.lock().unwrap() = AppState::Processing;
+
+            manager.on_press();
+            manager.finish_processing();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_queued_press_released_before_finish_cancelled() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_start_recording().never();
+            let manager = TestHotkeyManager::new(mock_audio, None).with_queue_press();
+            *manager.state.lock().unwrap() = AppState::Loading;
+
+            manager.on_press();
+            manager.on_release();
+            manager.finish_processing();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_queued_start_waits_for_idle() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_start_recording().never();
+            let manager = TestHotkeyManager::new(mock_audio, None).with_queue_press();
+            *manager.state.lock().unwrap() = AppState::Processing;
+
+            manager.on_press();
+            manager.start_queued_recording(); // Still processing: keep waiting
+            assert_eq!(manager.get_state(), AppState::Processing);
+            assert!(manager.pending_start.load(Ordering::SeqCst));
+        }
+
+        #[test]
+        fn test_queued_start_failure_recovers_to_idle() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Err(anyhow!("device busy")));
+            let manager = TestHotkeyManager::new(mock_audio, None).with_queue_press();
+            *manager.state.lock().unwrap() = AppState::Processing;
+
+            manager.on_press();
+            manager.finish_processing();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
         #[test]
         fn test_on_release_from_recording_stops_and_transcribes() {
             let mut mock_audio = MockAudioCapture::new();
@@ -870,6 +3078,117 @@ mod tests {
             assert_eq!(manager.get_state(), AppState::Idle);
         }
 
+        #[test]
+        fn test_cancel_while_recording_skips_transcription() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(1)
+                .returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Ok(vec![0.1, 0.2, 0.3]));
+            let mut mock_transcription = MockTranscriptionEngine::new();
+            mock_transcription.expect_transcribe().never();
+
+            let manager = TestHotkeyManager::new(mock_audio, Some(mock_transcription));
+            manager.on_press();
+            assert!(manager.cancel());
+            assert_eq!(manager.get_state(), AppState::Idle);
+
+            // Hotkey let go after cancelling: nothing left to process
+            manager.on_release();
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_cancel_toggle_recording_then_press_starts_fresh() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio
+                .expect_start_recording()
+                .times(2)
+                .returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Ok(vec![0.1, 0.2, 0.3]));
+            let mut mock_transcription = MockTranscriptionEngine::new();
+            mock_transcription.expect_transcribe().never();
+
+            let manager =
+                TestHotkeyManager::new(mock_audio, Some(mock_transcription)).with_toggle_mode();
+            manager.on_press();
+            assert!(manager.cancel());
+            assert_eq!(manager.get_state(), AppState::Idle);
+
+            // The next press starts a new recording instead of stopping the cancelled one
+            manager.on_press();
+            assert_eq!(manager.get_state(), AppState::Recording);
+        }
+
+        #[test]
+        fn test_cancel_while_idle_is_noop() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_stop_recording().never();
+            let manager = TestHotkeyManager::new(mock_audio, None);
+
+            assert!(!manager.cancel());
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_cancel_while_processing_is_noop() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_stop_recording().never();
+            let manager = TestHotkeyManager::new(mock_audio, None);
+            *manager.state.lock().unwrap() = AppState::Processing;
+
+            assert!(!manager.cancel());
+            assert_eq!(manager.get_state(), AppState::Processing);
+        }
+
+        #[test]
+        fn test_release_with_discard_modifier_skips_transcription() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_start_recording().returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Ok(vec![0.1, 0.2, 0.3]));
+            let mut mock_transcription = MockTranscriptionEngine::new();
+            mock_transcription.expect_transcribe().never();
+
+            let manager = TestHotkeyManager::new(mock_audio, Some(mock_transcription))
+                .with_discard_gesture(Modifier::Shift, vec![Modifier::Command, Modifier::Shift]);
+            manager.on_press();
+            manager.on_release();
+
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
+        #[test]
+        fn test_release_without_discard_modifier_commits() {
+            let mut mock_audio = MockAudioCapture::new();
+            mock_audio.expect_start_recording().returning(|| Ok(()));
+            mock_audio
+                .expect_stop_recording()
+                .times(1)
+                .returning(|| Ok(vec![0.1, 0.2, 0.3]));
+            let mut mock_transcription = MockTranscriptionEngine::new();
+            mock_transcription
+                .expect_transcribe()
+                .times(1)
+                .returning(|_| Ok("keep this".to_owned()));
+
+            let manager = TestHotkeyManager::new(mock_audio, Some(mock_transcription))
+                .with_discard_gesture(Modifier::Shift, vec![Modifier::Command]);
+            manager.on_press();
+            manager.on_release();
+
+            assert_eq!(manager.get_state(), AppState::Idle);
+        }
+
         #[test]
         fn test_on_release_from_idle_ignored() {
             let mock_audio = MockAudioCapture::new();
@@ -1,4 +1,14 @@
 /// CGEvent-based text insertion
 pub mod cgevent;
+/// Suppression of immediately repeated insertions
+pub mod dedupe;
+/// Commit/discard gesture at hotkey release
+pub mod gesture;
 /// Global hotkey management
 pub mod hotkey;
+/// Insertion pacing (minimum gap between insertions)
+pub mod pacing;
+/// Numbered profile list opened by the quick-pick hotkey
+pub mod quick_pick;
+/// Ordered insertion methods with verification and fallback
+pub mod strategy;
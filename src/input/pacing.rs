@@ -0,0 +1,245 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Time source for insertion pacing (injectable for tests)
+pub trait Clock: Send + Sync {
+    /// Current instant
+    fn now(&self) -> Instant;
+    /// Block the current thread for `duration`
+    fn sleep(&self, duration: Duration);
+}
+
+/// Real wall clock
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// Serializes text insertions and enforces a minimum gap between them
+///
+/// Shared by all profiles so two transcriptions finishing close together
+/// can't interleave keystrokes into the same target. The gap is measured
+/// from the end of one insertion to the start of the next.
+pub struct InsertionPacer<C: Clock = SystemClock> {
+    min_gap: Duration,
+    clock: C,
+    /// Completion time of the previous insertion; lock held for the whole insertion
+    last_insertion: Mutex<Option<Instant>>,
+}
+
+impl InsertionPacer<SystemClock> {
+    /// Create pacer using the system clock
+    #[must_use]
+    pub const fn new(min_gap: Duration) -> Self {
+        Self::with_clock(min_gap, SystemClock)
+    }
+}
+
+impl<C: Clock> InsertionPacer<C> {
+    /// Create pacer with a custom clock
+    #[must_use]
+    pub const fn with_clock(min_gap: Duration, clock: C) -> Self {
+        Self {
+            min_gap,
+            clock,
+            last_insertion: Mutex::new(None),
+        }
+    }
+
+    /// Delay required before the next insertion may start (pure)
+    #[must_use]
+    pub fn delay_before_next(
+        min_gap: Duration,
+        last_insertion: Option<Instant>,
+        now: Instant,
+    ) -> Duration {
+        last_insertion.map_or(Duration::ZERO, |last| {
+            min_gap.saturating_sub(now.saturating_duration_since(last))
+        })
+    }
+
+    /// Run an insertion, waiting out the minimum gap since the previous one
+    ///
+    /// Concurrent callers are serialized; each insertion completes before the next begins.
+    pub fn run<T>(&self, insert: impl FnOnce() -> T) -> T {
+        let mut last = self
+            .last_insertion
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let delay = Self::delay_before_next(self.min_gap, *last, self.clock.now());
+        if !delay.is_zero() {
+            tracing::debug!(delay_ms = delay.as_millis(), "pacing insertion");
+            self.clock.sleep(delay);
+        }
+
+        let result = insert();
+        *last = Some(self.clock.now());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Fake clock: `sleep` advances time instantly and records the requested delay
+    struct FakeClock {
+        now: Mutex<Instant>,
+        sleeps: Mutex<Vec<Duration>>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self {
+                now: Mutex::new(Instant::now()),
+                sleeps: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn advance(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+
+        fn sleeps(&self) -> Vec<Duration> {
+            self.sleeps.lock().unwrap().clone()
+        }
+    }
+
+    impl Clock for Arc<FakeClock> {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.sleeps.lock().unwrap().push(duration);
+            self.advance(duration);
+        }
+    }
+
+    const GAP: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn test_delay_before_next_first_insertion() {
+        let now = Instant::now();
+        assert_eq!(
+            InsertionPacer::<SystemClock>::delay_before_next(GAP, None, now),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_delay_before_next_partial_gap() {
+        let last = Instant::now();
+        let now = last + Duration::from_millis(30);
+        assert_eq!(
+            InsertionPacer::<SystemClock>::delay_before_next(GAP, Some(last), now),
+            Duration::from_millis(70)
+        );
+    }
+
+    #[test]
+    fn test_delay_before_next_gap_elapsed() {
+        let last = Instant::now();
+        let now = last + Duration::from_millis(250);
+        assert_eq!(
+            InsertionPacer::<SystemClock>::delay_before_next(GAP, Some(last), now),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_burst_is_spaced_by_min_gap() {
+        let clock = Arc::new(FakeClock::new());
+        let pacer = InsertionPacer::with_clock(GAP, Arc::clone(&clock));
+        let mut starts = Vec::new();
+
+        for _ in 0..4 {
+            pacer.run(|| {
+                starts.push(clock.now());
+                // Each insertion takes 10ms
+                clock.advance(Duration::from_millis(10));
+            });
+        }
+
+        // First insertion runs immediately, later ones wait the full gap
+        assert_eq!(clock.sleeps(), vec![GAP, GAP, GAP]);
+        for pair in starts.windows(2) {
+            assert_eq!(pair[1] - pair[0], GAP + Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_spaced_insertions_not_delayed() {
+        let clock = Arc::new(FakeClock::new());
+        let pacer = InsertionPacer::with_clock(GAP, Arc::clone(&clock));
+
+        pacer.run(|| ());
+        clock.advance(Duration::from_millis(40));
+        pacer.run(|| ());
+        clock.advance(Duration::from_millis(500));
+        pacer.run(|| ());
+
+        assert_eq!(clock.sleeps(), vec![Duration::from_millis(60)]);
+    }
+
+    #[test]
+    fn test_zero_gap_never_sleeps() {
+        let clock = Arc::new(FakeClock::new());
+        let pacer = InsertionPacer::with_clock(Duration::ZERO, Arc::clone(&clock));
+
+        for _ in 0..3 {
+            pacer.run(|| ());
+        }
+
+        assert!(clock.sleeps().is_empty());
+    }
+
+    #[test]
+    fn test_concurrent_burst_serialized_and_spaced() {
+        let clock = Arc::new(FakeClock::new());
+        let pacer = Arc::new(InsertionPacer::with_clock(GAP, Arc::clone(&clock)));
+        let spans = Arc::new(Mutex::new(Vec::new()));
+
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let pacer = Arc::clone(&pacer);
+                let clock = Arc::clone(&clock);
+                let spans = Arc::clone(&spans);
+                std::thread::spawn(move || {
+                    pacer.run(|| {
+                        let start = clock.now();
+                        clock.advance(Duration::from_millis(5));
+                        spans.lock().unwrap().push((start, clock.now()));
+                    });
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut spans = spans.lock().unwrap().clone();
+        spans.sort();
+        assert_eq!(spans.len(), 5);
+        // Each insertion starts at least GAP after the previous one finished
+        for pair in spans.windows(2) {
+            assert!(pair[1].0 >= pair[0].1 + GAP);
+        }
+    }
+
+    #[test]
+    fn test_run_returns_insertion_result() {
+        let pacer = InsertionPacer::new(Duration::ZERO);
+        assert!(pacer.run(|| true));
+    }
+}
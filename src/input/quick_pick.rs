@@ -0,0 +1,176 @@
+use std::time::{Duration, Instant};
+
+/// Digit keys 1-9: profiles beyond the ninth can't be picked
+pub const MAX_CHOICES: usize = 9;
+
+/// Whether the quick-pick list is waiting for a digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickPickState {
+    /// No list shown
+    Closed,
+    /// List shown, waiting for a digit until `deadline`
+    Open {
+        /// When the list closes without a selection
+        deadline: Instant,
+    },
+}
+
+/// Result of pressing a digit key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    /// Digit chose the profile at this index; the list closes
+    Profile(usize),
+    /// No profile has this number; the list stays open for another digit
+    Invalid,
+    /// Timeout passed before the digit; the list closes without a selection
+    Expired,
+    /// No list open
+    NotOpen,
+}
+
+/// Quick-pick state machine: open -> await digit -> select or time out
+pub struct QuickPick {
+    /// Profiles offered (at most [`MAX_CHOICES`])
+    choices: usize,
+    timeout: Duration,
+    state: QuickPickState,
+}
+
+impl QuickPick {
+    /// Create closed quick-pick over `profile_count` profiles
+    #[must_use]
+    pub fn new(profile_count: usize, timeout: Duration) -> Self {
+        Self {
+            choices: profile_count.min(MAX_CHOICES),
+            timeout,
+            state: QuickPickState::Closed,
+        }
+    }
+
+    /// Show the list, restarting the timeout if it is already open
+    pub fn open(&mut self, now: Instant) {
+        self.state = QuickPickState::Open {
+            deadline: now + self.timeout,
+        };
+    }
+
+    /// Apply a press of digit key `digit` (1 = first profile)
+    pub fn select(&mut self, digit: u8, now: Instant) -> Selection {
+        let QuickPickState::Open { deadline } = self.state else {
+            return Selection::NotOpen;
+        };
+        if now >= deadline {
+            self.state = QuickPickState::Closed;
+            return Selection::Expired;
+        }
+        match usize::from(digit) {
+            n @ 1.. if n <= self.choices => {
+                self.state = QuickPickState::Closed;
+                Selection::Profile(n - 1)
+            }
+            _ => Selection::Invalid,
+        }
+    }
+
+    /// Close the list once its timeout passed; true if it just expired
+    pub fn expire(&mut self, now: Instant) -> bool {
+        match self.state {
+            QuickPickState::Open { deadline } if now >= deadline => {
+                self.state = QuickPickState::Closed;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Numbered list of the pickable profiles, e.g. `1 base.en · 2 medical`
+#[must_use]
+pub fn menu_text(names: &[&str]) -> String {
+    names
+        .iter()
+        .take(MAX_CHOICES)
+        .enumerate()
+        .map(|(i, name)| format!("{} {name}", i + 1))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(3);
+
+    fn open_pick(profiles: usize) -> (QuickPick, Instant) {
+        let mut pick = QuickPick::new(profiles, TIMEOUT);
+        let t0 = Instant::now();
+        pick.open(t0);
+        (pick, t0)
+    }
+
+    #[test]
+    fn test_select_profile_closes_list() {
+        let (mut pick, t0) = open_pick(3);
+        assert_eq!(
+            pick.select(2, t0 + Duration::from_millis(500)),
+            Selection::Profile(1)
+        );
+        assert_eq!(pick.state, QuickPickState::Closed);
+        // Next digit goes nowhere until the list opens again
+        assert_eq!(pick.select(1, t0), Selection::NotOpen);
+    }
+
+    #[test]
+    fn test_invalid_digit_keeps_list_open() {
+        let (mut pick, t0) = open_pick(2);
+        assert_eq!(pick.select(3, t0), Selection::Invalid);
+        assert_eq!(pick.select(0, t0), Selection::Invalid);
+        assert!(matches!(pick.state, QuickPickState::Open { .. }));
+        assert_eq!(pick.select(1, t0), Selection::Profile(0));
+    }
+
+    #[test]
+    fn test_timeout_expires_list() {
+        let (mut pick, t0) = open_pick(3);
+        assert!(!pick.expire(t0 + Duration::from_secs(1)));
+        assert!(pick.expire(t0 + TIMEOUT));
+        assert_eq!(pick.state, QuickPickState::Closed);
+        // Already closed: nothing more to expire
+        assert!(!pick.expire(t0 + TIMEOUT * 2));
+    }
+
+    #[test]
+    fn test_digit_after_timeout_is_expired() {
+        let (mut pick, t0) = open_pick(3);
+        assert_eq!(pick.select(1, t0 + TIMEOUT), Selection::Expired);
+        assert_eq!(pick.state, QuickPickState::Closed);
+    }
+
+    #[test]
+    fn test_reopen_restarts_timeout() {
+        let (mut pick, t0) = open_pick(3);
+        pick.open(t0 + Duration::from_secs(2));
+        assert_eq!(
+            pick.select(1, t0 + Duration::from_secs(4)),
+            Selection::Profile(0)
+        );
+    }
+
+    #[test]
+    fn test_only_nine_profiles_pickable() {
+        let (mut pick, t0) = open_pick(12);
+        assert_eq!(pick.select(9, t0), Selection::Profile(8));
+        pick.open(t0);
+        assert_eq!(pick.select(10, t0), Selection::Invalid);
+    }
+
+    #[test]
+    fn test_menu_text() {
+        assert_eq!(
+            menu_text(&["base.en", "medical", "small"]),
+            "1 base.en · 2 medical · 3 small"
+        );
+        assert_eq!(menu_text(&[]), "");
+    }
+}
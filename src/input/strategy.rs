@@ -0,0 +1,853 @@
+use crate::config::InsertionConfig;
+use crate::input::cgevent::{self, Chunking, KeyStroke, TextInsertionError};
+use core_graphics::event::KeyCode;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+use tracing::{debug, error, info, warn};
+
+/// Time given to the target app to process insertion before reading the field back
+const VERIFY_SETTLE: Duration = Duration::from_millis(50);
+
+/// Time given to the target app to read the pasted text before the clipboard is restored
+const PASTE_SETTLE: Duration = Duration::from_millis(200);
+
+/// How text is delivered to the focused app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InsertionMethod {
+    /// Synthesized unicode keyboard event (`CGEvent`)
+    Keystroke,
+    /// Copy to clipboard, then press Cmd+V (overwrites clipboard contents)
+    Clipboard,
+}
+
+/// Result of checking whether inserted text reached the focused field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// Focused field contains the inserted text
+    Confirmed,
+    /// Focused field is unchanged since before the insertion
+    NotConfirmed,
+    /// Focused field can't be read (no AX support, secure field) or changed
+    /// without containing the exact text (app rewrote it) - assume success
+    Unavailable,
+}
+
+/// One way of inserting text (mockable for tests)
+#[cfg_attr(test, mockall::automock)]
+pub trait InsertionBackend: Send + Sync {
+    /// Method this backend implements
+    fn method(&self) -> InsertionMethod;
+
+    /// Deliver `text` to the focused app
+    ///
+    /// # Errors
+    /// Returns error if the text could not be sent
+    fn insert(&self, text: &str) -> Result<(), TextInsertionError>;
+
+    /// Focused field contents before inserting (None = unreadable)
+    fn field_value(&self) -> Option<String>;
+
+    /// Check whether `text` reached the focused field, given its contents `before` insertion
+    fn verify(&self, before: Option<String>, text: &str) -> Verification;
+}
+
+/// Failed attempt of a single method in the chain
+#[derive(Debug)]
+pub struct MethodFailure {
+    /// Method that failed
+    pub method: InsertionMethod,
+    /// Why it failed
+    pub error: TextInsertionError,
+}
+
+/// Insertion chain errors
+#[derive(Debug, Error)]
+pub enum InsertionChainError {
+    /// Chain has no backends
+    #[error("no insertion methods configured")]
+    NoMethods,
+
+    /// Every backend failed or couldn't be verified
+    #[error("all insertion methods failed ({} attempted)", .0.len())]
+    AllFailed(Vec<MethodFailure>),
+}
+
+/// Ordered insertion methods with optional verification and fallback
+///
+/// Tries each backend in order. A backend succeeds when it sends the text
+/// and (if verification is enabled) the read-back doesn't contradict it;
+/// otherwise the next backend is tried.
+pub struct InsertionChain {
+    backends: Vec<Box<dyn InsertionBackend>>,
+    verify: bool,
+    /// Marker where the cursor is left after insertion (None = end of text)
+    cursor_placeholder: Option<String>,
+    /// Append a space after inserted text
+    append_space: bool,
+    /// Append a newline after inserted text
+    append_newline: bool,
+}
+
+impl InsertionChain {
+    /// Create chain from backends in priority order
+    #[must_use]
+    pub fn new(backends: Vec<Box<dyn InsertionBackend>>, verify: bool) -> Self {
+        Self {
+            backends,
+            verify,
+            cursor_placeholder: None,
+            append_space: false,
+            append_newline: false,
+        }
+    }
+
+    /// Leave the cursor where `placeholder` appears in inserted text (removing it)
+    #[must_use]
+    pub fn with_cursor_placeholder(mut self, placeholder: Option<String>) -> Self {
+        self.cursor_placeholder = placeholder;
+        self
+    }
+
+    /// Append a space and/or a newline after each non-empty insertion
+    #[must_use]
+    pub const fn with_trailing(mut self, space: bool, newline: bool) -> Self {
+        self.append_space = space;
+        self.append_newline = newline;
+        self
+    }
+
+    /// Create chain of real backends for `methods` (duplicates ignored)
+    ///
+    /// `prime_insertion` makes keystroke insertion post a no-op event first and
+    /// `chunking` splits its text into several events;
+    /// `restore_clipboard` makes clipboard insertion put the previous text back.
+    #[must_use]
+    pub fn from_methods(
+        methods: &[InsertionMethod],
+        verify: bool,
+        prime_insertion: bool,
+        chunking: Chunking,
+        restore_clipboard: bool,
+    ) -> Self {
+        let mut seen = Vec::with_capacity(methods.len());
+        let backends = methods
+            .iter()
+            .filter(|method| {
+                if seen.contains(*method) {
+                    return false;
+                }
+                seen.push(**method);
+                true
+            })
+            .map(|method| -> Box<dyn InsertionBackend> {
+                match method {
+                    InsertionMethod::Keystroke => Box::new(KeystrokeBackend {
+                        prime_insertion,
+                        chunking,
+                    }),
+                    InsertionMethod::Clipboard => Box::new(ClipboardBackend { restore_clipboard }),
+                }
+            })
+            .collect();
+        Self::new(backends, verify)
+    }
+
+    /// Create chain from `[insertion]` config
+    #[must_use]
+    pub fn from_config(config: &InsertionConfig) -> Self {
+        Self::from_methods(
+            &config.methods,
+            config.verify,
+            config.prime_insertion,
+            Chunking {
+                size: config.chunk_size,
+                delay: Duration::from_millis(config.chunk_delay_ms),
+            },
+            config.restore_clipboard,
+        )
+        .with_cursor_placeholder(config.cursor_placeholder.clone())
+        .with_trailing(config.append_space, config.append_newline)
+    }
+
+    /// Insert `text`, falling back through the chain; returns the method that worked
+    ///
+    /// # Errors
+    /// Returns error if the chain is empty or every method failed
+    pub fn insert(&self, text: &str) -> Result<InsertionMethod, InsertionChainError> {
+        if self.backends.is_empty() {
+            return Err(InsertionChainError::NoMethods);
+        }
+
+        let mut failures = Vec::new();
+        for backend in &self.backends {
+            let method = backend.method();
+            // Snapshot to tell "nothing arrived" from "the app rewrote the text"
+            let before = if self.verify {
+                backend.field_value()
+            } else {
+                None
+            };
+            if let Err(error) = backend.insert(text) {
+                warn!(?method, error = %error, "insertion method failed, trying next");
+                failures.push(MethodFailure { method, error });
+                continue;
+            }
+
+            if !self.verify {
+                return Ok(method);
+            }
+
+            match backend.verify(before, text) {
+                Verification::Confirmed => {
+                    debug!(?method, "insertion verified");
+                    return Ok(method);
+                }
+                Verification::Unavailable => {
+                    debug!(?method, "insertion can't be verified, assuming success");
+                    return Ok(method);
+                }
+                Verification::NotConfirmed => {
+                    warn!(
+                        ?method,
+                        "focused field unchanged after insertion, trying next"
+                    );
+                    failures.push(MethodFailure {
+                        method,
+                        error: TextInsertionError::NotVerified,
+                    });
+                }
+            }
+        }
+
+        Err(InsertionChainError::AllFailed(failures))
+    }
+
+    /// Insert `text`, logging errors without panicking
+    ///
+    /// Configured trailing space/newline is appended first. With a cursor
+    /// placeholder configured, the placeholder is removed and the cursor moved
+    /// back to it once the text is in.
+    pub fn insert_safe(&self, text: &str) -> bool {
+        let text = cgevent::with_suffix(text, self.append_space, self.append_newline);
+        let placement = cgevent::place_cursor(&text, self.cursor_placeholder.as_deref());
+        let text = placement.text.as_str();
+        match self.insert(text) {
+            Ok(method) => {
+                info!(?method, text_len = text.len(), "✓ text inserted");
+                // Text is already in; a failed move only leaves the cursor at the end
+                if let Err(e) = cgevent::move_cursor_left(placement.left_moves) {
+                    warn!(error = %e, "failed to move cursor to placeholder");
+                }
+                true
+            }
+            Err(e) => {
+                if let InsertionChainError::AllFailed(failures) = &e {
+                    for failure in failures {
+                        error!(method = ?failure.method, error = %failure.error, "insertion attempt failed");
+                    }
+                }
+                error!(error = %e, text_len = text.len(), "text insertion failed");
+                false
+            }
+        }
+    }
+}
+
+impl Default for InsertionChain {
+    /// Keystroke insertion only, unverified (original behavior)
+    fn default() -> Self {
+        Self::from_methods(
+            &[InsertionMethod::Keystroke],
+            false,
+            false,
+            Chunking::default(),
+            false,
+        )
+    }
+}
+
+/// `CGEvent` unicode keystroke insertion
+struct KeystrokeBackend {
+    /// Post a no-op event before the text (first-character drop workaround)
+    prime_insertion: bool,
+    /// Split long text into several keyboard events
+    chunking: Chunking,
+}
+
+impl InsertionBackend for KeystrokeBackend {
+    fn method(&self) -> InsertionMethod {
+        InsertionMethod::Keystroke
+    }
+
+    fn insert(&self, text: &str) -> Result<(), TextInsertionError> {
+        if let Some(priming) = cgevent::priming_for(self.prime_insertion, text) {
+            // Best effort: the insertion itself reports real failures
+            if let Err(e) = cgevent::prime(&priming) {
+                debug!(error = %e, "priming failed, inserting anyway");
+            }
+        }
+        cgevent::insert_text_chunked(text, &self.chunking)
+    }
+
+    fn field_value(&self) -> Option<String> {
+        focused_field_value()
+    }
+
+    fn verify(&self, before: Option<String>, text: &str) -> Verification {
+        verify_focused_field(before.as_deref(), text)
+    }
+}
+
+/// Clipboard + Cmd+V insertion
+struct ClipboardBackend {
+    /// Put the previous clipboard text back after pasting
+    restore_clipboard: bool,
+}
+
+/// Key events for Cmd+V
+const fn paste_sequence() -> [KeyStroke; 2] {
+    [
+        KeyStroke {
+            keycode: KeyCode::ANSI_V,
+            key_down: true,
+            command: true,
+        },
+        KeyStroke {
+            keycode: KeyCode::ANSI_V,
+            key_down: false,
+            command: true,
+        },
+    ]
+}
+
+impl InsertionBackend for ClipboardBackend {
+    fn method(&self) -> InsertionMethod {
+        InsertionMethod::Clipboard
+    }
+
+    fn insert(&self, text: &str) -> Result<(), TextInsertionError> {
+        if text.is_empty() {
+            return Err(TextInsertionError::EmptyText);
+        }
+        let paste = || cgevent::post_key_sequence(&paste_sequence());
+        if self.restore_clipboard {
+            return paste_preserving_clipboard(&SystemPasteboard, text, paste, PASTE_SETTLE);
+        }
+        SystemPasteboard
+            .write(text)
+            .map_err(|e| TextInsertionError::Clipboard(format!("{e:#}")))?;
+        paste()
+    }
+
+    fn field_value(&self) -> Option<String> {
+        focused_field_value()
+    }
+
+    fn verify(&self, before: Option<String>, text: &str) -> Verification {
+        verify_focused_field(before.as_deref(), text)
+    }
+}
+
+/// Text clipboard (mockable for tests)
+#[cfg_attr(test, mockall::automock)]
+trait Pasteboard {
+    /// Current text contents
+    fn read(&self) -> anyhow::Result<String>;
+
+    /// Replace the contents with `text`
+    fn write(&self, text: &str) -> anyhow::Result<()>;
+}
+
+/// macOS general pasteboard (`pbpaste`/`pbcopy`)
+struct SystemPasteboard;
+
+impl Pasteboard for SystemPasteboard {
+    fn read(&self) -> anyhow::Result<String> {
+        crate::transcription::file::read_clipboard()
+    }
+
+    fn write(&self, text: &str) -> anyhow::Result<()> {
+        crate::transcription::file::copy_to_clipboard(text)
+    }
+}
+
+/// Paste `text` through the clipboard, then put the previous text back
+///
+/// The previous contents are restored after `settle` (the target app reads the
+/// clipboard asynchronously), even if the paste failed. A clipboard without text,
+/// or one that can't be read, is left holding `text`.
+fn paste_preserving_clipboard(
+    pasteboard: &impl Pasteboard,
+    text: &str,
+    paste: impl FnOnce() -> Result<(), TextInsertionError>,
+    settle: Duration,
+) -> Result<(), TextInsertionError> {
+    let saved = match pasteboard.read() {
+        Ok(saved) => Some(saved).filter(|saved| !saved.is_empty()),
+        Err(e) => {
+            warn!(error = %e, "failed to read clipboard, it won't be restored");
+            None
+        }
+    };
+    pasteboard
+        .write(text)
+        .map_err(|e| TextInsertionError::Clipboard(format!("{e:#}")))?;
+    let result = paste();
+
+    if let Some(saved) = saved {
+        std::thread::sleep(settle);
+        match pasteboard.write(&saved) {
+            Ok(()) => debug!("clipboard restored"),
+            Err(e) => warn!(error = %e, "failed to restore clipboard"),
+        }
+    }
+    result
+}
+
+/// Compare focused field contents before and after insertion against inserted text (pure)
+///
+/// Only an unchanged field counts as a failed insertion: apps that rewrite
+/// input (smart quotes, autocapitalization, rich editors) change the field
+/// without containing the exact text, and falling back would insert it twice.
+#[must_use]
+pub fn verification_for(before: Option<&str>, after: Option<&str>, text: &str) -> Verification {
+    match (before, after) {
+        (_, Some(after)) if after.contains(text) => Verification::Confirmed,
+        (Some(before), Some(after)) if before == after => Verification::NotConfirmed,
+        _ => Verification::Unavailable,
+    }
+}
+
+fn verify_focused_field(before: Option<&str>, text: &str) -> Verification {
+    std::thread::sleep(VERIFY_SETTLE);
+    verification_for(before, focused_field_value().as_deref(), text)
+}
+
+/// Read the focused UI element's value via the Accessibility API
+#[cfg(target_os = "macos")]
+fn focused_field_value() -> Option<String> {
+    use core_foundation::base::{CFType, CFTypeRef, TCFType};
+    use core_foundation::string::{CFString, CFStringRef};
+
+    // SAFETY: FFI declarations for Accessibility API (stable since macOS 10.2)
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXUIElementCreateSystemWide() -> CFTypeRef;
+        fn AXUIElementCopyAttributeValue(
+            element: CFTypeRef,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> i32;
+    }
+
+    /// Copy `attribute` of `element`; None on AX error or missing value
+    fn copy_attribute(element: &CFType, attribute: &'static str) -> Option<CFType> {
+        let attribute = CFString::from_static_string(attribute);
+        let mut value: CFTypeRef = std::ptr::null();
+        // SAFETY: element and attribute are valid CF objects; on success value is
+        // a +1 retained CFTypeRef which we take ownership of via the create rule
+        #[allow(unsafe_code)]
+        unsafe {
+            let status = AXUIElementCopyAttributeValue(
+                element.as_CFTypeRef(),
+                attribute.as_concrete_TypeRef(),
+                &mut value,
+            );
+            (status == 0 && !value.is_null()).then(|| CFType::wrap_under_create_rule(value))
+        }
+    }
+
+    // SAFETY: AXUIElementCreateSystemWide returns a new +1 retained element
+    #[allow(unsafe_code)]
+    let system = unsafe {
+        let element = AXUIElementCreateSystemWide();
+        if element.is_null() {
+            return None;
+        }
+        CFType::wrap_under_create_rule(element)
+    };
+
+    let focused = copy_attribute(&system, "AXFocusedUIElement")?;
+    let value = copy_attribute(&focused, "AXValue")?;
+    value.downcast::<CFString>().map(|s| s.to_string())
+}
+
+#[cfg(not(target_os = "macos"))]
+const fn focused_field_value() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::Sequence;
+
+    fn backend(
+        method: InsertionMethod,
+        insert_ok: bool,
+        verification: Verification,
+    ) -> Box<dyn InsertionBackend> {
+        let mut mock = MockInsertionBackend::new();
+        mock.expect_method().return_const(method);
+        mock.expect_insert().times(1).returning(move |_| {
+            if insert_ok {
+                Ok(())
+            } else {
+                Err(TextInsertionError::EventCreation)
+            }
+        });
+        mock.expect_field_value().return_const(None::<String>);
+        mock.expect_verify().return_const(verification);
+        Box::new(mock)
+    }
+
+    fn unused_backend(method: InsertionMethod) -> Box<dyn InsertionBackend> {
+        let mut mock = MockInsertionBackend::new();
+        mock.expect_method().return_const(method);
+        mock.expect_insert().never();
+        mock.expect_field_value().never();
+        mock.expect_verify().never();
+        Box::new(mock)
+    }
+
+    #[test]
+    fn test_chain_primary_succeeds() {
+        let chain = InsertionChain::new(
+            vec![
+                backend(InsertionMethod::Keystroke, true, Verification::Confirmed),
+                unused_backend(InsertionMethod::Clipboard),
+            ],
+            true,
+        );
+        assert_eq!(chain.insert("hello").unwrap(), InsertionMethod::Keystroke);
+    }
+
+    #[test]
+    fn test_chain_unverifiable_counts_as_success() {
+        let chain = InsertionChain::new(
+            vec![
+                backend(InsertionMethod::Keystroke, true, Verification::Unavailable),
+                unused_backend(InsertionMethod::Clipboard),
+            ],
+            true,
+        );
+        assert_eq!(chain.insert("hello").unwrap(), InsertionMethod::Keystroke);
+    }
+
+    #[test]
+    fn test_chain_falls_back_on_insert_error() {
+        let chain = InsertionChain::new(
+            vec![
+                backend(InsertionMethod::Keystroke, false, Verification::Confirmed),
+                backend(InsertionMethod::Clipboard, true, Verification::Confirmed),
+            ],
+            true,
+        );
+        assert_eq!(chain.insert("hello").unwrap(), InsertionMethod::Clipboard);
+    }
+
+    #[test]
+    fn test_chain_falls_back_on_failed_verification() {
+        let chain = InsertionChain::new(
+            vec![
+                backend(InsertionMethod::Keystroke, true, Verification::NotConfirmed),
+                backend(InsertionMethod::Clipboard, true, Verification::Confirmed),
+            ],
+            true,
+        );
+        assert_eq!(chain.insert("hello").unwrap(), InsertionMethod::Clipboard);
+    }
+
+    #[test]
+    fn test_chain_skips_verification_when_disabled() {
+        let mut primary = MockInsertionBackend::new();
+        primary
+            .expect_method()
+            .return_const(InsertionMethod::Keystroke);
+        primary.expect_insert().times(1).returning(|_| Ok(()));
+        primary.expect_field_value().never();
+        primary.expect_verify().never();
+
+        let chain = InsertionChain::new(
+            vec![
+                Box::new(primary),
+                unused_backend(InsertionMethod::Clipboard),
+            ],
+            false,
+        );
+        assert_eq!(chain.insert("hello").unwrap(), InsertionMethod::Keystroke);
+    }
+
+    #[test]
+    fn test_chain_all_methods_fail() {
+        let chain = InsertionChain::new(
+            vec![
+                backend(InsertionMethod::Keystroke, false, Verification::Confirmed),
+                backend(InsertionMethod::Clipboard, true, Verification::NotConfirmed),
+            ],
+            true,
+        );
+
+        let result = chain.insert("hello");
+        assert!(matches!(&result, Err(InsertionChainError::AllFailed(f)) if f.len() == 2));
+        if let Err(InsertionChainError::AllFailed(failures)) = result {
+            assert_eq!(failures[0].method, InsertionMethod::Keystroke);
+            assert!(matches!(
+                failures[0].error,
+                TextInsertionError::EventCreation
+            ));
+            assert_eq!(failures[1].method, InsertionMethod::Clipboard);
+            assert!(matches!(failures[1].error, TextInsertionError::NotVerified));
+        }
+    }
+
+    #[test]
+    fn test_chain_empty() {
+        let chain = InsertionChain::new(Vec::new(), true);
+        assert!(matches!(
+            chain.insert("hello"),
+            Err(InsertionChainError::NoMethods)
+        ));
+    }
+
+    #[test]
+    fn test_from_methods_dedupes_and_keeps_order() {
+        let chain = InsertionChain::from_methods(
+            &[
+                InsertionMethod::Clipboard,
+                InsertionMethod::Keystroke,
+                InsertionMethod::Clipboard,
+            ],
+            false,
+            false,
+            Chunking::default(),
+            true,
+        );
+        let methods: Vec<_> = chain.backends.iter().map(|b| b.method()).collect();
+        assert_eq!(
+            methods,
+            vec![InsertionMethod::Clipboard, InsertionMethod::Keystroke]
+        );
+    }
+
+    #[test]
+    fn test_insert_safe_strips_cursor_placeholder() {
+        let mut mock = MockInsertionBackend::new();
+        mock.expect_method()
+            .return_const(InsertionMethod::Keystroke);
+        mock.expect_insert()
+            .withf(|text| text == "hello world")
+            .times(1)
+            .returning(|_| Ok(()));
+        let chain = InsertionChain::new(vec![Box::new(mock)], false)
+            .with_cursor_placeholder(Some("|".to_owned()));
+
+        // Placeholder at the end: stripped, no cursor movement needed
+        assert!(chain.insert_safe("hello world|"));
+    }
+
+    #[test]
+    fn test_insert_safe_appends_suffix() {
+        let mut mock = MockInsertionBackend::new();
+        mock.expect_method()
+            .return_const(InsertionMethod::Keystroke);
+        mock.expect_insert()
+            .withf(|text| text == "hello world \n")
+            .times(1)
+            .returning(|_| Ok(()));
+        let chain = InsertionChain::new(vec![Box::new(mock)], false).with_trailing(true, true);
+
+        assert!(chain.insert_safe("hello world"));
+    }
+
+    #[test]
+    fn test_paste_restores_previous_clipboard() {
+        let mut seq = Sequence::new();
+        let mut pasteboard = MockPasteboard::new();
+        pasteboard
+            .expect_read()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|| Ok("copied earlier".to_owned()));
+        pasteboard
+            .expect_write()
+            .withf(|text| text == "dictated")
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(()));
+        let pasted = std::cell::Cell::new(false);
+        pasteboard
+            .expect_write()
+            .withf(|text| text == "copied earlier")
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(()));
+
+        let result = paste_preserving_clipboard(
+            &pasteboard,
+            "dictated",
+            || {
+                pasted.set(true);
+                Ok(())
+            },
+            Duration::ZERO,
+        );
+        assert!(result.is_ok());
+        assert!(pasted.get());
+    }
+
+    #[test]
+    fn test_paste_failure_still_restores_clipboard() {
+        let mut pasteboard = MockPasteboard::new();
+        pasteboard
+            .expect_read()
+            .returning(|| Ok("copied earlier".to_owned()));
+        pasteboard.expect_write().times(2).returning(|_| Ok(()));
+
+        let result = paste_preserving_clipboard(
+            &pasteboard,
+            "dictated",
+            || Err(TextInsertionError::EventCreation),
+            Duration::ZERO,
+        );
+        assert!(matches!(result, Err(TextInsertionError::EventCreation)));
+    }
+
+    #[test]
+    fn test_paste_empty_or_unreadable_clipboard_not_restored() {
+        for previous in [Ok(String::new()), Err(anyhow::anyhow!("pbpaste missing"))] {
+            let mut pasteboard = MockPasteboard::new();
+            pasteboard.expect_read().return_once(move || previous);
+            pasteboard
+                .expect_write()
+                .withf(|text| text == "dictated")
+                .times(1)
+                .returning(|_| Ok(()));
+
+            let result =
+                paste_preserving_clipboard(&pasteboard, "dictated", || Ok(()), Duration::ZERO);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_paste_write_failure_skips_paste() {
+        let mut pasteboard = MockPasteboard::new();
+        pasteboard
+            .expect_read()
+            .returning(|| Ok("earlier".to_owned()));
+        pasteboard
+            .expect_write()
+            .times(1)
+            .returning(|_| Err(anyhow::anyhow!("pbcopy failed")));
+
+        let pasted = std::cell::Cell::new(false);
+        let result = paste_preserving_clipboard(
+            &pasteboard,
+            "dictated",
+            || {
+                pasted.set(true);
+                Ok(())
+            },
+            Duration::ZERO,
+        );
+        assert!(matches!(result, Err(TextInsertionError::Clipboard(_))));
+        // Pasting would insert whatever was on the clipboard before
+        assert!(!pasted.get());
+    }
+
+    #[test]
+    #[ignore = "requires the macOS pasteboard"]
+    fn test_system_pasteboard_round_trip() {
+        let previous = SystemPasteboard.read().unwrap();
+        SystemPasteboard
+            .write("whisper-hotkey clipboard test")
+            .unwrap();
+        assert_eq!(
+            SystemPasteboard.read().unwrap(),
+            "whisper-hotkey clipboard test"
+        );
+
+        let result =
+            paste_preserving_clipboard(&SystemPasteboard, "dictated", || Ok(()), Duration::ZERO);
+        assert!(result.is_ok());
+        assert_eq!(
+            SystemPasteboard.read().unwrap(),
+            "whisper-hotkey clipboard test"
+        );
+        SystemPasteboard.write(&previous).unwrap();
+    }
+
+    #[test]
+    fn test_verification_for() {
+        assert_eq!(
+            verification_for(Some("say "), Some("say hello world"), "hello"),
+            Verification::Confirmed
+        );
+        assert_eq!(
+            verification_for(Some("unchanged"), Some("unchanged"), "hello"),
+            Verification::NotConfirmed
+        );
+        assert_eq!(
+            verification_for(Some("x"), None, "hello"),
+            Verification::Unavailable
+        );
+    }
+
+    #[test]
+    fn test_verification_for_rewritten_text_is_not_a_failure() {
+        // Smart quotes / autocapitalization: text arrived, just not verbatim
+        assert_eq!(
+            verification_for(Some(""), Some("It\u{2019}s fine"), "it's fine"),
+            Verification::Unavailable
+        );
+        // Before unreadable: can't tell whether anything arrived
+        assert_eq!(
+            verification_for(None, Some("unchanged"), "hello"),
+            Verification::Unavailable
+        );
+    }
+
+    #[test]
+    fn test_chain_passes_snapshot_to_verify() {
+        let mut primary = MockInsertionBackend::new();
+        primary
+            .expect_method()
+            .return_const(InsertionMethod::Keystroke);
+        primary
+            .expect_field_value()
+            .times(1)
+            .return_const(Some("before".to_owned()));
+        primary.expect_insert().times(1).returning(|_| Ok(()));
+        primary
+            .expect_verify()
+            .withf(|before, text| before.as_deref() == Some("before") && text == "hello")
+            .times(1)
+            .return_const(Verification::Unavailable);
+
+        let chain = InsertionChain::new(
+            vec![
+                Box::new(primary),
+                unused_backend(InsertionMethod::Clipboard),
+            ],
+            true,
+        );
+        assert_eq!(chain.insert("hello").unwrap(), InsertionMethod::Keystroke);
+    }
+
+    #[test]
+    fn test_insertion_method_serde() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            methods: Vec<InsertionMethod>,
+        }
+        let w: Wrapper = toml::from_str(r#"methods = ["clipboard", "keystroke"]"#).unwrap();
+        assert_eq!(
+            w.methods,
+            vec![InsertionMethod::Clipboard, InsertionMethod::Keystroke]
+        );
+        assert!(toml::from_str::<Wrapper>(r#"methods = ["telepathy"]"#).is_err());
+    }
+}
@@ -6,15 +6,33 @@
 pub mod alias;
 /// Audio capture and processing
 pub mod audio;
+/// Command-line arguments and startup output
+pub mod cli;
 /// Configuration management
 pub mod config;
+/// Locale-aware number and date formatting
+pub mod formatting;
+/// Searchable transcription history
+pub mod history;
 /// Input handling (hotkeys, text insertion)
 pub mod input;
+/// User notifications (macOS)
+pub mod notify;
+/// Output formatting applied before text insertion
+pub mod output;
 /// macOS permission checks
 pub mod permissions;
 /// Recording cleanup and retention
 pub mod recording_cleanup;
+/// Graceful shutdown and relaunch
+pub mod restart;
+/// Per-profile usage statistics
+pub mod stats;
 /// Telemetry and crash logging
 pub mod telemetry;
 /// Whisper transcription engine
 pub mod transcription;
+/// Translation of transcriptions (native or external command)
+pub mod translation;
+/// Completion webhook
+pub mod webhook;
@@ -19,13 +19,22 @@
 
 mod alias;
 mod audio;
+mod cli;
 mod config;
+mod formatting;
+mod history;
 mod input;
+mod notify;
+mod output;
 mod permissions;
 mod recording_cleanup;
+mod restart;
+mod stats;
 mod telemetry;
 mod transcription;
+mod translation;
 mod tray;
+mod webhook;
 
 use anyhow::{Context, Result};
 use global_hotkey::GlobalHotKeyEvent;
@@ -36,26 +45,118 @@ use objc2_app_kit::{NSApp, NSApplicationActivationPolicy};
 #[cfg(target_os = "macos")]
 use objc2_foundation::MainThreadMarker;
 
+/// Activation policy for config: `Regular` shows a Dock icon, `Accessory` is menubar-only
+#[cfg(target_os = "macos")]
+const fn activation_policy(app: &config::AppConfig) -> NSApplicationActivationPolicy {
+    if app.show_dock_icon {
+        NSApplicationActivationPolicy::Regular
+    } else {
+        NSApplicationActivationPolicy::Accessory
+    }
+}
+
+/// How often the main loop checks that the input stream is still alive
+const STREAM_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Reopen the input stream if its device disappeared (e.g. USB mic unplugged)
+///
+/// Waits for a recording in progress to end; failures are retried on the next check.
+fn recover_audio_stream(audio: &Mutex<audio::AudioCapture>) {
+    let mut audio = audio
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if audio.is_stream_healthy() || audio.is_recording() {
+        return;
+    }
+    match audio.rebuild_stream() {
+        Ok(()) => tracing::info!("🎙️ Audio input reopened"),
+        Err(e) => tracing::warn!(error = %e, "failed to reopen audio input, will retry"),
+    }
+}
+
+/// Components torn down before the process is replaced by a restart
+struct AppRestart {
+    hotkeys: Option<input::hotkey::MultiHotkeyManager>,
+    tray: Option<tray::TrayManager>,
+}
+
+impl restart::RestartSteps for AppRestart {
+    fn unregister_hotkeys(&mut self) {
+        // Dropping unregisters every hotkey; exec would skip the destructors
+        drop(self.hotkeys.take());
+        drop(self.tray.take());
+    }
+
+    fn flush_state(&mut self) -> Result<()> {
+        use std::io::Write;
+        std::io::stdout()
+            .flush()
+            .context("failed to flush stdout")?;
+        std::io::stderr().flush().context("failed to flush stderr")
+    }
+
+    fn exec(&mut self, command: &restart::RelaunchCommand) -> anyhow::Error {
+        restart::exec(command)
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Phase 1: Foundation
+    let cli = cli::CliArgs::from_env()?;
+
+    // Load configuration (before NSApp setup: activation policy depends on it)
+    let mut config = config::Config::load().context("failed to load configuration")?;
+    // Command-line overrides (`--model`, `--hotkey`, ...) win over the file
+    cli::apply_overrides(&mut config, &cli);
+    if cli.hotkey.is_some() {
+        config
+            .validate_hotkeys()
+            .context("--hotkey conflicts with another profile")?;
+    }
+
+    // `--check-config`: load and validation passed, print a summary and exit
+    if cli.check_config {
+        return cli::run_check_config(&config);
+    }
+
+    // `--search`: print matching history and exit (no app startup)
+    if let Some(query) = &cli.search {
+        return cli::run_search(&config.history, query);
+    }
+
+    // `--stats`: print per-profile usage and exit
+    if cli.stats {
+        return cli::run_stats(&config.stats);
+    }
+
+    // `--list-devices`: print input device names for `[audio] device_name` and exit
+    if cli.list_devices {
+        return cli::run_list_devices();
+    }
+
+    // `--transcribe-dir`: batch-transcribe a directory and exit
+    if let Some(dir) = &cli.transcribe_dir {
+        return cli::run_transcribe_dir(&config, dir, cli.out.as_deref(), cli.srt);
+    }
+
+    // Decorative stdout output only; errors still reach stderr, details the tracing log
+    let quiet = cli.quiet || config.app.quiet;
+    cli::status_print(quiet, "✓ Config loaded from ~/.whisper-hotkey.toml");
+
     // macOS: Initialize NSApplication event loop (required for global-hotkey)
     #[cfg(target_os = "macos")]
     {
         // Safety: main() runs on the main thread
         let mtm = unsafe { MainThreadMarker::new_unchecked() };
         let app = NSApp(mtm);
-        app.setActivationPolicy(NSApplicationActivationPolicy::Accessory);
+        app.setActivationPolicy(activation_policy(&config.app));
     }
-    // Phase 1: Foundation
-    // Load configuration
-    let config = config::Config::load().context("failed to load configuration")?;
-    println!("✓ Config loaded from ~/.whisper-hotkey.toml");
 
     // Initialize telemetry
-    telemetry::init(config.telemetry.enabled, &config.telemetry.log_path)
-        .context("failed to initialize telemetry")?;
+    telemetry::init(&config.telemetry).context("failed to initialize telemetry")?;
     tracing::info!("whisper-hotkey starting");
-    println!("✓ Telemetry initialized");
+    cli::status_print(quiet, "✓ Telemetry initialized");
 
     // Cleanup old recordings
     match tokio::task::spawn_blocking({
@@ -77,39 +178,87 @@ async fn main() -> Result<()> {
         }
     }
 
-    // Request permissions
-    permissions::request_all_permissions().context("permission check failed")?;
-    println!("✓ Permissions OK");
+    // Request permissions (insertion checks optionally deferred to first use)
+    permissions::request_startup_permissions(config.app.defer_permissions)
+        .context("permission check failed")?;
+    if config.app.defer_permissions {
+        cli::status_print(quiet, "✓ Permissions deferred until first insertion");
+    } else {
+        cli::status_print(quiet, "✓ Permissions OK");
+    }
 
     // Phase 4: Whisper model setup - Download models for all profiles
-    println!(
-        "Checking models for {} profile(s)...",
-        config.profiles.len()
+    cli::status_print(
+        quiet,
+        &format!(
+            "Checking models for {} profile(s)...",
+            config.profiles.len()
+        ),
     );
     for profile in &config.profiles {
-        let model_path = config::Config::expand_path(&profile.model_path())
-            .context("failed to expand model path")?;
-        let downloaded =
-            transcription::ensure_model_downloaded(profile.model_type.model_name(), &model_path)
-                .with_context(|| {
-                    format!(
-                        "failed to download/verify model for profile {}",
-                        profile.name()
-                    )
-                })?;
-        if downloaded {
-            println!(
-                "  ✓ {} downloaded to {}",
-                profile.name(),
-                model_path.display()
+        // Missing model: start on the small bootstrap model, fetch the real one in the background
+        if transcription::bootstrap::profile_needs_bootstrap(profile, &config.download)? {
+            let bootstrap = config.download.bootstrap_model;
+            transcription::download::ensure_model_downloaded(
+                bootstrap,
+                &transcription::bootstrap::bootstrap_model_path(&config.download)?,
+                &config.download,
+                quiet,
+            )
+            .with_context(|| {
+                format!("failed to download bootstrap model {}", bootstrap.as_str())
+            })?;
+            cli::status_print(
+                quiet,
+                &format!(
+                    "  ⏳ {} downloading in background, using {} until ready",
+                    profile.name(),
+                    bootstrap.as_str()
+                ),
+            );
+            continue;
+        }
+        let model = transcription::ensure_profile_model(profile, &config.download, quiet)
+            .with_context(|| {
+                format!(
+                    "failed to download/verify model for profile {}",
+                    profile.name()
+                )
+            })?;
+        let model_path = &model.path;
+        if model.downloaded {
+            cli::status_print(
+                quiet,
+                &format!(
+                    "  ✓ {} downloaded to {}",
+                    profile.name(),
+                    model_path.display()
+                ),
             );
             tracing::info!(
                 profile = %profile.name(),
                 path = %model_path.display(),
                 "model downloaded"
             );
+        } else if model.custom {
+            cli::status_print(
+                quiet,
+                &format!(
+                    "  ✓ {} using custom model {}",
+                    profile.name(),
+                    model_path.display()
+                ),
+            );
+            tracing::info!(
+                profile = %profile.name(),
+                path = %model_path.display(),
+                "custom model found"
+            );
         } else {
-            println!("  ✓ {} found at {}", profile.name(), model_path.display());
+            cli::status_print(
+                quiet,
+                &format!("  ✓ {} found at {}", profile.name(), model_path.display()),
+            );
             tracing::info!(
                 profile = %profile.name(),
                 path = %model_path.display(),
@@ -117,14 +266,28 @@ async fn main() -> Result<()> {
             );
         }
     }
-    println!("✓ All models ready");
+    // Fallback models are loaded on demand, so fetch them now rather than mid-session
+    if config.latency.max_inference_ms > 0 {
+        for fallback in config.profiles.iter().filter_map(|p| p.fallback_model) {
+            let path = config::Config::expand_path(&fallback.model_path())
+                .context("failed to expand model path")?;
+            transcription::download::ensure_model_downloaded(
+                fallback,
+                &path,
+                &config.download,
+                quiet,
+            )
+            .with_context(|| format!("failed to download fallback model {}", fallback.as_str()))?;
+        }
+    }
+    cli::status_print(quiet, "✓ All models ready");
 
     // Phase 3: Audio recording
     let audio_capture =
         audio::AudioCapture::new(&config.audio).context("failed to initialize audio capture")?;
     #[allow(clippy::arc_with_non_send_sync)]
     let audio_capture = Arc::new(Mutex::new(audio_capture));
-    println!("✓ Audio capture initialized");
+    cli::status_print(quiet, "✓ Audio capture initialized");
     tracing::info!(
         "audio capture initialized: buffer_size={}, sample_rate={}",
         config.audio.buffer_size,
@@ -132,16 +295,21 @@ async fn main() -> Result<()> {
     );
 
     // Phase 2: Global hotkey (with Phase 5 transcription integration)
-    // Clone necessary: config.aliases needed in Arc, but config borrowed later by tray manager
-    let multi_hotkey_manager = input::hotkey::MultiHotkeyManager::new(
-        &config.profiles,
-        Arc::clone(&audio_capture),
-        config.recording.enabled,
-        &Arc::new(config.aliases.clone()),
-    )
-    .context("failed to register global hotkeys")?;
-    println!("✓ {} profile(s) registered", config.profiles.len());
+    let multi_hotkey_manager =
+        input::hotkey::MultiHotkeyManager::new(&config, Arc::clone(&audio_capture), cli.dry_run)
+            .context("failed to register global hotkeys")?;
+    cli::status_print(
+        quiet,
+        &format!("✓ {} profile(s) registered", config.profiles.len()),
+    );
     tracing::info!(profiles = config.profiles.len(), "all profiles registered");
+    if cli.dry_run {
+        cli::status_print(
+            quiet,
+            "✓ Dry run: transcriptions will be logged, not inserted",
+        );
+        tracing::info!("dry run enabled - text insertion disabled");
+    }
 
     // Menubar tray icon (use first profile's state for icon updates)
     if config.profiles.is_empty() {
@@ -150,33 +318,49 @@ async fn main() -> Result<()> {
     let app_state = multi_hotkey_manager
         .profile_state(config.profiles[0].name())
         .context("failed to get state for first profile (profile may be misconfigured)")?;
-    let mut tray_manager =
-        tray::TrayManager::new(&config, app_state).context("failed to create tray icon")?;
-    println!("✓ Menubar icon created");
+    let mut tray_manager = tray::TrayManager::new(&config, app_state)
+        .context("failed to create tray icon")?
+        .with_last_transcription(multi_hotkey_manager.last_transcription())
+        .with_input_level(
+            audio_capture
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .level_meter(),
+        );
+    cli::status_print(quiet, "✓ Menubar icon created");
     tracing::info!("menubar tray icon initialized");
 
     // Phase 6: Integration & Polish - Main event loop
     tracing::info!("all components initialized successfully");
     tracing::info!("event loop starting (press Ctrl+C to exit)");
-    println!(
-        "\nWhisper Hotkey is running with {} profile(s). Check menubar for config options.",
-        config.profiles.len()
+    cli::status_print(
+        quiet,
+        &format!(
+            "\nWhisper Hotkey is running with {} profile(s). Check menubar for config options.",
+            config.profiles.len()
+        ),
     );
     for profile in &config.profiles {
-        println!(
-            "  • {}: {:?}+{} {}",
-            profile.name(),
-            profile.hotkey.modifiers,
-            profile.hotkey.key,
-            if profile.preload {
-                "(preloaded)"
-            } else {
-                "(lazy load)"
-            }
+        cli::status_print(
+            quiet,
+            &format!(
+                "  • {}: {:?}+{} {}",
+                profile.name(),
+                profile.hotkey.modifiers,
+                profile.hotkey.key,
+                if profile.preload {
+                    "(preloaded)"
+                } else {
+                    "(lazy load)"
+                }
+            ),
         );
     }
-    println!("✓ Full pipeline ready: hotkey → audio → transcription → text insertion");
-    println!("Press Ctrl+C to exit or use menubar Quit option.\n");
+    cli::status_print(
+        quiet,
+        "✓ Full pipeline ready: hotkey → audio → transcription → text insertion",
+    );
+    cli::status_print(quiet, "Press Ctrl+C to exit or use menubar Quit option.\n");
 
     let receiver = GlobalHotKeyEvent::receiver();
 
@@ -218,6 +402,8 @@ async fn main() -> Result<()> {
         );
     }
 
+    let mut restart_requested = false;
+    let mut last_stream_check = std::time::Instant::now();
     loop {
         // macOS: Pump the event loop to process global hotkey events
         #[cfg(target_os = "macos")]
@@ -256,6 +442,20 @@ async fn main() -> Result<()> {
             multi_hotkey_manager.handle_event(event);
         }
 
+        // Start recordings queued by presses during processing (capture runs on main thread)
+        multi_hotkey_manager.start_queued_recordings();
+        if last_stream_check.elapsed() >= STREAM_CHECK_INTERVAL {
+            last_stream_check = std::time::Instant::now();
+            recover_audio_stream(&audio_capture);
+            for capture in multi_hotkey_manager.profile_captures() {
+                recover_audio_stream(capture);
+            }
+        }
+        multi_hotkey_manager.expire_quick_pick();
+        if multi_hotkey_manager.take_cancelled() {
+            tray_manager.flash_cancelled();
+        }
+
         // Update tray menu/icon based on app state
         if let Err(e) = tray_manager.update_icon_if_needed(&config) {
             tracing::warn!(error = %e, "failed to update tray");
@@ -278,16 +478,69 @@ async fn main() -> Result<()> {
                             tracing::info!("config file location: {:?}", path);
                         }
                     }
+                }
+                tray::TrayCommand::TranscribeFile => {
+                    // Tray icon doesn't support file drop, so use the native picker.
+                    // Blocks the event loop while the dialog is open (modal anyway).
+                    if let Some(path) = transcription::file::pick_audio_file() {
+                        multi_hotkey_manager.transcribe_file(path);
+                    }
+                }
+                tray::TrayCommand::RecheckPermissions => {
+                    let report = permissions::recheck_permissions();
+                    match report.denied_message(false) {
+                        Some(message) => tracing::warn!("⚠️  {message}"),
+                        None => cli::status_print(quiet, "✓ Permissions OK"),
+                    }
+                }
+                tray::TrayCommand::CopyLastTranscription => {
+                    if let Some(text) = tray_manager.last_transcription() {
+                        match transcription::file::copy_to_clipboard(&text) {
+                            Ok(()) => tracing::info!(
+                                text_len = text.len(),
+                                "📋 Copied last transcription"
+                            ),
+                            Err(e) => {
+                                tracing::warn!(error = %e, "failed to copy last transcription");
+                            }
+                        }
+                    }
+                }
+                tray::TrayCommand::TogglePause => {
+                    tray_manager.set_paused(multi_hotkey_manager.toggle_pause());
+                }
+                tray::TrayCommand::SelectProfile(name) => {
+                    match multi_hotkey_manager.select_profile(&name) {
+                        Ok(()) => {
+                            if let Some(state) = multi_hotkey_manager.profile_state(&name) {
+                                tray_manager.select_profile(&name, state);
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!(error = %e, "failed to switch profile");
+                            // macOS already toggled the clicked item's check
+                            tray_manager.refresh_menu();
+                        }
+                    }
+                }
+                tray::TrayCommand::Restart => {
+                    tracing::info!("🔄 Restart requested, waiting for in-flight work to finish");
+                    restart_requested = true;
                 } // Note: Quit case removed - PredefinedMenuItem::quit() calls native
                   // macOS terminate: selector which bypasses event system entirely
             }
         }
 
+        // Restart once nothing is recording or transcribing, so no dictation is lost
+        if restart_requested && multi_hotkey_manager.is_idle() {
+            break;
+        }
+
         // Check for shutdown signal
         tokio::select! {
             _ = tokio::signal::ctrl_c() => {
                 tracing::info!("shutdown signal received");
-                println!("\nShutting down...");
+                cli::status_print(quiet, "\nShutting down...");
                 break;
             }
             () = tokio::time::sleep(tokio::time::Duration::from_millis(10)) => {
@@ -296,6 +549,43 @@ async fn main() -> Result<()> {
         }
     }
 
+    if restart_requested {
+        let command = restart::RelaunchCommand::current()?;
+        let mut steps = AppRestart {
+            hotkeys: Some(multi_hotkey_manager),
+            tray: Some(tray_manager),
+        };
+        // Only returns if the relaunch failed
+        return Err(restart::restart(&mut steps, &command));
+    }
+
+    tracing::info!("📈 Latency: {}", telemetry::metrics_snapshot().summary());
     tracing::info!("whisper-hotkey shutdown complete");
     Ok(())
 }
+
+#[cfg(all(test, target_os = "macos"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activation_policy_default_hides_dock_icon() {
+        let app = config::AppConfig::default();
+        assert_eq!(
+            activation_policy(&app),
+            NSApplicationActivationPolicy::Accessory
+        );
+    }
+
+    #[test]
+    fn test_activation_policy_show_dock_icon() {
+        let app = config::AppConfig {
+            show_dock_icon: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            activation_policy(&app),
+            NSApplicationActivationPolicy::Regular
+        );
+    }
+}
@@ -0,0 +1,69 @@
+/// `text` as a quoted string literal for `osascript`, quotes and backslashes escaped (pure)
+#[must_use]
+pub fn applescript_string(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
+
+/// `osascript` script posting `message` titled "Whisper Hotkey: `title`" (pure)
+#[must_use]
+// Only `notify` on macOS posts the script
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn notification_script(title: &str, message: &str) -> String {
+    format!(
+        "display notification {} with title {}",
+        applescript_string(message),
+        applescript_string(&format!("Whisper Hotkey: {title}"))
+    )
+}
+
+/// Post a user notification (no-op outside macOS)
+///
+/// Runs `osascript` without waiting for it; a failure to spawn is logged.
+pub fn notify(title: &str, message: &str) {
+    #[cfg(target_os = "macos")]
+    {
+        if let Err(e) = std::process::Command::new("osascript")
+            .arg("-e")
+            .arg(notification_script(title, message))
+            .spawn()
+        {
+            tracing::warn!(error = %e, "failed to post notification");
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        tracing::debug!(title, message, "notifications are only posted on macOS");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applescript_string_escapes() {
+        assert_eq!(
+            applescript_string("Inserted 240 chars"),
+            "\"Inserted 240 chars\""
+        );
+        assert_eq!(
+            applescript_string(r#"say "hi" \ bye"#),
+            r#""say \"hi\" \\ bye""#
+        );
+    }
+
+    #[test]
+    fn test_notification_script() {
+        assert_eq!(
+            notification_script("transcription failed", "model \"small\" crashed"),
+            r#"display notification "model \"small\" crashed" with title "Whisper Hotkey: transcription failed""#
+        );
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn test_notify_is_noop_off_macos() {
+        notify("transcription complete", "Inserted 240 chars");
+    }
+}
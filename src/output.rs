@@ -0,0 +1,605 @@
+use crate::alias;
+use crate::config::{AliasesConfig, LanguageRule, ProfileMode};
+use crate::formatting::{self, Locale};
+use crate::translation::HookCommand;
+use anyhow::Result;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// Default template for combining two transcriptions (e.g. original and translation)
+pub const DEFAULT_COMBINE_TEMPLATE: &str = "{primary}\n{secondary}";
+
+/// Parsed piece of a combine template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    /// Literal text copied as-is (escaped braces already unescaped)
+    Literal(&'a str),
+    /// `{primary}` placeholder
+    Primary,
+    /// `{secondary}` placeholder
+    Secondary,
+}
+
+/// Split template into literals and placeholders
+///
+/// `{{` and `}}` are escapes for literal braces. Unknown placeholders and
+/// lone braces are kept verbatim.
+fn tokenize(template: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = template;
+
+    while let Some(idx) = rest.find(['{', '}']) {
+        if idx > 0 {
+            tokens.push(Token::Literal(&rest[..idx]));
+        }
+        let tail = &rest[idx..];
+
+        if let Some(after) = tail.strip_prefix("{primary}") {
+            tokens.push(Token::Primary);
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("{secondary}") {
+            tokens.push(Token::Secondary);
+            rest = after;
+        } else if tail.starts_with("{{") || tail.starts_with("}}") {
+            tokens.push(Token::Literal(&tail[..1]));
+            rest = &tail[2..];
+        } else {
+            tokens.push(Token::Literal(&tail[..1]));
+            rest = &tail[1..];
+        }
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Literal(rest));
+    }
+
+    tokens
+}
+
+/// Combine two transcriptions using a template with `{primary}`/`{secondary}` placeholders
+///
+/// Used when two outputs are inserted together (dual output, ensemble).
+/// Use `{{` / `}}` for literal braces.
+#[must_use]
+pub fn combine(template: &str, primary: &str, secondary: &str) -> String {
+    let mut result = String::with_capacity(template.len() + primary.len() + secondary.len());
+    for token in tokenize(template) {
+        match token {
+            Token::Literal(text) => result.push_str(text),
+            Token::Primary => result.push_str(primary),
+            Token::Secondary => result.push_str(secondary),
+        }
+    }
+    result
+}
+
+/// Validate that a combine template references both outputs
+///
+/// # Errors
+/// Returns error if `{primary}` or `{secondary}` is missing (that output would be silently dropped)
+pub fn validate_combine_template(template: &str) -> Result<()> {
+    let tokens = tokenize(template);
+    if !tokens.contains(&Token::Primary) {
+        anyhow::bail!("combine_template is missing {{primary}} placeholder: {template:?}");
+    }
+    if !tokens.contains(&Token::Secondary) {
+        anyhow::bail!("combine_template is missing {{secondary}} placeholder: {template:?}");
+    }
+    Ok(())
+}
+
+/// Emphasis markers stripped when they wrap the whole transcription
+const EMPHASIS_MARKERS: [&str; 3] = ["**", "__", "*"];
+
+/// Remove markdown Whisper sometimes wraps around a transcription
+///
+/// Only strips wrapping around the *whole* text: code fences, backticks and
+/// emphasis markers. Inline code or emphasis inside a sentence is kept, and
+/// text with no wrapping is returned unchanged.
+#[must_use]
+pub fn strip_markdown_artifacts(text: &str) -> String {
+    let mut current = text.trim();
+    let mut stripped = false;
+
+    // Nested wrapping (e.g. **`code`**) is unwrapped layer by layer
+    while let Some(inner) = strip_code_fence(current)
+        .or_else(|| strip_backticks(current))
+        .or_else(|| strip_emphasis(current))
+    {
+        current = inner.trim();
+        stripped = true;
+    }
+
+    if stripped {
+        current.to_owned()
+    } else {
+        text.to_owned()
+    }
+}
+
+/// Unwrap ```` ```lang\n...\n``` ```` spanning the whole text
+fn strip_code_fence(text: &str) -> Option<&str> {
+    let body = text.strip_prefix("```")?.strip_suffix("```")?;
+    // Opening fence line may carry a language tag; content starts on the next line
+    let content = match body.split_once('\n') {
+        Some((info, rest)) if !info.trim().contains(' ') => rest,
+        _ => body,
+    };
+    (!content.contains("```")).then_some(content)
+}
+
+/// Unwrap `` `...` `` or ``` ``...`` ``` spanning the whole text
+fn strip_backticks(text: &str) -> Option<&str> {
+    ["``", "`"].iter().find_map(|tick| {
+        let inner = text.strip_prefix(tick)?.strip_suffix(tick)?;
+        (!inner.is_empty() && !inner.contains('`')).then_some(inner)
+    })
+}
+
+/// Unwrap `**...**`, `__...__` or `*...*` spanning the whole text
+fn strip_emphasis(text: &str) -> Option<&str> {
+    EMPHASIS_MARKERS.iter().find_map(|marker| {
+        let inner = text.strip_prefix(marker)?.strip_suffix(marker)?;
+        let inner_trimmed = inner.trim();
+        // Marker inside means it's not a single wrapping pair ("*a* and *b*")
+        (!inner_trimmed.is_empty()
+            && !inner.contains(marker)
+            && !inner_trimmed.starts_with(['*', '_'])
+            && inner == inner_trimmed)
+            .then_some(inner)
+    })
+}
+
+/// Post-processing applied to a transcription before insertion
+///
+/// Steps run in order: markdown stripping, translation, aliases, locale formatting.
+#[derive(Debug, Clone)]
+pub struct TextPipeline {
+    /// Strip markdown wrapping Whisper adds around transcriptions
+    pub strip_markdown: bool,
+    /// External translation command (None = no external translation)
+    pub translation: Option<HookCommand>,
+    /// Joins original and translation, `{primary}`/`{secondary}` (None = translation only)
+    pub combine_template: Option<Arc<str>>,
+    /// Alias replacements
+    pub aliases: Arc<AliasesConfig>,
+    /// Locale for number/date formatting (None = leave as transcribed)
+    pub locale: Option<Locale>,
+    /// Dictation inserts text; command mode only emits exact alias matches
+    pub mode: ProfileMode,
+    /// Apply alias replacements in dictation (off via `[[language_rules]]`)
+    pub apply_aliases: bool,
+    /// Per-language overrides of the steps above
+    pub language_rules: Arc<[LanguageRule]>,
+}
+
+/// `[[language_rules]]` entry for `language` (codes compare case-insensitively)
+///
+/// None when the language is unknown or has no rule: the profile's settings apply.
+#[must_use]
+pub fn resolve_language_rule<'a>(
+    rules: &'a [LanguageRule],
+    language: Option<&str>,
+) -> Option<&'a LanguageRule> {
+    let language = language?;
+    rules
+        .iter()
+        .find(|rule| rule.language.eq_ignore_ascii_case(language))
+}
+
+impl TextPipeline {
+    /// Pipeline that only applies `aliases`
+    #[must_use]
+    pub fn new(aliases: Arc<AliasesConfig>) -> Self {
+        Self {
+            strip_markdown: false,
+            translation: None,
+            combine_template: None,
+            aliases,
+            locale: None,
+            mode: ProfileMode::Dictation,
+            apply_aliases: true,
+            language_rules: Arc::new([]),
+        }
+    }
+
+    /// Pipeline with the `[[language_rules]]` entry for `language` applied
+    ///
+    /// Borrowed unchanged when no rule matches.
+    #[must_use]
+    pub fn for_language(&self, language: Option<&str>) -> Cow<'_, Self> {
+        let Some(rule) = resolve_language_rule(&self.language_rules, language) else {
+            return Cow::Borrowed(self);
+        };
+        tracing::debug!(language = %rule.language, "applying language rule");
+
+        let mut pipeline = self.clone();
+        if rule.disable_formatting {
+            pipeline.locale = None;
+        } else if let Some(locale) = rule.locale {
+            pipeline.locale = Some(locale);
+        }
+        if let Some(apply_aliases) = rule.aliases {
+            pipeline.apply_aliases = apply_aliases;
+        }
+        if let Some(strip_markdown) = rule.strip_markdown {
+            pipeline.strip_markdown = strip_markdown;
+        }
+        Cow::Owned(pipeline)
+    }
+
+    /// Produce the text to insert, or None if a command-mode profile rejected it
+    ///
+    /// Command mode skips translation and formatting: the matched alias value is
+    /// inserted as configured.
+    #[must_use]
+    pub fn output(&self, text: String) -> Option<String> {
+        match self.mode {
+            ProfileMode::Dictation => Some(self.apply(text)),
+            ProfileMode::Command => {
+                let text = if self.strip_markdown {
+                    strip_markdown_artifacts(&text)
+                } else {
+                    text
+                };
+                match alias::match_command(&text, &self.aliases) {
+                    alias::CommandMatch::Matched(output) => Some(output),
+                    alias::CommandMatch::Unrecognized => {
+                        tracing::info!(text = %text, "🚫 Unrecognized command");
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    /// Run all enabled steps on `text`
+    ///
+    /// A failing translation command is logged and the untranslated text kept.
+    #[must_use]
+    pub fn apply(&self, text: String) -> String {
+        let text = if self.strip_markdown {
+            strip_markdown_artifacts(&text)
+        } else {
+            text
+        };
+
+        let text = match &self.translation {
+            Some(hook) if !text.is_empty() => match hook.translate(&text) {
+                Ok(translated) => match &self.combine_template {
+                    Some(template) => combine(template, &text, &translated),
+                    None => translated,
+                },
+                Err(e) => {
+                    tracing::warn!(error = %e, "⚠️  Translation failed - inserting original");
+                    text
+                }
+            },
+            _ => text,
+        };
+
+        let text = if self.apply_aliases {
+            alias::apply_aliases(&text, &self.aliases)
+        } else {
+            text
+        };
+
+        match self.locale {
+            Some(locale) => formatting::apply_locale(&text, locale),
+            None => text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_default_template() {
+        let result = combine(DEFAULT_COMBINE_TEMPLATE, "hello", "cześć");
+        assert_eq!(result, "hello\ncześć");
+    }
+
+    #[test]
+    fn test_combine_custom_template() {
+        assert_eq!(
+            combine("{primary} ({secondary})", "hello", "hola"),
+            "hello (hola)"
+        );
+        assert_eq!(
+            combine("{secondary} / {primary}", "hello", "hola"),
+            "hola / hello"
+        );
+    }
+
+    #[test]
+    fn test_combine_repeated_placeholders() {
+        assert_eq!(combine("{primary}-{primary}", "a", "b"), "a-a");
+    }
+
+    #[test]
+    fn test_combine_missing_placeholder_drops_output() {
+        assert_eq!(combine("{primary}", "a", "b"), "a");
+        assert_eq!(combine("only text", "a", "b"), "only text");
+    }
+
+    #[test]
+    fn test_combine_unknown_placeholder_kept_verbatim() {
+        assert_eq!(combine("{primary} {tertiary}", "a", "b"), "a {tertiary}");
+    }
+
+    #[test]
+    fn test_combine_escaped_braces() {
+        assert_eq!(
+            combine("{{{primary}}}: {{secondary}}", "a", "b"),
+            "{a}: {secondary}"
+        );
+        assert_eq!(combine("{{}}", "a", "b"), "{}");
+    }
+
+    #[test]
+    fn test_combine_lone_braces_kept() {
+        assert_eq!(combine("{ {primary} }", "a", "b"), "{ a }");
+        assert_eq!(combine("{primary", "a", "b"), "{primary");
+    }
+
+    #[test]
+    fn test_combine_values_not_reinterpreted() {
+        // Placeholder-like text inside values must not be substituted again
+        assert_eq!(
+            combine("{primary}|{secondary}", "{secondary}", "x"),
+            "{secondary}|x"
+        );
+    }
+
+    #[test]
+    fn test_combine_empty_template() {
+        assert_eq!(combine("", "a", "b"), "");
+    }
+
+    #[test]
+    fn test_validate_combine_template_valid() {
+        assert!(validate_combine_template(DEFAULT_COMBINE_TEMPLATE).is_ok());
+        assert!(validate_combine_template("{secondary} {primary}").is_ok());
+    }
+
+    #[test]
+    fn test_validate_combine_template_missing_placeholder() {
+        let err = validate_combine_template("{primary} only").unwrap_err();
+        assert!(err.to_string().contains("{secondary}"));
+
+        let err = validate_combine_template("{secondary} only").unwrap_err();
+        assert!(err.to_string().contains("{primary}"));
+    }
+
+    #[test]
+    fn test_validate_combine_template_escaped_is_not_placeholder() {
+        assert!(validate_combine_template("{{primary}} {secondary}").is_err());
+    }
+
+    #[test]
+    fn test_strip_markdown_fenced_block() {
+        assert_eq!(
+            strip_markdown_artifacts("```\nlet x = 5;\n```"),
+            "let x = 5;"
+        );
+        assert_eq!(
+            strip_markdown_artifacts("```rust\nfn main() {}\n```"),
+            "fn main() {}"
+        );
+        assert_eq!(strip_markdown_artifacts("```ls -la```"), "ls -la");
+    }
+
+    #[test]
+    fn test_strip_markdown_fence_keeps_multiline_content() {
+        assert_eq!(
+            strip_markdown_artifacts("```python\nimport os\nprint(os.getcwd())\n```"),
+            "import os\nprint(os.getcwd())"
+        );
+    }
+
+    #[test]
+    fn test_strip_markdown_wrapping_backticks() {
+        assert_eq!(strip_markdown_artifacts("`git status`"), "git status");
+        assert_eq!(strip_markdown_artifacts("``git status``"), "git status");
+        assert_eq!(strip_markdown_artifacts(" `npm install` "), "npm install");
+    }
+
+    #[test]
+    fn test_strip_markdown_keeps_inline_code() {
+        let text = "Run `cargo test` before pushing.";
+        assert_eq!(strip_markdown_artifacts(text), text);
+
+        // Two separate code spans are intentional, not one wrapping pair
+        let text = "`foo` and `bar`";
+        assert_eq!(strip_markdown_artifacts(text), text);
+    }
+
+    #[test]
+    fn test_strip_markdown_emphasis_wrapping() {
+        assert_eq!(strip_markdown_artifacts("**Hello world.**"), "Hello world.");
+        assert_eq!(strip_markdown_artifacts("__Hello world.__"), "Hello world.");
+        assert_eq!(strip_markdown_artifacts("*Hello world.*"), "Hello world.");
+    }
+
+    #[test]
+    fn test_strip_markdown_nested_wrapping() {
+        assert_eq!(strip_markdown_artifacts("**`make build`**"), "make build");
+    }
+
+    #[test]
+    fn test_strip_markdown_leaves_untouched() {
+        for text in [
+            "Hello world.",
+            "2 * 3 * 4 equals 24",
+            "*nix systems are great",
+            "snake_case_name",
+            "*a* and *b*",
+            "Use **bold** for emphasis.",
+            "```",
+            "``",
+            "",
+        ] {
+            assert_eq!(strip_markdown_artifacts(text), text, "changed: {text:?}");
+        }
+    }
+
+    #[test]
+    fn test_strip_markdown_preserves_original_when_nothing_stripped() {
+        // Surrounding whitespace kept when no markdown was removed
+        assert_eq!(strip_markdown_artifacts("  hello  "), "  hello  ");
+    }
+
+    fn rule(language: &str) -> LanguageRule {
+        LanguageRule {
+            language: language.to_owned(),
+            locale: None,
+            disable_formatting: false,
+            aliases: None,
+            strip_markdown: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_language_rule_matched() {
+        let rules = [rule("ja"), rule("de")];
+        let resolved = resolve_language_rule(&rules, Some("de"));
+        assert_eq!(resolved.map(|r| r.language.as_str()), Some("de"));
+        // Codes are case-insensitive
+        assert!(resolve_language_rule(&rules, Some("JA")).is_some());
+    }
+
+    #[test]
+    fn test_resolve_language_rule_unmatched_falls_back() {
+        let rules = [rule("ja")];
+        assert!(resolve_language_rule(&rules, Some("en")).is_none());
+        assert!(resolve_language_rule(&rules, None).is_none());
+        assert!(resolve_language_rule(&[], Some("ja")).is_none());
+    }
+
+    #[test]
+    fn test_for_language_applies_rule() {
+        let mut pipeline = pipeline_with_alias("hello", "HELLO");
+        pipeline.locale = Some(Locale::EnUs);
+        pipeline.language_rules = Arc::new([LanguageRule {
+            disable_formatting: true,
+            aliases: Some(false),
+            strip_markdown: Some(true),
+            ..rule("ja")
+        }]);
+
+        let adjusted = pipeline.for_language(Some("ja"));
+        assert_eq!(adjusted.locale, None);
+        assert!(!adjusted.apply_aliases);
+        assert!(adjusted.strip_markdown);
+        assert_eq!(adjusted.apply("`hello`".to_owned()), "hello");
+    }
+
+    #[test]
+    fn test_for_language_locale_override() {
+        let mut pipeline = pipeline_with_alias("a", "b");
+        pipeline.locale = Some(Locale::EnUs);
+        pipeline.language_rules = Arc::new([LanguageRule {
+            locale: Some(Locale::DeDe),
+            ..rule("de")
+        }]);
+        assert_eq!(pipeline.for_language(Some("de")).locale, Some(Locale::DeDe));
+    }
+
+    #[test]
+    fn test_for_language_without_rule_keeps_pipeline() {
+        let mut pipeline = pipeline_with_alias("hello", "HELLO");
+        pipeline.locale = Some(Locale::EnUs);
+        pipeline.language_rules = Arc::new([LanguageRule {
+            disable_formatting: true,
+            ..rule("ja")
+        }]);
+
+        let adjusted = pipeline.for_language(Some("en"));
+        assert!(matches!(adjusted, Cow::Borrowed(_)));
+        assert_eq!(adjusted.locale, Some(Locale::EnUs));
+        assert_eq!(adjusted.apply("hello".to_owned()), "HELLO");
+    }
+
+    fn pipeline_with_alias(from: &str, to: &str) -> TextPipeline {
+        let mut entries = std::collections::HashMap::new();
+        entries.insert(from.to_owned(), to.to_owned());
+        // Threshold 1.0: exact (case-insensitive) matches only
+        TextPipeline::new(Arc::new(AliasesConfig {
+            enabled: true,
+            threshold: 1.0,
+            entries,
+            import_path: None,
+            preserve_case: false,
+            case_insensitive: true,
+            patterns: std::collections::BTreeMap::new(),
+            compiled_patterns: Vec::new(),
+        }))
+    }
+
+    #[test]
+    fn test_pipeline_strips_markdown_before_aliases() {
+        let mut pipeline = pipeline_with_alias("run tests", "make test");
+        // Without stripping, only the trigger inside the backticks is replaced
+        assert_eq!(pipeline.apply("`run tests`".to_owned()), "`make test`");
+
+        pipeline.strip_markdown = true;
+        assert_eq!(pipeline.apply("`run tests`".to_owned()), "make test");
+    }
+
+    #[test]
+    fn test_pipeline_output_dictation_inserts_free_text() {
+        let pipeline = pipeline_with_alias("run tests", "make test");
+        assert_eq!(
+            pipeline.output("hello there".to_owned()),
+            Some("hello there".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_pipeline_output_command_mode() {
+        let mut pipeline = pipeline_with_alias("run tests", "make test");
+        pipeline.mode = ProfileMode::Command;
+        assert_eq!(
+            pipeline.output("Run tests.".to_owned()),
+            Some("make test".to_owned())
+        );
+        assert_eq!(pipeline.output("hello there".to_owned()), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pipeline_translation_failure_keeps_original() {
+        let mut pipeline = pipeline_with_alias("run tests", "make test");
+        pipeline.translation = Some(HookCommand::build("false", "de").unwrap());
+        assert_eq!(pipeline.apply("hello".to_owned()), "hello");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pipeline_translation_output_used() {
+        let mut pipeline = pipeline_with_alias("run tests", "make test");
+        // `cat` echoes its input: stands in for a translator
+        pipeline.translation = Some(HookCommand::build("cat", "de").unwrap());
+        assert_eq!(pipeline.apply("run tests".to_owned()), "make test");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pipeline_inserts_original_with_translation() {
+        let mut pipeline = TextPipeline::new(Arc::new(AliasesConfig::default()));
+        pipeline.translation = Some(HookCommand::build("tr a-z A-Z", "de").unwrap());
+        pipeline.combine_template = Some("{primary} ({secondary})".into());
+        assert_eq!(pipeline.apply("hello".to_owned()), "hello (HELLO)");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pipeline_failed_translation_not_combined() {
+        let mut pipeline = TextPipeline::new(Arc::new(AliasesConfig::default()));
+        pipeline.translation = Some(HookCommand::build("false", "de").unwrap());
+        pipeline.combine_template = Some(DEFAULT_COMBINE_TEMPLATE.into());
+        assert_eq!(pipeline.apply("hello".to_owned()), "hello");
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::{bail, Result};
+use std::sync::Mutex;
 
 /// Extract .app bundle path from executable path
 ///
@@ -84,6 +85,59 @@ pub fn check_microphone_permission() -> Result<()> {
     Ok(())
 }
 
+/// Whether the app currently has accessibility permission (`AXIsProcessTrusted`, no prompt)
+///
+/// Always true off macOS.
+#[must_use]
+pub fn is_accessibility_trusted() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        // SAFETY: FFI declaration for Accessibility API (stable since macOS 10.9)
+        #[link(name = "ApplicationServices", kind = "framework")]
+        extern "C" {
+            fn AXIsProcessTrusted() -> bool;
+        }
+
+        // SAFETY: AXIsProcessTrusted is a safe macOS API that only reads permission status
+        #[allow(unsafe_code)]
+        unsafe {
+            AXIsProcessTrusted()
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    true
+}
+
+/// Show the system dialog directing the user to the Accessibility settings
+#[cfg(target_os = "macos")]
+fn prompt_accessibility() {
+    use core_foundation::base::TCFType;
+    use core_foundation::boolean::CFBoolean;
+    use core_foundation::dictionary::CFDictionary;
+    use core_foundation::string::CFString;
+
+    // SAFETY: FFI declarations for Accessibility API
+    // These are stable macOS APIs available since 10.9
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXIsProcessTrustedWithOptions(
+            options: core_foundation::dictionary::CFDictionaryRef,
+        ) -> bool;
+    }
+
+    // Create options dictionary to trigger system prompt
+    let key = CFString::from_static_string("AXTrustedCheckOptionPrompt");
+    let value = CFBoolean::true_value();
+    let options = CFDictionary::from_CFType_pairs(&[(key.as_CFType(), value.as_CFType())]);
+
+    // Show system dialog directing user to System Settings
+    // SAFETY: AXIsProcessTrustedWithOptions is safe, shows system permission dialog
+    // Note: This returns immediately with current status (false), before user can grant permission
+    #[allow(unsafe_code)]
+    let _ = unsafe { AXIsProcessTrustedWithOptions(options.as_concrete_TypeRef()) };
+}
+
 /// Check and request accessibility permission (for text insertion)
 ///
 /// Uses the official macOS Accessibility API (`AXIsProcessTrusted`) to check permission.
@@ -97,43 +151,14 @@ pub fn check_accessibility_permission() -> Result<()> {
 
     #[cfg(target_os = "macos")]
     {
-        use core_foundation::base::TCFType;
-        use core_foundation::boolean::CFBoolean;
-        use core_foundation::dictionary::CFDictionary;
-        use core_foundation::string::CFString;
-
-        // SAFETY: FFI declarations for Accessibility API
-        // These are stable macOS APIs available since 10.9
-        #[link(name = "ApplicationServices", kind = "framework")]
-        extern "C" {
-            fn AXIsProcessTrusted() -> bool;
-            fn AXIsProcessTrustedWithOptions(
-                options: core_foundation::dictionary::CFDictionaryRef,
-            ) -> bool;
-        }
-
         // First check if we already have permission
-        // SAFETY: AXIsProcessTrusted is a safe macOS API that only reads permission status
-        #[allow(unsafe_code)]
-        let is_trusted = unsafe { AXIsProcessTrusted() };
-
-        if is_trusted {
+        if is_accessibility_trusted() {
             tracing::info!("accessibility permission already granted");
             return Ok(());
         }
 
         tracing::warn!("accessibility permission not granted, showing system dialog...");
-
-        // Create options dictionary to trigger system prompt
-        let key = CFString::from_static_string("AXTrustedCheckOptionPrompt");
-        let value = CFBoolean::true_value();
-        let options = CFDictionary::from_CFType_pairs(&[(key.as_CFType(), value.as_CFType())]);
-
-        // Show system dialog directing user to System Settings
-        // SAFETY: AXIsProcessTrustedWithOptions is safe, shows system permission dialog
-        // Note: This returns immediately with current status (false), before user can grant permission
-        #[allow(unsafe_code)]
-        let _ = unsafe { AXIsProcessTrustedWithOptions(options.as_concrete_TypeRef()) };
+        prompt_accessibility();
 
         // Check for quarantine attribute that might be blocking permissions
         let quarantine_msg = check_quarantine_status().unwrap_or_default();
@@ -191,24 +216,663 @@ pub fn check_input_monitoring_permission() -> Result<()> {
     Ok(())
 }
 
+/// Status of one permission as reported by the system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermStatus {
+    /// Permission is granted
+    Granted,
+    /// Permission is denied (app must be enabled in System Settings)
+    Denied,
+    /// Can't be checked without prompting (e.g. microphone before first capture)
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))] // Only reported on macOS
+    Unknown,
+}
+
+impl PermStatus {
+    /// Status from a yes/no system check
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))] // Only checked on macOS
+    #[must_use]
+    pub const fn from_granted(granted: bool) -> Self {
+        if granted {
+            Self::Granted
+        } else {
+            Self::Denied
+        }
+    }
+}
+
+/// Status of every permission the app needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PermissionReport {
+    /// Microphone (audio capture)
+    pub microphone: PermStatus,
+    /// Accessibility (text insertion)
+    pub accessibility: PermStatus,
+    /// Input Monitoring (global hotkeys and text insertion)
+    pub input_monitoring: PermStatus,
+}
+
+impl PermissionReport {
+    /// Report with every permission granted
+    pub const GRANTED: Self = Self {
+        microphone: PermStatus::Granted,
+        accessibility: PermStatus::Granted,
+        input_monitoring: PermStatus::Granted,
+    };
+
+    /// Permissions paired with their System Settings names
+    #[must_use]
+    pub const fn entries(self) -> [(&'static str, PermStatus); 3] {
+        [
+            ("Microphone", self.microphone),
+            ("Accessibility", self.accessibility),
+            ("Input Monitoring", self.input_monitoring),
+        ]
+    }
+
+    /// Names of denied permissions (pure)
+    #[must_use]
+    pub fn denied(self) -> Vec<&'static str> {
+        self.entries()
+            .into_iter()
+            .filter(|(_, status)| *status == PermStatus::Denied)
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// User-facing instructions for the denied permissions (None if none are denied)
+    ///
+    /// `restart` appends the restart hint; the tray re-check picks up grants
+    /// without one, so it leaves it off.
+    #[must_use]
+    pub fn denied_message(self, restart: bool) -> Option<String> {
+        let denied = self.denied();
+        if denied.is_empty() {
+            return None;
+        }
+        let steps: String = denied
+            .iter()
+            .map(|name| format!("  • System Settings → Privacy & Security → {name}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut message = format!(
+            "Missing permissions: {}\n\n\
+            Enable this app in:\n{steps}",
+            denied.join(", ")
+        );
+        if restart {
+            message.push_str("\nThen restart the app.");
+        }
+        Some(message)
+    }
+
+    /// Log each permission's status
+    pub fn log(self) {
+        for (name, status) in self.entries() {
+            match status {
+                PermStatus::Granted => tracing::info!("{name} permission granted"),
+                PermStatus::Denied => tracing::warn!("{name} permission denied"),
+                PermStatus::Unknown => tracing::info!("{name} permission not checked yet"),
+            }
+        }
+    }
+}
+
+/// Whether an event source with HID system state can be created (Input Monitoring, no prompt)
+#[cfg(target_os = "macos")]
+fn is_input_monitoring_granted() -> bool {
+    use core_graphics::event::CGEvent;
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .and_then(|source| CGEvent::new_keyboard_event(source, 0, true))
+        .is_ok()
+}
+
+/// Current status of every permission, without prompting
+///
+/// The microphone is `Unknown` on macOS: it's only prompted for on first audio
+/// capture. Everything is granted off macOS.
+#[must_use]
+pub fn permission_report() -> PermissionReport {
+    #[cfg(target_os = "macos")]
+    {
+        PermissionReport {
+            microphone: PermStatus::Unknown,
+            accessibility: PermStatus::from_granted(is_accessibility_trusted()),
+            input_monitoring: PermStatus::from_granted(is_input_monitoring_granted()),
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    PermissionReport::GRANTED
+}
+
 /// Request all required permissions
 ///
+/// Shows the accessibility dialog if that permission is missing.
+///
 /// # Errors
-/// Returns error if any permission check fails
+/// Returns error listing the denied permissions
 pub fn request_all_permissions() -> Result<()> {
     tracing::info!("requesting all permissions");
 
-    check_microphone_permission()?;
-    check_accessibility_permission()?;
-    check_input_monitoring_permission()?;
+    let report = permission_report();
+    report.log();
+    if let Some(message) = report.denied_message(true) {
+        #[cfg(target_os = "macos")]
+        if report.accessibility == PermStatus::Denied {
+            prompt_accessibility();
+        }
+        // Check for quarantine attribute that might be blocking permissions
+        let quarantine_msg = check_quarantine_status().unwrap_or_default();
+        bail!("{message}{quarantine_msg}\n");
+    }
 
     tracing::info!("all permissions checked");
     Ok(())
 }
 
+/// Whether insertion permissions have been verified yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionState {
+    /// Not checked yet (deferred)
+    Unchecked,
+    /// Checked and granted
+    Granted,
+    /// Last check failed (re-checked on next attempt)
+    Denied,
+}
+
+/// Lazily runs a permission check, caching success
+///
+/// Used to defer accessibility/input monitoring checks from startup to the
+/// first insertion. Denials are not cached so granting permission later works.
+pub struct PermissionGate {
+    state: Mutex<PermissionState>,
+}
+
+impl PermissionGate {
+    /// Create gate in the unchecked state
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: Mutex::new(PermissionState::Unchecked),
+        }
+    }
+
+    /// Record that permissions were verified elsewhere (e.g. at startup)
+    pub fn mark_granted(&self) {
+        *self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = PermissionState::Granted;
+    }
+
+    /// Record the result of a check run elsewhere (e.g. a manual re-check)
+    ///
+    /// Returns true when this enables a gate that wasn't granted before.
+    pub fn set_granted(&self, granted: bool) -> bool {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let enabled = granted && *state != PermissionState::Granted;
+        *state = if granted {
+            PermissionState::Granted
+        } else {
+            PermissionState::Denied
+        };
+        enabled
+    }
+
+    /// Whether a check must run before proceeding
+    #[must_use]
+    pub const fn should_check(state: PermissionState) -> bool {
+        !matches!(state, PermissionState::Granted)
+    }
+
+    /// Run `check` unless permissions were already granted
+    ///
+    /// # Errors
+    /// Returns the check's error if permissions are denied
+    #[allow(clippy::significant_drop_tightening)] // Lock held during check so concurrent insertions prompt once
+    pub fn ensure(&self, check: impl FnOnce() -> Result<()>) -> Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if !Self::should_check(*state) {
+            return Ok(());
+        }
+
+        match check() {
+            Ok(()) => {
+                *state = PermissionState::Granted;
+                Ok(())
+            }
+            Err(e) => {
+                *state = PermissionState::Denied;
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Default for PermissionGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gate for permissions needed by text insertion (accessibility, input monitoring)
+static INSERTION_GATE: PermissionGate = PermissionGate::new();
+
+/// Ensure insertion permissions, checking them on first use if deferred
+///
+/// # Errors
+/// Returns error if accessibility or input monitoring permission is denied
+pub fn ensure_insertion_permissions() -> Result<()> {
+    INSERTION_GATE.ensure(|| {
+        tracing::info!("checking deferred insertion permissions");
+        check_accessibility_permission()?;
+        check_input_monitoring_permission()
+    })
+}
+
+/// Re-run the permission checks without prompting (tray "Re-check permissions")
+///
+/// Once accessibility and input monitoring are granted, text insertion is
+/// enabled without restarting the app.
+pub fn recheck_permissions() -> PermissionReport {
+    tracing::info!("re-checking permissions");
+    let report = permission_report();
+    report.log();
+    let insertion_granted =
+        report.accessibility != PermStatus::Denied && report.input_monitoring != PermStatus::Denied;
+    if INSERTION_GATE.set_granted(insertion_granted) {
+        tracing::info!("✓ insertion permissions granted, text insertion enabled");
+    }
+    report
+}
+
+/// Insertion permission as observed at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertionAccess {
+    /// Insertions allowed; counts consecutive failed insertions
+    Granted {
+        /// Failed insertions since the last success
+        failures: u32,
+    },
+    /// Permission revoked: insertions are skipped until it is restored
+    Revoked,
+}
+
+/// Change of [`InsertionAccess`] worth telling the user about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessChange {
+    /// Accessibility permission was revoked while running
+    Revoked,
+    /// Permission is back; insertions resume
+    Restored,
+}
+
+impl InsertionAccess {
+    /// State after an insertion attempt (pure)
+    ///
+    /// A success clears the failure count. Failures only accumulate; revocation
+    /// is confirmed by a permission check (see [`AccessMonitor::record`]).
+    #[must_use]
+    pub const fn after_insertion(self, succeeded: bool) -> Self {
+        match (self, succeeded) {
+            (_, true) => Self::Granted { failures: 0 },
+            (Self::Granted { failures }, false) => Self::Granted {
+                failures: failures.saturating_add(1),
+            },
+            (Self::Revoked, false) => Self::Revoked,
+        }
+    }
+
+    /// State after asking the system whether the app is trusted (pure)
+    #[must_use]
+    pub const fn after_check(self, trusted: bool) -> Self {
+        match (self, trusted) {
+            (_, false) => Self::Revoked,
+            (Self::Revoked, true) => Self::Granted { failures: 0 },
+            (granted @ Self::Granted { .. }, true) => granted,
+        }
+    }
+
+    /// Whether `threshold` consecutive failures call for a permission check
+    #[must_use]
+    pub const fn suspects_revocation(self, threshold: u32) -> bool {
+        matches!(self, Self::Granted { failures } if threshold > 0 && failures >= threshold)
+    }
+
+    /// Whether insertions should be skipped
+    #[must_use]
+    pub const fn is_revoked(self) -> bool {
+        matches!(self, Self::Revoked)
+    }
+
+    /// Change between two states, if any
+    #[must_use]
+    pub const fn change(from: Self, to: Self) -> Option<AccessChange> {
+        match (from.is_revoked(), to.is_revoked()) {
+            (false, true) => Some(AccessChange::Revoked),
+            (true, false) => Some(AccessChange::Restored),
+            _ => None,
+        }
+    }
+}
+
+/// Detects accessibility permission revoked at runtime from failing insertions
+///
+/// Shared by all profiles. After `threshold` consecutive failed insertions the
+/// permission is checked; if it is gone, insertions stop until a later check
+/// (before each skipped insertion) finds it restored.
+pub struct AccessMonitor {
+    threshold: u32,
+    state: Mutex<InsertionAccess>,
+}
+
+impl AccessMonitor {
+    /// Create monitor suspecting revocation after `threshold` consecutive failures (0 = off)
+    #[must_use]
+    pub const fn new(threshold: u32) -> Self {
+        Self {
+            threshold,
+            state: Mutex::new(InsertionAccess::Granted { failures: 0 }),
+        }
+    }
+
+    /// Before inserting: while revoked, re-check with `is_trusted`
+    ///
+    /// Returns whether to insert, and `Restored` when permission came back.
+    #[allow(clippy::significant_drop_tightening)] // Lock held during check so concurrent insertions check once
+    pub fn before_insertion(
+        &self,
+        is_trusted: impl FnOnce() -> bool,
+    ) -> (bool, Option<AccessChange>) {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if !state.is_revoked() {
+            return (true, None);
+        }
+        let next = state.after_check(is_trusted());
+        let change = InsertionAccess::change(*state, next);
+        *state = next;
+        (!next.is_revoked(), change)
+    }
+
+    /// Record an insertion outcome, checking permission with `is_trusted` once failures pile up
+    ///
+    /// Returns `Revoked` when the check confirms the permission is gone. Failures
+    /// with the permission still granted (e.g. secure input) reset the count.
+    #[allow(clippy::significant_drop_tightening)] // Lock held during check so concurrent insertions check once
+    pub fn record(
+        &self,
+        succeeded: bool,
+        is_trusted: impl FnOnce() -> bool,
+    ) -> Option<AccessChange> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let previous = *state;
+        let mut next = previous.after_insertion(succeeded);
+        if next.suspects_revocation(self.threshold) {
+            next = if is_trusted() {
+                InsertionAccess::Granted { failures: 0 }
+            } else {
+                InsertionAccess::Revoked
+            };
+        }
+        *state = next;
+        InsertionAccess::change(previous, next)
+    }
+}
+
+/// Check permissions at startup, optionally deferring insertion checks to first use
+///
+/// # Errors
+/// Returns error if a permission checked now is denied
+pub fn request_startup_permissions(defer: bool) -> Result<()> {
+    if defer {
+        check_microphone_permission()?;
+        tracing::info!("accessibility/input monitoring checks deferred until first insertion");
+        return Ok(());
+    }
+
+    request_all_permissions()?;
+    INSERTION_GATE.mark_granted();
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+
+    fn gate_state(gate: &PermissionGate) -> PermissionState {
+        *gate.state.lock().unwrap()
+    }
+
+    #[test]
+    fn test_permission_gate_starts_unchecked() {
+        let gate = PermissionGate::new();
+        assert_eq!(gate_state(&gate), PermissionState::Unchecked);
+        assert!(PermissionGate::should_check(gate_state(&gate)));
+    }
+
+    #[test]
+    fn test_permission_gate_should_check() {
+        assert!(PermissionGate::should_check(PermissionState::Unchecked));
+        assert!(PermissionGate::should_check(PermissionState::Denied));
+        assert!(!PermissionGate::should_check(PermissionState::Granted));
+    }
+
+    #[test]
+    fn test_permission_gate_checks_once_when_granted() {
+        let gate = PermissionGate::new();
+        let calls = Cell::new(0);
+
+        for _ in 0..3 {
+            gate.ensure(|| {
+                calls.set(calls.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+        }
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(gate_state(&gate), PermissionState::Granted);
+    }
+
+    #[test]
+    fn test_permission_gate_rechecks_after_denial() {
+        let gate = PermissionGate::new();
+        let calls = Cell::new(0);
+
+        let result = gate.ensure(|| {
+            calls.set(calls.get() + 1);
+            bail!("Accessibility permission denied")
+        });
+        assert!(result.unwrap_err().to_string().contains("denied"));
+        assert_eq!(gate_state(&gate), PermissionState::Denied);
+
+        // User granted permission meanwhile: next attempt checks again and succeeds
+        gate.ensure(|| {
+            calls.set(calls.get() + 1);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(calls.get(), 2);
+        assert_eq!(gate_state(&gate), PermissionState::Granted);
+    }
+
+    fn access_state(monitor: &AccessMonitor) -> InsertionAccess {
+        *monitor.state.lock().unwrap()
+    }
+
+    fn run_outcomes(
+        monitor: &AccessMonitor,
+        outcomes: &[bool],
+        trusted: bool,
+    ) -> Vec<AccessChange> {
+        outcomes
+            .iter()
+            .filter_map(|&succeeded| monitor.record(succeeded, || trusted))
+            .collect()
+    }
+
+    #[test]
+    fn test_insertion_access_counts_consecutive_failures() {
+        let granted = InsertionAccess::Granted { failures: 0 };
+        let state = granted.after_insertion(false).after_insertion(false);
+        assert_eq!(state, InsertionAccess::Granted { failures: 2 });
+        assert!(state.suspects_revocation(2));
+        assert!(!state.suspects_revocation(3));
+        // Disabled detection never suspects
+        assert!(!state.suspects_revocation(0));
+        // A success resets the count
+        assert_eq!(state.after_insertion(true), granted);
+    }
+
+    #[test]
+    fn test_insertion_access_check_transitions() {
+        let granted = InsertionAccess::Granted { failures: 1 };
+        assert_eq!(granted.after_check(false), InsertionAccess::Revoked);
+        assert_eq!(granted.after_check(true), granted);
+        assert_eq!(
+            InsertionAccess::Revoked.after_check(true),
+            InsertionAccess::Granted { failures: 0 }
+        );
+        assert_eq!(
+            InsertionAccess::change(granted, InsertionAccess::Revoked),
+            Some(AccessChange::Revoked)
+        );
+        assert_eq!(InsertionAccess::change(granted, granted), None);
+    }
+
+    #[test]
+    fn test_monitor_revokes_after_repeated_failures() {
+        let monitor = AccessMonitor::new(3);
+        let changes = run_outcomes(&monitor, &[true, false, false], false);
+        assert!(changes.is_empty());
+        assert!(!access_state(&monitor).is_revoked());
+
+        assert_eq!(
+            run_outcomes(&monitor, &[false], false),
+            vec![AccessChange::Revoked]
+        );
+        assert!(access_state(&monitor).is_revoked());
+        assert_eq!(monitor.before_insertion(|| false), (false, None));
+    }
+
+    #[test]
+    fn test_monitor_interrupted_failures_do_not_revoke() {
+        let monitor = AccessMonitor::new(3);
+        let changes = run_outcomes(&monitor, &[false, false, true, false, false, true], false);
+        assert!(changes.is_empty());
+        assert_eq!(
+            access_state(&monitor),
+            InsertionAccess::Granted { failures: 0 }
+        );
+    }
+
+    #[test]
+    fn test_monitor_failures_with_permission_granted_do_not_revoke() {
+        let monitor = AccessMonitor::new(2);
+        let changes = run_outcomes(&monitor, &[false, false, false, false], true);
+        assert!(changes.is_empty());
+        assert!(!access_state(&monitor).is_revoked());
+    }
+
+    #[test]
+    fn test_monitor_resumes_when_permission_restored() {
+        let monitor = AccessMonitor::new(1);
+        assert_eq!(
+            run_outcomes(&monitor, &[false], false),
+            vec![AccessChange::Revoked]
+        );
+        assert_eq!(monitor.before_insertion(|| false), (false, None));
+        assert_eq!(
+            monitor.before_insertion(|| true),
+            (true, Some(AccessChange::Restored))
+        );
+        assert_eq!(monitor.before_insertion(|| false), (true, None));
+    }
+
+    #[test]
+    fn test_monitor_disabled() {
+        let monitor = AccessMonitor::new(0);
+        let checks = Cell::new(0);
+        for _ in 0..10 {
+            assert_eq!(
+                monitor.record(false, || {
+                    checks.set(checks.get() + 1);
+                    false
+                }),
+                None
+            );
+        }
+        assert_eq!(checks.get(), 0);
+        assert!(!access_state(&monitor).is_revoked());
+    }
+
+    #[test]
+    fn test_permission_gate_set_granted_after_denial() {
+        let gate = PermissionGate::new();
+        assert!(gate.ensure(|| bail!("denied")).is_err());
+        assert_eq!(gate_state(&gate), PermissionState::Denied);
+
+        // Granted in System Settings, then re-checked
+        assert!(gate.set_granted(true));
+        assert_eq!(gate_state(&gate), PermissionState::Granted);
+        assert!(gate.ensure(|| bail!("must not re-check")).is_ok());
+
+        // Already enabled: no transition reported
+        assert!(!gate.set_granted(true));
+    }
+
+    #[test]
+    fn test_permission_gate_set_denied() {
+        let gate = PermissionGate::new();
+        gate.mark_granted();
+        assert!(!gate.set_granted(false));
+        assert_eq!(gate_state(&gate), PermissionState::Denied);
+        assert!(PermissionGate::should_check(gate_state(&gate)));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_recheck_permissions_on_non_macos() {
+        assert_eq!(recheck_permissions(), PermissionReport::GRANTED);
+    }
+
+    #[test]
+    fn test_permission_gate_mark_granted_skips_check() {
+        let gate = PermissionGate::new();
+        gate.mark_granted();
+
+        let calls = Cell::new(0);
+        gate.ensure(|| {
+            calls.set(calls.get() + 1);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_request_startup_permissions_deferred_on_non_macos() {
+        assert!(request_startup_permissions(true).is_ok());
+        assert!(ensure_insertion_permissions().is_ok());
+    }
 
     #[test]
     fn test_check_microphone_permission_always_ok() {
@@ -249,6 +913,72 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_permission_report_on_non_macos() {
+        let report = permission_report();
+        assert_eq!(report, PermissionReport::GRANTED);
+        assert_eq!(report.denied_message(true), None);
+    }
+
+    #[test]
+    fn test_perm_status_from_granted() {
+        assert_eq!(PermStatus::from_granted(true), PermStatus::Granted);
+        assert_eq!(PermStatus::from_granted(false), PermStatus::Denied);
+    }
+
+    #[test]
+    fn test_permission_report_denied() {
+        let report = PermissionReport {
+            microphone: PermStatus::Unknown,
+            accessibility: PermStatus::Denied,
+            input_monitoring: PermStatus::Denied,
+        };
+        assert_eq!(report.denied(), vec!["Accessibility", "Input Monitoring"]);
+
+        let report = PermissionReport {
+            input_monitoring: PermStatus::Denied,
+            ..PermissionReport::GRANTED
+        };
+        assert_eq!(report.denied(), vec!["Input Monitoring"]);
+    }
+
+    #[test]
+    fn test_permission_report_unknown_is_not_denied() {
+        let report = PermissionReport {
+            microphone: PermStatus::Unknown,
+            ..PermissionReport::GRANTED
+        };
+        assert!(report.denied().is_empty());
+        assert_eq!(report.denied_message(true), None);
+    }
+
+    #[test]
+    fn test_permission_report_denied_message() {
+        let report = PermissionReport {
+            microphone: PermStatus::Denied,
+            accessibility: PermStatus::Granted,
+            input_monitoring: PermStatus::Denied,
+        };
+        let message = report.denied_message(true).unwrap();
+        assert!(message.starts_with("Missing permissions: Microphone, Input Monitoring\n"));
+        assert!(message.contains("Privacy & Security → Microphone"));
+        assert!(message.contains("Privacy & Security → Input Monitoring"));
+        assert!(!message.contains("→ Accessibility"));
+        assert!(message.ends_with("Then restart the app."));
+    }
+
+    #[test]
+    fn test_permission_report_denied_message_without_restart() {
+        let report = PermissionReport {
+            accessibility: PermStatus::Denied,
+            ..PermissionReport::GRANTED
+        };
+        let message = report.denied_message(false).unwrap();
+        assert!(message.ends_with("Privacy & Security → Accessibility"));
+        assert!(!message.contains("restart"));
+    }
+
     #[test]
     #[ignore = "requires Input Monitoring permission on macOS"]
     fn test_check_input_monitoring_permission() {
@@ -329,13 +1059,13 @@ mod tests {
     #[cfg(target_os = "macos")]
     fn test_request_all_permissions_fails_without_permissions() {
         // In CI without permissions, request_all_permissions should fail
-        // at accessibility check (first permission that requires prompt)
+        // listing the denied permissions
         let result = request_all_permissions();
 
-        // Will fail on accessibility check in CI
         if let Err(e) = result {
             let error_msg = e.to_string();
-            assert!(error_msg.contains("Accessibility permission required"));
+            assert!(error_msg.contains("Missing permissions"));
+            assert!(error_msg.contains("Accessibility"));
         }
         // If all permissions granted (dev machine), that's also fine
     }
@@ -2,34 +2,57 @@ use crate::config::RecordingConfig;
 use anyhow::{Context, Result};
 use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Recording file found in the debug directory
+struct Recording {
+    path: PathBuf,
+    /// Seconds since the epoch, from the file name
+    timestamp: u64,
+    /// File size in bytes
+    size: u64,
+}
+
 /// Clean up old recordings based on retention policy
 ///
-/// Deletes recordings older than `retention_days` OR beyond `max_count` limit.
+/// Deletes recordings older than `retention_days`, beyond the `max_count` limit,
+/// OR beyond the `max_total_mb` size budget.
+/// With `use_trash`, files are moved to `~/.Trash` instead of being unlinked.
 /// Returns the number of files deleted.
 ///
 /// # Errors
 /// Returns error if directory listing fails. Individual file deletion failures are logged but don't stop cleanup.
 pub fn cleanup_old_recordings(config: &RecordingConfig) -> Result<usize> {
     let debug_dir = get_debug_dir()?;
-    cleanup_recordings_in_dir(config, &debug_dir)
+    let trash_dir = if config.use_trash {
+        Some(get_trash_dir()?)
+    } else {
+        None
+    };
+    cleanup_recordings_in_dir(config, &debug_dir, trash_dir.as_deref())
 }
 
-/// Internal cleanup function that accepts directory parameter (more testable)
-fn cleanup_recordings_in_dir(config: &RecordingConfig, dir: &PathBuf) -> Result<usize> {
+/// Internal cleanup function that accepts directory parameters (more testable)
+///
+/// Deleted recordings are moved into `trash_dir` when given.
+fn cleanup_recordings_in_dir(
+    config: &RecordingConfig,
+    dir: &PathBuf,
+    trash_dir: Option<&Path>,
+) -> Result<usize> {
     // If directory doesn't exist, nothing to clean
     if !dir.exists() {
         tracing::debug!("debug directory does not exist, skipping cleanup");
         return Ok(0);
     }
 
-    // Collect all recording files with their timestamps
-    let mut recordings: Vec<(PathBuf, u64)> = fs::read_dir(dir)
+    // Collect all recording files with their timestamps and sizes
+    let mut recordings: Vec<Recording> = fs::read_dir(dir)
         .context("failed to read debug directory")?
         .filter_map(std::result::Result::ok)
         .filter_map(|entry| {
+            let size = entry.metadata().map_or(0, |metadata| metadata.len());
             let path = entry.path();
             if !path.is_file() {
                 return None;
@@ -48,7 +71,11 @@ fn cleanup_recordings_in_dir(config: &RecordingConfig, dir: &PathBuf) -> Result<
             let timestamp_str = filename.strip_prefix("recording_")?.strip_suffix(".wav")?;
             let timestamp: u64 = timestamp_str.parse().ok()?;
 
-            Some((path, timestamp))
+            Some(Recording {
+                path,
+                timestamp,
+                size,
+            })
         })
         .collect();
 
@@ -58,7 +85,7 @@ fn cleanup_recordings_in_dir(config: &RecordingConfig, dir: &PathBuf) -> Result<
     }
 
     // Sort by timestamp (newest first)
-    recordings.sort_by(|a, b| b.1.cmp(&a.1));
+    recordings.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
 
     let mut to_delete = HashSet::new();
 
@@ -70,31 +97,40 @@ fn cleanup_recordings_in_dir(config: &RecordingConfig, dir: &PathBuf) -> Result<
             .as_secs();
         let retention_secs = u64::from(config.retention_days) * 24 * 60 * 60;
 
-        for (path, timestamp) in &recordings {
-            if now.saturating_sub(*timestamp) > retention_secs {
-                to_delete.insert(path.clone());
+        for recording in &recordings {
+            if now.saturating_sub(recording.timestamp) > retention_secs {
+                to_delete.insert(recording.path.clone());
             }
         }
     }
 
     // Apply count-based retention
     if config.max_count > 0 && recordings.len() > config.max_count {
-        for (path, _) in recordings.iter().skip(config.max_count) {
-            to_delete.insert(path.clone());
+        for recording in recordings.iter().skip(config.max_count) {
+            to_delete.insert(recording.path.clone());
+        }
+    }
+
+    // Apply size-based retention: keep the newest recordings that fit the budget
+    if config.max_total_mb > 0 {
+        let budget = config.max_total_mb.saturating_mul(BYTES_PER_MB);
+        let mut total: u64 = 0;
+        for recording in &recordings {
+            if to_delete.contains(&recording.path) {
+                continue;
+            }
+            total = total.saturating_add(recording.size);
+            if total > budget {
+                to_delete.insert(recording.path.clone());
+            }
         }
     }
 
     // Delete files
     let mut deleted_count = 0;
     for path in to_delete {
-        match fs::remove_file(&path) {
-            Ok(()) => {
-                deleted_count += 1;
-                tracing::debug!("deleted recording: {}", path.display());
-            }
-            Err(e) => {
-                tracing::warn!("failed to delete {}: {}", path.display(), e);
-            }
+        if remove_recording(&path, trash_dir) {
+            deleted_count += 1;
         }
     }
 
@@ -110,11 +146,92 @@ fn cleanup_recordings_in_dir(config: &RecordingConfig, dir: &PathBuf) -> Result<
     Ok(deleted_count)
 }
 
+/// Move `path` into `trash_dir` (when given) or delete it, returning whether it's gone
+///
+/// A failed move to the Trash falls back to deleting the file.
+fn remove_recording(path: &Path, trash_dir: Option<&Path>) -> bool {
+    if let Some(trash_dir) = trash_dir {
+        match move_to_trash(path, trash_dir) {
+            Ok(trashed) => {
+                tracing::debug!(
+                    "moved recording to Trash: {} -> {}",
+                    path.display(),
+                    trashed.display()
+                );
+                return true;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "failed to move {} to Trash, deleting instead: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+    match fs::remove_file(path) {
+        Ok(()) => {
+            tracing::debug!("deleted recording: {}", path.display());
+            true
+        }
+        Err(e) => {
+            tracing::warn!("failed to delete {}: {}", path.display(), e);
+            false
+        }
+    }
+}
+
+/// Bytes in one MB of `max_total_mb`
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
 fn get_debug_dir() -> Result<PathBuf> {
     let home = std::env::var("HOME").context("HOME environment variable not set")?;
     Ok(PathBuf::from(home).join(".whisper-hotkey").join("debug"))
 }
 
+fn get_trash_dir() -> Result<PathBuf> {
+    let home = std::env::var("HOME").context("HOME environment variable not set")?;
+    Ok(PathBuf::from(home).join(".Trash"))
+}
+
+/// Path in `dir` for a file named `file_name` that doesn't exist yet
+///
+/// Taken names get a Finder-style counter before the extension:
+/// `recording_1.wav`, `recording_1 2.wav`, `recording_1 3.wav`, ...
+fn unique_destination(dir: &Path, file_name: &Path) -> PathBuf {
+    let candidate = dir.join(file_name);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let stem = file_name
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    let extension = file_name
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    (2..=u32::MAX)
+        .map(|n| dir.join(format!("{stem} {n}{extension}")))
+        .find(|candidate| !candidate.exists())
+        .unwrap_or(candidate)
+}
+
+/// Move `path` into `trash_dir` under a name that doesn't clash, returning the new path
+///
+/// # Errors
+/// Returns error if `path` has no file name or the rename fails (e.g. no Trash
+/// directory, or it's on another volume)
+fn move_to_trash(path: &Path, trash_dir: &Path) -> std::io::Result<PathBuf> {
+    let file_name = path.file_name().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "path has no file name")
+    })?;
+    let destination = unique_destination(trash_dir, Path::new(file_name));
+    fs::rename(path, &destination)?;
+    Ok(destination)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +258,12 @@ mod tests {
         path
     }
 
+    fn create_sized_recording(dir: &Path, timestamp: u64, size: usize) -> PathBuf {
+        let path = dir.join(format!("recording_{timestamp}.wav"));
+        fs::write(&path, vec![0_u8; size]).unwrap();
+        path
+    }
+
     #[test]
     fn test_get_debug_dir() {
         let dir = get_debug_dir().unwrap();
@@ -156,10 +279,11 @@ mod tests {
             retention_days: 7,
             max_count: 100,
             cleanup_interval_hours: 1,
+            ..Default::default()
         };
 
         // Test existing but empty directory - should return 0 deleted
-        let deleted = cleanup_recordings_in_dir(&config, &test_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
         assert_eq!(deleted, 0);
 
         let _ = fs::remove_dir_all(&test_dir);
@@ -175,9 +299,10 @@ mod tests {
             retention_days: 7,
             max_count: 100,
             cleanup_interval_hours: 1,
+            ..Default::default()
         };
 
-        let deleted = cleanup_recordings_in_dir(&config, &nonexistent_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &nonexistent_dir, None).unwrap();
         assert_eq!(deleted, 0);
 
         let _ = fs::remove_dir_all(&test_dir);
@@ -205,9 +330,10 @@ mod tests {
             retention_days: 7,
             max_count: 0,
             cleanup_interval_hours: 1,
+            ..Default::default()
         };
 
-        let deleted = cleanup_recordings_in_dir(&config, &test_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
         assert_eq!(deleted, 1);
 
         // Verify old file deleted, recent remains
@@ -237,9 +363,10 @@ mod tests {
             retention_days: 0,
             max_count: 3,
             cleanup_interval_hours: 1,
+            ..Default::default()
         };
 
-        let deleted = cleanup_recordings_in_dir(&config, &test_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
         assert_eq!(deleted, 2);
 
         // Verify 3 most recent remain
@@ -287,9 +414,10 @@ mod tests {
             retention_days: 7,
             max_count: 3,
             cleanup_interval_hours: 1,
+            ..Default::default()
         };
 
-        let deleted = cleanup_recordings_in_dir(&config, &test_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
         assert_eq!(deleted, 2); // 1 old + 1 exceeding count
 
         // Verify 3 most recent remain
@@ -321,9 +449,10 @@ mod tests {
             retention_days: 0,
             max_count: 0,
             cleanup_interval_hours: 0,
+            ..Default::default()
         };
 
-        let deleted = cleanup_recordings_in_dir(&config, &test_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
         assert_eq!(deleted, 0);
 
         // All files should remain
@@ -355,9 +484,10 @@ mod tests {
             retention_days: 7,
             max_count: 0,
             cleanup_interval_hours: 1,
+            ..Default::default()
         };
 
-        let deleted = cleanup_recordings_in_dir(&config, &test_dir).unwrap();
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
         assert_eq!(deleted, 1); // Only the valid old recording
 
         // Other files should still exist
@@ -367,4 +497,192 @@ mod tests {
 
         let _ = fs::remove_dir_all(&test_dir);
     }
+
+    #[test]
+    fn test_cleanup_size_based() {
+        let test_dir = create_test_dir();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mb = usize::try_from(BYTES_PER_MB).unwrap();
+
+        // Newest first: 1 MB, 1.5 MB, 1 MB, 0.5 MB
+        let newest = create_sized_recording(&test_dir, now, mb);
+        let second = create_sized_recording(&test_dir, now - 60, mb + mb / 2);
+        let third = create_sized_recording(&test_dir, now - 120, mb);
+        let oldest = create_sized_recording(&test_dir, now - 180, mb / 2);
+
+        let config = RecordingConfig {
+            retention_days: 0,
+            max_count: 0,
+            max_total_mb: 3,
+            ..Default::default()
+        };
+
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
+        assert_eq!(deleted, 2);
+
+        // 2.5 MB kept; the third would bring it to 3.5 MB, so it and everything older go
+        assert!(newest.exists());
+        assert!(second.exists());
+        assert!(!third.exists());
+        assert!(!oldest.exists());
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_cleanup_size_budget_ignores_files_deleted_by_count() {
+        let test_dir = create_test_dir();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mb = usize::try_from(BYTES_PER_MB).unwrap();
+
+        for i in 0..3 {
+            create_sized_recording(&test_dir, now - i * 60, mb);
+        }
+
+        let config = RecordingConfig {
+            retention_days: 0,
+            max_count: 2,
+            max_total_mb: 2,
+            ..Default::default()
+        };
+
+        // Count removes the oldest; the remaining 2 MB fit the budget exactly
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, None).unwrap();
+        assert_eq!(deleted, 1);
+        assert_eq!(fs::read_dir(&test_dir).unwrap().count(), 2);
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_cleanup_size_zero_disables_budget() {
+        let test_dir = create_test_dir();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        for i in 0..3 {
+            create_sized_recording(&test_dir, now - i * 60, 1024 * 1024);
+        }
+
+        let config = RecordingConfig {
+            retention_days: 0,
+            max_count: 0,
+            max_total_mb: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            cleanup_recordings_in_dir(&config, &test_dir, None).unwrap(),
+            0
+        );
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_unique_destination_free_name() {
+        let test_dir = create_test_dir();
+        assert_eq!(
+            unique_destination(&test_dir, Path::new("recording_1.wav")),
+            test_dir.join("recording_1.wav")
+        );
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_unique_destination_counts_past_taken_names() {
+        let test_dir = create_test_dir();
+        fs::write(test_dir.join("recording_1.wav"), b"old").unwrap();
+        assert_eq!(
+            unique_destination(&test_dir, Path::new("recording_1.wav")),
+            test_dir.join("recording_1 2.wav")
+        );
+
+        fs::write(test_dir.join("recording_1 2.wav"), b"old").unwrap();
+        fs::write(test_dir.join("recording_1 3.wav"), b"old").unwrap();
+        assert_eq!(
+            unique_destination(&test_dir, Path::new("recording_1.wav")),
+            test_dir.join("recording_1 4.wav")
+        );
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_unique_destination_without_extension() {
+        let test_dir = create_test_dir();
+        fs::write(test_dir.join("notes"), b"old").unwrap();
+        assert_eq!(
+            unique_destination(&test_dir, Path::new("notes")),
+            test_dir.join("notes 2")
+        );
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_cleanup_moves_to_trash() {
+        let test_dir = create_test_dir();
+        let trash_dir = test_dir.join(".Trash");
+        fs::create_dir_all(&trash_dir).unwrap();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let old = create_recording(&test_dir, now - 10 * 24 * 60 * 60);
+        // Same name already in the Trash from an earlier cleanup
+        let file_name = old.file_name().unwrap().to_owned();
+        fs::write(trash_dir.join(&file_name), b"earlier").unwrap();
+
+        let config = RecordingConfig {
+            retention_days: 7,
+            max_count: 0,
+            use_trash: true,
+            ..Default::default()
+        };
+
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, Some(&trash_dir)).unwrap();
+        assert_eq!(deleted, 1);
+        assert!(!old.exists());
+        assert_eq!(fs::read(trash_dir.join(&file_name)).unwrap(), b"earlier");
+        let stem = Path::new(&file_name).file_stem().unwrap().to_string_lossy();
+        assert_eq!(
+            fs::read(trash_dir.join(format!("{stem} 2.wav"))).unwrap(),
+            b"fake wav data"
+        );
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_cleanup_falls_back_to_delete_without_trash() {
+        let test_dir = create_test_dir();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let old = create_recording(&test_dir, now - 10 * 24 * 60 * 60);
+
+        let config = RecordingConfig {
+            retention_days: 7,
+            max_count: 0,
+            use_trash: true,
+            ..Default::default()
+        };
+
+        let missing_trash = test_dir.join("no-such-trash");
+        let deleted = cleanup_recordings_in_dir(&config, &test_dir, Some(&missing_trash)).unwrap();
+        assert_eq!(deleted, 1);
+        assert!(!old.exists());
+        assert!(!missing_trash.exists());
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
 }
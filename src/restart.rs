@@ -0,0 +1,187 @@
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Program and arguments that relaunch the running app
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelaunchCommand {
+    /// Executable to run
+    pub program: PathBuf,
+    /// Arguments, without the program name
+    pub args: Vec<OsString>,
+}
+
+impl RelaunchCommand {
+    /// Relaunch `program` with `argv` as the process received it (pure)
+    ///
+    /// `argv[0]` is the name the app was started as, which may be relative or a
+    /// symlink; `program` (the resolved executable) replaces it.
+    #[must_use]
+    pub fn from_argv(program: PathBuf, argv: impl IntoIterator<Item = OsString>) -> Self {
+        Self {
+            program,
+            args: argv.into_iter().skip(1).collect(),
+        }
+    }
+
+    /// Relaunch command for the current process
+    ///
+    /// # Errors
+    /// Returns error if the running executable can't be determined
+    pub fn current() -> Result<Self> {
+        let program = std::env::current_exe().context("failed to locate running executable")?;
+        Ok(Self::from_argv(program, std::env::args_os()))
+    }
+
+    /// Process command for this relaunch
+    #[must_use]
+    pub fn to_command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        command
+    }
+}
+
+/// Steps of a graceful restart (enables testing via mocking)
+#[cfg_attr(test, mockall::automock)]
+pub trait RestartSteps {
+    /// Unregister global hotkeys and remove the tray icon
+    ///
+    /// The new process registers the same hotkeys, and exec skips destructors.
+    fn unregister_hotkeys(&mut self);
+
+    /// Flush buffered output so nothing is lost when the process image is replaced
+    ///
+    /// # Errors
+    /// Returns error if a flush fails
+    fn flush_state(&mut self) -> Result<()>;
+
+    /// Replace the process with `command`, only returning on failure
+    fn exec(&mut self, command: &RelaunchCommand) -> anyhow::Error;
+}
+
+/// Shut down gracefully, then replace the process with `command`
+///
+/// Only returns if the relaunch failed. A failed flush is logged and the
+/// restart goes ahead: a relaunch matters more than the last buffered output.
+pub fn restart(steps: &mut impl RestartSteps, command: &RelaunchCommand) -> anyhow::Error {
+    tracing::info!(program = %command.program.display(), "🔄 Restarting");
+    steps.unregister_hotkeys();
+    if let Err(e) = steps.flush_state() {
+        tracing::warn!(error = %e, "failed to flush state before restart");
+    }
+    steps.exec(command)
+}
+
+/// Replace the current process with `command` (only returns on failure)
+#[cfg(unix)]
+#[must_use]
+pub fn exec(command: &RelaunchCommand) -> anyhow::Error {
+    use std::os::unix::process::CommandExt;
+
+    anyhow::Error::new(command.to_command().exec())
+        .context(format!("failed to relaunch {}", command.program.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use mockall::Sequence;
+
+    fn command() -> RelaunchCommand {
+        RelaunchCommand::from_argv(
+            PathBuf::from("/Applications/WhisperHotkey.app/Contents/MacOS/whisper-hotkey"),
+            ["whisper-hotkey", "--quiet"].map(OsString::from),
+        )
+    }
+
+    #[test]
+    fn test_from_argv_skips_program_name() {
+        let command = command();
+        assert_eq!(
+            command.program,
+            PathBuf::from("/Applications/WhisperHotkey.app/Contents/MacOS/whisper-hotkey")
+        );
+        assert_eq!(command.args, vec![OsString::from("--quiet")]);
+    }
+
+    #[test]
+    fn test_from_argv_without_args() {
+        let command = RelaunchCommand::from_argv(PathBuf::from("/bin/app"), Vec::new());
+        assert!(command.args.is_empty());
+        let command =
+            RelaunchCommand::from_argv(PathBuf::from("/bin/app"), [OsString::from("./app")]);
+        assert!(command.args.is_empty());
+    }
+
+    #[test]
+    fn test_to_command_keeps_args() {
+        let command = RelaunchCommand::from_argv(
+            PathBuf::from("/bin/app"),
+            ["app", "--profile", "work notes"].map(OsString::from),
+        );
+        let process = command.to_command();
+        assert_eq!(process.get_program(), "/bin/app");
+        let args: Vec<_> = process.get_args().collect();
+        assert_eq!(args, ["--profile", "work notes"]);
+    }
+
+    #[test]
+    fn test_current_uses_running_executable() {
+        let command = RelaunchCommand::current().unwrap();
+        assert_eq!(command.program, std::env::current_exe().unwrap());
+    }
+
+    #[test]
+    fn test_restart_runs_steps_in_order() {
+        let mut seq = Sequence::new();
+        let mut steps = MockRestartSteps::new();
+        steps
+            .expect_unregister_hotkeys()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_const(());
+        steps
+            .expect_flush_state()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|| Ok(()));
+        steps
+            .expect_exec()
+            .withf(|command| command.args == [OsString::from("--quiet")])
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| anyhow!("exec failed"));
+
+        let error = restart(&mut steps, &command());
+        assert_eq!(error.to_string(), "exec failed");
+    }
+
+    #[test]
+    fn test_restart_continues_after_flush_failure() {
+        let mut steps = MockRestartSteps::new();
+        steps.expect_unregister_hotkeys().times(1).return_const(());
+        steps
+            .expect_flush_state()
+            .times(1)
+            .returning(|| Err(anyhow!("stdout closed")));
+        steps
+            .expect_exec()
+            .times(1)
+            .returning(|_| anyhow!("exec failed"));
+
+        restart(&mut steps, &command());
+    }
+
+    #[test]
+    fn test_exec_missing_program_fails() {
+        let command = RelaunchCommand::from_argv(
+            PathBuf::from("/nonexistent/whisper-hotkey"),
+            Vec::<OsString>::new(),
+        );
+        let error = exec(&command);
+        assert!(format!("{error:#}").contains("/nonexistent/whisper-hotkey"));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::config::{Config, StatsConfig};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Usage totals for one profile
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct ProfileStats {
+    /// Completed transcriptions
+    pub invocations: u64,
+    /// Recorded audio, in seconds
+    pub audio_secs: f64,
+    /// Characters produced (after aliases/formatting)
+    pub chars: u64,
+}
+
+/// Per-profile usage totals (contents of the stats file)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct UsageStats {
+    /// Totals keyed by profile name
+    pub profiles: BTreeMap<String, ProfileStats>,
+}
+
+impl UsageStats {
+    /// Add one transcription to `profile`'s totals
+    pub fn record(&mut self, profile: &str, audio_secs: f64, chars: usize) {
+        let stats = self.profiles.entry(profile.to_owned()).or_default();
+        stats.invocations += 1;
+        stats.audio_secs += audio_secs;
+        stats.chars += u64::try_from(chars).unwrap_or(u64::MAX);
+    }
+
+    /// Table of per-profile totals, most used first, with a total row
+    #[must_use]
+    pub fn report(&self) -> String {
+        if self.profiles.is_empty() {
+            return "No usage recorded yet\n".to_owned();
+        }
+
+        let mut rows: Vec<(&str, &ProfileStats)> = self
+            .profiles
+            .iter()
+            .map(|(name, stats)| (name.as_str(), stats))
+            .collect();
+        rows.sort_by(|a, b| b.1.invocations.cmp(&a.1.invocations).then(a.0.cmp(b.0)));
+
+        let total = ProfileStats {
+            invocations: rows.iter().map(|(_, s)| s.invocations).sum(),
+            audio_secs: rows.iter().map(|(_, s)| s.audio_secs).sum(),
+            chars: rows.iter().map(|(_, s)| s.chars).sum(),
+        };
+        let width = rows
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Profile".len());
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<width$}  {:>6}  {:>9}  {:>8}",
+            "Profile", "Uses", "Audio", "Chars"
+        );
+        for (name, stats) in rows.iter().copied().chain([("Total", &total)]) {
+            let _ = writeln!(
+                out,
+                "{:<width$}  {:>6}  {:>9}  {:>8}",
+                name,
+                stats.invocations,
+                format_duration(stats.audio_secs),
+                stats.chars
+            );
+        }
+        out
+    }
+}
+
+/// Compact duration for the report (`42s`, `3m 05s`, `1h 02m`)
+fn format_duration(secs: f64) -> String {
+    // Whole seconds are enough for a usage summary
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let secs = secs.max(0.0).round() as u64;
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s:02}s"),
+        (h, m, _) => format!("{h}h {m:02}m"),
+    }
+}
+
+/// Load the stats file (missing file = no usage yet)
+///
+/// # Errors
+/// Returns error if the file exists but can't be read or parsed
+pub fn load(path: &Path) -> Result<UsageStats> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse stats file {}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(UsageStats::default()),
+        Err(e) => Err(e).with_context(|| format!("failed to read stats file {}", path.display())),
+    }
+}
+
+/// Persistent per-profile usage counters
+pub struct StatsLog {
+    path: PathBuf,
+    /// Serializes read-modify-write from concurrent transcriptions
+    write_lock: Mutex<()>,
+}
+
+impl StatsLog {
+    /// Create log stored at `path`
+    #[must_use]
+    pub const fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Create log from `[stats]` config (`None` when disabled)
+    ///
+    /// # Errors
+    /// Returns error if the stats path can't be expanded
+    pub fn from_config(config: &StatsConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let path = Config::expand_path(&config.path).context("failed to expand stats path")?;
+        Ok(Some(Self::new(path)))
+    }
+
+    /// Add one transcription to `profile`'s totals and save
+    ///
+    /// # Errors
+    /// Returns error if the stats file can't be read or written
+    pub fn record(&self, profile: &str, audio_secs: f64, chars: usize) -> Result<()> {
+        let _guard = self
+            .write_lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let mut stats = load(&self.path)?;
+        stats.record(profile, audio_secs, chars);
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).context("failed to create stats directory")?;
+        }
+        let json = serde_json::to_string_pretty(&stats).context("failed to serialize stats")?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, json).context("failed to write stats")?;
+        fs::rename(&tmp_path, &self.path).context("failed to replace stats file")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_stats() -> UsageStats {
+        let mut stats = UsageStats::default();
+        for (profile, secs, chars) in [
+            ("base.en", 4.0, 40),
+            ("medical", 30.5, 300),
+            ("base.en", 6.0, 60),
+            ("base.en", 2.5, 0),
+        ] {
+            stats.record(profile, secs, chars);
+        }
+        stats
+    }
+
+    #[test]
+    fn test_record_accumulates_per_profile() {
+        let stats = sample_stats();
+        assert_eq!(
+            stats.profiles["base.en"],
+            ProfileStats {
+                invocations: 3,
+                audio_secs: 12.5,
+                chars: 100,
+            }
+        );
+        assert_eq!(
+            stats.profiles["medical"],
+            ProfileStats {
+                invocations: 1,
+                audio_secs: 30.5,
+                chars: 300,
+            }
+        );
+    }
+
+    #[test]
+    fn test_report_breakdown() {
+        let report = sample_stats().report();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Profile    Uses      Audio     Chars",
+                "base.en       3        13s       100",
+                "medical       1        31s       300",
+                "Total         4        43s       400",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_empty() {
+        assert_eq!(UsageStats::default().report(), "No usage recorded yet\n");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0.0), "0s");
+        assert_eq!(format_duration(42.4), "42s");
+        assert_eq!(format_duration(185.0), "3m 05s");
+        assert_eq!(format_duration(3720.0), "1h 02m");
+    }
+
+    #[test]
+    fn test_stats_log_persists() {
+        let dir = std::env::temp_dir().join(format!("whisper-stats-test-{}", std::process::id()));
+        let path = dir.join("stats.json");
+        let _ = fs::remove_dir_all(&dir);
+
+        let log = StatsLog::new(path.clone());
+        log.record("base.en", 3.0, 10).unwrap();
+        log.record("base.en", 2.0, 5).unwrap();
+
+        let stats = load(&path).unwrap();
+        assert_eq!(stats.profiles["base.en"].invocations, 2);
+        assert_eq!(stats.profiles["base.en"].chars, 15);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let stats = load(Path::new("/nonexistent/whisper-stats.json")).unwrap();
+        assert!(stats.profiles.is_empty());
+    }
+}
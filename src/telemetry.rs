@@ -1,15 +1,23 @@
 use anyhow::{Context, Result};
-use std::fs::{self, OpenOptions};
-use std::io;
-use std::path::PathBuf;
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+use crate::audio::CaptureTimings;
+use crate::config::{LogFormat, TelemetryConfig};
 
 /// Initialize telemetry logging
 ///
 /// # Errors
 /// Returns error if log directory creation or file opening fails
-pub fn init(enabled: bool, log_path: &str) -> Result<()> {
-    if !enabled {
+pub fn init(config: &TelemetryConfig) -> Result<()> {
+    if !config.enabled {
         // Basic stdout logging only
         tracing_subscriber::fmt()
             .with_target(false)
@@ -18,19 +26,20 @@ pub fn init(enabled: bool, log_path: &str) -> Result<()> {
         return Ok(());
     }
 
-    let expanded_path = expand_log_path(log_path)?;
+    let expanded_path = expand_log_path(&config.log_path)?;
 
     // Create parent directory if needed
     if let Some(parent) = expanded_path.parent() {
         fs::create_dir_all(parent).context("failed to create log directory")?;
     }
 
-    // Set up file appender
-    let file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&expanded_path)
-        .context("failed to open log file")?;
+    // Set up file appender, rolled over by size
+    let file = RotatingFile::open(
+        expanded_path.clone(),
+        config.log_max_mb.saturating_mul(1024 * 1024),
+        config.log_keep_files,
+    )
+    .context("failed to open log file")?;
 
     // Create console layer (with colors for terminal)
     let console_layer = fmt::layer()
@@ -38,12 +47,7 @@ pub fn init(enabled: bool, log_path: &str) -> Result<()> {
         .with_writer(io::stdout)
         .compact();
 
-    // Create file layer (no colors for file)
-    let file_layer = fmt::layer()
-        .with_target(false)
-        .with_writer(file)
-        .with_ansi(false)
-        .compact();
+    let file_layer = file_layer(config.format, Mutex::new(file));
 
     // Combine layers with env filter (defaults to "info" if RUST_LOG not set)
     let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
@@ -59,6 +63,276 @@ pub fn init(enabled: bool, log_path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Log file layer writing `format` lines to `writer` (no colors for file)
+fn file_layer<S, W>(format: LogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let layer = fmt::layer().with_writer(writer).with_ansi(false);
+    match format {
+        LogFormat::Text => layer.with_target(false).compact().boxed(),
+        LogFormat::Json => layer.json().with_target(true).boxed(),
+    }
+}
+
+/// Whether writing `incoming` bytes to a log of `current` bytes should roll it over first (pure)
+///
+/// `max_bytes` of 0 disables rotation. An empty log is never rolled over, so a
+/// single oversized write still lands somewhere.
+#[must_use]
+pub const fn should_rotate(current: u64, incoming: u64, max_bytes: u64) -> bool {
+    max_bytes > 0 && current > 0 && current.saturating_add(incoming) > max_bytes
+}
+
+/// Path of the `index`th rolled-over log (`crash.log` → `crash.log.1`)
+fn archive_path(path: &Path, index: u32) -> PathBuf {
+    let mut archive = path.as_os_str().to_owned();
+    archive.push(format!(".{index}"));
+    PathBuf::from(archive)
+}
+
+/// Shift `crash.log.N` to `crash.log.N+1` (dropping the oldest) and move the log to `crash.log.1`
+///
+/// With `keep` of 0 the log is simply removed.
+fn roll_over(path: &Path, keep: u32) -> io::Result<()> {
+    if keep == 0 {
+        return fs::remove_file(path);
+    }
+    match fs::remove_file(archive_path(path, keep)) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    for index in (1..keep).rev() {
+        let from = archive_path(path, index);
+        if from.exists() {
+            fs::rename(&from, archive_path(path, index + 1))?;
+        }
+    }
+    fs::rename(path, archive_path(path, 1))
+}
+
+/// Log file that rolls over to numbered archives once it exceeds a size
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    keep: u32,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64, keep: u32) -> io::Result<Self> {
+        let file = Self::append(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            size,
+            max_bytes,
+            keep,
+        })
+    }
+
+    fn append(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        roll_over(&self.path, self.keep)?;
+        self.file = Self::append(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    #[allow(clippy::print_stderr)] // Tracing would write back into this file
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if should_rotate(self.size, buf.len() as u64, self.max_bytes) {
+            // Keep appending to the current file instead of failing every write
+            if let Err(e) = self.rotate() {
+                eprintln!("failed to rotate log {}: {e}", self.path.display());
+                self.max_bytes = 0;
+            }
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Per-stage durations of one transcription (`[telemetry] timing_debug`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimingRecord {
+    /// Draining the capture ring buffer
+    pub drain: Duration,
+    /// Downmixing and resampling to 16kHz mono
+    pub resample: Duration,
+    /// Queued behind other transcriptions or waiting for the model to load
+    pub wait: Duration,
+    /// Whisper inference (including latency fallback retries)
+    pub inference: Duration,
+    /// Output pipeline: markdown stripping, translation, aliases, formatting
+    pub formatting: Duration,
+    /// Text insertion (zero when nothing was inserted)
+    pub insertion: Duration,
+}
+
+impl TimingRecord {
+    /// Start a record from the capture stage timings
+    #[must_use]
+    pub const fn from_capture(capture: CaptureTimings) -> Self {
+        Self {
+            drain: capture.drain,
+            resample: capture.resample,
+            wait: Duration::ZERO,
+            inference: Duration::ZERO,
+            formatting: Duration::ZERO,
+            insertion: Duration::ZERO,
+        }
+    }
+
+    /// End-to-end time: the stages run back to back from key release to insertion
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.drain + self.resample + self.wait + self.inference + self.formatting + self.insertion
+    }
+
+    /// One-line breakdown in milliseconds
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "drain {}ms, resample {}ms, wait {}ms, inference {}ms, formatting {}ms, insertion {}ms, total {}ms",
+            self.drain.as_millis(),
+            self.resample.as_millis(),
+            self.wait.as_millis(),
+            self.inference.as_millis(),
+            self.formatting.as_millis(),
+            self.insertion.as_millis(),
+            self.total().as_millis()
+        )
+    }
+
+    /// Emit the record as one structured log event
+    pub fn log(&self) {
+        tracing::info!(
+            drain_ms = self.drain.as_millis(),
+            resample_ms = self.resample.as_millis(),
+            wait_ms = self.wait.as_millis(),
+            inference_ms = self.inference.as_millis(),
+            formatting_ms = self.formatting.as_millis(),
+            insertion_ms = self.insertion.as_millis(),
+            total_ms = self.total().as_millis(),
+            "⏱️  Timing: {}",
+            self.summary()
+        );
+    }
+}
+
+/// Running transcription latency totals since startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    /// Completed transcriptions
+    pub count: u64,
+    /// Audio transcribed, in 16kHz samples
+    pub samples: u64,
+    /// Sum of inference times
+    pub total_ms: u128,
+    /// Fastest inference (0 before the first transcription)
+    pub min_ms: u128,
+    /// Slowest inference
+    pub max_ms: u128,
+}
+
+impl Metrics {
+    /// No transcriptions yet
+    pub const EMPTY: Self = Self {
+        count: 0,
+        samples: 0,
+        total_ms: 0,
+        min_ms: 0,
+        max_ms: 0,
+    };
+
+    /// Add one transcription of `samples` that took `ms`
+    pub fn record(&mut self, samples: usize, ms: u128) {
+        self.min_ms = if self.count == 0 {
+            ms
+        } else {
+            self.min_ms.min(ms)
+        };
+        self.max_ms = self.max_ms.max(ms);
+        self.count += 1;
+        self.samples = self
+            .samples
+            .saturating_add(u64::try_from(samples).unwrap_or(u64::MAX));
+        self.total_ms = self.total_ms.saturating_add(ms);
+    }
+
+    /// Mean inference time (None before the first transcription)
+    #[must_use]
+    pub fn avg_ms(&self) -> Option<u128> {
+        (self.count > 0).then(|| self.total_ms / u128::from(self.count))
+    }
+
+    /// One-line summary
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.avg_ms().map_or_else(
+            || "no transcriptions".to_owned(),
+            |avg| {
+                format!(
+                    "{} transcriptions, {:.1}s audio, inference avg {avg}ms, min {}ms, max {}ms, total {}ms",
+                    self.count,
+                    Duration::from_millis(self.samples.saturating_mul(1000) / 16000).as_secs_f64(),
+                    self.min_ms,
+                    self.max_ms,
+                    self.total_ms
+                )
+            },
+        )
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// Latency totals shared by all transcription threads
+static METRICS: Mutex<Metrics> = Mutex::new(Metrics::EMPTY);
+
+/// Add one transcription of `samples` (16kHz) whose inference took `ms`
+pub fn record_transcription(samples: usize, ms: u128) {
+    METRICS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .record(samples, ms);
+}
+
+/// Latency totals recorded so far
+#[must_use]
+pub fn metrics_snapshot() -> Metrics {
+    *METRICS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Time since `start`, restarting it for the next stage
+pub fn lap(start: &mut Instant) -> Duration {
+    let now = Instant::now();
+    let elapsed = now.saturating_duration_since(*start);
+    *start = now;
+    elapsed
+}
+
 fn expand_log_path(path: &str) -> Result<PathBuf> {
     if let Some(stripped) = path.strip_prefix("~/") {
         let home = std::env::var("HOME").context("HOME environment variable not set")?;
@@ -129,6 +403,89 @@ mod tests {
         assert_eq!(result, PathBuf::from("/logs/~backup/app.log"));
     }
 
+    #[test]
+    fn test_should_rotate() {
+        assert!(!should_rotate(500, 100, 1000));
+        assert!(!should_rotate(900, 100, 1000));
+        assert!(should_rotate(901, 100, 1000));
+        assert!(should_rotate(2000, 1, 1000));
+        assert!(should_rotate(u64::MAX, u64::MAX, 1000));
+    }
+
+    #[test]
+    fn test_should_rotate_disabled_or_empty() {
+        assert!(!should_rotate(u64::MAX, 100, 0));
+        // Oversized first write goes to the fresh log
+        assert!(!should_rotate(0, 5000, 1000));
+    }
+
+    #[test]
+    fn test_archive_path() {
+        assert_eq!(
+            archive_path(Path::new("/logs/crash.log"), 2),
+            PathBuf::from("/logs/crash.log.2")
+        );
+    }
+
+    #[test]
+    fn test_rotating_file_rolls_over() {
+        let dir = env::temp_dir().join(format!("whisper-telemetry-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("crash.log");
+        let mut file = RotatingFile::open(path.clone(), 10, 2).unwrap();
+        for line in ["first-1\n", "second\n", "third-\n", "fourth\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(
+            fs::read_to_string(archive_path(&path, 1)).unwrap(),
+            "third-\n"
+        );
+        assert_eq!(
+            fs::read_to_string(archive_path(&path, 2)).unwrap(),
+            "second\n"
+        );
+        // Oldest archive dropped beyond `keep`
+        assert!(!archive_path(&path, 3).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Log one event through a file layer of `format` and return the written text
+    fn log_line(format: LogFormat, name: &str) -> String {
+        let dir = env::temp_dir().join(format!("whisper-telemetry-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("crash.log");
+        let file = RotatingFile::open(path.clone(), 0, 0).unwrap();
+        let subscriber = tracing_subscriber::registry().with(file_layer(format, Mutex::new(file)));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(samples = 16000, "model loaded");
+        });
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        text
+    }
+
+    #[test]
+    fn test_file_layer_text() {
+        let line = log_line(LogFormat::Text, "text");
+        assert!(line.contains("WARN"));
+        assert!(line.contains("model loaded"));
+        assert!(line.contains("samples=16000"));
+    }
+
+    #[test]
+    fn test_file_layer_json() {
+        let text = log_line(LogFormat::Json, "json");
+        let line: serde_json::Value = serde_json::from_str(text.trim()).unwrap();
+        assert!(line["timestamp"].is_string());
+        assert_eq!(line["level"], "WARN");
+        assert_eq!(line["target"], module_path!());
+        assert_eq!(line["fields"]["message"], "model loaded");
+        assert_eq!(line["fields"]["samples"], 16000);
+    }
+
     #[test]
     #[ignore = "requires filesystem access and global tracing subscriber"]
     fn test_init_with_telemetry_enabled() {
@@ -154,4 +511,100 @@ mod tests {
         // 2. Verify parent directory creation
         // Skip for now as it's integration-level testing
     }
+
+    fn record() -> TimingRecord {
+        TimingRecord {
+            drain: Duration::from_millis(2),
+            resample: Duration::from_millis(5),
+            wait: Duration::from_millis(40),
+            inference: Duration::from_millis(800),
+            formatting: Duration::from_micros(1500),
+            insertion: Duration::from_millis(30),
+        }
+    }
+
+    #[test]
+    fn test_timing_record_from_capture() {
+        let capture = CaptureTimings {
+            drain: Duration::from_millis(3),
+            resample: Duration::from_millis(7),
+        };
+        let timing = TimingRecord::from_capture(capture);
+        assert_eq!(timing.drain, Duration::from_millis(3));
+        assert_eq!(timing.resample, Duration::from_millis(7));
+        assert_eq!(timing.inference, Duration::ZERO);
+        assert_eq!(timing.total(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_timing_record_total_sums_stages() {
+        assert_eq!(record().total(), Duration::from_micros(878_500));
+        assert_eq!(TimingRecord::default().total(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_timing_record_summary() {
+        assert_eq!(
+            record().summary(),
+            "drain 2ms, resample 5ms, wait 40ms, inference 800ms, formatting 1ms, insertion 30ms, total 878ms"
+        );
+    }
+
+    #[test]
+    fn test_metrics_running_average() {
+        let mut metrics = Metrics::default();
+        assert_eq!(metrics.avg_ms(), None);
+        assert_eq!(metrics.summary(), "no transcriptions");
+
+        metrics.record(16000, 400);
+        assert_eq!(metrics.avg_ms(), Some(400));
+        assert_eq!((metrics.min_ms, metrics.max_ms), (400, 400));
+
+        metrics.record(32000, 200);
+        metrics.record(8000, 900);
+        assert_eq!(metrics.count, 3);
+        assert_eq!(metrics.samples, 56000);
+        assert_eq!(metrics.total_ms, 1500);
+        assert_eq!(metrics.avg_ms(), Some(500));
+        assert_eq!((metrics.min_ms, metrics.max_ms), (200, 900));
+    }
+
+    #[test]
+    fn test_metrics_min_tracks_zero_latency() {
+        let mut metrics = Metrics::default();
+        metrics.record(1600, 50);
+        metrics.record(1600, 0);
+        assert_eq!(metrics.min_ms, 0);
+        assert_eq!(metrics.avg_ms(), Some(25));
+    }
+
+    #[test]
+    fn test_metrics_summary() {
+        let mut metrics = Metrics::default();
+        metrics.record(16000, 300);
+        metrics.record(24000, 500);
+        assert_eq!(
+            metrics.summary(),
+            "2 transcriptions, 2.5s audio, inference avg 400ms, min 300ms, max 500ms, total 800ms"
+        );
+    }
+
+    #[test]
+    fn test_record_transcription_updates_snapshot() {
+        let before = metrics_snapshot();
+        record_transcription(16000, 250);
+        let after = metrics_snapshot();
+        // Other tests may record concurrently: only check growth
+        assert!(after.count > before.count);
+        assert!(after.total_ms >= before.total_ms + 250);
+    }
+
+    #[test]
+    fn test_lap_restarts_stopwatch() {
+        let mut start = Instant::now()
+            .checked_sub(Duration::from_millis(50))
+            .unwrap();
+        assert!(lap(&mut start) >= Duration::from_millis(50));
+        assert!(lap(&mut start) < Duration::from_millis(50));
+    }
 }
@@ -0,0 +1,354 @@
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::file::{self, FileRouteError};
+
+/// Sample rate of loaded audio (see [`file::load_audio_file`])
+const SAMPLE_RATE: u32 = 16000;
+
+/// One audio file and where its transcription goes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchJob {
+    /// Audio file to transcribe
+    pub input: PathBuf,
+    /// Plain-text transcription
+    pub text_output: PathBuf,
+    /// Subtitle file (None = `.txt` only)
+    pub srt_output: Option<PathBuf>,
+}
+
+/// Files found in a directory, split into transcribable jobs and skipped files
+#[derive(Debug, Default)]
+pub struct BatchPlan {
+    /// Supported audio files, sorted by path
+    pub jobs: Vec<BatchJob>,
+    /// Files that aren't supported audio (not an error)
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Output paths for `input`: same file stem in `out_dir`, `.txt` (and `.srt`) extension (pure)
+#[must_use]
+pub fn output_paths(input: &Path, out_dir: &Path, srt: bool) -> (PathBuf, Option<PathBuf>) {
+    let stem = input.file_stem().unwrap_or(input.as_os_str());
+    // Appended rather than `with_extension`, which would cut stems containing dots
+    let output = |extension: &str| {
+        let mut name = stem.to_os_string();
+        name.push(extension);
+        out_dir.join(name)
+    };
+    (output(".txt"), srt.then(|| output(".srt")))
+}
+
+/// Pair audio files in `entries` with their outputs (pure)
+///
+/// Entries are routed by extension like dropped files; anything else is skipped.
+#[must_use]
+pub fn plan_batch(mut entries: Vec<PathBuf>, out_dir: &Path, srt: bool) -> BatchPlan {
+    entries.sort();
+    let mut plan = BatchPlan::default();
+    for input in entries {
+        match file::route_file(&input) {
+            Ok(_) => {
+                let (text_output, srt_output) = output_paths(&input, out_dir, srt);
+                plan.jobs.push(BatchJob {
+                    input,
+                    text_output,
+                    srt_output,
+                });
+            }
+            Err(
+                FileRouteError::NoExtension { .. } | FileRouteError::UnsupportedExtension { .. },
+            ) => {
+                plan.skipped.push(input);
+            }
+        }
+    }
+    plan
+}
+
+/// Plan a batch over the regular files directly inside `dir` (not recursive)
+///
+/// # Errors
+/// Returns error if the directory can't be read
+pub fn scan_dir(dir: &Path, out_dir: &Path, srt: bool) -> Result<BatchPlan> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let path = entry.context("failed to read directory entry")?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+        if path.is_file() && !hidden {
+            entries.push(path);
+        }
+    }
+    Ok(plan_batch(entries, out_dir, srt))
+}
+
+/// `HH:MM:SS,mmm` subtitle timestamp
+fn srt_timestamp(at: Duration) -> String {
+    let millis = at.as_millis();
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// Subtitle file with the whole transcription as one cue spanning the recording (pure)
+#[must_use]
+pub fn single_cue_srt(text: &str, duration: Duration) -> String {
+    format!(
+        "1\n{} --> {}\n{}\n",
+        srt_timestamp(Duration::ZERO),
+        srt_timestamp(duration),
+        text.trim()
+    )
+}
+
+/// Per-file results of a batch run
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// Files transcribed and written
+    pub transcribed: usize,
+    /// Files that failed, with the reason
+    pub failed: Vec<(PathBuf, String)>,
+    /// Files that aren't supported audio
+    pub skipped: usize,
+}
+
+impl BatchSummary {
+    /// Record the outcome of one file
+    pub fn record(&mut self, input: &Path, result: &Result<()>) {
+        match result {
+            Ok(()) => self.transcribed += 1,
+            Err(e) => self.failed.push((input.to_path_buf(), format!("{e:#}"))),
+        }
+    }
+
+    /// Whether every audio file was transcribed
+    #[must_use]
+    pub fn all_succeeded(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Summary lines: counts, then one line per failed file
+    #[must_use]
+    pub fn report(&self) -> String {
+        let mut out = format!(
+            "{} transcribed, {} failed, {} skipped\n",
+            self.transcribed,
+            self.failed.len(),
+            self.skipped
+        );
+        for (path, reason) in &self.failed {
+            let _ = writeln!(out, "  ✗ {}: {reason}", path.display());
+        }
+        out
+    }
+}
+
+/// Transcribe one job and write its outputs
+fn run_job(job: &BatchJob, transcribe: &mut impl FnMut(&[f32]) -> Result<String>) -> Result<()> {
+    let samples = file::load_audio_file(&job.input)?;
+    let text = transcribe(&samples)?;
+    fs::write(&job.text_output, format!("{}\n", text.trim()))
+        .with_context(|| format!("failed to write {}", job.text_output.display()))?;
+    if let Some(srt_output) = &job.srt_output {
+        // Precision loss only matters beyond 2^52 samples
+        #[allow(clippy::cast_precision_loss)]
+        let duration = Duration::from_secs_f64(samples.len() as f64 / f64::from(SAMPLE_RATE));
+        fs::write(srt_output, single_cue_srt(&text, duration))
+            .with_context(|| format!("failed to write {}", srt_output.display()))?;
+    }
+    Ok(())
+}
+
+/// Transcribe every job in `plan`, continuing past failures
+///
+/// `progress` is called before each file with its 1-based index and the job count.
+pub fn run_batch(
+    plan: &BatchPlan,
+    mut transcribe: impl FnMut(&[f32]) -> Result<String>,
+    mut progress: impl FnMut(usize, usize, &BatchJob),
+) -> BatchSummary {
+    let mut summary = BatchSummary {
+        skipped: plan.skipped.len(),
+        ..BatchSummary::default()
+    };
+    for (index, job) in plan.jobs.iter().enumerate() {
+        progress(index + 1, plan.jobs.len(), job);
+        let result = run_job(job, &mut transcribe);
+        if let Err(e) = &result {
+            tracing::warn!(error = %e, path = %job.input.display(), "batch file failed");
+        }
+        summary.record(&job.input, &result);
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("whisper-batch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_wav(path: &Path, frames: usize) {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for _ in 0..frames {
+            writer.write_sample(1000_i16).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn test_output_paths() {
+        let (txt, srt) = output_paths(Path::new("/memos/Memo 1.WAV"), Path::new("/out"), true);
+        assert_eq!(txt, PathBuf::from("/out/Memo 1.txt"));
+        assert_eq!(srt, Some(PathBuf::from("/out/Memo 1.srt")));
+
+        let (txt, srt) = output_paths(Path::new("/memos/a.b.wav"), Path::new("/out"), false);
+        assert_eq!(txt, PathBuf::from("/out/a.b.txt"));
+        assert_eq!(srt, None);
+    }
+
+    #[test]
+    fn test_plan_batch_pairs_audio_and_skips_rest() {
+        let plan = plan_batch(
+            vec![
+                PathBuf::from("/in/b.wav"),
+                PathBuf::from("/in/notes.txt"),
+                PathBuf::from("/in/a.WAVE"),
+                PathBuf::from("/in/README"),
+            ],
+            Path::new("/out"),
+            false,
+        );
+        let inputs: Vec<_> = plan.jobs.iter().map(|job| job.input.clone()).collect();
+        assert_eq!(
+            inputs,
+            vec![PathBuf::from("/in/a.WAVE"), PathBuf::from("/in/b.wav")]
+        );
+        assert_eq!(plan.jobs[0].text_output, PathBuf::from("/out/a.txt"));
+        assert_eq!(
+            plan.skipped,
+            vec![PathBuf::from("/in/README"), PathBuf::from("/in/notes.txt")]
+        );
+    }
+
+    #[test]
+    fn test_srt_single_cue() {
+        assert_eq!(
+            single_cue_srt(" hello world ", Duration::from_millis(3_723_456)),
+            "1\n00:00:00,000 --> 01:02:03,456\nhello world\n"
+        );
+    }
+
+    #[test]
+    fn test_summary_report() {
+        let mut summary = BatchSummary {
+            skipped: 1,
+            ..BatchSummary::default()
+        };
+        summary.record(Path::new("/in/a.wav"), &Ok(()));
+        summary.record(Path::new("/in/b.wav"), &Err(anyhow!("bad header")));
+        assert!(!summary.all_succeeded());
+        assert_eq!(
+            summary.report(),
+            "1 transcribed, 1 failed, 1 skipped\n  ✗ /in/b.wav: bad header\n"
+        );
+    }
+
+    #[test]
+    fn test_run_batch_mixed_directory() {
+        let dir = temp_dir("mixed");
+        let out = dir.join("out");
+        fs::create_dir_all(&out).unwrap();
+        write_wav(&dir.join("one.wav"), 16000);
+        write_wav(&dir.join("two.wav"), 8000);
+        fs::write(dir.join("broken.wav"), b"not a wav file").unwrap();
+        fs::write(dir.join("notes.md"), b"# notes").unwrap();
+        fs::write(dir.join(".hidden.wav"), b"ignored").unwrap();
+
+        let plan = scan_dir(&dir, &out, true).unwrap();
+        assert_eq!(plan.jobs.len(), 3);
+        assert_eq!(plan.skipped, vec![dir.join("notes.md")]);
+
+        let mut seen = Vec::new();
+        let summary = run_batch(
+            &plan,
+            |samples| Ok(format!("{} samples", samples.len())),
+            |index, total, job| seen.push((index, total, job.input.clone())),
+        );
+
+        assert_eq!(summary.transcribed, 2);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].0, dir.join("broken.wav"));
+        assert_eq!(seen.len(), 3);
+        assert_eq!(seen[2].0, 3);
+
+        assert_eq!(
+            fs::read_to_string(out.join("one.txt")).unwrap(),
+            "16000 samples\n"
+        );
+        assert_eq!(
+            fs::read_to_string(out.join("two.srt")).unwrap(),
+            "1\n00:00:00,000 --> 00:00:00,500\n8000 samples\n"
+        );
+        assert!(!out.join("broken.txt").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_run_batch_continues_after_transcription_error() {
+        let dir = temp_dir("errors");
+        write_wav(&dir.join("a.wav"), 1600);
+        write_wav(&dir.join("b.wav"), 1600);
+
+        let plan = scan_dir(&dir, &dir, false).unwrap();
+        let mut calls = 0;
+        let summary = run_batch(
+            &plan,
+            |_| {
+                calls += 1;
+                if calls == 1 {
+                    Err(anyhow!("inference failed"))
+                } else {
+                    Ok("ok".to_owned())
+                }
+            },
+            |_, _, _| {},
+        );
+
+        assert_eq!(summary.transcribed, 1);
+        assert_eq!(summary.failed[0].0, dir.join("a.wav"));
+        assert!(summary.failed[0].1.contains("inference failed"));
+        assert!(dir.join("b.txt").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_scan_missing_dir_fails() {
+        assert!(scan_dir(Path::new("/nonexistent/memos"), Path::new("/tmp"), false).is_err());
+    }
+}
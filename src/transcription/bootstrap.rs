@@ -0,0 +1,320 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use super::download::ensure_model_downloaded;
+use super::engine::{ModelManager, TranscriptionEngine};
+use crate::config::{Config, DownloadConfig, TranscriptionProfile};
+
+/// Progress of a profile that started on the bootstrap model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapPhase {
+    /// Configured model downloading in the background
+    Downloading,
+    /// Configured model downloaded, loading in the background
+    Loading,
+    /// Configured model loaded; transcriptions use it from now on
+    Ready,
+    /// Download or load failed; the bootstrap model stays in use until restart
+    Failed,
+}
+
+/// Outcome reported by the background download/load
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapEvent {
+    /// Configured model file is on disk
+    Downloaded,
+    /// Download failed
+    DownloadFailed,
+    /// Configured model is loaded
+    Loaded,
+    /// Loading the downloaded model failed
+    LoadFailed,
+}
+
+impl BootstrapPhase {
+    /// Phase after `event` (out-of-order events leave the phase unchanged)
+    #[must_use]
+    pub const fn advance(self, event: BootstrapEvent) -> Self {
+        match (self, event) {
+            (Self::Downloading, BootstrapEvent::Downloaded) => Self::Loading,
+            (Self::Loading, BootstrapEvent::Loaded) => Self::Ready,
+            (Self::Downloading, BootstrapEvent::DownloadFailed)
+            | (Self::Loading, BootstrapEvent::LoadFailed) => Self::Failed,
+            (phase, _) => phase,
+        }
+    }
+
+    /// Whether transcriptions still go to the bootstrap model
+    #[must_use]
+    pub const fn uses_bootstrap(self) -> bool {
+        !matches!(self, Self::Ready)
+    }
+}
+
+/// Whether `profile` starts on the bootstrap model (pure)
+///
+/// Only downloadable models that are missing qualify; a profile already using the
+/// bootstrap model type just downloads it as usual.
+#[must_use]
+pub fn needs_bootstrap(
+    profile: &TranscriptionProfile,
+    download: &DownloadConfig,
+    model_exists: bool,
+) -> bool {
+    download.bootstrap
+        && !model_exists
+        && profile.custom_path.is_none()
+        && profile.model_type != download.bootstrap_model
+}
+
+/// Whether `profile`'s model is missing and it starts on the bootstrap model
+///
+/// # Errors
+/// Returns error if the model path can't be expanded
+pub fn profile_needs_bootstrap(
+    profile: &TranscriptionProfile,
+    download: &DownloadConfig,
+) -> Result<bool> {
+    let path = Config::expand_path(&profile.model_path()).context("failed to expand model path")?;
+    Ok(needs_bootstrap(profile, download, path.exists()))
+}
+
+/// Bootstrap model file for `download`
+///
+/// # Errors
+/// Returns error if the model path can't be expanded
+pub fn bootstrap_model_path(download: &DownloadConfig) -> Result<PathBuf> {
+    Config::expand_path(&download.bootstrap_model.model_path())
+        .context("failed to expand bootstrap model path")
+}
+
+/// A profile's bootstrap engine, used until its configured model is downloaded and loaded
+pub struct ModelBootstrap {
+    engine: Arc<TranscriptionEngine>,
+    phase: Mutex<BootstrapPhase>,
+}
+
+impl ModelBootstrap {
+    /// Start `profile` on the bootstrap model and fetch its configured model in the background
+    ///
+    /// The caller decides with [`profile_needs_bootstrap`], before anything is downloaded.
+    ///
+    /// # Errors
+    /// Returns error if the bootstrap model can't be loaded
+    pub fn start(
+        profile: &TranscriptionProfile,
+        download: &DownloadConfig,
+        model_manager: &Arc<Mutex<ModelManager>>,
+    ) -> Result<Arc<Self>> {
+        let model = model_manager
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .model_config()
+            .clone();
+        let engine =
+            TranscriptionEngine::for_profile(profile, &model, &bootstrap_model_path(download)?)
+                .context("failed to load bootstrap model")?;
+        tracing::info!(
+            profile = %profile.name(),
+            bootstrap = download.bootstrap_model.as_str(),
+            "🚀 Using bootstrap model until the configured model is downloaded"
+        );
+
+        let bootstrap = Arc::new(Self {
+            engine: Arc::new(engine),
+            phase: Mutex::new(BootstrapPhase::Downloading),
+        });
+        bootstrap.spawn_swap(profile, download, Arc::clone(model_manager));
+        Ok(bootstrap)
+    }
+
+    /// Current phase
+    #[must_use]
+    pub fn phase(&self) -> BootstrapPhase {
+        *self.phase.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Apply a background event, returning the new phase
+    pub fn record(&self, event: BootstrapEvent) -> BootstrapPhase {
+        let mut phase = self.phase.lock().unwrap_or_else(PoisonError::into_inner);
+        *phase = phase.advance(event);
+        *phase
+    }
+
+    /// Bootstrap engine while the configured model isn't ready (None = use the configured model)
+    #[must_use]
+    pub fn active_engine(&self) -> Option<Arc<TranscriptionEngine>> {
+        self.phase()
+            .uses_bootstrap()
+            .then(|| Arc::clone(&self.engine))
+    }
+
+    /// Download the configured model, then load it through the shared model manager
+    fn spawn_swap(
+        self: &Arc<Self>,
+        profile: &TranscriptionProfile,
+        download: &DownloadConfig,
+        model_manager: Arc<Mutex<ModelManager>>,
+    ) {
+        let bootstrap = Arc::clone(self);
+        let model_type = profile.model_type;
+        let profile_name = profile.name().to_owned();
+        let model_path = profile.model_path();
+        let download = download.clone();
+
+        std::thread::spawn(move || {
+            // Startup output is long done, so progress only goes to the log
+            let downloaded = Config::expand_path(&model_path)
+                .and_then(|path| ensure_model_downloaded(model_type, &path, &download, true));
+            if let Err(e) = downloaded {
+                tracing::warn!(
+                    error = %e,
+                    profile = %profile_name,
+                    "❌ Background model download failed, staying on bootstrap model"
+                );
+                bootstrap.record(BootstrapEvent::DownloadFailed);
+                return;
+            }
+            bootstrap.record(BootstrapEvent::Downloaded);
+
+            let loaded = model_manager
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get_or_load(&profile_name);
+            match loaded {
+                Ok(_) => {
+                    bootstrap.record(BootstrapEvent::Loaded);
+                    tracing::info!(
+                        profile = %profile_name,
+                        "✅ Switched from bootstrap model to configured model"
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        profile = %profile_name,
+                        "❌ Failed to load downloaded model, staying on bootstrap model"
+                    );
+                    bootstrap.record(BootstrapEvent::LoadFailed);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ModelType;
+
+    fn profile(model_type: ModelType) -> TranscriptionProfile {
+        TranscriptionProfile {
+            model_type,
+            ..Config::default().profiles.remove(0)
+        }
+    }
+
+    fn run(events: &[BootstrapEvent]) -> BootstrapPhase {
+        events
+            .iter()
+            .fold(BootstrapPhase::Downloading, |phase, &event| {
+                phase.advance(event)
+            })
+    }
+
+    #[test]
+    fn test_bootstrap_then_swap() {
+        assert_eq!(run(&[]), BootstrapPhase::Downloading);
+        assert_eq!(run(&[BootstrapEvent::Downloaded]), BootstrapPhase::Loading);
+        assert_eq!(
+            run(&[BootstrapEvent::Downloaded, BootstrapEvent::Loaded]),
+            BootstrapPhase::Ready
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_used_until_ready() {
+        assert!(BootstrapPhase::Downloading.uses_bootstrap());
+        assert!(BootstrapPhase::Loading.uses_bootstrap());
+        assert!(BootstrapPhase::Failed.uses_bootstrap());
+        assert!(!BootstrapPhase::Ready.uses_bootstrap());
+    }
+
+    #[test]
+    fn test_download_failure_keeps_bootstrap() {
+        assert_eq!(
+            run(&[BootstrapEvent::DownloadFailed]),
+            BootstrapPhase::Failed
+        );
+        // A late completion can't revive a failed bootstrap
+        assert_eq!(
+            run(&[BootstrapEvent::DownloadFailed, BootstrapEvent::Loaded]),
+            BootstrapPhase::Failed
+        );
+    }
+
+    #[test]
+    fn test_load_failure_keeps_bootstrap() {
+        assert_eq!(
+            run(&[BootstrapEvent::Downloaded, BootstrapEvent::LoadFailed]),
+            BootstrapPhase::Failed
+        );
+    }
+
+    #[test]
+    fn test_out_of_order_events_ignored() {
+        // Loaded before the download finished
+        assert_eq!(run(&[BootstrapEvent::Loaded]), BootstrapPhase::Downloading);
+        // Duplicate completion after the swap
+        assert_eq!(
+            run(&[
+                BootstrapEvent::Downloaded,
+                BootstrapEvent::Loaded,
+                BootstrapEvent::Downloaded,
+                BootstrapEvent::LoadFailed,
+            ]),
+            BootstrapPhase::Ready
+        );
+    }
+
+    #[test]
+    fn test_needs_bootstrap() {
+        let download = DownloadConfig::default();
+        assert!(needs_bootstrap(
+            &profile(ModelType::Small),
+            &download,
+            false
+        ));
+        assert!(!needs_bootstrap(
+            &profile(ModelType::Small),
+            &download,
+            true
+        ));
+        // Profile already on the bootstrap model downloads it normally
+        assert!(!needs_bootstrap(
+            &profile(ModelType::TinyEn),
+            &download,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_needs_bootstrap_disabled_or_custom() {
+        let download = DownloadConfig {
+            bootstrap: false,
+            ..DownloadConfig::default()
+        };
+        assert!(!needs_bootstrap(
+            &profile(ModelType::Small),
+            &download,
+            false
+        ));
+
+        let custom = TranscriptionProfile {
+            custom_path: Some("/models/custom.bin".to_owned()),
+            ..profile(ModelType::Small)
+        };
+        assert!(!needs_bootstrap(&custom, &DownloadConfig::default(), false));
+    }
+}
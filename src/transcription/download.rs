@@ -1,20 +1,253 @@
 use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::io::Write;
-use std::path::Path;
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
+use crate::config::{Config, DownloadConfig, ModelType, TranscriptionProfile};
+
+/// Default host for `ggml-<model>.bin` files (`[download] model_base_url` overrides it)
 const MODEL_BASE_URL: &str = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main";
 
+/// Size of chunks streamed from the response to disk
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Downloads tried before a checksum mismatch becomes an error
+const VERIFY_ATTEMPTS: u32 = 2;
+
+/// Percentage between progress reports when the size is known
+const PROGRESS_STEP_PERCENT: u64 = 10;
+
+/// Bytes between progress reports when the server sends no Content-Length
+const PROGRESS_STEP_BYTES: u64 = 100 * BYTES_PER_MB;
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Network timeouts applied to model downloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DownloadTimeouts {
+    /// Max time to establish the connection
+    connect: Duration,
+    /// Max time a single read may wait for data (stalled mirror detection)
+    read: Duration,
+    /// Max total download time (None = unlimited)
+    deadline: Option<Duration>,
+}
+
+impl DownloadTimeouts {
+    fn from_config(config: &DownloadConfig) -> Self {
+        Self {
+            connect: Duration::from_secs(config.connect_timeout_secs),
+            read: Duration::from_secs(config.read_timeout_secs),
+            deadline: (config.deadline_secs > 0).then(|| Duration::from_secs(config.deadline_secs)),
+        }
+    }
+}
+
+/// Build HTTP client with connect and read timeouts
+fn build_client(timeouts: &DownloadTimeouts) -> Result<reqwest::blocking::Client> {
+    // Blocking client applies `timeout` to each read, so a stalled body fails fast
+    reqwest::blocking::Client::builder()
+        .connect_timeout(timeouts.connect)
+        .timeout(timeouts.read)
+        .build()
+        .context("failed to build HTTP client")
+}
+
 /// Maps model names to their `HuggingFace` filenames
 fn model_filename(model_name: &str) -> String {
     format!("ggml-{model_name}.bin")
 }
 
+/// Check that `base_url` is an absolute http(s) URL a model filename can be appended to
+///
+/// # Errors
+/// Returns error if the URL doesn't parse, isn't http(s), or has no host
+pub fn validate_base_url(base_url: &str) -> Result<()> {
+    let url = reqwest::Url::parse(base_url.trim())
+        .with_context(|| format!("not a valid URL: {base_url:?}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!("URL must use http or https: {base_url:?}");
+    }
+    if url.host_str().map_or(true, str::is_empty) {
+        anyhow::bail!("URL has no host: {base_url:?}");
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        anyhow::bail!("URL must not have a query or fragment: {base_url:?}");
+    }
+    Ok(())
+}
+
+/// Download URL of `model_name` under `base_url` (Hugging Face when None)
+fn model_url(base_url: Option<&str>, model_name: &str) -> String {
+    let base = base_url.map_or(MODEL_BASE_URL, str::trim);
+    format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        model_filename(model_name)
+    )
+}
+
+/// SHA-256 of the published `ggml-<model>.bin` file (None = not verified)
+///
+/// `large` is an old alias for `large-v1` with no file of its own upstream;
+/// quantized models aren't in the table yet.
+const fn model_sha256(model: ModelType) -> Option<&'static str> {
+    match model {
+        ModelType::Tiny => Some("be07e048e1e599ad46341c8d2a135645097a538221678b7acdd1b1919c6e1b21"),
+        ModelType::TinyEn => {
+            Some("921e4cf8686fdd993dcd081a5da5b6c365bfde1162e72b08d75ac75289920b1f")
+        }
+        ModelType::Base => Some("60ed5bc3dd14eea856493d334349b405782ddcaf0028d4b5df4088345fba2efe"),
+        ModelType::BaseEn => {
+            Some("a03779c86df3323075f5e796cb2ce5029f00ec8869eee3fdfb897afe36c6d002")
+        }
+        ModelType::Small => {
+            Some("1be3a9b2063867b937e64e2ec7483364a79917e157fa98c5d94b5c1fffea987b")
+        }
+        ModelType::SmallEn => {
+            Some("c6138d6d58ecc8322097e0f987c32f1be8bb0a18532a3f88f734d1bbf9c41e5d")
+        }
+        ModelType::Medium => {
+            Some("6c14d5adee5f86394037b4e4e8b59f1673b6cee10e3cf0b11bbdbee79c156208")
+        }
+        ModelType::MediumEn => {
+            Some("cc37e93478338ec7700281a7ac30a10128929eb8f427dda2e865faa8f6da4356")
+        }
+        ModelType::LargeV1 => {
+            Some("7d99f41a10525d0206bddadd86760181fa920438b6b33237e3118ff6c83bb53d")
+        }
+        ModelType::LargeV2 => {
+            Some("9a423fe4d40c82774b6af34115b8b935f34152246eb19e80e376071d3f999487")
+        }
+        ModelType::LargeV3 => {
+            Some("64d182b440b98d5203c4f9bd541544d84c605196c4f7b845dfa11fb23594d1e2")
+        }
+        ModelType::Large
+        | ModelType::TinyQ5_1
+        | ModelType::TinyEnQ5_1
+        | ModelType::TinyQ8_0
+        | ModelType::BaseQ5_1
+        | ModelType::BaseEnQ5_1
+        | ModelType::BaseQ8_0
+        | ModelType::SmallQ5_1
+        | ModelType::SmallEnQ5_1
+        | ModelType::SmallQ8_0
+        | ModelType::MediumQ5_0
+        | ModelType::MediumEnQ5_0
+        | ModelType::MediumQ8_0
+        | ModelType::LargeV2Q5_0
+        | ModelType::LargeV2Q8_0
+        | ModelType::LargeV3Q5_0 => None,
+    }
+}
+
+/// Whether the file at `path` has SHA-256 `expected` (hex, case-insensitive)
+///
+/// # Errors
+/// Returns error if the file can't be read
+pub fn verify_model(path: &Path, expected: &str) -> Result<bool> {
+    use std::fmt::Write as _;
+
+    let mut file =
+        fs::File::open(path).with_context(|| format!("failed to open model {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .with_context(|| format!("failed to read model {}", path.display()))?;
+    let actual = hasher
+        .finalize()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+    Ok(actual.eq_ignore_ascii_case(expected.trim()))
+}
+
+/// Throttles per-chunk progress into a report every few percent (or every 100MB)
+#[derive(Debug, Default)]
+struct ProgressReporter {
+    /// Percent (or bytes, without a known total) at which the next report is due
+    next: u64,
+}
+
+impl ProgressReporter {
+    /// Report line if `downloaded` crossed the next step (pure)
+    fn update(&mut self, downloaded: u64, total: Option<u64>) -> Option<String> {
+        if let Some(total) = total.filter(|&total| total > 0) {
+            let percent = (downloaded.saturating_mul(100) / total).min(100);
+            if percent < self.next.max(PROGRESS_STEP_PERCENT) {
+                return None;
+            }
+            self.next = (percent / PROGRESS_STEP_PERCENT + 1) * PROGRESS_STEP_PERCENT;
+            return Some(format!(
+                "{percent}% ({} / {} MB)",
+                downloaded / BYTES_PER_MB,
+                total / BYTES_PER_MB
+            ));
+        }
+        if downloaded < self.next.max(PROGRESS_STEP_BYTES) {
+            return None;
+        }
+        self.next = (downloaded / PROGRESS_STEP_BYTES + 1) * PROGRESS_STEP_BYTES;
+        Some(format!("{} MB", downloaded / BYTES_PER_MB))
+    }
+}
+
+/// Run `download`, then check the file against `expected`, retrying once on mismatch
+///
+/// A mismatching file is deleted so a corrupt model never stays on disk.
+fn download_verified(
+    model_path: &Path,
+    expected: Option<&str>,
+    mut download: impl FnMut() -> Result<()>,
+) -> Result<()> {
+    let Some(expected) = expected else {
+        download()?;
+        tracing::debug!(path = %model_path.display(), "no known checksum, skipping verification");
+        return Ok(());
+    };
+
+    for attempt in 1..=VERIFY_ATTEMPTS {
+        download()?;
+        if verify_model(model_path, expected)? {
+            tracing::info!(path = %model_path.display(), "✓ model checksum verified");
+            return Ok(());
+        }
+        tracing::warn!(
+            attempt,
+            path = %model_path.display(),
+            "downloaded model failed SHA-256 verification, removing it"
+        );
+        fs::remove_file(model_path)
+            .with_context(|| format!("failed to remove corrupt model {}", model_path.display()))?;
+    }
+
+    anyhow::bail!(
+        "downloaded model {} failed SHA-256 verification {VERIFY_ATTEMPTS} times (expected {expected})",
+        model_path.display()
+    )
+}
+
 /// Ensures the model is downloaded, returns true if downloaded, false if already existed
 ///
+/// New downloads are checked against the model's known SHA-256 and fetched again
+/// once on mismatch. Existing files are trusted: downloads only land via rename.
+///
+/// Progress is logged every 10%, and printed to stdout unless `quiet`.
+///
 /// # Errors
-/// Returns error if directory creation, HTTP download, or file write fails
-pub fn ensure_model_downloaded(model_name: &str, model_path: &Path) -> Result<bool> {
+/// Returns error if directory creation, HTTP download, or file write fails,
+/// if the download stalls or exceeds the configured deadline, or if the
+/// downloaded file still fails verification after a retry
+pub fn ensure_model_downloaded(
+    model: ModelType,
+    model_path: &Path,
+    config: &DownloadConfig,
+    quiet: bool,
+) -> Result<bool> {
+    let model_name = model.model_name();
     if model_path.exists() {
         tracing::info!(
             path = %model_path.display(),
@@ -29,60 +262,317 @@ pub fn ensure_model_downloaded(model_name: &str, model_path: &Path) -> Result<bo
         "model not found, starting download"
     );
 
-    download_model(model_name, model_path)?;
+    download_verified(model_path, model_sha256(model), || {
+        let mut reporter = ProgressReporter::default();
+        download_model_with_progress(model_name, model_path, config, |downloaded, total| {
+            if let Some(line) = reporter.update(downloaded, total) {
+                tracing::info!(
+                    model = model_name,
+                    downloaded,
+                    total,
+                    "⬇️  Downloading: {line}"
+                );
+                crate::cli::status_print(quiet, &format!("  ⬇ {model_name}: {line}"));
+            }
+        })
+    })?;
 
     Ok(true)
 }
 
-fn download_model(model_name: &str, model_path: &Path) -> Result<()> {
-    let filename = model_filename(model_name);
-    let url = format!("{MODEL_BASE_URL}/{filename}");
+/// Model file resolved for a profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileModel {
+    /// Expanded path to the model file
+    pub path: PathBuf,
+    /// File was downloaded just now
+    pub downloaded: bool,
+    /// File comes from the profile's `custom_path`
+    pub custom: bool,
+}
+
+/// Ensures a profile's model is available, downloading it unless `custom_path` is set
+///
+/// Custom models are used as-is: they are never downloaded and must already exist.
+///
+/// # Errors
+/// Returns error if the custom model file is missing or the download fails
+pub fn ensure_profile_model(
+    profile: &TranscriptionProfile,
+    config: &DownloadConfig,
+    quiet: bool,
+) -> Result<ProfileModel> {
+    let path = Config::expand_path(&profile.model_path()).context("failed to expand model path")?;
+
+    if profile.custom_path.is_some() {
+        if !path.is_file() {
+            anyhow::bail!("custom model not found: {}", path.display());
+        }
+        tracing::info!(path = %path.display(), "using custom model, skipping download");
+        return Ok(ProfileModel {
+            path,
+            downloaded: false,
+            custom: true,
+        });
+    }
+
+    let downloaded = ensure_model_downloaded(profile.model_type, &path, config, quiet)?;
+    Ok(ProfileModel {
+        path,
+        downloaded,
+        custom: false,
+    })
+}
+
+/// Download `model_name` to `model_path`, calling `on_progress(downloaded, total)` per chunk
+///
+/// `total` is the response's Content-Length (None if the server didn't send one).
+///
+/// # Errors
+/// Returns error if directory creation, HTTP download, or file write fails,
+/// or if the download stalls or exceeds the configured deadline
+pub fn download_model_with_progress(
+    model_name: &str,
+    model_path: &Path,
+    config: &DownloadConfig,
+    mut on_progress: impl FnMut(u64, Option<u64>),
+) -> Result<()> {
+    let url = model_url(config.model_base_url.as_deref(), model_name);
 
     // Create parent directory if it doesn't exist
     if let Some(parent) = model_path.parent() {
         fs::create_dir_all(parent).context("failed to create model directory")?;
     }
 
-    tracing::info!(url = %url, "downloading model");
+    let timeouts = DownloadTimeouts::from_config(config);
+    let client = build_client(&timeouts)?;
 
-    // Download to temporary file first for atomic operation
-    let temp_path = model_path.with_extension("tmp");
+    let existing = fs::metadata(part_path(model_path)).map_or(0, |meta| meta.len());
+    tracing::info!(url = %url, resume_from = existing, "downloading model");
 
-    let response = reqwest::blocking::get(&url)
-        .with_context(|| format!("failed to download model from {url}"))?;
+    let mut response = send_request(&client, &url, existing)?;
+    if existing > 0 && response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        // Partial file is no prefix of the current model (e.g. it was replaced upstream)
+        tracing::warn!("server rejected resume offset, restarting download");
+        fs::remove_file(part_path(model_path)).context("failed to remove partial download")?;
+        response = send_request(&client, &url, 0)?;
+    }
 
     if !response.status().is_success() {
         anyhow::bail!("download failed with status {}: {}", response.status(), url);
     }
 
-    let bytes = response.bytes().context("failed to read response bytes")?;
+    let content_range = response
+        .headers()
+        .get(reqwest::header::CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok());
+    let range = body_range(
+        existing,
+        response.status() == reqwest::StatusCode::PARTIAL_CONTENT,
+        content_range,
+        response.content_length(),
+    );
+    if existing > 0 && range.start == 0 {
+        tracing::info!("server doesn't support resuming, restarting download");
+    }
+
+    let size = write_model_file(
+        &mut response,
+        model_path,
+        range,
+        &timeouts,
+        &mut |downloaded| on_progress(downloaded, range.total),
+    )?;
+
+    tracing::info!(
+        path = %model_path.display(),
+        size = size,
+        "model downloaded successfully"
+    );
+
+    Ok(())
+}
+
+/// GET `url`, asking for the bytes from `offset` on when resuming
+fn send_request(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    offset: u64,
+) -> Result<reqwest::blocking::Response> {
+    let mut request = client.get(url);
+    if offset > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={offset}-"));
+    }
+    request
+        .send()
+        .with_context(|| format!("failed to download model from {url}"))
+}
+
+/// Partial download kept next to the model until it is complete
+fn part_path(model_path: &Path) -> PathBuf {
+    let mut name = model_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    model_path.with_file_name(name)
+}
+
+/// Where a response body starts in the model file, and the file's full size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BodyRange {
+    /// Offset of the first body byte (0 = download from scratch)
+    start: u64,
+    /// Full model size (None = unknown)
+    total: Option<u64>,
+}
 
-    // Write to temp file
-    let mut file = fs::File::create(&temp_path)
-        .with_context(|| format!("failed to create temp file at {}", temp_path.display()))?;
+/// Start offset and total size from a `Content-Range: bytes START-END/TOTAL` value
+fn parse_content_range(value: &str) -> Option<BodyRange> {
+    let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _end) = range.split_once('-')?;
+    Some(BodyRange {
+        start: start.trim().parse().ok()?,
+        total: total.trim().parse().ok(),
+    })
+}
 
-    file.write_all(&bytes)
-        .context("failed to write model to temp file")?;
+/// Body range of a response to a request resuming at `requested` (pure)
+///
+/// Only a 206 whose Content-Range starts exactly at `requested` resumes; a plain
+/// 200 (server ignores ranges) or any other range restarts from 0.
+fn body_range(
+    requested: u64,
+    partial: bool,
+    content_range: Option<&str>,
+    content_length: Option<u64>,
+) -> BodyRange {
+    match content_range.and_then(parse_content_range) {
+        Some(range) if partial && requested > 0 && range.start == requested => BodyRange {
+            total: range
+                .total
+                .or_else(|| content_length.map(|length| range.start + length)),
+            ..range
+        },
+        _ => BodyRange {
+            start: 0,
+            total: (!partial).then_some(content_length).flatten(),
+        },
+    }
+}
+
+/// Stream model bytes into the `.part` file, then rename it into place once complete
+///
+/// With `range.start` > 0 the bytes are appended to the existing partial file.
+/// If streaming fails (stall, deadline, I/O error) the partial file is kept so
+/// the next attempt can resume.
+fn write_model_file(
+    reader: &mut impl Read,
+    model_path: &Path,
+    range: BodyRange,
+    timeouts: &DownloadTimeouts,
+    on_progress: &mut impl FnMut(u64),
+) -> Result<u64> {
+    let part = part_path(model_path);
+    let mut file = if range.start > 0 {
+        fs::OpenOptions::new().append(true).open(&part)
+    } else {
+        fs::File::create(&part)
+    }
+    .with_context(|| format!("failed to open partial download {}", part.display()))?;
+
+    copy_with_deadline(reader, &mut file, timeouts, &mut |written| {
+        on_progress(range.start + written);
+    })
+    .with_context(|| format!("partial download kept at {}", part.display()))?;
 
     // Drop file handle before rename
     drop(file);
 
-    // Atomic rename - if this fails, temp file remains and will be cleaned up next run
-    fs::rename(&temp_path, model_path).with_context(|| {
+    finish_part(&part, model_path, range.total)
+}
+
+/// Rename a complete `.part` file to `model_path`; returns the model size
+///
+/// An incomplete file stays in place for the next attempt to resume.
+fn finish_part(part: &Path, model_path: &Path, expected: Option<u64>) -> Result<u64> {
+    let size = fs::metadata(part)
+        .with_context(|| format!("failed to read partial download {}", part.display()))?
+        .len();
+    if let Some(expected) = expected.filter(|&expected| expected != size) {
+        anyhow::bail!(
+            "incomplete model download: {size} of {expected} bytes, partial download kept at {}",
+            part.display()
+        );
+    }
+
+    // Atomic rename: the model path only ever holds a complete file
+    fs::rename(part, model_path).with_context(|| {
         format!(
             "failed to rename {} to {}",
-            temp_path.display(),
+            part.display(),
             model_path.display()
         )
     })?;
 
-    tracing::info!(
-        path = %model_path.display(),
-        size = bytes.len(),
-        "model downloaded successfully"
-    );
+    Ok(size)
+}
 
-    Ok(())
+/// Whether a body read failed because no data arrived within the read timeout
+///
+/// reqwest's blocking reader reports its timeout as `ErrorKind::Other` wrapping
+/// a `reqwest::Error`; `ErrorKind::TimedOut` covers other readers.
+fn is_read_timeout(e: &std::io::Error) -> bool {
+    e.kind() == ErrorKind::TimedOut
+        || e.get_ref()
+            .and_then(|inner| inner.downcast_ref::<reqwest::Error>())
+            .is_some_and(reqwest::Error::is_timeout)
+}
+
+/// Copy reader to writer in chunks, failing if the overall deadline passes
+///
+/// `on_progress` gets the running byte count after each chunk. The deadline is
+/// checked between reads, so a download can overrun it by up to one read timeout.
+fn copy_with_deadline(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    timeouts: &DownloadTimeouts,
+    on_progress: &mut impl FnMut(u64),
+) -> Result<u64> {
+    let start = Instant::now();
+    let mut buf = vec![0_u8; CHUNK_SIZE];
+    let mut total: u64 = 0;
+
+    loop {
+        if let Some(deadline) = timeouts.deadline {
+            if start.elapsed() > deadline {
+                anyhow::bail!(
+                    "model download exceeded deadline of {}s ({} bytes received)",
+                    deadline.as_secs(),
+                    total
+                );
+            }
+        }
+
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) if is_read_timeout(&e) => {
+                anyhow::bail!(
+                    "model download stalled: no data for {}s ({} bytes received)",
+                    timeouts.read.as_secs(),
+                    total
+                );
+            }
+            Err(e) => return Err(e).context("failed to read response body"),
+        };
+
+        writer
+            .write_all(&buf[..n])
+            .context("failed to write model to temp file")?;
+        total += n as u64;
+        on_progress(total);
+    }
+
+    Ok(total)
 }
 
 #[cfg(test)]
@@ -96,6 +586,39 @@ mod tests {
         assert_eq!(model_filename("tiny"), "ggml-tiny.bin");
     }
 
+    #[test]
+    fn test_model_url_default_host() {
+        assert_eq!(
+            model_url(None, ModelType::LargeV3.model_name()),
+            "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3.bin"
+        );
+    }
+
+    #[test]
+    fn test_model_url_mirror() {
+        let base = Some("https://mirror.corp.example/models/whisper");
+        assert_eq!(
+            model_url(base, ModelType::BaseEn.model_name()),
+            "https://mirror.corp.example/models/whisper/ggml-base.en.bin"
+        );
+        // Trailing slash doesn't double up
+        assert_eq!(
+            model_url(Some("http://10.0.0.5:8080/"), ModelType::Tiny.model_name()),
+            "http://10.0.0.5:8080/ggml-tiny.bin"
+        );
+    }
+
+    #[test]
+    fn test_validate_base_url() {
+        assert!(validate_base_url("https://mirror.example.com/whisper").is_ok());
+        assert!(validate_base_url("http://localhost:8000/").is_ok());
+        assert!(validate_base_url("mirror.example.com/whisper").is_err());
+        assert!(validate_base_url("ftp://mirror.example.com").is_err());
+        assert!(validate_base_url("file:///models").is_err());
+        assert!(validate_base_url("https://mirror.example.com/?token=x").is_err());
+        assert!(validate_base_url("").is_err());
+    }
+
     #[test]
     fn test_ensure_model_downloaded_existing_file() {
         let temp_dir = std::env::temp_dir();
@@ -104,7 +627,13 @@ mod tests {
         // Create a dummy file
         fs::write(&model_path, b"dummy model data").unwrap();
 
-        let result = ensure_model_downloaded("small", &model_path).unwrap();
+        let result = ensure_model_downloaded(
+            ModelType::Small,
+            &model_path,
+            &DownloadConfig::default(),
+            true,
+        )
+        .unwrap();
 
         // Should return false because file already existed
         assert!(!result);
@@ -113,6 +642,50 @@ mod tests {
         fs::remove_file(&model_path).unwrap();
     }
 
+    fn profile_with_custom_path(custom_path: &Path) -> TranscriptionProfile {
+        toml::from_str(&format!(
+            r#"
+model_type = "large-v3"
+modifiers = ["Control"]
+key = "K"
+custom_path = "{}"
+"#,
+            custom_path.display()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ensure_profile_model_custom_path_skips_download() {
+        let model_path = std::env::temp_dir().join("test_custom_finetuned_model.bin");
+        fs::write(&model_path, b"custom model data").unwrap();
+
+        let profile = profile_with_custom_path(&model_path);
+        // Custom path wins over the large-v3 default, and nothing is fetched
+        let model = ensure_profile_model(&profile, &DownloadConfig::default(), true).unwrap();
+        assert_eq!(
+            model,
+            ProfileModel {
+                path: model_path.clone(),
+                downloaded: false,
+                custom: true,
+            }
+        );
+
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_ensure_profile_model_missing_custom_file() {
+        let model_path = std::env::temp_dir().join("test_missing_custom_model.bin");
+        let _ = fs::remove_file(&model_path);
+
+        let profile = profile_with_custom_path(&model_path);
+        let err = ensure_profile_model(&profile, &DownloadConfig::default(), true).unwrap_err();
+        assert!(err.to_string().contains("custom model not found"));
+        assert!(!model_path.exists());
+    }
+
     #[test]
     #[ignore = "requires network access and downloads large file"]
     fn test_download_model_integration() {
@@ -122,7 +695,12 @@ mod tests {
         // Ensure file doesn't exist
         let _ = fs::remove_file(&model_path);
 
-        let result = ensure_model_downloaded("tiny", &model_path);
+        let result = ensure_model_downloaded(
+            ModelType::Tiny,
+            &model_path,
+            &DownloadConfig::default(),
+            true,
+        );
 
         // Should succeed
         assert!(result.is_ok());
@@ -149,7 +727,12 @@ mod tests {
         let _ = fs::remove_file(&model_path);
 
         // Try to download a model that doesn't exist
-        let result = download_model("nonexistent-model-xyz", &model_path);
+        let result = download_model_with_progress(
+            "nonexistent-model-xyz",
+            &model_path,
+            &DownloadConfig::default(),
+            |_, _| {},
+        );
 
         // Should fail
         assert!(result.is_err());
@@ -173,7 +756,12 @@ mod tests {
         fs::create_dir_all(nested_path.parent().unwrap()).unwrap();
         fs::write(&nested_path, b"test").unwrap();
 
-        let result = ensure_model_downloaded("small", &nested_path);
+        let result = ensure_model_downloaded(
+            ModelType::Small,
+            &nested_path,
+            &DownloadConfig::default(),
+            true,
+        );
 
         // Should succeed
         assert!(result.is_ok());
@@ -199,7 +787,13 @@ mod tests {
         // Create dummy file
         fs::write(&model_path, b"dummy").unwrap();
 
-        let result = ensure_model_downloaded("base", &model_path).unwrap();
+        let result = ensure_model_downloaded(
+            ModelType::Base,
+            &model_path,
+            &DownloadConfig::default(),
+            true,
+        )
+        .unwrap();
 
         // Should return false because file existed
         assert!(!result);
@@ -218,7 +812,12 @@ mod tests {
         let _ = fs::remove_file(&model_path);
 
         // Try to download with invalid model name (should trigger 404)
-        let result = download_model("invalid-model-!@#$%", &model_path);
+        let result = download_model_with_progress(
+            "invalid-model-!@#$%",
+            &model_path,
+            &DownloadConfig::default(),
+            |_, _| {},
+        );
 
         // Should fail with error
         assert!(result.is_err());
@@ -250,7 +849,12 @@ mod tests {
         // Create dummy file
         fs::write(model_path, b"dummy").unwrap();
 
-        let result = ensure_model_downloaded("tiny", model_path);
+        let result = ensure_model_downloaded(
+            ModelType::Tiny,
+            model_path,
+            &DownloadConfig::default(),
+            true,
+        );
 
         // Should return false (file exists)
         assert!(result.is_ok());
@@ -259,4 +863,501 @@ mod tests {
         // Cleanup
         fs::remove_file(model_path).unwrap();
     }
+
+    /// SHA-256 of `b"model bytes"`
+    const MODEL_BYTES_SHA256: &str =
+        "9cb7487000bc86ac36ce83c4acfabe8878552be99572a6770f65ab1d048a5c48";
+
+    #[test]
+    fn test_verify_model_known_hash() {
+        let model_path = std::env::temp_dir().join("test_verify_model.bin");
+        fs::write(&model_path, b"model bytes").unwrap();
+
+        assert!(verify_model(&model_path, MODEL_BYTES_SHA256).unwrap());
+        assert!(verify_model(&model_path, &MODEL_BYTES_SHA256.to_uppercase()).unwrap());
+        assert!(!verify_model(&model_path, &"0".repeat(64)).unwrap());
+
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_verify_model_empty_file() {
+        let model_path = std::env::temp_dir().join("test_verify_empty_model.bin");
+        fs::write(&model_path, b"").unwrap();
+
+        assert!(verify_model(
+            &model_path,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        )
+        .unwrap());
+
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_verify_model_missing_file() {
+        let model_path = std::env::temp_dir().join("test_verify_missing_model.bin");
+        let _ = fs::remove_file(&model_path);
+        assert!(verify_model(&model_path, MODEL_BYTES_SHA256).is_err());
+    }
+
+    #[test]
+    fn test_model_sha256_table() {
+        assert_eq!(model_sha256(ModelType::Large), None);
+        assert_eq!(model_sha256(ModelType::BaseEnQ5_1), None);
+        for model in [ModelType::Tiny, ModelType::SmallEn, ModelType::LargeV3] {
+            let hash = model_sha256(model).unwrap();
+            assert_eq!(hash.len(), 64);
+            assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+        }
+    }
+
+    #[test]
+    fn test_download_verified_retries_then_succeeds() {
+        let model_path = std::env::temp_dir().join("test_verified_retry_model.bin");
+        let mut attempts = 0;
+        download_verified(&model_path, Some(MODEL_BYTES_SHA256), || {
+            attempts += 1;
+            let bytes: &[u8] = if attempts == 1 {
+                b"corrupt"
+            } else {
+                b"model bytes"
+            };
+            fs::write(&model_path, bytes)?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(attempts, 2);
+        assert_eq!(fs::read(&model_path).unwrap(), b"model bytes");
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_download_verified_gives_up_and_removes_file() {
+        let model_path = std::env::temp_dir().join("test_verified_corrupt_model.bin");
+        let mut attempts = 0;
+        let err = download_verified(&model_path, Some(MODEL_BYTES_SHA256), || {
+            attempts += 1;
+            fs::write(&model_path, b"corrupt")?;
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(attempts, VERIFY_ATTEMPTS);
+        assert!(err.to_string().contains("SHA-256"));
+        assert!(!model_path.exists());
+    }
+
+    #[test]
+    fn test_download_verified_without_checksum() {
+        let model_path = std::env::temp_dir().join("test_unverified_model.bin");
+        let mut attempts = 0;
+        download_verified(&model_path, None, || {
+            attempts += 1;
+            fs::write(&model_path, b"anything")?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(attempts, 1);
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    /// Reader that always times out (simulates a mirror that never sends bytes)
+    struct StalledReader;
+
+    impl Read for StalledReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(ErrorKind::TimedOut, "read timed out"))
+        }
+    }
+
+    /// Reader that trickles one byte per call with a delay, forever
+    struct TrickleReader;
+
+    impl Read for TrickleReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            std::thread::sleep(Duration::from_millis(5));
+            buf[0] = 0;
+            Ok(1)
+        }
+    }
+
+    fn test_timeouts(deadline: Option<Duration>) -> DownloadTimeouts {
+        DownloadTimeouts {
+            connect: Duration::from_secs(1),
+            read: Duration::from_secs(2),
+            deadline,
+        }
+    }
+
+    #[test]
+    fn test_download_timeouts_from_config() {
+        let config = DownloadConfig {
+            connect_timeout_secs: 5,
+            read_timeout_secs: 15,
+            deadline_secs: 600,
+            ..DownloadConfig::default()
+        };
+        let timeouts = DownloadTimeouts::from_config(&config);
+        assert_eq!(timeouts.connect, Duration::from_secs(5));
+        assert_eq!(timeouts.read, Duration::from_secs(15));
+        assert_eq!(timeouts.deadline, Some(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_download_timeouts_zero_deadline_is_unlimited() {
+        let config = DownloadConfig {
+            deadline_secs: 0,
+            ..DownloadConfig::default()
+        };
+        assert_eq!(DownloadTimeouts::from_config(&config).deadline, None);
+    }
+
+    #[test]
+    fn test_build_client_with_timeouts() {
+        let timeouts = DownloadTimeouts::from_config(&DownloadConfig::default());
+        assert!(build_client(&timeouts).is_ok());
+    }
+
+    #[test]
+    fn test_copy_with_deadline_success() {
+        let data = vec![7_u8; CHUNK_SIZE * 2 + 10];
+        let mut output = Vec::new();
+        let copied = copy_with_deadline(
+            &mut data.as_slice(),
+            &mut output,
+            &test_timeouts(None),
+            &mut |_| {},
+        )
+        .unwrap();
+        assert_eq!(copied, data.len() as u64);
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn test_copy_with_deadline_stalled_reader() {
+        let mut output = Vec::new();
+        let err = copy_with_deadline(
+            &mut StalledReader,
+            &mut output,
+            &test_timeouts(None),
+            &mut |_| {},
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("stalled"));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_copy_with_deadline_reqwest_read_timeout() {
+        // Server sends headers and a few bytes of the body, then goes silent
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0_u8; 1024];
+            let _ = stream.read(&mut request);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\nabc")
+                .unwrap();
+            let _ = release_rx.recv();
+        });
+
+        let timeouts = DownloadTimeouts {
+            connect: Duration::from_secs(1),
+            read: Duration::from_millis(300),
+            deadline: None,
+        };
+        let mut response = build_client(&timeouts)
+            .unwrap()
+            .get(format!("http://{addr}/ggml-tiny.bin"))
+            .send()
+            .unwrap();
+        let mut output = Vec::new();
+        let err =
+            copy_with_deadline(&mut response, &mut output, &timeouts, &mut |_| {}).unwrap_err();
+
+        assert!(err.to_string().contains("stalled"), "{err:#}");
+        assert_eq!(output, b"abc");
+        release_tx.send(()).unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_copy_with_deadline_exceeded() {
+        let mut output = Vec::new();
+        let start = Instant::now();
+        let err = copy_with_deadline(
+            &mut TrickleReader,
+            &mut output,
+            &test_timeouts(Some(Duration::from_millis(50))),
+            &mut |_| {},
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("deadline"));
+        // Should fail shortly after the deadline rather than hanging
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_copy_with_deadline_reports_increasing_progress() {
+        let data = vec![1_u8; CHUNK_SIZE * 3 + 100];
+        let mut output = Vec::new();
+        let mut reported = Vec::new();
+        copy_with_deadline(
+            &mut data.as_slice(),
+            &mut output,
+            &test_timeouts(None),
+            &mut |downloaded| reported.push(downloaded),
+        )
+        .unwrap();
+
+        assert!(reported.len() >= 2);
+        assert!(reported.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(reported.last(), Some(&(data.len() as u64)));
+    }
+
+    #[test]
+    fn test_progress_reporter_steps_by_percent() {
+        let mut reporter = ProgressReporter::default();
+        let total = Some(1000);
+        assert_eq!(reporter.update(50, total), None);
+        assert_eq!(
+            reporter.update(100, total).as_deref(),
+            Some("10% (0 / 0 MB)")
+        );
+        assert_eq!(reporter.update(150, total), None);
+        // A big jump reports once, at the percentage reached
+        assert_eq!(
+            reporter.update(730, total).as_deref(),
+            Some("73% (0 / 0 MB)")
+        );
+        assert_eq!(reporter.update(790, total), None);
+        assert!(reporter.update(800, total).is_some());
+        assert_eq!(
+            reporter.update(1000, total).as_deref(),
+            Some("100% (0 / 0 MB)")
+        );
+    }
+
+    #[test]
+    fn test_progress_reporter_megabytes() {
+        let mut reporter = ProgressReporter::default();
+        let total = Some(1500 * BYTES_PER_MB);
+        assert_eq!(
+            reporter.update(600 * BYTES_PER_MB, total).as_deref(),
+            Some("40% (600 / 1500 MB)")
+        );
+    }
+
+    #[test]
+    fn test_progress_reporter_unknown_total() {
+        let mut reporter = ProgressReporter::default();
+        assert_eq!(reporter.update(BYTES_PER_MB, None), None);
+        assert_eq!(
+            reporter.update(120 * BYTES_PER_MB, None).as_deref(),
+            Some("120 MB")
+        );
+        assert_eq!(reporter.update(150 * BYTES_PER_MB, None), None);
+        assert_eq!(
+            reporter.update(200 * BYTES_PER_MB, None).as_deref(),
+            Some("200 MB")
+        );
+        // Zero Content-Length is treated as unknown
+        let mut reporter = ProgressReporter::default();
+        assert_eq!(reporter.update(10, Some(0)), None);
+    }
+
+    const FRESH: BodyRange = BodyRange {
+        start: 0,
+        total: None,
+    };
+
+    #[test]
+    fn test_write_model_file_keeps_part_on_stall() {
+        let model_path = std::env::temp_dir().join("test_stalled_model.bin");
+        let _ = fs::remove_file(&model_path);
+
+        let err = write_model_file(
+            &mut StalledReader,
+            &model_path,
+            FRESH,
+            &test_timeouts(None),
+            &mut |_| {},
+        )
+        .unwrap_err();
+
+        assert!(format!("{err:#}").contains("stalled"));
+        assert!(!model_path.exists());
+        // Kept for the next attempt to resume
+        assert!(part_path(&model_path).exists());
+        fs::remove_file(part_path(&model_path)).unwrap();
+    }
+
+    #[test]
+    fn test_write_model_file_success() {
+        let temp_dir = std::env::temp_dir();
+        let model_path = temp_dir.join("test_written_model.bin");
+        let _ = fs::remove_file(&model_path);
+
+        let size = write_model_file(
+            &mut b"model bytes".as_slice(),
+            &model_path,
+            FRESH,
+            &test_timeouts(None),
+            &mut |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(size, 11);
+        assert_eq!(fs::read(&model_path).unwrap(), b"model bytes");
+        assert!(!part_path(&model_path).exists());
+
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_write_model_file_resumes_part() {
+        let model_path = std::env::temp_dir().join("test_resumed_model.bin");
+        let _ = fs::remove_file(&model_path);
+        fs::write(part_path(&model_path), b"model ").unwrap();
+
+        let mut reported = Vec::new();
+        let size = write_model_file(
+            &mut b"bytes".as_slice(),
+            &model_path,
+            BodyRange {
+                start: 6,
+                total: Some(11),
+            },
+            &test_timeouts(None),
+            &mut |downloaded| reported.push(downloaded),
+        )
+        .unwrap();
+
+        assert_eq!(size, 11);
+        assert_eq!(reported, [11]);
+        assert_eq!(fs::read(&model_path).unwrap(), b"model bytes");
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_write_model_file_restart_truncates_part() {
+        let model_path = std::env::temp_dir().join("test_restarted_model.bin");
+        let _ = fs::remove_file(&model_path);
+        fs::write(part_path(&model_path), b"stale partial bytes").unwrap();
+
+        write_model_file(
+            &mut b"model bytes".as_slice(),
+            &model_path,
+            FRESH,
+            &test_timeouts(None),
+            &mut |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(fs::read(&model_path).unwrap(), b"model bytes");
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_finish_part_incomplete_is_kept() {
+        let model_path = std::env::temp_dir().join("test_incomplete_model.bin");
+        let _ = fs::remove_file(&model_path);
+        let part = part_path(&model_path);
+        fs::write(&part, b"model").unwrap();
+
+        let err = finish_part(&part, &model_path, Some(11)).unwrap_err();
+        assert!(err.to_string().contains("5 of 11 bytes"));
+        assert!(part.exists());
+        assert!(!model_path.exists());
+
+        // Unknown size: whatever arrived is taken as complete
+        assert_eq!(finish_part(&part, &model_path, None).unwrap(), 5);
+        assert!(!part.exists());
+        fs::remove_file(&model_path).unwrap();
+    }
+
+    #[test]
+    fn test_part_path() {
+        assert_eq!(
+            part_path(Path::new("/models/ggml-base.en.bin")),
+            PathBuf::from("/models/ggml-base.en.bin.part")
+        );
+    }
+
+    #[test]
+    fn test_parse_content_range() {
+        assert_eq!(
+            parse_content_range("bytes 100-999/1000"),
+            Some(BodyRange {
+                start: 100,
+                total: Some(1000),
+            })
+        );
+        assert_eq!(
+            parse_content_range("bytes 100-999/*"),
+            Some(BodyRange {
+                start: 100,
+                total: None,
+            })
+        );
+        assert_eq!(parse_content_range("items 0-1/2"), None);
+        assert_eq!(parse_content_range("bytes */1000"), None);
+    }
+
+    #[test]
+    fn test_body_range_resumes_matching_partial_response() {
+        assert_eq!(
+            body_range(600, true, Some("bytes 600-999/1000"), Some(400)),
+            BodyRange {
+                start: 600,
+                total: Some(1000),
+            }
+        );
+        // Unknown total: offset plus the remaining length
+        assert_eq!(
+            body_range(600, true, Some("bytes 600-999/*"), Some(400)),
+            BodyRange {
+                start: 600,
+                total: Some(1000),
+            }
+        );
+    }
+
+    #[test]
+    fn test_body_range_restarts_without_range_support() {
+        // Server ignored the Range header and sent the whole file
+        assert_eq!(
+            body_range(600, false, None, Some(1000)),
+            BodyRange {
+                start: 0,
+                total: Some(1000),
+            }
+        );
+        // Fresh download
+        assert_eq!(
+            body_range(0, false, None, None),
+            BodyRange {
+                start: 0,
+                total: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_body_range_restarts_on_unexpected_range() {
+        // Partial content from a different offset can't be appended
+        assert_eq!(
+            body_range(600, true, Some("bytes 500-999/1000"), Some(500)),
+            BodyRange {
+                start: 0,
+                total: None,
+            }
+        );
+        assert_eq!(body_range(600, true, None, Some(400)).start, 0);
+    }
 }
@@ -1,9 +1,20 @@
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
+use super::{gpu, script};
+
+/// Silent samples transcribed to warm up a freshly loaded model (100ms at 16kHz)
+const WARMUP_SAMPLES: usize = 1600;
+
+/// No-speech probability above which a segment is dropped as a likely hallucination
+pub const DEFAULT_NO_SPEECH_THRESHOLD: f32 = 0.6;
+
 /// Trait for transcription operations (enables testing via mocking)
 ///
 /// This trait abstracts transcription functionality to enable dependency injection
@@ -21,6 +32,41 @@ trait TranscriptionInterface: Send + Sync {
     fn transcribe(&self, audio_data: &[f32]) -> Result<String, TranscriptionError>;
 }
 
+/// One Whisper segment with its position in the recording
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// Raw segment text
+    pub text: String,
+    /// Where the segment starts
+    pub start: Duration,
+    /// Where the segment ends
+    pub end: Duration,
+}
+
+impl Segment {
+    /// Segment from Whisper's timestamps (centiseconds; negative clamps to zero)
+    #[must_use]
+    pub fn from_centiseconds(text: String, start: i64, end: i64) -> Self {
+        let at = |centis: i64| Duration::from_millis(u64::try_from(centis).unwrap_or(0) * 10);
+        Self {
+            text,
+            start: at(start),
+            end: at(end),
+        }
+    }
+}
+
+/// Transcribed text with the language it was transcribed as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    /// Final text (trimmed unless `preserve_whitespace`)
+    pub text: String,
+    /// Configured language, or the one Whisper auto-detected (None if unknown)
+    pub language: Option<String>,
+    /// Segments the text was joined from, with timings
+    pub segments: Vec<Segment>,
+}
+
 /// Errors that can occur during transcription
 #[derive(Debug, Error)]
 pub enum TranscriptionError {
@@ -54,6 +100,103 @@ pub struct TranscriptionEngine {
     beam_size: i32,
     /// Language code (None = auto-detect)
     language: Option<String>,
+    /// Translate to English (Whisper native translation)
+    translate: bool,
+    /// Skip trimming the transcription's leading/trailing whitespace
+    preserve_whitespace: bool,
+    /// Text Whisper is primed with (None = no prompt)
+    initial_prompt: Option<String>,
+    /// Segments more likely than this to be non-speech are dropped
+    no_speech_threshold: f32,
+    /// Set to stop the running inference early (cleared once it stops)
+    abort: Arc<AtomicBool>,
+    /// Model file, kept to reload the context on the CPU
+    model_path: String,
+    /// Options the context was actually loaded with
+    options: Mutex<ContextOptions>,
+}
+
+/// Whisper context settings fixed when a model is loaded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContextOptions {
+    /// Flash attention (library default: off)
+    pub flash_attn: bool,
+    /// Run inference on the CPU only (library default: GPU when available)
+    pub cpu_only: bool,
+}
+
+impl ContextOptions {
+    /// Context options requested by `profile` under the `[model]` settings
+    #[must_use]
+    pub const fn for_profile(
+        profile: &crate::config::TranscriptionProfile,
+        model: &crate::config::ModelConfig,
+    ) -> Self {
+        Self {
+            flash_attn: profile.flash_attn,
+            cpu_only: !model.use_gpu,
+        }
+    }
+
+    /// whisper-rs context parameters with these options applied
+    fn params(self) -> WhisperContextParameters<'static> {
+        let mut params = WhisperContextParameters::default();
+        params.flash_attn(self.flash_attn);
+        params.use_gpu(!self.cpu_only);
+        params
+    }
+}
+
+/// Whisper abort callback: stops inference once `abort` is set
+fn abort_callback(abort: Arc<AtomicBool>) -> impl FnMut() -> bool + 'static {
+    move || abort.load(Ordering::Relaxed)
+}
+
+/// Whether a segment with `no_speech_prob` is kept at `threshold` (pure)
+///
+/// Whisper emits phrases like "Thank you." for silence or noise; such segments carry a
+/// high no-speech probability. A probability that isn't a number keeps the segment.
+fn keep_segment(no_speech_prob: f32, threshold: f32) -> bool {
+    no_speech_prob.is_nan() || no_speech_prob <= threshold
+}
+
+/// Prompt passed to Whisper, None if `prompt` is blank (pure)
+///
+/// NUL characters are dropped: whisper.cpp takes the prompt as a C string.
+fn prompt_text(prompt: &str) -> Option<String> {
+    let prompt = prompt.replace('\0', "");
+    (!prompt.trim().is_empty()).then_some(prompt)
+}
+
+/// Text of `segments` as one transcription (pure)
+///
+/// Whitespace is kept as Whisper produced it, or segments are joined per script
+/// (no spaces in CJK), then the result is finalized.
+fn joined_text(segments: &[Segment], language: Option<&str>, preserve_whitespace: bool) -> String {
+    let text = if preserve_whitespace {
+        segments
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect()
+    } else {
+        script::join_segments(segments, language)
+    };
+    finalize_text(text, preserve_whitespace)
+}
+
+/// Final cleanup of Whisper's raw output
+///
+/// Trims surrounding whitespace unless `preserve_whitespace`; whitespace-only
+/// output always becomes empty so silence never inserts blanks.
+fn finalize_text(raw: String, preserve_whitespace: bool) -> String {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        String::new()
+    } else if preserve_whitespace {
+        raw
+    } else {
+        trimmed.to_owned()
+    }
 }
 
 impl TranscriptionEngine {
@@ -73,11 +216,35 @@ impl TranscriptionEngine {
     ///
     /// # Errors
     /// Returns error if model file doesn't exist, is invalid, or if `threads`/`beam_size` exceed `i32::MAX`
+    #[allow(dead_code)] // App loads through `for_profile`/`ModelManager`; kept for tests and library use
     pub fn new(
         model_path: &Path,
         threads: usize,
         beam_size: usize,
         language: Option<String>,
+    ) -> Result<Self, TranscriptionError> {
+        Self::with_context_options(
+            model_path,
+            threads,
+            beam_size,
+            language,
+            ContextOptions::default(),
+        )
+    }
+
+    /// Like [`Self::new`], with explicit Whisper context options
+    ///
+    /// When loading with flash attention fails (unsupported build), the model is
+    /// loaded again without it.
+    ///
+    /// # Errors
+    /// Returns error if model file doesn't exist, is invalid, or if `threads`/`beam_size` exceed `i32::MAX`
+    pub fn with_context_options(
+        model_path: &Path,
+        threads: usize,
+        beam_size: usize,
+        language: Option<String>,
+        options: ContextOptions,
     ) -> Result<Self, TranscriptionError> {
         if threads == 0 {
             return Err(TranscriptionError::ModelLoad {
@@ -118,39 +285,237 @@ impl TranscriptionEngine {
                 source: anyhow::anyhow!("model path contains invalid UTF-8"),
             })?;
 
-        let params = WhisperContextParameters::default();
-        let ctx = WhisperContext::new_with_params(path_str, params).map_err(|e| {
-            TranscriptionError::ModelLoad {
-                path: model_path.display().to_string(),
-                source: anyhow::anyhow!("{e:?}"),
+        let options = Self::apply_gpu_marker(options, &gpu::marker_path());
+        let (ctx, flash_attn) = match WhisperContext::new_with_params(path_str, options.params()) {
+            Err(e) if options.flash_attn => {
+                tracing::warn!(
+                    error = ?e,
+                    "failed to load model with flash attention, retrying without it"
+                );
+                let fallback = ContextOptions {
+                    flash_attn: false,
+                    ..options
+                };
+                (
+                    WhisperContext::new_with_params(path_str, fallback.params()),
+                    false,
+                )
             }
+            result => (result, options.flash_attn),
+        };
+        let ctx = ctx.map_err(|e| TranscriptionError::ModelLoad {
+            path: model_path.display().to_string(),
+            source: anyhow::anyhow!("{e:?}"),
         })?;
 
-        tracing::info!("whisper model loaded successfully");
+        tracing::info!(
+            flash_attn,
+            "whisper model loaded successfully (flash attention {})",
+            if flash_attn { "enabled" } else { "disabled" }
+        );
 
         Ok(Self {
             ctx: Arc::new(Mutex::new(ctx)),
             threads: threads_i32,
             beam_size: beam_size_i32,
             language,
+            translate: false,
+            preserve_whitespace: false,
+            initial_prompt: None,
+            no_speech_threshold: DEFAULT_NO_SPEECH_THRESHOLD,
+            abort: Arc::new(AtomicBool::new(false)),
+            model_path: path_str.to_owned(),
+            options: Mutex::new(ContextOptions {
+                flash_attn,
+                ..options
+            }),
         })
     }
 
+    /// Load on the CPU when an earlier GPU failure left the marker file behind
+    fn apply_gpu_marker(options: ContextOptions, marker: &Path) -> ContextOptions {
+        if options.cpu_only || !gpu::is_disabled(marker) {
+            return options;
+        }
+        tracing::info!(
+            marker = %marker.display(),
+            "GPU disabled after earlier errors, loading model on CPU (delete the marker to retry GPU)"
+        );
+        ContextOptions {
+            cpu_only: true,
+            ..options
+        }
+    }
+
+    /// Whether inference currently runs on the GPU (false after a CPU fallback)
+    fn using_gpu(&self) -> bool {
+        !self
+            .options
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .cpu_only
+    }
+
+    /// Reload the model on the CPU after a GPU failure and remember the downgrade
+    ///
+    /// A no-op if another transcription already fell back.
+    fn fall_back_to_cpu(&self, cause: &TranscriptionError) -> Result<(), TranscriptionError> {
+        let mut options = self.options.lock().unwrap_or_else(PoisonError::into_inner);
+        if options.cpu_only {
+            return Ok(());
+        }
+        let cpu = ContextOptions {
+            cpu_only: true,
+            ..*options
+        };
+        let ctx = WhisperContext::new_with_params(&self.model_path, cpu.params()).map_err(|e| {
+            TranscriptionError::ModelLoad {
+                path: self.model_path.clone(),
+                source: anyhow::anyhow!("{e:?}"),
+            }
+        })?;
+        *self.ctx.lock().unwrap_or_else(PoisonError::into_inner) = ctx;
+        *options = cpu;
+        drop(options);
+
+        tracing::warn!("⚠️  GPU disabled due to errors - model reloaded on CPU");
+        let marker = gpu::marker_path();
+        if let Err(e) = gpu::record_disabled(&marker, &gpu::describe(cause)) {
+            tracing::warn!(error = %e, "failed to persist GPU downgrade");
+        }
+        Ok(())
+    }
+
+    /// Load the engine from `model_path` with `profile`'s inference settings
+    ///
+    /// Settings shared by all profiles (GPU, no-speech threshold) come from `model`.
+    ///
+    /// # Errors
+    /// Returns error if the model fails to load
+    pub fn for_profile(
+        profile: &crate::config::TranscriptionProfile,
+        model: &crate::config::ModelConfig,
+        model_path: &Path,
+    ) -> Result<Self, TranscriptionError> {
+        Ok(Self::with_context_options(
+            model_path,
+            profile.threads,
+            profile.beam_size,
+            profile.language.clone(),
+            ContextOptions::for_profile(profile, model),
+        )?
+        .with_translate(translates_natively(profile))
+        .with_preserve_whitespace(profile.preserve_whitespace)
+        .with_initial_prompt(profile.initial_prompt.as_deref())
+        .with_no_speech_threshold(model.no_speech_threshold))
+    }
+
+    /// Enable Whisper's native translation to English
+    #[must_use]
+    pub const fn with_translate(mut self, translate: bool) -> Self {
+        self.translate = translate;
+        self
+    }
+
+    /// Keep leading/trailing whitespace instead of trimming the result
+    #[must_use]
+    pub const fn with_preserve_whitespace(mut self, preserve_whitespace: bool) -> Self {
+        self.preserve_whitespace = preserve_whitespace;
+        self
+    }
+
+    /// Prime Whisper with `prompt` to bias it toward its vocabulary (blank = no prompt)
+    #[must_use]
+    pub fn with_initial_prompt(mut self, prompt: Option<&str>) -> Self {
+        self.initial_prompt = prompt.and_then(prompt_text);
+        self
+    }
+
+    /// Drop segments whose no-speech probability exceeds `threshold` (1.0 = keep all)
+    #[must_use]
+    pub const fn with_no_speech_threshold(mut self, threshold: f32) -> Self {
+        self.no_speech_threshold = threshold;
+        self
+    }
+
+    /// Flag that stops the running transcription early when set
+    ///
+    /// The interrupted transcription returns what was transcribed so far (often
+    /// nothing). The flag is cleared when it stops, so later transcriptions run normally.
+    #[allow(dead_code)] // Public API for callers that cancel long transcriptions
+    #[must_use]
+    pub fn abort_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.abort)
+    }
+
+    /// Transcribe a short silent buffer so the first real transcription isn't slowed
+    /// down by first-use allocations
+    ///
+    /// # Errors
+    /// Returns error if Whisper inference fails or mutex is poisoned
+    pub fn warmup(&self) -> Result<(), TranscriptionError> {
+        let start = Instant::now();
+        self.transcribe_impl(&[0.0; WARMUP_SAMPLES])?;
+        tracing::debug!(warmup_ms = start.elapsed().as_millis(), "model warmed up");
+        Ok(())
+    }
+
     /// Transcribes audio samples (public interface)
     ///
     /// # Errors
     /// Returns error if Whisper inference fails or mutex is poisoned
     #[allow(dead_code)] // Used in Phase 5
     pub fn transcribe(&self, audio_data: &[f32]) -> Result<String, TranscriptionError> {
+        self.transcribe_impl(audio_data)
+            .map(|transcript| transcript.text)
+    }
+
+    /// Transcribes audio samples, also reporting the (detected) language
+    ///
+    /// # Errors
+    /// Returns error if Whisper inference fails or mutex is poisoned
+    pub fn transcribe_detailed(
+        &self,
+        audio_data: &[f32],
+    ) -> Result<Transcript, TranscriptionError> {
         self.transcribe_impl(audio_data)
     }
 
+    /// Transcribes audio samples into timed segments (e.g. for subtitles)
+    ///
+    /// Segments dropped as likely non-speech are left out, like in the joined text.
+    ///
+    /// # Errors
+    /// Returns error if Whisper inference fails or mutex is poisoned
+    #[allow(dead_code)] // Public API for subtitle export
+    pub fn transcribe_with_segments(
+        &self,
+        audio_data: &[f32],
+    ) -> Result<Vec<Segment>, TranscriptionError> {
+        self.transcribe_impl(audio_data)
+            .map(|transcript| transcript.segments)
+    }
+
+    /// Transcribes audio samples, retrying once on the CPU if the GPU fails
+    ///
+    /// # Errors
+    /// Returns error if Whisper inference fails or mutex is poisoned
+    fn transcribe_impl(&self, audio_data: &[f32]) -> Result<Transcript, TranscriptionError> {
+        match self.run_inference(audio_data) {
+            Err(e) if gpu::should_fall_back(self.using_gpu(), &e) => {
+                tracing::warn!(error = %gpu::describe(&e), "⚠️  GPU inference failed, retrying on CPU");
+                self.fall_back_to_cpu(&e)?;
+                self.run_inference(audio_data)
+            }
+            result => result,
+        }
+    }
+
     /// Transcribes audio samples (16kHz mono f32) to text with language auto-detection
     ///
     /// # Errors
     /// Returns error if Whisper inference fails or mutex is poisoned
-    #[allow(dead_code)] // Used in Phase 5
-    fn transcribe_impl(&self, audio_data: &[f32]) -> Result<String, TranscriptionError> {
+    fn run_inference(&self, audio_data: &[f32]) -> Result<Transcript, TranscriptionError> {
         let _span = tracing::debug_span!("transcription", samples = audio_data.len()).entered();
         tracing::debug!("starting transcription");
 
@@ -171,32 +536,64 @@ impl TranscriptionEngine {
         params.set_print_realtime(false);
         params.set_print_timestamps(false);
         params.set_language(self.language.as_deref()); // Use configured language or auto-detect
-        params.set_translate(false);
+        params.set_translate(self.translate);
+        if let Some(prompt) = self.initial_prompt.as_deref() {
+            params.set_initial_prompt(prompt);
+        }
+        params.set_abort_callback_safe(abort_callback(Arc::clone(&self.abort)));
 
-        // Run transcription
+        // Run transcription (an aborted run keeps the segments decoded so far)
         let start = std::time::Instant::now();
-        state
-            .full(params, audio_data)
-            .context("whisper inference failed")?;
+        let full = state.full(params, audio_data);
+        if self.abort.swap(false, Ordering::Relaxed) {
+            tracing::info!("⏹️  Transcription aborted");
+        } else {
+            full.context("whisper inference failed")?;
+        }
         let inference_duration = start.elapsed();
 
-        // Extract text from all segments
-        let mut result = String::new();
-        for segment in state.as_iter() {
-            result.push_str(&segment.to_string());
-        }
+        // Extract text from all segments, skipping likely hallucinations over silence
+        let segments: Vec<Segment> = state
+            .as_iter()
+            .filter(|segment| {
+                let no_speech_prob = segment.no_speech_probability();
+                let keep = keep_segment(no_speech_prob, self.no_speech_threshold);
+                if !keep {
+                    tracing::debug!(
+                        no_speech_prob,
+                        threshold = self.no_speech_threshold,
+                        "dropping segment that is likely not speech"
+                    );
+                }
+                keep
+            })
+            .map(|segment| {
+                Segment::from_centiseconds(
+                    segment.to_string(),
+                    segment.start_timestamp(),
+                    segment.end_timestamp(),
+                )
+            })
+            .collect();
+        let language = self.language.clone().or_else(|| {
+            whisper_rs::get_lang_str(state.full_lang_id_from_state()).map(str::to_owned)
+        });
 
-        // Trim whitespace
-        let result = result.trim().to_owned();
+        let result = joined_text(&segments, language.as_deref(), self.preserve_whitespace);
 
         tracing::info!(
             segments = state.full_n_segments(),
             text_len = result.len(),
+            language = ?language,
             inference_ms = inference_duration.as_millis(),
             "transcription completed"
         );
 
-        Ok(result)
+        Ok(Transcript {
+            text: result,
+            language,
+            segments,
+        })
     }
 }
 
@@ -204,6 +601,7 @@ impl TranscriptionEngine {
 impl TranscriptionInterface for TranscriptionEngine {
     fn transcribe(&self, audio_data: &[f32]) -> Result<String, TranscriptionError> {
         self.transcribe_impl(audio_data)
+            .map(|transcript| transcript.text)
     }
 }
 
@@ -225,61 +623,154 @@ pub struct ModelManager {
     lazy_configs: std::collections::HashMap<String, LazyModelConfig>,
     /// Models currently being loaded (prevents concurrent load race condition)
     loading: std::collections::HashSet<String>,
+    /// Resident engines allowed at once (0 = unlimited)
+    max_loaded: usize,
+    /// Last use of each loaded engine (LRU eviction order)
+    last_used: HashMap<String, Instant>,
+    /// Configs of loaded engines, moved back to `lazy_configs` on eviction
+    loaded_configs: HashMap<String, LazyModelConfig>,
+    /// Settings applied to every engine (`[model]`)
+    model: crate::config::ModelConfig,
 }
 
 /// Configuration for lazy-loading a model
+#[derive(Clone)]
 struct LazyModelConfig {
     model_path: std::path::PathBuf,
     threads: usize,
     beam_size: usize,
     language: Option<String>,
+    translate: bool,
+    preserve_whitespace: bool,
+    initial_prompt: Option<String>,
+    context: ContextOptions,
+}
+
+/// Whether the profile uses Whisper's built-in translation (`translate = true` or `translate_to = "en"`)
+fn translates_natively(profile: &crate::config::TranscriptionProfile) -> bool {
+    crate::translation::route(profile.translation_target())
+        == Some(crate::translation::TranslationRoute::Native)
+}
+
+/// Loaded models to evict, least recently used first, so one more fits within `max_loaded`
+///
+/// Engines still referenced elsewhere (`in_use`) are never evicted, so the budget is
+/// exceeded temporarily when every candidate is busy.
+fn lru_evictions(
+    last_used: &HashMap<String, Instant>,
+    in_use: impl Fn(&str) -> bool,
+    max_loaded: usize,
+) -> Vec<String> {
+    if max_loaded == 0 {
+        return Vec::new();
+    }
+    let excess = (last_used.len() + 1).saturating_sub(max_loaded);
+    let mut candidates: Vec<(&String, &Instant)> =
+        last_used.iter().filter(|(name, _)| !in_use(name)).collect();
+    candidates.sort_by_key(|(_, used)| **used);
+    candidates
+        .into_iter()
+        .take(excess)
+        .map(|(name, _)| name.clone())
+        .collect()
 }
 
 impl ModelManager {
     /// Creates new `ModelManager` and preloads models where `profile.preload=true`
     ///
+    /// At most `model.max_loaded_models` engines stay resident (0 = unlimited).
+    ///
     /// # Errors
     /// Returns error if any preloaded model fails to load
-    pub fn new(profiles: &[crate::config::TranscriptionProfile]) -> Result<Self> {
-        use std::collections::{HashMap, HashSet};
-
-        let mut preloaded = HashMap::new();
-        let mut lazy_configs = HashMap::new();
+    pub fn new(
+        profiles: &[crate::config::TranscriptionProfile],
+        model: &crate::config::ModelConfig,
+    ) -> Result<Self> {
+        use std::collections::HashSet;
+
+        let mut manager = Self {
+            preloaded: HashMap::new(),
+            lazy_configs: HashMap::new(),
+            loading: HashSet::new(),
+            max_loaded: model.max_loaded_models,
+            last_used: HashMap::new(),
+            loaded_configs: HashMap::new(),
+            model: model.clone(),
+        };
 
         for profile in profiles {
             let model_name = profile.name().to_owned();
             let model_path = crate::config::Config::expand_path(&profile.model_path())?;
+            let config = LazyModelConfig {
+                model_path,
+                threads: profile.threads,
+                beam_size: profile.beam_size,
+                language: profile.language.clone(),
+                translate: translates_natively(profile),
+                preserve_whitespace: profile.preserve_whitespace,
+                initial_prompt: profile.initial_prompt.clone(),
+                context: ContextOptions::for_profile(profile, model),
+            };
 
             if profile.preload {
                 // Preload model at startup
                 tracing::info!("preloading model: {}", model_name);
-                let engine = Arc::new(TranscriptionEngine::new(
-                    &model_path,
-                    profile.threads,
-                    profile.beam_size,
-                    profile.language.clone(),
+                manager.make_room();
+                let engine = Arc::new(TranscriptionEngine::for_profile(
+                    profile,
+                    model,
+                    &config.model_path,
                 )?);
-                preloaded.insert(model_name, engine);
+                if model.warmup {
+                    if let Err(e) = engine.warmup() {
+                        tracing::warn!(model = %model_name, error = %e, "model warm-up failed");
+                    }
+                }
+                manager.insert_loaded(model_name, engine, config);
             } else {
                 // Store config for lazy loading
                 tracing::info!("deferring load for model: {}", model_name);
-                lazy_configs.insert(
-                    model_name,
-                    LazyModelConfig {
-                        model_path,
-                        threads: profile.threads,
-                        beam_size: profile.beam_size,
-                        language: profile.language.clone(),
-                    },
-                );
+                manager.lazy_configs.insert(model_name, config);
             }
         }
 
-        Ok(Self {
-            preloaded,
-            lazy_configs,
-            loading: HashSet::new(),
-        })
+        Ok(manager)
+    }
+
+    /// Evict least recently used engines so one more model fits within the budget
+    ///
+    /// Runs before loading, so peak memory stays within the budget.
+    fn make_room(&mut self) {
+        let preloaded = &self.preloaded;
+        let evictions = lru_evictions(
+            &self.last_used,
+            |name| {
+                preloaded
+                    .get(name)
+                    .is_some_and(|engine| Arc::strong_count(engine) > 1)
+            },
+            self.max_loaded,
+        );
+        for name in evictions {
+            tracing::info!(model = %name, "♻️ Evicting least recently used model");
+            self.preloaded.remove(&name);
+            self.last_used.remove(&name);
+            if let Some(config) = self.loaded_configs.remove(&name) {
+                self.lazy_configs.insert(name, config);
+            }
+        }
+    }
+
+    /// Register a loaded engine as most recently used
+    fn insert_loaded(
+        &mut self,
+        model_name: String,
+        engine: Arc<TranscriptionEngine>,
+        config: LazyModelConfig,
+    ) {
+        self.last_used.insert(model_name.clone(), Instant::now());
+        self.loaded_configs.insert(model_name.clone(), config);
+        self.preloaded.insert(model_name, engine);
     }
 
     /// Gets engine for model (preloaded or lazy loads on first use)
@@ -289,6 +780,7 @@ impl ModelManager {
     pub fn get_or_load(&mut self, model_name: &str) -> Result<Arc<TranscriptionEngine>> {
         // Return preloaded engine if exists (fast path)
         if let Some(engine) = self.preloaded.get(model_name) {
+            self.last_used.insert(model_name.to_owned(), Instant::now());
             return Ok(Arc::clone(engine));
         }
 
@@ -299,35 +791,49 @@ impl ModelManager {
             );
         }
 
-        // Lazy load if config exists
-        if let Some(config) = self.lazy_configs.remove(model_name) {
+        // Lazy load if config exists (kept until loaded so a failed load can be retried)
+        if let Some(config) = self.lazy_configs.get(model_name).cloned() {
             // Mark as loading to prevent concurrent loads
             self.loading.insert(model_name.to_owned());
 
             tracing::info!("lazy loading model: {}", model_name);
-            let load_result = TranscriptionEngine::new(
+            self.make_room();
+            let load_result = TranscriptionEngine::with_context_options(
                 &config.model_path,
                 config.threads,
                 config.beam_size,
-                config.language,
-            );
+                config.language.clone(),
+                config.context,
+            )
+            .map(|engine| {
+                engine
+                    .with_translate(config.translate)
+                    .with_preserve_whitespace(config.preserve_whitespace)
+                    .with_initial_prompt(config.initial_prompt.as_deref())
+                    .with_no_speech_threshold(self.model.no_speech_threshold)
+            });
 
             // Remove from loading set before returning (cleanup in all paths)
             self.loading.remove(model_name);
 
             // Handle load result
             let engine = Arc::new(load_result?);
-            self.preloaded
-                .insert(model_name.to_owned(), Arc::clone(&engine));
+            self.lazy_configs.remove(model_name);
+            self.insert_loaded(model_name.to_owned(), Arc::clone(&engine), config);
             return Ok(engine);
         }
 
         anyhow::bail!("model not found in configuration: {model_name}")
     }
 
+    /// `[model]` settings the manager's engines are loaded with
+    #[must_use]
+    pub const fn model_config(&self) -> &crate::config::ModelConfig {
+        &self.model
+    }
+
     /// Returns whether a model is currently loaded (preloaded or lazily loaded)
     #[must_use]
-    #[allow(dead_code)] // Will be used for UI feedback
     pub fn is_loaded(&self, model_name: &str) -> bool {
         self.preloaded.contains_key(model_name)
     }
@@ -354,6 +860,267 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_finalize_text_trims_by_default() {
+        assert_eq!(
+            finalize_text(" Hello world. ".to_owned(), false),
+            "Hello world."
+        );
+        assert_eq!(
+            finalize_text("\tfn main()\n".to_owned(), false),
+            "fn main()"
+        );
+    }
+
+    #[test]
+    fn test_finalize_text_preserve_whitespace() {
+        assert_eq!(
+            finalize_text("\tfn main()\n".to_owned(), true),
+            "\tfn main()\n"
+        );
+        assert_eq!(
+            finalize_text("    indented".to_owned(), true),
+            "    indented"
+        );
+    }
+
+    #[test]
+    fn test_finalize_text_whitespace_only_is_empty() {
+        assert_eq!(finalize_text("  \n ".to_owned(), false), "");
+        assert_eq!(finalize_text("  \n ".to_owned(), true), "");
+    }
+
+    #[test]
+    fn test_warmup_signature() {
+        // Callable on a shared engine, as ModelManager holds them
+        fn warm(engine: &Arc<TranscriptionEngine>) -> Result<(), TranscriptionError> {
+            engine.warmup()
+        }
+        let _ = warm;
+        assert_eq!(WARMUP_SAMPLES, 1600);
+    }
+
+    #[test]
+    fn test_abort_callback_follows_flag() {
+        let abort = Arc::new(AtomicBool::new(false));
+        let mut callback = abort_callback(Arc::clone(&abort));
+        assert!(!callback());
+        abort.store(true, Ordering::Relaxed);
+        assert!(callback());
+        abort.store(false, Ordering::Relaxed);
+        assert!(!callback());
+    }
+
+    #[test]
+    fn test_keep_segment() {
+        assert!(keep_segment(0.05, DEFAULT_NO_SPEECH_THRESHOLD));
+        assert!(keep_segment(0.6, 0.6));
+        assert!(!keep_segment(0.61, 0.6));
+        assert!(!keep_segment(0.97, DEFAULT_NO_SPEECH_THRESHOLD));
+    }
+
+    #[test]
+    fn test_keep_segment_threshold_extremes() {
+        // 1.0 keeps everything, 0.0 keeps only segments Whisper is certain are speech
+        assert!(keep_segment(1.0, 1.0));
+        assert!(keep_segment(0.0, 0.0));
+        assert!(!keep_segment(0.01, 0.0));
+        assert!(keep_segment(f32::NAN, 0.5));
+    }
+
+    #[test]
+    fn test_prompt_text() {
+        assert_eq!(
+            prompt_text("Kubernetes, kubectl, Grafana.").as_deref(),
+            Some("Kubernetes, kubectl, Grafana.")
+        );
+        assert_eq!(prompt_text("a\0b").as_deref(), Some("ab"));
+        assert_eq!(prompt_text(""), None);
+        assert_eq!(prompt_text("  \n"), None);
+        assert_eq!(prompt_text("\0"), None);
+    }
+
+    fn segments(texts: &[&str]) -> Vec<Segment> {
+        (0_i64..)
+            .zip(texts)
+            .map(|(i, text)| Segment::from_centiseconds((*text).to_owned(), i * 100, i * 100 + 90))
+            .collect()
+    }
+
+    #[test]
+    fn test_joined_text_matches_segment_concatenation() {
+        let segments = segments(&[" Hello there.", " How are you?"]);
+        let concatenated: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(
+            joined_text(&segments, Some("en"), false),
+            concatenated.trim()
+        );
+        assert_eq!(joined_text(&segments, Some("en"), true), concatenated);
+    }
+
+    #[test]
+    fn test_joined_text_empty_segments() {
+        assert_eq!(joined_text(&[], None, false), "");
+        assert_eq!(joined_text(&segments(&["  "]), None, true), "");
+    }
+
+    #[test]
+    fn test_segment_from_centiseconds() {
+        let segment = Segment::from_centiseconds(" hi".to_owned(), 150, 275);
+        assert_eq!(segment.start, Duration::from_millis(1500));
+        assert_eq!(segment.end, Duration::from_millis(2750));
+        assert_eq!(
+            Segment::from_centiseconds(String::new(), -1, 0).start,
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_context_options_flash_attn() {
+        assert!(!ContextOptions::default().params().flash_attn);
+        assert!(
+            ContextOptions {
+                flash_attn: true,
+                ..ContextOptions::default()
+            }
+            .params()
+            .flash_attn
+        );
+
+        let mut profile = crate::config::Config::default().profiles.remove(0);
+        let model = crate::config::ModelConfig {
+            use_gpu: true,
+            ..crate::config::ModelConfig::default()
+        };
+        assert_eq!(
+            ContextOptions::for_profile(&profile, &model),
+            ContextOptions::default()
+        );
+        profile.flash_attn = true;
+        assert!(ContextOptions::for_profile(&profile, &model).flash_attn);
+    }
+
+    #[test]
+    fn test_context_options_use_gpu() {
+        let profile = crate::config::Config::default().profiles.remove(0);
+        let model = crate::config::ModelConfig {
+            use_gpu: false,
+            ..crate::config::ModelConfig::default()
+        };
+        let options = ContextOptions::for_profile(&profile, &model);
+        assert!(options.cpu_only);
+        assert!(!options.params().use_gpu);
+
+        let model = crate::config::ModelConfig {
+            use_gpu: true,
+            ..model
+        };
+        assert!(
+            ContextOptions::for_profile(&profile, &model)
+                .params()
+                .use_gpu
+        );
+    }
+
+    #[test]
+    fn test_use_gpu_validates_params_before_loading() {
+        for cpu_only in [false, true] {
+            let options = ContextOptions {
+                cpu_only,
+                ..ContextOptions::default()
+            };
+            let result = TranscriptionEngine::with_context_options(
+                Path::new("/tmp/nonexistent_gpu_model.bin"),
+                0,
+                1,
+                None,
+                options,
+            );
+            assert!(matches!(result, Err(TranscriptionError::ModelLoad { .. })));
+            if let Err(TranscriptionError::ModelLoad { source, .. }) = result {
+                assert!(source.to_string().contains("threads must be > 0"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_context_options_cpu_only() {
+        assert!(ContextOptions::default().params().use_gpu);
+        let cpu = ContextOptions {
+            cpu_only: true,
+            ..ContextOptions::default()
+        };
+        assert!(!cpu.params().use_gpu);
+    }
+
+    #[test]
+    fn test_gpu_marker_forces_cpu() {
+        let dir = std::env::temp_dir().join(format!("whisper-engine-gpu-{}", std::process::id()));
+        let marker = dir.join("gpu_disabled");
+        let _ = std::fs::remove_dir_all(&dir);
+        let options = ContextOptions::default();
+        assert_eq!(
+            TranscriptionEngine::apply_gpu_marker(options, &marker),
+            options
+        );
+
+        gpu::record_disabled(&marker, "Metal device lost").unwrap();
+        assert!(TranscriptionEngine::apply_gpu_marker(options, &marker).cpu_only);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_transcription_error_gpu_classification() {
+        let error = TranscriptionError::Transcription(
+            anyhow::anyhow!("ggml_metal_graph_compute: command buffer 0 failed")
+                .context("whisper inference failed"),
+        );
+        assert!(gpu::should_fall_back(true, &error));
+        assert!(!gpu::should_fall_back(false, &error));
+        assert!(!gpu::should_fall_back(
+            true,
+            &TranscriptionError::StateCreation
+        ));
+    }
+
+    #[test]
+    fn test_model_load_nonexistent_path_with_flash_attn() {
+        let path = Path::new("/tmp/nonexistent_model.bin");
+        let result = TranscriptionEngine::with_context_options(
+            path,
+            4,
+            5,
+            None,
+            ContextOptions {
+                flash_attn: true,
+                ..ContextOptions::default()
+            },
+        );
+        assert!(matches!(result, Err(TranscriptionError::ModelLoad { .. })));
+    }
+
+    #[test]
+    #[ignore = "requires actual model file"]
+    fn test_transcribe_with_flash_attn() {
+        let Some(model_path) = get_test_model_path() else {
+            eprintln!("Skipping test: no model found");
+            return;
+        };
+
+        let engine = TranscriptionEngine::with_context_options(
+            &model_path,
+            4,
+            1,
+            None,
+            ContextOptions {
+                flash_attn: true,
+                ..ContextOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(engine.transcribe(&vec![0.0; 16000]).is_ok());
+    }
+
     #[test]
     fn test_model_load_nonexistent_path() {
         let nonexistent_path = Path::new("/tmp/nonexistent_model.bin");
@@ -402,6 +1169,60 @@ mod tests {
         );
     }
 
+    #[test]
+    #[ignore = "requires actual model file"]
+    fn test_transcribe_with_segments_matches_transcribe() {
+        let Some(model_path) = get_test_model_path() else {
+            eprintln!("Skipping test: no model found");
+            return;
+        };
+
+        let engine = TranscriptionEngine::new(&model_path, 4, 1, None).unwrap();
+        // 3 seconds of a quiet tone (16kHz)
+        let audio: Vec<f32> = (0..48000_u16)
+            .map(|i| (f32::from(i) * 0.05).sin() * 0.1)
+            .collect();
+
+        let segments = engine.transcribe_with_segments(&audio).unwrap();
+        for segment in &segments {
+            assert!(segment.start <= segment.end);
+        }
+        assert_eq!(
+            joined_text(&segments, None, false),
+            engine.transcribe(&audio).unwrap()
+        );
+    }
+
+    #[test]
+    #[ignore = "requires actual model file"]
+    fn test_warmup() {
+        let Some(model_path) = get_test_model_path() else {
+            eprintln!("Skipping test: no model found");
+            return;
+        };
+
+        let engine = TranscriptionEngine::new(&model_path, 4, 1, None).unwrap();
+        assert!(engine.warmup().is_ok());
+        assert!(engine.transcribe(&vec![0.0; 16000]).is_ok());
+    }
+
+    #[test]
+    #[ignore = "requires actual model file"]
+    fn test_transcribe_aborted() {
+        let Some(model_path) = get_test_model_path() else {
+            eprintln!("Skipping test: no model found");
+            return;
+        };
+
+        let engine = TranscriptionEngine::new(&model_path, 4, 1, None).unwrap();
+        let abort = engine.abort_handle();
+        abort.store(true, Ordering::Relaxed);
+
+        // 25 seconds of silence stops right away instead of running to the end
+        assert!(engine.transcribe(&vec![0.0; 16000 * 25]).is_ok());
+        assert!(!abort.load(Ordering::Relaxed));
+    }
+
     #[test]
     #[ignore = "requires actual model file"]
     fn test_transcribe_empty_audio() {
@@ -737,7 +1558,7 @@ mod tests {
     #[test]
     fn test_model_manager_new_empty_profiles() {
         let profiles = vec![];
-        let manager = ModelManager::new(&profiles).unwrap();
+        let manager = ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         assert_eq!(manager.preloaded.len(), 0);
         assert_eq!(manager.lazy_configs.len(), 0);
         assert_eq!(manager.loading.len(), 0);
@@ -745,7 +1566,7 @@ mod tests {
 
     #[test]
     fn test_model_manager_new_preload_false() {
-        use crate::config::{HotkeyConfig, ModelType, TranscriptionProfile};
+        use crate::config::{HotkeyConfig, ModelType, ProfileMode, TranscriptionProfile};
 
         let profiles = vec![TranscriptionProfile {
             name: Some("test-model".to_owned()),
@@ -755,18 +1576,68 @@ mod tests {
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
 
-        let manager = ModelManager::new(&profiles).unwrap();
+        let manager = ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         assert_eq!(manager.preloaded.len(), 0);
         assert_eq!(manager.lazy_configs.len(), 1);
         assert!(manager.lazy_configs.contains_key("test-model"));
     }
 
+    #[test]
+    fn test_model_manager_keeps_initial_prompt() {
+        let profile = crate::config::TranscriptionProfile {
+            name: Some("ops".to_owned()),
+            preload: false,
+            initial_prompt: Some("Kubernetes, kubectl, Grafana.".to_owned()),
+            ..crate::config::Config::default().profiles.remove(0)
+        };
+
+        let manager =
+            ModelManager::new(&[profile], &crate::config::ModelConfig::default()).unwrap();
+        assert_eq!(
+            manager.lazy_configs["ops"].initial_prompt.as_deref(),
+            Some("Kubernetes, kubectl, Grafana.")
+        );
+    }
+
+    #[test]
+    #[ignore = "requires actual model file"]
+    fn test_engine_stores_initial_prompt() {
+        let Some(model_path) = get_test_model_path() else {
+            eprintln!("Skipping test: no model found at ~/.whisper-hotkey/models/ggml-tiny.bin");
+            return;
+        };
+        let engine = TranscriptionEngine::new(&model_path, 4, 1, None)
+            .unwrap()
+            .with_initial_prompt(Some("Kubernetes, kubectl."));
+        assert_eq!(
+            engine.initial_prompt.as_deref(),
+            Some("Kubernetes, kubectl.")
+        );
+        assert!(engine.transcribe(&vec![0.0; 16000]).is_ok());
+
+        let engine = engine.with_initial_prompt(Some("   "));
+        assert_eq!(engine.initial_prompt, None);
+    }
+
     #[test]
     fn test_model_manager_get_or_load_model_not_found() {
         let profiles = vec![];
-        let mut manager = ModelManager::new(&profiles).unwrap();
+        let mut manager =
+            ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
 
         let result = manager.get_or_load("nonexistent");
         assert!(result.is_err());
@@ -775,9 +1646,95 @@ mod tests {
         }
     }
 
+    fn usage(entries: &[(&str, u64)]) -> HashMap<String, Instant> {
+        let base = Instant::now();
+        entries
+            .iter()
+            .map(|&(name, secs)| (name.to_owned(), base + std::time::Duration::from_secs(secs)))
+            .collect()
+    }
+
+    #[test]
+    fn test_lru_evictions_unlimited_budget() {
+        let last_used = usage(&[("a", 1), ("b", 2), ("c", 3)]);
+        assert!(lru_evictions(&last_used, |_| false, 0).is_empty());
+    }
+
+    #[test]
+    fn test_lru_evictions_within_budget() {
+        let last_used = usage(&[("a", 1)]);
+        assert!(lru_evictions(&last_used, |_| false, 2).is_empty());
+    }
+
+    #[test]
+    fn test_lru_evictions_least_recent_first() {
+        let last_used = usage(&[("recent", 30), ("oldest", 10), ("middle", 20)]);
+        assert_eq!(lru_evictions(&last_used, |_| false, 3), vec!["oldest"]);
+        assert_eq!(
+            lru_evictions(&last_used, |_| false, 2),
+            vec!["oldest", "middle"]
+        );
+        assert_eq!(
+            lru_evictions(&last_used, |_| false, 1),
+            vec!["oldest", "middle", "recent"]
+        );
+    }
+
+    #[test]
+    fn test_lru_evictions_skip_in_use() {
+        let last_used = usage(&[("busy", 10), ("idle", 20), ("recent", 30)]);
+        assert_eq!(
+            lru_evictions(&last_used, |name| name == "busy", 3),
+            vec!["idle"]
+        );
+    }
+
+    #[test]
+    fn test_lru_evictions_all_in_use_exceeds_budget() {
+        let last_used = usage(&[("a", 10), ("b", 20)]);
+        assert!(lru_evictions(&last_used, |_| true, 1).is_empty());
+    }
+
+    #[test]
+    fn test_model_manager_failed_lazy_load_can_retry() {
+        use crate::config::{HotkeyConfig, ModelType, ProfileMode, TranscriptionProfile};
+
+        let profiles = vec![TranscriptionProfile {
+            name: Some("missing-model".to_owned()),
+            model_type: ModelType::BaseEn,
+            hotkey: HotkeyConfig::default(),
+            preload: false,
+            threads: 4,
+            beam_size: 1,
+            language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: Some("/nonexistent/ggml-missing.bin".to_owned()),
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
+        }];
+
+        let mut manager =
+            ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
+        assert!(manager.get_or_load("missing-model").is_err());
+        assert!(!manager.is_loaded("missing-model"));
+        assert!(manager.loading.is_empty());
+
+        // Config survives the failure: the next press retries instead of "not found"
+        let err = manager.get_or_load("missing-model").err().unwrap();
+        assert!(!err.to_string().contains("model not found in configuration"));
+    }
+
     #[test]
     fn test_model_manager_is_loaded_false() {
-        use crate::config::{HotkeyConfig, ModelType, TranscriptionProfile};
+        use crate::config::{HotkeyConfig, ModelType, ProfileMode, TranscriptionProfile};
 
         let profiles = vec![TranscriptionProfile {
             name: Some("test-model".to_owned()),
@@ -787,15 +1744,29 @@ mod tests {
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
 
-        let manager = ModelManager::new(&profiles).unwrap();
+        let manager = ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         assert!(!manager.is_loaded("test-model"));
     }
 
     #[test]
     fn test_model_manager_multiple_profiles_mixed_preload() {
-        use crate::config::{HotkeyConfig, ModelType, TranscriptionProfile};
+        use crate::config::{
+            HotkeyConfig, HotkeyMode, ModelType, ProfileMode, TranscriptionProfile,
+        };
 
         let profiles = vec![
             TranscriptionProfile {
@@ -804,11 +1775,24 @@ mod tests {
                 hotkey: HotkeyConfig {
                     modifiers: vec!["Command".to_owned()],
                     key: "A".to_owned(),
+                    mode: HotkeyMode::Hold,
                 },
                 preload: false,
                 threads: 4,
                 beam_size: 1,
                 language: Some("en".to_owned()),
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             },
             TranscriptionProfile {
                 name: Some("another-lazy".to_owned()),
@@ -816,15 +1800,28 @@ mod tests {
                 hotkey: HotkeyConfig {
                     modifiers: vec!["Command".to_owned()],
                     key: "B".to_owned(),
+                    mode: HotkeyMode::Hold,
                 },
                 preload: false,
                 threads: 8,
                 beam_size: 5,
                 language: Some("es".to_owned()),
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             },
         ];
 
-        let manager = ModelManager::new(&profiles).unwrap();
+        let manager = ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         assert_eq!(manager.preloaded.len(), 0);
         assert_eq!(manager.lazy_configs.len(), 2);
         assert!(manager.lazy_configs.contains_key("lazy-model"));
@@ -833,7 +1830,7 @@ mod tests {
 
     #[test]
     fn test_model_manager_lazy_config_stores_correct_values() {
-        use crate::config::{HotkeyConfig, ModelType, TranscriptionProfile};
+        use crate::config::{HotkeyConfig, ModelType, ProfileMode, TranscriptionProfile};
 
         let profiles = vec![TranscriptionProfile {
             name: Some("custom-model".to_owned()),
@@ -843,9 +1840,21 @@ mod tests {
             threads: 8,
             beam_size: 5,
             language: Some("es".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
 
-        let manager = ModelManager::new(&profiles).unwrap();
+        let manager = ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         let config = manager.lazy_configs.get("custom-model").unwrap();
         assert_eq!(config.threads, 8);
         assert_eq!(config.beam_size, 5);
@@ -856,7 +1865,7 @@ mod tests {
     #[test]
     #[ignore = "requires actual model file"]
     fn test_model_manager_get_or_load_lazy() {
-        use crate::config::{HotkeyConfig, ModelType, TranscriptionProfile};
+        use crate::config::{HotkeyConfig, ModelType, ProfileMode, TranscriptionProfile};
 
         let profiles = vec![TranscriptionProfile {
             name: Some("test-model".to_owned()),
@@ -866,9 +1875,22 @@ mod tests {
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
 
-        let mut manager = ModelManager::new(&profiles).unwrap();
+        let mut manager =
+            ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         assert!(!manager.is_loaded("test-model"));
 
         // First get_or_load should trigger lazy load
@@ -883,7 +1905,7 @@ mod tests {
     #[test]
     #[ignore = "requires actual model file"]
     fn test_model_manager_new_with_preload() {
-        use crate::config::{HotkeyConfig, ModelType, TranscriptionProfile};
+        use crate::config::{HotkeyConfig, ModelType, ProfileMode, TranscriptionProfile};
 
         let profiles = vec![TranscriptionProfile {
             name: Some("preloaded-model".to_owned()),
@@ -893,9 +1915,21 @@ mod tests {
             threads: 4,
             beam_size: 1,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         }];
 
-        let manager = ModelManager::new(&profiles).unwrap();
+        let manager = ModelManager::new(&profiles, &crate::config::ModelConfig::default()).unwrap();
         assert_eq!(manager.preloaded.len(), 1);
         assert_eq!(manager.lazy_configs.len(), 0);
         assert!(manager.is_loaded("preloaded-model"));
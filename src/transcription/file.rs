@@ -0,0 +1,309 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use crate::audio::AudioCapture;
+
+/// Audio file formats that can be routed to the transcription pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFileKind {
+    /// WAV (PCM integer or float, any rate/channel count)
+    Wav,
+}
+
+/// Errors when routing a file to transcription
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FileRouteError {
+    /// File has no extension to route by
+    #[error("file has no extension: {path}")]
+    NoExtension {
+        /// Path of the rejected file
+        path: String,
+    },
+
+    /// Extension is not a supported audio format
+    #[error("unsupported audio file type: .{extension} (supported: wav)")]
+    UnsupportedExtension {
+        /// Lowercased extension of the rejected file
+        extension: String,
+    },
+}
+
+/// Route a file path to the transcription pipeline by extension (case-insensitive)
+///
+/// # Errors
+/// Returns error if the path has no extension or the extension is unsupported
+pub fn route_file(path: &Path) -> Result<AudioFileKind, FileRouteError> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .ok_or_else(|| FileRouteError::NoExtension {
+            path: path.display().to_string(),
+        })?
+        .to_ascii_lowercase();
+
+    match extension.as_str() {
+        "wav" | "wave" => Ok(AudioFileKind::Wav),
+        _ => Err(FileRouteError::UnsupportedExtension { extension }),
+    }
+}
+
+/// Parse a dropped file URL or pasted path into a filesystem path
+///
+/// Accepts `file://` URLs (percent-encoded) and plain paths, optionally quoted.
+/// Returns `None` for empty input or non-file URLs.
+#[must_use]
+pub fn parse_dropped_path(input: &str) -> Option<PathBuf> {
+    let trimmed = input.trim().trim_matches(|c| c == '"' || c == '\'');
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("file://") {
+        // Strip optional host ("localhost") before the absolute path
+        let path = rest.strip_prefix("localhost").unwrap_or(rest);
+        return Some(PathBuf::from(percent_decode(path)?));
+    }
+
+    if trimmed.contains("://") {
+        return None;
+    }
+
+    Some(PathBuf::from(trimmed))
+}
+
+/// Decode `%XX` escapes in a URL path (returns `None` on malformed escapes or invalid UTF-8)
+fn percent_decode(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = input.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+/// Load an audio file as 16kHz mono samples ready for transcription
+///
+/// # Errors
+/// Returns error if the file type is unsupported or the file cannot be decoded
+pub fn load_audio_file(path: &Path) -> Result<Vec<f32>> {
+    match route_file(path)? {
+        AudioFileKind::Wav => load_wav(path),
+    }
+}
+
+fn load_wav(path: &Path) -> Result<Vec<f32>> {
+    let mut reader = hound::WavReader::open(path)
+        .with_context(|| format!("failed to open WAV file {}", path.display()))?;
+    let spec = reader.spec();
+
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .context("failed to read WAV samples")?,
+        // Scale signed PCM to [-1.0, 1.0]; f32 precision is sufficient for audio
+        #[allow(clippy::cast_precision_loss)]
+        hound::SampleFormat::Int => {
+            let scale = (1_i64 << (spec.bits_per_sample.saturating_sub(1))) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / scale))
+                .collect::<Result<_, _>>()
+                .context("failed to read WAV samples")?
+        }
+    };
+
+    Ok(AudioCapture::to_16khz_mono(
+        &samples,
+        spec.sample_rate,
+        spec.channels,
+    ))
+}
+
+/// Show the native file picker and return the chosen path (`None` if cancelled)
+///
+/// Fallback for dropping files on the tray icon, which the tray library doesn't support.
+#[cfg(target_os = "macos")]
+#[must_use]
+pub fn pick_audio_file() -> Option<PathBuf> {
+    let output = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg("POSIX path of (choose file with prompt \"Choose an audio file to transcribe\")")
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        // User cancelled the dialog
+        return None;
+    }
+
+    parse_dropped_path(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// File picker is macOS-only
+#[cfg(not(target_os = "macos"))]
+#[must_use]
+pub const fn pick_audio_file() -> Option<PathBuf> {
+    None
+}
+
+/// Copy text to the system clipboard
+///
+/// # Errors
+/// Returns error if `pbcopy` cannot be spawned or fails
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    use std::io::Write;
+
+    let mut child = std::process::Command::new("pbcopy")
+        .stdin(std::process::Stdio::piped())
+        .spawn()
+        .context("failed to spawn pbcopy")?;
+
+    child
+        .stdin
+        .take()
+        .context("failed to open pbcopy stdin")?
+        .write_all(text.as_bytes())
+        .context("failed to write to pbcopy")?;
+
+    let status = child.wait().context("failed to wait for pbcopy")?;
+    if !status.success() {
+        anyhow::bail!("pbcopy exited with {status}");
+    }
+    Ok(())
+}
+
+/// Read the system clipboard's text contents
+///
+/// # Errors
+/// Returns error if `pbpaste` cannot be run or fails
+pub fn read_clipboard() -> Result<String> {
+    let output = std::process::Command::new("pbpaste")
+        .output()
+        .context("failed to run pbpaste")?;
+    if !output.status.success() {
+        anyhow::bail!("pbpaste exited with {}", output.status);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_file_wav() {
+        assert_eq!(
+            route_file(Path::new("/tmp/memo.wav")),
+            Ok(AudioFileKind::Wav)
+        );
+        assert_eq!(
+            route_file(Path::new("/tmp/memo.wave")),
+            Ok(AudioFileKind::Wav)
+        );
+    }
+
+    #[test]
+    fn test_route_file_case_insensitive() {
+        assert_eq!(
+            route_file(Path::new("/tmp/MEMO.WAV")),
+            Ok(AudioFileKind::Wav)
+        );
+    }
+
+    #[test]
+    fn test_route_file_unsupported_extension() {
+        let err = route_file(Path::new("/tmp/notes.txt")).unwrap_err();
+        assert_eq!(
+            err,
+            FileRouteError::UnsupportedExtension {
+                extension: "txt".to_owned()
+            }
+        );
+        assert!(err.to_string().contains(".txt"));
+
+        assert!(route_file(Path::new("/tmp/song.MP3")).is_err());
+    }
+
+    #[test]
+    fn test_route_file_no_extension() {
+        let err = route_file(Path::new("/tmp/recording")).unwrap_err();
+        assert!(matches!(err, FileRouteError::NoExtension { .. }));
+
+        // Dotfile without extension
+        assert!(route_file(Path::new("/tmp/.wav")).is_err());
+    }
+
+    #[test]
+    fn test_parse_dropped_path_file_url() {
+        assert_eq!(
+            parse_dropped_path("file:///Users/me/My%20Memo.wav"),
+            Some(PathBuf::from("/Users/me/My Memo.wav"))
+        );
+        assert_eq!(
+            parse_dropped_path("file://localhost/tmp/a.wav"),
+            Some(PathBuf::from("/tmp/a.wav"))
+        );
+    }
+
+    #[test]
+    fn test_parse_dropped_path_plain_and_quoted() {
+        assert_eq!(
+            parse_dropped_path("  /tmp/a.wav\n"),
+            Some(PathBuf::from("/tmp/a.wav"))
+        );
+        assert_eq!(
+            parse_dropped_path("\"/tmp/with space.wav\""),
+            Some(PathBuf::from("/tmp/with space.wav"))
+        );
+    }
+
+    #[test]
+    fn test_parse_dropped_path_rejects_invalid() {
+        assert_eq!(parse_dropped_path("   "), None);
+        assert_eq!(parse_dropped_path("https://example.com/a.wav"), None);
+        assert_eq!(parse_dropped_path("file:///tmp/bad%2"), None);
+    }
+
+    #[test]
+    fn test_load_audio_file_unsupported_type() {
+        let err = load_audio_file(Path::new("/tmp/does-not-matter.flac")).unwrap_err();
+        assert!(err.to_string().contains("unsupported audio file type"));
+    }
+
+    #[test]
+    fn test_load_wav_int_stereo_resampled() {
+        let path = std::env::temp_dir().join("test_load_wav_int_stereo.wav");
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 32000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for _ in 0..3200 {
+            writer.write_sample(i16::MAX / 2).unwrap();
+            writer.write_sample(i16::MAX / 2).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let samples = load_audio_file(&path).unwrap();
+
+        // 3200 frames at 32kHz -> 1600 samples at 16kHz
+        assert_eq!(samples.len(), 1600);
+        assert!((samples[0] - 0.5).abs() < 0.01);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
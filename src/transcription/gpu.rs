@@ -0,0 +1,169 @@
+use anyhow::{Context, Result};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Lowercase fragments that only show up in GPU backend failures
+///
+/// Matched against every message in an error's source chain. Generic words like
+/// "device" alone are left out: microphone errors mention devices too.
+const GPU_ERROR_PATTERNS: &[&str] = &[
+    "metal",
+    "mtl",
+    "gpu",
+    "cuda",
+    "vulkan",
+    "ggml_backend",
+    "command buffer",
+    "device memory",
+    "kiogpu",
+];
+
+/// Whether `message` describes a GPU backend failure (pure)
+#[must_use]
+pub fn is_gpu_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    GPU_ERROR_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+/// Whether any error in `error`'s source chain is GPU-related
+#[must_use]
+pub fn is_gpu_failure(error: &(dyn Error + 'static)) -> bool {
+    std::iter::successors(Some(error), |&e| e.source()).any(|e| is_gpu_error(&e.to_string()))
+}
+
+/// `error` and its sources joined into one line for logs and the marker file
+#[must_use]
+pub fn describe(error: &(dyn Error + 'static)) -> String {
+    std::iter::successors(Some(error), |&e| e.source())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(": ")
+}
+
+/// Whether a failed inference should be retried on the CPU (pure)
+///
+/// Only while the GPU is still in use: once downgraded, failures are real errors
+/// and are reported instead of retried again.
+#[must_use]
+pub fn should_fall_back(using_gpu: bool, error: &(dyn Error + 'static)) -> bool {
+    using_gpu && is_gpu_failure(error)
+}
+
+/// File noting that the GPU was disabled after errors
+///
+/// While it exists models load on the CPU; delete it to try the GPU again.
+#[must_use]
+pub fn marker_path() -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_owned());
+    PathBuf::from(home)
+        .join(".whisper-hotkey")
+        .join("gpu_disabled")
+}
+
+/// Whether the GPU was disabled by an earlier fallback
+#[must_use]
+pub fn is_disabled(marker: &Path) -> bool {
+    marker.exists()
+}
+
+/// Persist the downgrade so later launches load on the CPU directly
+///
+/// # Errors
+/// Returns error if the marker can't be written
+pub fn record_disabled(marker: &Path, reason: &str) -> Result<()> {
+    if let Some(dir) = marker.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    fs::write(marker, format!("GPU disabled due to errors: {reason}\n"))
+        .with_context(|| format!("failed to write {}", marker.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_gpu_errors_classified() {
+        for message in [
+            "ggml_metal_graph_compute: command buffer 0 failed with status 5",
+            "ggml_metal_init: error: failed to allocate MTLBuffer",
+            "Metal device lost",
+            "GPU hang occurred (kIOGPUCommandBufferCallbackErrorHang)",
+            "CUDA error: out of memory",
+            "ggml_backend_sched_graph_compute failed",
+            "insufficient device memory",
+        ] {
+            assert!(is_gpu_error(message), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_other_errors_not_classified() {
+        for message in [
+            "failed to transcribe audio",
+            "failed to create whisper state",
+            "audio device disconnected",
+            "model path contains invalid UTF-8",
+            "whisper inference failed: GenericError(-7)",
+            "",
+        ] {
+            assert!(!is_gpu_error(message), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_gpu_failure_found_in_source_chain() {
+        let error = anyhow!("ggml_metal_graph_compute: command buffer failed")
+            .context("whisper inference failed");
+        let error: &(dyn Error + 'static) = error.as_ref();
+        assert!(is_gpu_failure(error));
+
+        let error = anyhow!("GenericError(-7)").context("whisper inference failed");
+        assert!(!is_gpu_failure(error.as_ref()));
+    }
+
+    #[test]
+    fn test_describe_joins_chain() {
+        let error = anyhow!("command buffer failed").context("whisper inference failed");
+        assert_eq!(
+            describe(error.as_ref()),
+            "whisper inference failed: command buffer failed"
+        );
+    }
+
+    #[test]
+    fn test_fall_back_only_once() {
+        let error = anyhow!("Metal command buffer failed");
+        assert!(should_fall_back(true, error.as_ref()));
+        // Already on the CPU: the retry's failure is reported, not retried
+        assert!(!should_fall_back(false, error.as_ref()));
+
+        let error = anyhow!("GenericError(-7)");
+        assert!(!should_fall_back(true, error.as_ref()));
+    }
+
+    #[test]
+    fn test_marker_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("whisper-gpu-marker-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let marker = dir.join("gpu_disabled");
+        assert!(!is_disabled(&marker));
+
+        record_disabled(&marker, "Metal device lost").unwrap();
+        assert!(is_disabled(&marker));
+        assert!(fs::read_to_string(&marker)
+            .unwrap()
+            .contains("GPU disabled due to errors: Metal device lost"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_marker_path() {
+        assert!(marker_path().ends_with(".whisper-hotkey/gpu_disabled"));
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use super::engine::{Transcript, TranscriptionEngine, TranscriptionError};
+use crate::config::{Config, LatencyConfig, ModelConfig, TranscriptionProfile};
+
+/// Thresholds for switching between a profile's model and its faster fallback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyPolicy {
+    /// Downgrade when the rolling average exceeds this
+    pub max: Duration,
+    /// Upgrade back when the rolling average (on the fallback) drops below this
+    pub recover: Duration,
+    /// Number of transcriptions averaged
+    pub window: usize,
+}
+
+impl LatencyPolicy {
+    /// Policy from `[latency]`, or None when disabled (`max_inference_ms = 0`)
+    ///
+    /// `recover_inference_ms` of 0 or not below the maximum uses half the maximum,
+    /// so there is always a gap between the two thresholds.
+    #[must_use]
+    pub fn from_config(config: &LatencyConfig) -> Option<Self> {
+        if config.max_inference_ms == 0 {
+            return None;
+        }
+        let max = Duration::from_millis(config.max_inference_ms);
+        let recover = if config.recover_inference_ms == 0
+            || config.recover_inference_ms >= config.max_inference_ms
+        {
+            max / 2
+        } else {
+            Duration::from_millis(config.recover_inference_ms)
+        };
+        Some(Self {
+            max,
+            recover,
+            window: config.window.max(1),
+        })
+    }
+}
+
+/// Which model a profile currently transcribes with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelTier {
+    /// The profile's configured model
+    Primary,
+    /// The faster `fallback_model`
+    Fallback,
+}
+
+/// Rolling inference-time average with downgrade/upgrade hysteresis
+#[derive(Debug)]
+pub struct LatencyMonitor {
+    policy: LatencyPolicy,
+    samples: VecDeque<Duration>,
+    tier: ModelTier,
+}
+
+impl LatencyMonitor {
+    /// Monitor starting on the primary model
+    #[must_use]
+    pub fn new(policy: LatencyPolicy) -> Self {
+        Self {
+            policy,
+            samples: VecDeque::with_capacity(policy.window),
+            tier: ModelTier::Primary,
+        }
+    }
+
+    /// Current tier
+    #[must_use]
+    pub const fn tier(&self) -> ModelTier {
+        self.tier
+    }
+
+    /// Record one inference time; returns the new tier if it changed
+    ///
+    /// Decisions need a full window of samples, and the window restarts after each
+    /// switch so the new model is judged on its own timings.
+    pub fn record(&mut self, inference: Duration) -> Option<ModelTier> {
+        if self.samples.len() == self.policy.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(inference);
+        if self.samples.len() < self.policy.window {
+            return None;
+        }
+
+        let total: Duration = self.samples.iter().sum();
+        let average = total / u32::try_from(self.samples.len()).unwrap_or(u32::MAX);
+        let next = match self.tier {
+            ModelTier::Primary if average > self.policy.max => ModelTier::Fallback,
+            ModelTier::Fallback if average < self.policy.recover => ModelTier::Primary,
+            _ => return None,
+        };
+        self.switch_to(next);
+        Some(next)
+    }
+
+    /// Force `tier` and restart the window
+    pub fn switch_to(&mut self, tier: ModelTier) {
+        self.tier = tier;
+        self.samples.clear();
+    }
+}
+
+/// A profile's fallback model, loaded on first downgrade and used while latency is high
+pub struct LatencyFallback {
+    monitor: Mutex<LatencyMonitor>,
+    profile: TranscriptionProfile,
+    model_path: PathBuf,
+    model: ModelConfig,
+    engine: Mutex<Option<Arc<TranscriptionEngine>>>,
+}
+
+impl LatencyFallback {
+    /// Fallback for `profile`, or None if latency control is off or no `fallback_model` is set
+    ///
+    /// # Errors
+    /// Returns error if the fallback model path can't be expanded
+    pub fn for_profile(
+        profile: &TranscriptionProfile,
+        config: &LatencyConfig,
+        model: &ModelConfig,
+    ) -> anyhow::Result<Option<Self>> {
+        let (Some(policy), Some(fallback)) =
+            (LatencyPolicy::from_config(config), profile.fallback_model)
+        else {
+            return Ok(None);
+        };
+        let model_path = Config::expand_path(&fallback.model_path())?;
+        Ok(Some(Self {
+            monitor: Mutex::new(LatencyMonitor::new(policy)),
+            profile: profile.clone(),
+            model_path,
+            model: model.clone(),
+            engine: Mutex::new(None),
+        }))
+    }
+
+    /// Transcribe with the engine for the current tier, then update the latency average
+    ///
+    /// `on_switch` is called when the tier changes.
+    ///
+    /// # Errors
+    /// Returns error if Whisper inference fails
+    pub fn transcribe(
+        &self,
+        primary: &Arc<TranscriptionEngine>,
+        audio_data: &[f32],
+        on_switch: impl FnOnce(ModelTier),
+    ) -> Result<Transcript, TranscriptionError> {
+        let engine = match self.tier() {
+            ModelTier::Primary => Arc::clone(primary),
+            ModelTier::Fallback => self
+                .fallback_engine()
+                .unwrap_or_else(|| Arc::clone(primary)),
+        };
+
+        let start = Instant::now();
+        let result = engine.transcribe_detailed(audio_data)?;
+        let elapsed = start.elapsed();
+
+        let change = self
+            .monitor
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .record(elapsed);
+        if let Some(tier) = change {
+            self.apply_switch(tier, on_switch);
+        }
+        Ok(result)
+    }
+
+    fn tier(&self) -> ModelTier {
+        self.monitor
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .tier()
+    }
+
+    fn fallback_engine(&self) -> Option<Arc<TranscriptionEngine>> {
+        self.engine
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Load the fallback engine on downgrade; stay on the primary model if that fails
+    fn apply_switch(&self, tier: ModelTier, on_switch: impl FnOnce(ModelTier)) {
+        if tier == ModelTier::Fallback && self.fallback_engine().is_none() {
+            match TranscriptionEngine::for_profile(&self.profile, &self.model, &self.model_path) {
+                Ok(engine) => {
+                    *self.engine.lock().unwrap_or_else(PoisonError::into_inner) =
+                        Some(Arc::new(engine));
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "❌ Failed to load fallback model");
+                    self.monitor
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .switch_to(ModelTier::Primary);
+                    return;
+                }
+            }
+        }
+
+        tracing::info!(
+            profile = %self.profile.name(),
+            tier = ?tier,
+            "🐢 Latency model switch"
+        );
+        on_switch(tier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> LatencyPolicy {
+        LatencyPolicy {
+            max: Duration::from_secs(1),
+            recover: Duration::from_millis(400),
+            window: 3,
+        }
+    }
+
+    /// Feed millisecond timings, returning every tier change
+    fn run(monitor: &mut LatencyMonitor, timings_ms: &[u64]) -> Vec<Option<ModelTier>> {
+        timings_ms
+            .iter()
+            .map(|&ms| monitor.record(Duration::from_millis(ms)))
+            .collect()
+    }
+
+    #[test]
+    fn test_no_decision_before_window_full() {
+        let mut monitor = LatencyMonitor::new(policy());
+        assert_eq!(run(&mut monitor, &[5000, 5000]), vec![None, None]);
+        assert_eq!(monitor.tier(), ModelTier::Primary);
+    }
+
+    #[test]
+    fn test_downgrade_on_sustained_high_latency() {
+        let mut monitor = LatencyMonitor::new(policy());
+        assert_eq!(
+            run(&mut monitor, &[1200, 1300, 1100]),
+            vec![None, None, Some(ModelTier::Fallback)]
+        );
+        assert_eq!(monitor.tier(), ModelTier::Fallback);
+    }
+
+    #[test]
+    fn test_single_spike_does_not_downgrade() {
+        let mut monitor = LatencyMonitor::new(policy());
+        // Average of (300, 2400, 200) = 966ms, below the 1000ms maximum
+        assert_eq!(run(&mut monitor, &[300, 2400, 200]), vec![None; 3]);
+        assert_eq!(monitor.tier(), ModelTier::Primary);
+    }
+
+    #[test]
+    fn test_rolling_window_drops_old_samples() {
+        let mut monitor = LatencyMonitor::new(policy());
+        run(&mut monitor, &[200, 200, 200]);
+        // Window slides until only slow samples remain
+        assert_eq!(
+            run(&mut monitor, &[1200, 1200, 1200]),
+            vec![None, None, Some(ModelTier::Fallback)]
+        );
+    }
+
+    #[test]
+    fn test_hysteresis_between_thresholds() {
+        let mut monitor = LatencyMonitor::new(policy());
+        run(&mut monitor, &[1500, 1500, 1500]);
+        assert_eq!(monitor.tier(), ModelTier::Fallback);
+
+        // Between recover (400) and max (1000): stay on fallback
+        assert_eq!(run(&mut monitor, &[700, 600, 800, 500]), vec![None; 4]);
+        assert_eq!(monitor.tier(), ModelTier::Fallback);
+    }
+
+    #[test]
+    fn test_upgrade_when_latency_recovers() {
+        let mut monitor = LatencyMonitor::new(policy());
+        run(&mut monitor, &[1500, 1500, 1500]);
+        assert_eq!(
+            run(&mut monitor, &[300, 350, 250]),
+            vec![None, None, Some(ModelTier::Primary)]
+        );
+        assert_eq!(monitor.tier(), ModelTier::Primary);
+    }
+
+    #[test]
+    fn test_window_restarts_after_switch() {
+        let mut monitor = LatencyMonitor::new(policy());
+        run(&mut monitor, &[1500, 1500, 1500]);
+        // Fast fallback samples alone decide the upgrade, not pre-switch timings
+        assert_eq!(run(&mut monitor, &[100]), vec![None]);
+    }
+
+    #[test]
+    fn test_policy_from_config() {
+        assert_eq!(LatencyPolicy::from_config(&LatencyConfig::default()), None);
+
+        let config = LatencyConfig {
+            max_inference_ms: 2000,
+            recover_inference_ms: 0,
+            window: 0,
+        };
+        assert_eq!(
+            LatencyPolicy::from_config(&config),
+            Some(LatencyPolicy {
+                max: Duration::from_secs(2),
+                recover: Duration::from_secs(1),
+                window: 1,
+            })
+        );
+
+        // Recover threshold must stay below the maximum
+        let config = LatencyConfig {
+            max_inference_ms: 2000,
+            recover_inference_ms: 2500,
+            window: 5,
+        };
+        assert_eq!(
+            LatencyPolicy::from_config(&config).map(|p| p.recover),
+            Some(Duration::from_secs(1))
+        );
+    }
+}
@@ -1,7 +1,23 @@
+/// Batch transcription of a directory of audio files
+pub mod batch;
+/// Small model used while a profile's configured model downloads
+pub mod bootstrap;
 /// Model download and management
 pub mod download;
 /// Whisper model inference engine
 pub mod engine;
+/// Audio file routing and loading for file transcription
+pub mod file;
+/// GPU failure detection and CPU fallback
+pub mod gpu;
+/// Latency monitoring and fallback model switching
+pub mod latency;
+/// Punctuation from pauses between segments
+pub mod punctuation;
+/// Bounded transcription job queue
+pub mod queue;
+/// Script-aware joining of segments (no spaces for CJK)
+pub mod script;
 
-pub use download::ensure_model_downloaded;
-pub use engine::{ModelManager, TranscriptionEngine};
+pub use download::ensure_profile_model;
+pub use engine::{ModelManager, Transcript, TranscriptionEngine};
@@ -0,0 +1,252 @@
+use std::time::Duration;
+
+use super::engine::Segment;
+use super::script;
+use crate::config::AutoPunctuationConfig;
+
+/// Punctuation a pause between two segments turns into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapMark {
+    /// Short pause: plain space
+    Space,
+    /// Medium pause: comma
+    Comma,
+    /// Long pause: end of sentence, next segment capitalized
+    Period,
+}
+
+/// Mark for a pause of length `gap` (pure)
+#[must_use]
+pub fn gap_mark(gap: Duration, config: &AutoPunctuationConfig) -> GapMark {
+    if gap >= Duration::from_millis(config.period_gap_ms) {
+        GapMark::Period
+    } else if gap >= Duration::from_millis(config.comma_gap_ms) {
+        GapMark::Comma
+    } else {
+        GapMark::Space
+    }
+}
+
+/// Whether `text` already ends with punctuation Whisper put there itself
+fn ends_with_punctuation(text: &str) -> bool {
+    text.chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_punctuation() || matches!(c, '…' | '。' | '、' | '！' | '？'))
+}
+
+/// Uppercase the first character of `text`
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Join `segments` with punctuation chosen from the pauses between them (pure)
+///
+/// Segments already ending in punctuation keep it: Whisper's own punctuation wins
+/// and only bare segment ends get a mark. After an inserted period the next
+/// segment is capitalized. Empty segments are skipped, and segments are separated
+/// as `language`'s script requires (no spaces for CJK).
+#[must_use]
+pub fn punctuate(
+    segments: &[Segment],
+    config: &AutoPunctuationConfig,
+    language: Option<&str>,
+) -> String {
+    let separator = script::separator(language);
+    let mut out = String::new();
+    let mut previous_end = None;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let mut capitalize = false;
+        if let Some(end) = previous_end {
+            let mark = gap_mark(segment.start.saturating_sub(end), config);
+            if !ends_with_punctuation(&out) {
+                match mark {
+                    GapMark::Space => {}
+                    GapMark::Comma => out.push_str(&config.comma),
+                    GapMark::Period => {
+                        out.push_str(&config.period);
+                        capitalize = true;
+                    }
+                }
+            }
+            out.push_str(separator);
+        }
+        if capitalize {
+            out.push_str(&capitalize_first(text));
+        } else {
+            out.push_str(text);
+        }
+        previous_end = Some(segment.end);
+    }
+    out
+}
+
+/// `text` re-joined from `segments` when auto punctuation is enabled
+///
+/// Without segments (nothing to time the pauses by) `text` is kept as transcribed.
+#[must_use]
+pub fn apply(
+    text: String,
+    segments: &[Segment],
+    config: Option<&AutoPunctuationConfig>,
+    language: Option<&str>,
+) -> String {
+    match config {
+        Some(config) if !segments.is_empty() => punctuate(segments, config, language),
+        _ => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AutoPunctuationConfig {
+        AutoPunctuationConfig {
+            enabled: true,
+            ..AutoPunctuationConfig::default()
+        }
+    }
+
+    fn segment(text: &str, start_ms: u64, end_ms: u64) -> Segment {
+        Segment {
+            text: text.to_owned(),
+            start: Duration::from_millis(start_ms),
+            end: Duration::from_millis(end_ms),
+        }
+    }
+
+    #[test]
+    fn test_gap_mark_thresholds() {
+        let config = config();
+        assert_eq!(gap_mark(Duration::ZERO, &config), GapMark::Space);
+        assert_eq!(
+            gap_mark(Duration::from_millis(299), &config),
+            GapMark::Space
+        );
+        assert_eq!(
+            gap_mark(Duration::from_millis(300), &config),
+            GapMark::Comma
+        );
+        assert_eq!(
+            gap_mark(Duration::from_millis(799), &config),
+            GapMark::Comma
+        );
+        assert_eq!(
+            gap_mark(Duration::from_millis(800), &config),
+            GapMark::Period
+        );
+        assert_eq!(gap_mark(Duration::from_secs(5), &config), GapMark::Period);
+    }
+
+    #[test]
+    fn test_gap_mark_custom_thresholds() {
+        let config = AutoPunctuationConfig {
+            comma_gap_ms: 100,
+            period_gap_ms: 400,
+            ..config()
+        };
+        assert_eq!(
+            gap_mark(Duration::from_millis(150), &config),
+            GapMark::Comma
+        );
+        assert_eq!(
+            gap_mark(Duration::from_millis(450), &config),
+            GapMark::Period
+        );
+    }
+
+    #[test]
+    fn test_punctuate_maps_gaps() {
+        let segments = [
+            segment(" so first", 0, 1000),
+            segment(" then", 1100, 1800),
+            segment(" a pause", 2300, 3000),
+            segment(" new sentence", 4000, 5000),
+        ];
+        assert_eq!(
+            punctuate(&segments, &config(), None),
+            "so first then, a pause. New sentence"
+        );
+    }
+
+    #[test]
+    fn test_punctuate_keeps_existing_punctuation() {
+        let segments = [
+            segment(" Is it done?", 0, 1000),
+            segment(" yes", 3000, 3500),
+            segment(" okay,", 3600, 4000),
+            segment(" sure", 4500, 5000),
+        ];
+        // No doubled marks, and only an inserted period capitalizes
+        assert_eq!(
+            punctuate(&segments, &config(), None),
+            "Is it done? yes okay, sure"
+        );
+    }
+
+    #[test]
+    fn test_punctuate_custom_marks_and_unicode_capitalization() {
+        let config = AutoPunctuationConfig {
+            comma: ";".to_owned(),
+            period: "!".to_owned(),
+            ..config()
+        };
+        let segments = [
+            segment("a", 0, 100),
+            segment("b", 500, 600),
+            segment("élan", 2000, 2500),
+        ];
+        assert_eq!(punctuate(&segments, &config, None), "a; b! Élan");
+    }
+
+    #[test]
+    fn test_punctuate_skips_empty_and_overlapping_segments() {
+        let segments = [
+            segment("one", 0, 1000),
+            segment("   ", 1000, 3000),
+            // Overlap (start before previous end) counts as no pause
+            segment("two", 900, 1500),
+        ];
+        assert_eq!(punctuate(&segments, &config(), None), "one two");
+        assert_eq!(punctuate(&[], &config(), None), "");
+    }
+
+    #[test]
+    fn test_punctuate_cjk_without_spaces() {
+        let config = AutoPunctuationConfig {
+            comma: "、".to_owned(),
+            period: "。".to_owned(),
+            ..config()
+        };
+        let segments = [
+            segment(" 今日は", 0, 500),
+            segment(" 晴れ", 900, 1200),
+            segment(" 行こう", 2500, 3000),
+        ];
+        assert_eq!(
+            punctuate(&segments, &config, Some("ja")),
+            "今日は、晴れ。行こう"
+        );
+    }
+
+    #[test]
+    fn test_apply_only_when_enabled_with_segments() {
+        let segments = [segment("one", 0, 500), segment("two", 1500, 2000)];
+        assert_eq!(
+            apply("one two".to_owned(), &segments, None, None),
+            "one two"
+        );
+        assert_eq!(
+            apply("one two".to_owned(), &segments, Some(&config()), None),
+            "one. Two"
+        );
+        assert_eq!(apply("raw".to_owned(), &[], Some(&config()), None), "raw");
+    }
+}
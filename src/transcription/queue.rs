@@ -0,0 +1,300 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use tracing::{debug, warn};
+
+/// What to do when a job arrives and the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Evict the oldest pending job to make room (latest recording wins)
+    #[default]
+    DropOldest,
+    /// Keep pending jobs and reject the new one
+    RejectNew,
+}
+
+/// Result of offering a job to a [`BoundedQueue`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Admission<T> {
+    /// Job queued without evicting anything
+    Queued,
+    /// Job queued; this oldest pending job was evicted
+    Evicted(T),
+    /// Queue full; the offered job was rejected
+    Rejected(T),
+}
+
+/// FIFO queue with a maximum depth and an overflow policy
+#[derive(Debug)]
+pub struct BoundedQueue<T> {
+    jobs: VecDeque<T>,
+    max_depth: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T> BoundedQueue<T> {
+    /// Create queue holding at most `max_depth` pending jobs (minimum 1)
+    #[must_use]
+    pub fn new(max_depth: usize, policy: OverflowPolicy) -> Self {
+        let max_depth = max_depth.max(1);
+        Self {
+            jobs: VecDeque::with_capacity(max_depth),
+            max_depth,
+            policy,
+        }
+    }
+
+    /// Offer a job, applying the overflow policy when full
+    pub fn push(&mut self, job: T) -> Admission<T> {
+        if self.jobs.len() < self.max_depth {
+            self.jobs.push_back(job);
+            return Admission::Queued;
+        }
+
+        match self.policy {
+            OverflowPolicy::DropOldest => {
+                let evicted = self.jobs.pop_front();
+                self.jobs.push_back(job);
+                evicted.map_or(Admission::Queued, Admission::Evicted)
+            }
+            OverflowPolicy::RejectNew => Admission::Rejected(job),
+        }
+    }
+
+    /// Take the oldest pending job
+    pub fn pop(&mut self) -> Option<T> {
+        self.jobs.pop_front()
+    }
+
+    /// Number of pending jobs
+    fn len(&self) -> usize {
+        self.jobs.len()
+    }
+}
+
+/// Queued transcription work
+pub struct Job {
+    /// Runs the transcription (on the worker thread)
+    pub run: Box<dyn FnOnce() + Send>,
+    /// Called instead of `run` if the job is evicted or rejected (e.g. reset state)
+    pub discard: Box<dyn FnOnce() + Send>,
+}
+
+struct QueueState {
+    jobs: BoundedQueue<Job>,
+    /// Set when the `JobQueue` is dropped; worker exits once drained
+    closed: bool,
+}
+
+struct Shared {
+    state: Mutex<QueueState>,
+    available: Condvar,
+}
+
+/// Bounded job queue drained by a single worker thread
+///
+/// Caps memory held by pending audio buffers when recordings arrive faster
+/// than they can be transcribed.
+pub struct JobQueue {
+    shared: Arc<Shared>,
+}
+
+impl JobQueue {
+    /// Create queue and spawn its worker thread
+    #[must_use]
+    pub fn new(max_depth: usize, policy: OverflowPolicy) -> Self {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(QueueState {
+                jobs: BoundedQueue::new(max_depth, policy),
+                closed: false,
+            }),
+            available: Condvar::new(),
+        });
+
+        let worker = Arc::clone(&shared);
+        std::thread::spawn(move || Self::worker_loop(&worker));
+
+        Self { shared }
+    }
+
+    fn worker_loop(shared: &Shared) {
+        loop {
+            let job = {
+                let mut guard = shared
+                    .state
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                loop {
+                    if let Some(job) = guard.jobs.pop() {
+                        break job;
+                    }
+                    if guard.closed {
+                        return;
+                    }
+                    guard = shared
+                        .available
+                        .wait(guard)
+                        .unwrap_or_else(std::sync::PoisonError::into_inner);
+                }
+            };
+            (job.run)();
+        }
+    }
+
+    /// Submit a job; returns false if it was rejected
+    ///
+    /// Evicted/rejected jobs have their `discard` callback run on the caller's thread.
+    pub fn submit(&self, job: Job) -> bool {
+        let admission = {
+            let mut guard = self
+                .shared
+                .state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let admission = guard.jobs.push(job);
+            debug!(pending = guard.jobs.len(), "transcription job submitted");
+            admission
+        };
+        self.shared.available.notify_one();
+
+        match admission {
+            Admission::Queued => true,
+            Admission::Evicted(old) => {
+                warn!("⚠️  Transcription queue full - dropped oldest pending recording");
+                (old.discard)();
+                true
+            }
+            Admission::Rejected(new) => {
+                warn!("⚠️  Transcription queue full - recording rejected");
+                (new.discard)();
+                false
+            }
+        }
+    }
+}
+
+impl Drop for JobQueue {
+    fn drop(&mut self) {
+        // Let the worker finish pending jobs, then exit
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .closed = true;
+        self.shared.available.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn test_bounded_queue_under_capacity() {
+        let mut queue = BoundedQueue::new(2, OverflowPolicy::DropOldest);
+        assert_eq!(queue.push(1), Admission::Queued);
+        assert_eq!(queue.push(2), Admission::Queued);
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_bounded_queue_drop_oldest_at_capacity() {
+        let mut queue = BoundedQueue::new(2, OverflowPolicy::DropOldest);
+        queue.push(1);
+        queue.push(2);
+
+        assert_eq!(queue.push(3), Admission::Evicted(1));
+        assert_eq!(queue.push(4), Admission::Evicted(2));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_bounded_queue_reject_new_at_capacity() {
+        let mut queue = BoundedQueue::new(2, OverflowPolicy::RejectNew);
+        queue.push(1);
+        queue.push(2);
+
+        assert_eq!(queue.push(3), Admission::Rejected(3));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some(1));
+
+        // Room again after a job is taken
+        assert_eq!(queue.push(4), Admission::Queued);
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_bounded_queue_zero_depth_treated_as_one() {
+        let mut queue = BoundedQueue::new(0, OverflowPolicy::RejectNew);
+        assert_eq!(queue.push(1), Admission::Queued);
+        assert_eq!(queue.push(2), Admission::Rejected(2));
+    }
+
+    #[test]
+    fn test_overflow_policy_serde() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            overflow: OverflowPolicy,
+        }
+        let w: Wrapper = toml::from_str("overflow = \"reject_new\"").unwrap();
+        assert_eq!(w.overflow, OverflowPolicy::RejectNew);
+        let w: Wrapper = toml::from_str("overflow = \"drop_oldest\"").unwrap();
+        assert_eq!(w.overflow, OverflowPolicy::DropOldest);
+        assert!(toml::from_str::<Wrapper>("overflow = \"lifo\"").is_err());
+    }
+
+    fn counting_job(ran: &Arc<AtomicUsize>, discarded: &Arc<AtomicUsize>) -> Job {
+        let ran = Arc::clone(ran);
+        let discarded = Arc::clone(discarded);
+        Job {
+            run: Box::new(move || {
+                ran.fetch_add(1, Ordering::SeqCst);
+            }),
+            discard: Box::new(move || {
+                discarded.fetch_add(1, Ordering::SeqCst);
+            }),
+        }
+    }
+
+    #[test]
+    fn test_job_queue_rejects_and_discards_when_full() {
+        let queue = JobQueue::new(1, OverflowPolicy::RejectNew);
+        let ran = Arc::new(AtomicUsize::new(0));
+        let discarded = Arc::new(AtomicUsize::new(0));
+
+        // Block the worker so later jobs stay pending
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let (started_tx, started_rx) = std::sync::mpsc::channel::<()>();
+        assert!(queue.submit(Job {
+            run: Box::new(move || {
+                started_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+            }),
+            discard: Box::new(|| {}),
+        }));
+        started_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        assert!(queue.submit(counting_job(&ran, &discarded)));
+        assert!(!queue.submit(counting_job(&ran, &discarded)));
+        assert_eq!(discarded.load(Ordering::SeqCst), 1);
+
+        release_tx.send(()).unwrap();
+        drop(queue);
+
+        // Worker drains the pending job before exiting
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while ran.load(Ordering::SeqCst) == 0 && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(ran.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,100 @@
+use super::engine::Segment;
+
+/// Languages written without spaces between words (Whisper language codes)
+const SPACELESS_LANGUAGES: &[&str] = &["ja", "zh", "yue", "th", "lo", "km", "my", "bo"];
+
+/// Whether `language` is written without spaces between words (pure)
+///
+/// Codes compare case-insensitively; region suffixes (`zh-TW`) are ignored.
+#[must_use]
+pub fn is_spaceless_language(language: &str) -> bool {
+    let base = language.split(['-', '_']).next().unwrap_or(language);
+    SPACELESS_LANGUAGES
+        .iter()
+        .any(|code| code.eq_ignore_ascii_case(base))
+}
+
+/// Text placed between two segments for `language` (unknown = space)
+#[must_use]
+pub fn separator(language: Option<&str>) -> &'static str {
+    if language.is_some_and(is_spaceless_language) {
+        ""
+    } else {
+        " "
+    }
+}
+
+/// Join `segments` with the separator of `language`'s script (pure)
+///
+/// Each segment is trimmed first, so Whisper's leading spaces never end up
+/// between CJK characters, and Latin text gets exactly one space per join.
+/// Empty segments are skipped.
+#[must_use]
+pub fn join_segments(segments: &[Segment], language: Option<&str>) -> String {
+    segments
+        .iter()
+        .map(|segment| segment.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator(language))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn from_texts(texts: &[&str]) -> Vec<Segment> {
+        texts
+            .iter()
+            .map(|text| Segment {
+                text: (*text).to_owned(),
+                start: Duration::ZERO,
+                end: Duration::ZERO,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_spaceless_languages() {
+        for language in ["ja", "zh", "ZH", "zh-TW", "yue", "th"] {
+            assert!(is_spaceless_language(language), "{language}");
+        }
+        for language in ["en", "de", "ko", "vi", ""] {
+            assert!(!is_spaceless_language(language), "{language}");
+        }
+    }
+
+    #[test]
+    fn test_join_latin_with_single_spaces() {
+        let segments = from_texts(&[" Hello there.", "  How are you?", " Fine"]);
+        assert_eq!(
+            join_segments(&segments, Some("en")),
+            "Hello there. How are you? Fine"
+        );
+        // Unknown language joins like Latin
+        assert_eq!(
+            join_segments(&segments, None),
+            "Hello there. How are you? Fine"
+        );
+    }
+
+    #[test]
+    fn test_join_cjk_without_spaces() {
+        let segments = from_texts(&[" 今日は", " いい天気ですね。", " 散歩に行きましょう"]);
+        assert_eq!(
+            join_segments(&segments, Some("ja")),
+            "今日はいい天気ですね。散歩に行きましょう"
+        );
+
+        let segments = from_texts(&[" 你好", " 世界"]);
+        assert_eq!(join_segments(&segments, Some("zh")), "你好世界");
+    }
+
+    #[test]
+    fn test_join_skips_empty_segments() {
+        let segments = from_texts(&["one", "   ", "", "two"]);
+        assert_eq!(join_segments(&segments, Some("en")), "one two");
+        assert_eq!(join_segments(&[], Some("ja")), "");
+    }
+}
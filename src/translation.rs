@@ -0,0 +1,334 @@
+use std::io::{Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Target language Whisper can translate to natively
+pub const NATIVE_TARGET: &str = "en";
+
+/// Placeholder in the hook command replaced with the target language
+pub const LANG_PLACEHOLDER: &str = "{lang}";
+
+/// Maximum time a translation command may run before it is killed
+const HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How a profile's transcription is translated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranslationRoute {
+    /// Whisper's built-in translation (English only)
+    Native,
+    /// External command from `[translation] command`
+    Hook {
+        /// Target language code substituted for `{lang}`
+        target: String,
+    },
+}
+
+/// Translation errors
+#[derive(Debug, Error)]
+pub enum TranslationError {
+    /// Target language isn't a plain language code
+    #[error("invalid translate_to language: {0:?} (expected a code like \"de\" or \"pt-BR\")")]
+    InvalidLanguage(String),
+
+    /// Profile needs the hook but none is configured
+    #[error("translate_to = {0:?} requires [translation] command")]
+    NoCommand(String),
+
+    /// Command template has no program
+    #[error("[translation] command is empty")]
+    EmptyCommand,
+
+    /// Command could not be started or talked to
+    #[error("failed to run translation command")]
+    Io(#[from] std::io::Error),
+
+    /// Command didn't finish in time
+    #[error("translation command timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
+
+    /// Command exited unsuccessfully
+    #[error("translation command exited with {status}: {stderr}")]
+    Failed {
+        /// Exit status
+        status: ExitStatus,
+        /// Captured stderr (trimmed)
+        stderr: String,
+    },
+}
+
+/// Decide how to translate for a profile's `translate_to` (None = no translation)
+///
+/// `"en"` uses Whisper's native translation; any other language goes through the hook.
+#[must_use]
+pub fn route(translate_to: Option<&str>) -> Option<TranslationRoute> {
+    let target = translate_to?.trim();
+    if target.is_empty() {
+        return None;
+    }
+    if target.eq_ignore_ascii_case(NATIVE_TARGET) {
+        Some(TranslationRoute::Native)
+    } else {
+        Some(TranslationRoute::Hook {
+            target: target.to_owned(),
+        })
+    }
+}
+
+/// External translation command with the target language filled in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookCommand {
+    argv: Vec<String>,
+}
+
+impl HookCommand {
+    /// Build command from `template`, substituting `{lang}` with `target`
+    ///
+    /// The template is split on whitespace and run without a shell; the text to
+    /// translate is written to the command's stdin.
+    ///
+    /// # Errors
+    /// Returns error if `target` isn't a plain language code or the template is empty
+    pub fn build(template: &str, target: &str) -> Result<Self, TranslationError> {
+        let valid_language = !target.is_empty()
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_language {
+            return Err(TranslationError::InvalidLanguage(target.to_owned()));
+        }
+
+        let argv: Vec<String> = template
+            .split_whitespace()
+            .map(|arg| arg.replace(LANG_PLACEHOLDER, target))
+            .collect();
+        if argv.is_empty() {
+            return Err(TranslationError::EmptyCommand);
+        }
+        Ok(Self { argv })
+    }
+
+    /// Command for a profile, or None if it doesn't translate through the hook
+    ///
+    /// # Errors
+    /// Returns error if the profile needs the hook and it's missing or invalid
+    pub fn for_profile(
+        translate_to: Option<&str>,
+        command: Option<&str>,
+    ) -> Result<Option<Self>, TranslationError> {
+        match route(translate_to) {
+            Some(TranslationRoute::Hook { target }) => {
+                let template =
+                    command.ok_or_else(|| TranslationError::NoCommand(target.clone()))?;
+                Self::build(template, &target).map(Some)
+            }
+            Some(TranslationRoute::Native) | None => Ok(None),
+        }
+    }
+
+    /// Run the command with `text` on stdin and return its trimmed stdout
+    ///
+    /// # Errors
+    /// Returns error if the command can't be spawned, times out, or exits non-zero
+    pub fn translate(&self, text: &str) -> Result<String, TranslationError> {
+        let (program, program_args) = self
+            .argv
+            .split_first()
+            .ok_or(TranslationError::EmptyCommand)?;
+
+        let mut child = Command::new(program)
+            .args(program_args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain output while the command runs: a full pipe would block it until the timeout
+        let stdout = drain(child.stdout.take());
+        let stderr = drain(child.stderr.take());
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // A command that exits without reading stdin is judged by its exit status
+            match stdin.write_all(text.as_bytes()) {
+                Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => return Err(e.into()),
+                _ => {}
+            }
+            // stdin dropped here so the command sees EOF
+        }
+
+        let deadline = Instant::now() + HOOK_TIMEOUT;
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(TranslationError::Timeout(HOOK_TIMEOUT));
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+
+        let stdout = join_drain(stdout)?;
+        if !status.success() {
+            let stderr = join_drain(stderr).unwrap_or_default();
+            return Err(TranslationError::Failed {
+                status,
+                stderr: stderr.trim().to_owned(),
+            });
+        }
+
+        Ok(stdout.trim().to_owned())
+    }
+}
+
+/// Read a child pipe to the end on a helper thread
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<std::io::Result<String>> {
+    std::thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_string(&mut output)?;
+        }
+        Ok(output)
+    })
+}
+
+/// Collect what a [`drain`] thread read
+fn join_drain(handle: JoinHandle<std::io::Result<String>>) -> std::io::Result<String> {
+    handle
+        .join()
+        .unwrap_or_else(|_| Err(std::io::Error::other("pipe reader panicked")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_none() {
+        assert_eq!(route(None), None);
+        assert_eq!(route(Some("")), None);
+        assert_eq!(route(Some("  ")), None);
+    }
+
+    #[test]
+    fn test_route_english_is_native() {
+        assert_eq!(route(Some("en")), Some(TranslationRoute::Native));
+        assert_eq!(route(Some("EN")), Some(TranslationRoute::Native));
+    }
+
+    #[test]
+    fn test_route_other_language_uses_hook() {
+        assert_eq!(
+            route(Some("de")),
+            Some(TranslationRoute::Hook {
+                target: "de".to_owned()
+            })
+        );
+        assert_eq!(
+            route(Some(" pt-BR ")),
+            Some(TranslationRoute::Hook {
+                target: "pt-BR".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_hook_command_build_substitutes_lang() {
+        assert_eq!(
+            HookCommand::build("trans -b :{lang}", "de").unwrap().argv,
+            vec!["trans", "-b", ":de"]
+        );
+        assert_eq!(
+            HookCommand::build("translate --from auto --to {lang} --fallback {lang}", "fr")
+                .unwrap()
+                .argv,
+            vec![
+                "translate",
+                "--from",
+                "auto",
+                "--to",
+                "fr",
+                "--fallback",
+                "fr"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hook_command_build_without_placeholder() {
+        assert_eq!(
+            HookCommand::build("my-translator", "de").unwrap().argv,
+            vec!["my-translator"]
+        );
+    }
+
+    #[test]
+    fn test_hook_command_build_rejects_invalid_language() {
+        for target in ["", "de; rm -rf ~", "de fr", "$(whoami)"] {
+            assert!(matches!(
+                HookCommand::build("trans :{lang}", target),
+                Err(TranslationError::InvalidLanguage(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_hook_command_build_empty_template() {
+        assert!(matches!(
+            HookCommand::build("   ", "de"),
+            Err(TranslationError::EmptyCommand)
+        ));
+    }
+
+    #[test]
+    fn test_hook_command_for_profile() {
+        // English: native translation, hook not needed even if unset
+        assert!(HookCommand::for_profile(Some("en"), None)
+            .unwrap()
+            .is_none());
+        assert!(HookCommand::for_profile(None, Some("trans :{lang}"))
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            HookCommand::for_profile(Some("es"), Some("trans :{lang}")).unwrap(),
+            Some(HookCommand {
+                argv: vec!["trans".to_owned(), ":es".to_owned()]
+            })
+        );
+        assert!(matches!(
+            HookCommand::for_profile(Some("es"), None),
+            Err(TranslationError::NoCommand(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_command_translate_pipes_stdin() {
+        let hook = HookCommand::build("cat", "de").unwrap();
+        assert_eq!(hook.translate("hallo welt\n").unwrap(), "hallo welt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_command_translate_large_output() {
+        // Far more than a pipe buffer holds: must not stall until the timeout
+        let hook = HookCommand::build("seq 1 100000", "de").unwrap();
+        let started = Instant::now();
+        let output = hook.translate("text").unwrap();
+        assert_eq!(output.lines().count(), 100_000);
+        assert!(started.elapsed() < HOOK_TIMEOUT);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_command_translate_failure() {
+        let hook = HookCommand::build("false", "de").unwrap();
+        assert!(matches!(
+            hook.translate("text"),
+            Err(TranslationError::Failed { .. })
+        ));
+    }
+}
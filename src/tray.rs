@@ -2,23 +2,199 @@ use anyhow::{anyhow, Context, Result};
 use objc2_app_kit::NSScreen;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use tray_icon::menu::{Menu, MenuItem, PredefinedMenuItem};
+use std::time::{Duration, Instant};
+use tray_icon::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tray_icon::{Icon, TrayIconBuilder};
 
+use crate::audio::{meter_bar, InputLevel};
 use crate::config::Config;
-use crate::input::hotkey::AppState;
+use crate::input::hotkey::{AppState, LastTranscription};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TrayCommand {
     OpenConfigFile,
+    /// Pick an audio file and transcribe it to the clipboard
+    TranscribeFile,
+    /// Re-run permission checks, enabling text insertion once granted
+    RecheckPermissions,
+    /// Shut down gracefully and relaunch with the same arguments
+    Restart,
+    /// Copy the latest transcription to the clipboard
+    CopyLastTranscription,
+    /// Ignore hotkey presses until toggled again
+    TogglePause,
+    /// Record with this profile when the first profile's hotkey is pressed
+    SelectProfile(String),
     // Note: Quit removed - PredefinedMenuItem::quit() bypasses event system entirely
 }
 
+/// One full blink cycle of the recording icon (bright half, then dim half)
+const RECORDING_BLINK_PERIOD: Duration = Duration::from_secs(1);
+
+/// Opacity of the dim recording frame relative to the normal icon
+const DIM_FRAME_ALPHA: f32 = 0.35;
+
+/// How long the status header shows a cancelled recording
+const CANCELLED_FLASH: Duration = Duration::from_secs(2);
+
+/// Status header while the cancelled flash is shown
+const CANCELLED_STATUS: &str = "✖️ Recording cancelled";
+
+/// Status header while hotkeys are paused and nothing is in flight
+const PAUSED_STATUS: &str = "⏸️ Paused";
+
+/// Label of the pause toggle for the current pause state (pure)
+const fn pause_label(paused: bool) -> &'static str {
+    if paused {
+        "Resume"
+    } else {
+        "Pause"
+    }
+}
+
+/// Menu id prefix of the "Profile" submenu entries, followed by the profile name
+const SELECT_PROFILE_ID: &str = "Select Profile:";
+
+/// Entry of the "Profile" submenu
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProfileChoice {
+    /// Menu id (`SELECT_PROFILE_ID` + profile name)
+    id: String,
+    /// Profile name shown in the menu
+    label: String,
+    /// Profile the hotkey currently records with
+    checked: bool,
+}
+
+/// "Profile" submenu entries, `active` checked (None = first profile) (pure)
+fn profile_choices(config: &Config, active: Option<&str>) -> Vec<ProfileChoice> {
+    config
+        .profiles
+        .iter()
+        .enumerate()
+        .map(|(index, profile)| {
+            let name = profile.name();
+            ProfileChoice {
+                id: format!("{SELECT_PROFILE_ID}{name}"),
+                label: name.to_owned(),
+                checked: active.map_or(index == 0, |active| active == name),
+            }
+        })
+        .collect()
+}
+
+/// Menu entries that change while the app runs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuState {
+    /// Latest transcription (None = nothing transcribed yet)
+    pub last_transcription: Option<String>,
+    /// Hotkeys are paused
+    pub paused: bool,
+    /// Profile the first profile's hotkey records with (None = first profile)
+    pub active_profile: Option<String>,
+}
+
+/// Cells in the tooltip's input level meter
+const LEVEL_METER_WIDTH: usize = 10;
+
+/// Characters of the latest transcription shown in the menu
+const LAST_TRANSCRIPTION_CHARS: usize = 40;
+
+/// Menu label previewing `text`: one line, at most `max_chars` characters (pure)
+fn transcription_preview(text: &str, max_chars: usize) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= max_chars {
+        return format!("“{line}”");
+    }
+    let cut: String = line.chars().take(max_chars.saturating_sub(1)).collect();
+    format!("“{}…”", cut.trim_end())
+}
+
+/// Elapsed recording time as minutes and seconds, e.g. "0:07" (pure)
+fn format_elapsed(secs: u64) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Tooltip while recording: elapsed time, then the input level meter if any (pure)
+fn recording_tooltip(elapsed_secs: u64, level: Option<f32>) -> String {
+    let elapsed = format_elapsed(elapsed_secs);
+    level.map_or_else(
+        || format!("🎤 Recording {elapsed}"),
+        |level| {
+            format!(
+                "🎤 Recording {elapsed} {}",
+                meter_bar(level, LEVEL_METER_WIDTH)
+            )
+        },
+    )
+}
+
+/// Whether the cancelled flash is still shown at `now` (pure)
+///
+/// A recording started during the flash ends it early.
+fn flash_active(until: Option<Instant>, state: AppState, now: Instant) -> bool {
+    state == AppState::Idle && until.is_some_and(|until| now < until)
+}
+
+/// Frame of the animated recording icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecordingFrame {
+    Bright,
+    Dim,
+}
+
+impl RecordingFrame {
+    /// Frame shown `elapsed` after recording started: bright for the first half of each `period`
+    const fn at(elapsed: Duration, period: Duration) -> Self {
+        let period_ms = period.as_millis();
+        if period_ms == 0 || elapsed.as_millis() % period_ms < period_ms / 2 {
+            Self::Bright
+        } else {
+            Self::Dim
+        }
+    }
+}
+
+/// Scale the icon's alpha channel to produce the dim blink frame
+fn dim_image(mut image: image::RgbaImage) -> image::RgbaImage {
+    for pixel in image.pixels_mut() {
+        // Alpha <= 255 and factor < 1, so the result always fits in u8
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let alpha = (f32::from(pixel[3]) * DIM_FRAME_ALPHA).round() as u8;
+        pixel[3] = alpha;
+    }
+    image
+}
+
 pub struct TrayManager {
     tray: tray_icon::TrayIcon,
     state: Arc<Mutex<AppState>>,
     current_icon_state: AppState,
     cached_icons: HashMap<AppState, Icon>,
+    /// Dim recording frame (None = animation disabled)
+    recording_dim_icon: Option<Icon>,
+    /// Dim idle icon shown while paused
+    paused_icon: Icon,
+    /// When the current recording started (None = not recording)
+    recording_started: Option<Instant>,
+    /// Recording frame currently shown
+    current_frame: RecordingFrame,
+    /// End of the "Recording cancelled" status flash (None = not flashing)
+    cancelled_until: Option<Instant>,
+    /// Whether the current menu shows the cancelled status
+    showing_cancelled: bool,
+    /// Input level shown in the tooltip while recording (None = no meter)
+    input_level: Option<InputLevel>,
+    /// Tooltip currently showing the meter (empty = default tooltip)
+    shown_tooltip: String,
+    /// Latest transcription, written by the hotkey threads (None = not shown)
+    last_transcription: Option<LastTranscription>,
+    /// Menu entries to show (the transcription is refreshed from `last_transcription`)
+    menu: MenuState,
+    /// Menu entries the current menu shows
+    shown_menu: MenuState,
+    /// Rebuild the menu even if `menu` is unchanged (native check toggles to undo)
+    menu_stale: bool,
 }
 
 impl TrayManager {
@@ -26,25 +202,98 @@ impl TrayManager {
         // Detect display scale for proper retina support
         let scale = Self::detect_display_scale();
 
-        // Preload all three icons into cache
+        // Preload all state icons into cache
         let mut cached_icons = HashMap::new();
-        cached_icons.insert(AppState::Idle, Self::load_icon(AppState::Idle, scale)?);
-        cached_icons.insert(
-            AppState::Recording,
-            Self::load_icon(AppState::Recording, scale)?,
-        );
+        let idle_image = Self::load_image(AppState::Idle, scale)?;
+        let paused_icon = Self::icon_from_image(dim_image(idle_image.clone()))?;
+        cached_icons.insert(AppState::Idle, Self::icon_from_image(idle_image)?);
+        let recording_image = Self::load_image(AppState::Recording, scale)?;
+        let recording_dim_icon = if config.tray.animate_recording {
+            Some(Self::icon_from_image(dim_image(recording_image.clone()))?)
+        } else {
+            None
+        };
+        cached_icons.insert(AppState::Recording, Self::icon_from_image(recording_image)?);
         cached_icons.insert(
             AppState::Processing,
             Self::load_icon(AppState::Processing, scale)?,
         );
+        cached_icons.insert(
+            AppState::Loading,
+            Self::load_icon(AppState::Loading, scale)?,
+        );
 
-        let tray = Self::build_tray(config, AppState::Idle, &cached_icons)?;
+        let tray = Self::build_tray(
+            config,
+            AppState::Idle,
+            Self::get_status_text(Some(AppState::Idle)),
+            &cached_icons,
+            &MenuState::default(),
+        )?;
 
         Ok(Self {
             tray,
             state,
             current_icon_state: AppState::Idle,
             cached_icons,
+            recording_dim_icon,
+            paused_icon,
+            recording_started: None,
+            current_frame: RecordingFrame::Bright,
+            cancelled_until: None,
+            showing_cancelled: false,
+            input_level: None,
+            shown_tooltip: String::new(),
+            last_transcription: None,
+            menu: MenuState::default(),
+            shown_menu: MenuState::default(),
+            menu_stale: false,
+        })
+    }
+
+    /// Show the input level in the tooltip while recording
+    #[must_use]
+    pub fn with_input_level(mut self, level: InputLevel) -> Self {
+        self.input_level = Some(level);
+        self
+    }
+
+    /// Show the latest transcription in the menu, with an action copying it
+    #[must_use]
+    pub fn with_last_transcription(mut self, last: LastTranscription) -> Self {
+        self.last_transcription = Some(last);
+        self
+    }
+
+    /// Show hotkeys as paused (dim icon, "Resume" in the menu)
+    ///
+    /// Picked up by the next `update_icon_if_needed`.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.menu.paused = paused;
+    }
+
+    /// Check `name` in the "Profile" submenu and follow its state in the icon
+    ///
+    /// Picked up by the next `update_icon_if_needed`.
+    pub fn select_profile(&mut self, name: &str, state: Arc<Mutex<AppState>>) {
+        self.menu.active_profile = Some(name.to_owned());
+        self.state = state;
+    }
+
+    /// Rebuild the menu from `MenuState`, e.g. to uncheck a refused "Profile" click
+    ///
+    /// Picked up by the next `update_icon_if_needed`.
+    pub fn refresh_menu(&mut self) {
+        self.menu_stale = true;
+    }
+
+    /// Latest transcription (None = nothing transcribed yet)
+    #[must_use]
+    pub fn last_transcription(&self) -> Option<String> {
+        self.last_transcription.as_ref().and_then(|last| {
+            last.lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone()
         })
     }
 
@@ -72,13 +321,15 @@ impl TrayManager {
     fn build_tray(
         config: &Config,
         app_state: AppState,
+        status: &str,
         cached_icons: &HashMap<AppState, Icon>,
+        menu_state: &MenuState,
     ) -> Result<tray_icon::TrayIcon> {
         let icon = cached_icons
             .get(&app_state)
             .with_context(|| format!("icon for state {:?} not in cache", app_state))?
             .clone();
-        let menu = Self::build_menu(config, Some(app_state))?;
+        let menu = Self::build_menu(config, status, menu_state)?;
 
         let mut builder = TrayIconBuilder::new()
             .with_menu(Box::new(menu))
@@ -95,6 +346,18 @@ impl TrayManager {
     }
 
     fn load_icon(state: AppState, scale: f64) -> Result<Icon> {
+        Self::icon_from_image(Self::load_image(state, scale)?)
+    }
+
+    fn icon_from_image(image: image::RgbaImage) -> Result<Icon> {
+        let (width, height) = image.dimensions();
+        let rgba = image.into_raw();
+        tracing::debug!("icon loaded: {}x{}, {} bytes", width, height, rgba.len());
+
+        Icon::from_rgba(rgba, width, height).context("failed to create icon from RGBA data")
+    }
+
+    fn load_image(state: AppState, scale: f64) -> Result<image::RgbaImage> {
         // Load appropriate icon based on state and display scale
         // Use 16px for @1x displays, 32px for @2x (retina) displays
         let size_suffix = if scale >= 2.0 { "32" } else { "16" };
@@ -102,7 +365,10 @@ impl TrayManager {
         let icon_filename = match state {
             AppState::Idle => format!("icon-{size_suffix}.png"),
             AppState::Recording => format!("icon-recording-{size_suffix}.png"),
-            AppState::Processing => format!("icon-processing-{size_suffix}.png"),
+            // No dedicated loading artwork: model loads read as "busy"
+            AppState::Processing | AppState::Loading => {
+                format!("icon-processing-{size_suffix}.png")
+            }
         };
 
         // Try to load from app bundle Resources folder first (for installed apps)
@@ -134,12 +400,14 @@ impl TrayManager {
                 )
             })?
             .into_rgba8();
+        Ok(image)
+    }
 
-        let (width, height) = image.dimensions();
-        let rgba = image.into_raw();
-        tracing::debug!("icon loaded: {}x{}, {} bytes", width, height, rgba.len());
-
-        Icon::from_rgba(rgba, width, height).context("failed to create icon from RGBA data")
+    /// Briefly show "Recording cancelled" in the status header
+    ///
+    /// Picked up by the next `update_icon_if_needed`.
+    pub fn flash_cancelled(&mut self) {
+        self.cancelled_until = Some(Instant::now() + CANCELLED_FLASH);
     }
 
     /// Update icon and menu if state changed
@@ -148,20 +416,98 @@ impl TrayManager {
             .state
             .lock()
             .map_err(|e| anyhow!("state lock poisoned: {}", e))?;
-        if new_state != self.current_icon_state {
+        let cancelled = flash_active(self.cancelled_until, new_state, Instant::now());
+        if !cancelled {
+            self.cancelled_until = None;
+        }
+        self.menu.last_transcription = self.last_transcription();
+        if new_state != self.current_icon_state
+            || cancelled != self.showing_cancelled
+            || self.menu != self.shown_menu
+            || self.menu_stale
+        {
             tracing::info!(
                 "🔄 tray state change: {:?} -> {:?}",
                 self.current_icon_state,
                 new_state
             );
 
+            let show_paused = self.menu.paused && new_state == AppState::Idle;
+            let status = if cancelled {
+                CANCELLED_STATUS
+            } else if show_paused {
+                PAUSED_STATUS
+            } else {
+                Self::get_status_text(Some(new_state))
+            };
             // Rebuild entire tray with new state (workaround for macOS set_icon() bug)
-            let new_tray = Self::build_tray(config, new_state, &self.cached_icons)?;
+            let new_tray =
+                Self::build_tray(config, new_state, status, &self.cached_icons, &self.menu)?;
             self.tray = new_tray;
-
+            if show_paused {
+                // Idle template mode kept, so set_icon is enough (as for the blink frames)
+                self.tray
+                    .set_icon(Some(self.paused_icon.clone()))
+                    .context("failed to show paused icon")?;
+            }
+
+            if new_state != self.current_icon_state {
+                self.recording_started = (new_state == AppState::Recording).then(Instant::now);
+                self.current_frame = RecordingFrame::Bright;
+            }
             self.current_icon_state = new_state;
+            self.showing_cancelled = cancelled;
+            self.shown_menu = self.menu.clone();
+            self.menu_stale = false;
+            // The rebuilt tray starts with the default tooltip
+            self.shown_tooltip.clear();
             tracing::info!("✓ tray icon rebuilt with state: {:?}", new_state);
         }
+        self.animate_recording()?;
+        self.update_recording_tooltip()
+    }
+
+    /// Refresh the recording tooltip when the elapsed time or displayed level changes
+    fn update_recording_tooltip(&mut self) -> Result<()> {
+        let Some(started) = self.recording_started else {
+            return Ok(());
+        };
+        let level = self.input_level.as_ref().map(InputLevel::get);
+        let tooltip = recording_tooltip(started.elapsed().as_secs(), level);
+        if tooltip == self.shown_tooltip {
+            return Ok(());
+        }
+        self.tray
+            .set_tooltip(Some(&tooltip))
+            .context("failed to update recording tooltip")?;
+        self.shown_tooltip = tooltip;
+        Ok(())
+    }
+
+    /// Swap recording frames when the blink phase changes
+    fn animate_recording(&mut self) -> Result<()> {
+        let (Some(dim_icon), Some(started)) = (&self.recording_dim_icon, self.recording_started)
+        else {
+            return Ok(());
+        };
+        let frame = RecordingFrame::at(started.elapsed(), RECORDING_BLINK_PERIOD);
+        if frame == self.current_frame {
+            return Ok(());
+        }
+
+        let icon = match frame {
+            RecordingFrame::Bright => self
+                .cached_icons
+                .get(&AppState::Recording)
+                .context("recording icon not in cache")?
+                .clone(),
+            RecordingFrame::Dim => dim_icon.clone(),
+        };
+        // Same state and template mode, so set_icon is enough (no rebuild needed)
+        self.tray
+            .set_icon(Some(icon))
+            .context("failed to swap recording icon frame")?;
+        self.current_frame = frame;
         Ok(())
     }
 
@@ -170,6 +516,7 @@ impl TrayManager {
             AppState::Idle => "Whisper Hotkey - Ready",
             AppState::Recording => "🎤 Recording...",
             AppState::Processing => "⏳ Transcribing...",
+            AppState::Loading => "⏳ Loading model...",
         })
     }
 
@@ -187,19 +534,27 @@ impl TrayManager {
             .name
             .as_deref()
             .unwrap_or(profile.model_type.as_str());
-        format!(
+        let label = format!(
             "{} ({}): {}",
             profile_name,
             hotkey_str,
             profile.model_type.as_str()
-        )
+        );
+        match profile.translation_target() {
+            Some(target) => format!("{label} → {target}"),
+            None => label,
+        }
     }
 
-    pub(crate) fn build_menu(config: &Config, app_state: Option<AppState>) -> Result<Menu> {
+    pub(crate) fn build_menu(
+        config: &Config,
+        status: &str,
+        menu_state: &MenuState,
+    ) -> Result<Menu> {
         let menu = Menu::new();
 
         // Status header
-        let status = MenuItem::new(Self::get_status_text(app_state), false, None);
+        let status = MenuItem::new(status, false, None);
         menu.append(&status).context("failed to append status")?;
         menu.append(&PredefinedMenuItem::separator())?;
 
@@ -209,14 +564,61 @@ impl TrayManager {
             menu.append(&MenuItem::new(&label, false, None))?;
         }
 
+        // Which profile the first profile's hotkey records with
+        if config.profiles.len() > 1 {
+            let submenu = Submenu::new("Profile", true);
+            for choice in profile_choices(config, menu_state.active_profile.as_deref()) {
+                submenu.append(&CheckMenuItem::with_id(
+                    choice.id,
+                    &choice.label,
+                    true,
+                    choice.checked,
+                    None,
+                ))?;
+            }
+            menu.append(&submenu)?;
+        }
+
+        // Latest transcription (read-only preview) and its copy action
+        if let Some(text) = menu_state.last_transcription.as_deref() {
+            menu.append(&PredefinedMenuItem::separator())?;
+            let preview = transcription_preview(text, LAST_TRANSCRIPTION_CHARS);
+            menu.append(&MenuItem::new(&preview, false, None))?;
+            menu.append(&MenuItem::with_id(
+                "Copy Last Transcription",
+                "Copy Last Transcription",
+                true,
+                None,
+            ))?;
+        }
+
         // Actions
         menu.append(&PredefinedMenuItem::separator())?;
+        menu.append(&MenuItem::with_id(
+            "Toggle Pause",
+            pause_label(menu_state.paused),
+            true,
+            None,
+        ))?;
+        menu.append(&MenuItem::with_id(
+            "Transcribe File",
+            "Transcribe File…",
+            true,
+            None,
+        ))?;
         menu.append(&MenuItem::with_id(
             "Open Config File",
             "Open Config File",
             true,
             None,
         ))?;
+        menu.append(&MenuItem::with_id(
+            "Re-check Permissions",
+            "Re-check Permissions",
+            true,
+            None,
+        ))?;
+        menu.append(&MenuItem::with_id("Restart", "Restart", true, None))?;
         menu.append(&PredefinedMenuItem::quit(None))?;
 
         Ok(menu)
@@ -237,9 +639,16 @@ impl TrayManager {
     fn parse_menu_event(id: &str) -> Option<TrayCommand> {
         match id {
             "Open Config File" => Some(TrayCommand::OpenConfigFile),
+            "Transcribe File" => Some(TrayCommand::TranscribeFile),
+            "Re-check Permissions" => Some(TrayCommand::RecheckPermissions),
+            "Restart" => Some(TrayCommand::Restart),
+            "Copy Last Transcription" => Some(TrayCommand::CopyLastTranscription),
+            "Toggle Pause" => Some(TrayCommand::TogglePause),
             // Note: "Quit" not handled here - PredefinedMenuItem::quit() uses native
             // macOS terminate: selector which bypasses event system entirely
-            _ => None,
+            _ => id
+                .strip_prefix(SELECT_PROFILE_ID)
+                .map(|name| TrayCommand::SelectProfile(name.to_owned())),
         }
     }
 }
@@ -247,7 +656,7 @@ impl TrayManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::ModelType;
+    use crate::config::{ModelType, ProfileMode};
 
     #[test]
     fn test_parse_menu_event_open_config() {
@@ -255,6 +664,156 @@ mod tests {
         assert!(matches!(cmd, Some(TrayCommand::OpenConfigFile)));
     }
 
+    #[test]
+    fn test_parse_menu_event_transcribe_file() {
+        let cmd = TrayManager::parse_menu_event("Transcribe File");
+        assert_eq!(cmd, Some(TrayCommand::TranscribeFile));
+    }
+
+    #[test]
+    fn test_recording_tooltip_meter() {
+        assert_eq!(
+            recording_tooltip(0, Some(0.0)),
+            "🎤 Recording 0:00 ▯▯▯▯▯▯▯▯▯▯"
+        );
+        assert_eq!(
+            recording_tooltip(7, Some(1.0)),
+            "🎤 Recording 0:07 ▮▮▮▮▮▮▮▮▮▮"
+        );
+    }
+
+    #[test]
+    fn test_recording_tooltip_without_meter() {
+        assert_eq!(recording_tooltip(29, None), "🎤 Recording 0:29");
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(0), "0:00");
+        assert_eq!(format_elapsed(7), "0:07");
+        assert_eq!(format_elapsed(30), "0:30");
+        assert_eq!(format_elapsed(59), "0:59");
+        assert_eq!(format_elapsed(60), "1:00");
+        assert_eq!(format_elapsed(605), "10:05");
+    }
+
+    #[test]
+    fn test_parse_menu_event_restart() {
+        let cmd = TrayManager::parse_menu_event("Restart");
+        assert_eq!(cmd, Some(TrayCommand::Restart));
+    }
+
+    #[test]
+    fn test_parse_menu_event_recheck_permissions() {
+        let cmd = TrayManager::parse_menu_event("Re-check Permissions");
+        assert_eq!(cmd, Some(TrayCommand::RecheckPermissions));
+    }
+
+    #[test]
+    fn test_parse_menu_event_copy_last_transcription() {
+        let cmd = TrayManager::parse_menu_event("Copy Last Transcription");
+        assert_eq!(cmd, Some(TrayCommand::CopyLastTranscription));
+    }
+
+    #[test]
+    fn test_parse_menu_event_toggle_pause() {
+        let cmd = TrayManager::parse_menu_event("Toggle Pause");
+        assert_eq!(cmd, Some(TrayCommand::TogglePause));
+        // Labels flip, the id doesn't
+        assert_eq!(TrayManager::parse_menu_event("Pause"), None);
+        assert_eq!(TrayManager::parse_menu_event("Resume"), None);
+    }
+
+    #[test]
+    fn test_parse_menu_event_select_profile() {
+        let cmd = TrayManager::parse_menu_event("Select Profile:notes");
+        assert_eq!(cmd, Some(TrayCommand::SelectProfile("notes".to_owned())));
+        // Names may contain the separator
+        let cmd = TrayManager::parse_menu_event("Select Profile:work: long form");
+        assert_eq!(
+            cmd,
+            Some(TrayCommand::SelectProfile("work: long form".to_owned()))
+        );
+        assert_eq!(TrayManager::parse_menu_event("notes"), None);
+    }
+
+    fn multi_profile_config() -> Config {
+        let mut config = Config::default();
+        let base = config.profiles[0].clone();
+        config.profiles.push(crate::config::TranscriptionProfile {
+            name: Some("notes".to_owned()),
+            model_type: ModelType::Small,
+            ..base.clone()
+        });
+        config.profiles.push(crate::config::TranscriptionProfile {
+            name: Some("commands".to_owned()),
+            ..base
+        });
+        config
+    }
+
+    #[test]
+    fn test_profile_choices_labels() {
+        let choices = profile_choices(&multi_profile_config(), None);
+        let labels: Vec<&str> = choices.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, ["base.en", "notes", "commands"]);
+        assert_eq!(choices[1].id, "Select Profile:notes");
+        // Nothing picked yet: the first profile is active
+        let checked: Vec<bool> = choices.iter().map(|c| c.checked).collect();
+        assert_eq!(checked, [true, false, false]);
+    }
+
+    #[test]
+    fn test_profile_choices_check_active() {
+        let choices = profile_choices(&multi_profile_config(), Some("commands"));
+        let checked: Vec<bool> = choices.iter().map(|c| c.checked).collect();
+        assert_eq!(checked, [false, false, true]);
+
+        let choices = profile_choices(&multi_profile_config(), Some("removed"));
+        assert!(choices.iter().all(|c| !c.checked));
+    }
+
+    #[test]
+    fn test_profile_choice_ids_parse_back() {
+        for choice in profile_choices(&multi_profile_config(), None) {
+            assert_eq!(
+                TrayManager::parse_menu_event(&choice.id),
+                Some(TrayCommand::SelectProfile(choice.label))
+            );
+        }
+    }
+
+    #[test]
+    fn test_pause_label() {
+        assert_eq!(pause_label(false), "Pause");
+        assert_eq!(pause_label(true), "Resume");
+    }
+
+    #[test]
+    fn test_transcription_preview_short() {
+        assert_eq!(transcription_preview("Hello world", 40), "“Hello world”");
+        assert_eq!(transcription_preview("", 40), "“”");
+    }
+
+    #[test]
+    fn test_transcription_preview_truncates() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(transcription_preview(text, 20), "“The quick brown fox…”");
+        // Exactly at the limit is kept whole
+        assert_eq!(transcription_preview("abcde", 5), "“abcde”");
+        assert_eq!(transcription_preview("abcdef", 5), "“abcd…”");
+    }
+
+    #[test]
+    fn test_transcription_preview_single_line() {
+        assert_eq!(
+            transcription_preview("First line\n\n  second\tline ", 40),
+            "“First line second line”"
+        );
+        // Counts characters, not bytes
+        assert_eq!(transcription_preview("héllo wörld", 6), "“héllo…”");
+    }
+
     #[test]
     fn test_parse_menu_event_unknown() {
         assert!(TrayManager::parse_menu_event("Unknown Item").is_none());
@@ -277,6 +836,55 @@ mod tests {
         assert_eq!(TrayManager::format_hotkey(&[], "V"), "V");
     }
 
+    #[test]
+    fn test_recording_frame_phase() {
+        let period = Duration::from_secs(1);
+        assert_eq!(
+            RecordingFrame::at(Duration::ZERO, period),
+            RecordingFrame::Bright
+        );
+        assert_eq!(
+            RecordingFrame::at(Duration::from_millis(499), period),
+            RecordingFrame::Bright
+        );
+        assert_eq!(
+            RecordingFrame::at(Duration::from_millis(500), period),
+            RecordingFrame::Dim
+        );
+        assert_eq!(
+            RecordingFrame::at(Duration::from_millis(999), period),
+            RecordingFrame::Dim
+        );
+    }
+
+    #[test]
+    fn test_recording_frame_repeats_each_period() {
+        let period = Duration::from_secs(1);
+        assert_eq!(
+            RecordingFrame::at(Duration::from_secs(1), period),
+            RecordingFrame::Bright
+        );
+        assert_eq!(
+            RecordingFrame::at(Duration::from_millis(12_750), period),
+            RecordingFrame::Dim
+        );
+    }
+
+    #[test]
+    fn test_recording_frame_zero_period_stays_bright() {
+        assert_eq!(
+            RecordingFrame::at(Duration::from_secs(3), Duration::ZERO),
+            RecordingFrame::Bright
+        );
+    }
+
+    #[test]
+    fn test_dim_image_scales_alpha() {
+        let image = image::RgbaImage::from_pixel(2, 2, image::Rgba([255, 0, 0, 200]));
+        let dimmed = dim_image(image);
+        assert_eq!(dimmed.get_pixel(0, 0), &image::Rgba([255, 0, 0, 70]));
+    }
+
     #[test]
     fn test_load_icon_idle() {
         let result = TrayManager::load_icon(AppState::Idle, 2.0);
@@ -316,7 +924,11 @@ mod tests {
 
     fn create_test_config() -> Config {
         use crate::config::{
-            AliasesConfig, AudioConfig, HotkeyConfig, ModelConfig, RecordingConfig, TelemetryConfig,
+            AliasesConfig, AppConfig, AudioConfig, AutoPunctuationConfig, DownloadConfig,
+            FormattingConfig, HistoryConfig, HotkeyConfig, HotkeyMode, InsertionConfig,
+            LatencyConfig, ModelConfig, OutputConfig, QueueConfig, QuickPickConfig,
+            RecordingConfig, StatsConfig, TelemetryConfig, TranslationConfig, TrayConfig,
+            WebhookConfig,
         };
         Config {
             profiles: vec![crate::config::TranscriptionProfile {
@@ -325,19 +937,46 @@ mod tests {
                 hotkey: HotkeyConfig {
                     modifiers: vec!["Control".to_owned(), "Option".to_owned()],
                     key: "Z".to_owned(),
+                    mode: HotkeyMode::Hold,
                 },
                 preload: true,
                 threads: 4,
                 beam_size: 5,
                 language: None,
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             }],
             hotkey: HotkeyConfig {
                 modifiers: vec!["Control".to_owned(), "Option".to_owned()],
                 key: "Z".to_owned(),
+                mode: HotkeyMode::Hold,
             },
             audio: AudioConfig {
                 buffer_size: 1024,
                 sample_rate: 16000,
+                clipping_threshold: 0.01,
+                continuous_drain: false,
+                resampler_auto: false,
+                resampler_threshold_ms: 5000,
+                max_recording_secs: 30,
+                device_name: None,
+                preroll_ms: 0,
+                trim_silence: false,
+                silence_threshold: 0.01,
+                trim_attack_ms: 100,
+                trim_release_ms: 200,
+                normalize: false,
+                target_peak: 0.9,
             },
             model: ModelConfig {
                 model_type: ModelType::Small,
@@ -345,13 +984,40 @@ mod tests {
                 threads: 4,
                 beam_size: 5,
                 language: None,
+                custom_path: None,
+                max_loaded_models: 0,
+                no_speech_threshold: 0.6,
+                use_gpu: true,
+                warmup: true,
             },
             telemetry: TelemetryConfig {
                 enabled: true,
                 log_path: "~/.whisper-hotkey/crash.log".to_owned(),
+                timing_debug: false,
+                audio_stats_debug: false,
+                log_max_mb: 10,
+                log_keep_files: 3,
+                format: crate::config::LogFormat::Text,
             },
             recording: RecordingConfig::default(),
             aliases: AliasesConfig::default(),
+            output: OutputConfig::default(),
+            download: DownloadConfig::default(),
+            insertion: InsertionConfig::default(),
+            app: AppConfig::default(),
+            formatting: FormattingConfig::default(),
+            queue: QueueConfig::default(),
+            translation: TranslationConfig::default(),
+            history: HistoryConfig::default(),
+            tray: TrayConfig::default(),
+            latency: LatencyConfig::default(),
+            stats: StatsConfig::default(),
+            webhook: WebhookConfig::default(),
+            quick_pick: QuickPickConfig::default(),
+            auto_punctuation: AutoPunctuationConfig::default(),
+            language_rules: Vec::new(),
+            version: None,
+            schema_version: crate::config::SCHEMA_VERSION,
         }
     }
 
@@ -363,11 +1029,24 @@ mod tests {
             hotkey: crate::config::HotkeyConfig {
                 modifiers: vec!["Control".to_owned(), "Option".to_owned()],
                 key: "Z".to_owned(),
+                mode: crate::config::HotkeyMode::Hold,
             },
             preload: true,
             threads: 4,
             beam_size: 5,
             language: None,
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         };
         let label = TrayManager::format_profile_label(&profile);
         assert_eq!(label, "small (Control+Option+Z): small");
@@ -381,11 +1060,24 @@ mod tests {
             hotkey: crate::config::HotkeyConfig {
                 modifiers: vec!["Command".to_owned(), "Shift".to_owned()],
                 key: "V".to_owned(),
+                mode: crate::config::HotkeyMode::Hold,
             },
             preload: false,
             threads: 2,
             beam_size: 3,
             language: Some("en".to_owned()),
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         };
         let label = TrayManager::format_profile_label(&profile);
         assert_eq!(label, "Custom Name (Command+Shift+V): base.en");
@@ -399,14 +1091,44 @@ mod tests {
             hotkey: crate::config::HotkeyConfig {
                 modifiers: vec![],
                 key: "F1".to_owned(),
+                mode: crate::config::HotkeyMode::Hold,
             },
             preload: true,
             threads: 1,
             beam_size: 1,
             language: None,
+            clear_before_insert: false,
+            strip_markdown: false,
+            translate_to: None,
+            custom_path: None,
+            mode: ProfileMode::Dictation,
+            preserve_whitespace: false,
+            fallback_model: None,
+            discard_modifier: None,
+            flash_attn: false,
+            translate: false,
+            initial_prompt: None,
+            audio: None,
         };
         let label = TrayManager::format_profile_label(&profile);
         assert_eq!(label, "Quick (F1): tiny");
+
+        let translating = crate::config::TranscriptionProfile {
+            translate: true,
+            ..profile.clone()
+        };
+        assert_eq!(
+            TrayManager::format_profile_label(&translating),
+            "Quick (F1): tiny → en"
+        );
+        let hooked = crate::config::TranscriptionProfile {
+            translate_to: Some("de".to_owned()),
+            ..profile
+        };
+        assert_eq!(
+            TrayManager::format_profile_label(&hooked),
+            "Quick (F1): tiny → de"
+        );
     }
 
     #[test]
@@ -428,11 +1150,24 @@ mod tests {
                 hotkey: crate::config::HotkeyConfig {
                     modifiers: vec!["Command".to_owned()],
                     key: "A".to_owned(),
+                    mode: crate::config::HotkeyMode::Hold,
                 },
                 preload: true,
                 threads: 4,
                 beam_size: 5,
                 language: None,
+                clear_before_insert: false,
+                strip_markdown: false,
+                translate_to: None,
+                custom_path: None,
+                mode: ProfileMode::Dictation,
+                preserve_whitespace: false,
+                fallback_model: None,
+                discard_modifier: None,
+                flash_attn: false,
+                translate: false,
+                initial_prompt: None,
+                audio: None,
             };
             let label = TrayManager::format_profile_label(&profile);
             assert_eq!(
@@ -460,13 +1195,38 @@ mod tests {
             TrayManager::load_icon(AppState::Processing, 2.0).unwrap(),
         );
 
-        let result = TrayManager::build_tray(&config, AppState::Idle, &cached_icons);
+        let result = TrayManager::build_tray(
+            &config,
+            AppState::Idle,
+            "Whisper Hotkey",
+            &cached_icons,
+            &MenuState::default(),
+        );
         assert!(result.is_ok());
 
-        let result = TrayManager::build_tray(&config, AppState::Recording, &cached_icons);
+        let result = TrayManager::build_tray(
+            &config,
+            AppState::Recording,
+            "Whisper Hotkey",
+            &cached_icons,
+            &MenuState {
+                paused: true,
+                ..MenuState::default()
+            },
+        );
         assert!(result.is_ok());
 
-        let result = TrayManager::build_tray(&config, AppState::Processing, &cached_icons);
+        let result = TrayManager::build_tray(
+            &config,
+            AppState::Processing,
+            "Whisper Hotkey",
+            &cached_icons,
+            &MenuState {
+                last_transcription: Some("Meeting notes for Tuesday".to_owned()),
+                paused: false,
+                active_profile: Some("small".to_owned()),
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -475,7 +1235,13 @@ mod tests {
         let config = create_test_config();
         let cached_icons = HashMap::new();
 
-        let result = TrayManager::build_tray(&config, AppState::Idle, &cached_icons);
+        let result = TrayManager::build_tray(
+            &config,
+            AppState::Idle,
+            "Whisper Hotkey",
+            &cached_icons,
+            &MenuState::default(),
+        );
         assert!(result.is_err());
     }
 
@@ -517,6 +1283,26 @@ mod tests {
             TrayManager::get_status_text(Some(AppState::Processing)),
             "⏳ Transcribing..."
         );
+        assert_eq!(
+            TrayManager::get_status_text(Some(AppState::Loading)),
+            "⏳ Loading model..."
+        );
         assert_eq!(TrayManager::get_status_text(None), "Whisper Hotkey");
     }
+
+    #[test]
+    fn test_cancelled_flash_expires() {
+        let now = Instant::now();
+        let until = Some(now + CANCELLED_FLASH);
+        assert!(flash_active(until, AppState::Idle, now));
+        assert!(!flash_active(until, AppState::Idle, now + CANCELLED_FLASH));
+        assert!(!flash_active(None, AppState::Idle, now));
+    }
+
+    #[test]
+    fn test_cancelled_flash_ends_on_new_recording() {
+        let now = Instant::now();
+        let until = Some(now + CANCELLED_FLASH);
+        assert!(!flash_active(until, AppState::Recording, now));
+    }
 }
@@ -0,0 +1,249 @@
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+use crate::config::WebhookConfig;
+
+/// JSON body posted for each completed transcription
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WebhookPayload {
+    /// Transcribed text (omitted unless `include_text` is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Profile that recorded it
+    pub profile: String,
+    /// Recording length in milliseconds
+    pub duration_ms: u64,
+    /// Unix timestamp (seconds, UTC)
+    pub timestamp: u64,
+}
+
+/// Delivers a JSON body to a URL (enables testing via mocking)
+#[cfg_attr(test, mockall::automock)]
+pub trait WebhookTransport: Send + Sync {
+    /// POST `body` to `url`
+    ///
+    /// # Errors
+    /// Returns error if the request fails or the server rejects it
+    fn post(&self, url: &str, body: &str) -> Result<()>;
+}
+
+/// HTTP transport with a whole-request timeout
+pub struct HttpTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl HttpTransport {
+    /// Create transport giving up after `timeout`
+    ///
+    /// # Errors
+    /// Returns error if the HTTP client can't be built
+    pub fn new(timeout: Duration) -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(timeout)
+            .build()
+            .context("failed to build HTTP client")?;
+        Ok(Self { client })
+    }
+}
+
+impl WebhookTransport for HttpTransport {
+    fn post(&self, url: &str, body: &str) -> Result<()> {
+        self.client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_owned())
+            .send()
+            .context("webhook request failed")?
+            .error_for_status()
+            .context("webhook rejected payload")?;
+        Ok(())
+    }
+}
+
+/// Posts completed transcriptions to a configured URL
+pub struct Webhook {
+    url: String,
+    /// Send the transcribed text (privacy: off by default)
+    include_text: bool,
+    transport: Arc<dyn WebhookTransport>,
+}
+
+impl Webhook {
+    /// Create webhook posting to `url` through `transport`
+    #[must_use]
+    pub fn new(url: &str, include_text: bool, transport: Arc<dyn WebhookTransport>) -> Self {
+        Self {
+            url: url.to_owned(),
+            include_text,
+            transport,
+        }
+    }
+
+    /// Create webhook from `[webhook]` config (`None` when disabled)
+    ///
+    /// # Errors
+    /// Returns error if enabled without a URL or the HTTP client can't be built
+    pub fn from_config(config: &WebhookConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let url = config.url.trim();
+        if url.is_empty() {
+            return Err(anyhow!("[webhook] enabled requires a url"));
+        }
+        let transport = HttpTransport::new(Duration::from_secs(config.timeout_secs))?;
+        Ok(Some(Self::new(
+            url,
+            config.include_text,
+            Arc::new(transport),
+        )))
+    }
+
+    /// Build the payload for one transcription, leaving out the text unless allowed
+    #[must_use]
+    pub fn payload(
+        &self,
+        profile: &str,
+        text: &str,
+        duration_ms: u64,
+        timestamp: u64,
+    ) -> WebhookPayload {
+        WebhookPayload {
+            text: self.include_text.then(|| text.to_owned()),
+            profile: profile.to_owned(),
+            duration_ms,
+            timestamp,
+        }
+    }
+
+    /// Deliver `payload` (blocking)
+    ///
+    /// # Errors
+    /// Returns error if serialization or delivery fails
+    pub fn send(&self, payload: &WebhookPayload) -> Result<()> {
+        let body = serde_json::to_string(payload).context("failed to serialize webhook payload")?;
+        self.transport.post(&self.url, &body)
+    }
+
+    /// Post a transcription from a background thread, logging failures
+    ///
+    /// Fire-and-forget: a slow or unreachable endpoint never delays insertion.
+    pub fn notify(self: &Arc<Self>, profile: &str, text: &str, duration_ms: u64) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let payload = self.payload(profile, text, duration_ms, timestamp);
+        let webhook = Arc::clone(self);
+        std::thread::spawn(move || match webhook.send(&payload) {
+            Ok(()) => debug!(profile = %payload.profile, "webhook delivered"),
+            Err(e) => warn!(error = %e, "failed to deliver webhook"),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    fn webhook(include_text: bool, transport: MockWebhookTransport) -> Webhook {
+        Webhook::new("http://localhost/hook", include_text, Arc::new(transport))
+    }
+
+    #[test]
+    fn test_payload_with_text() {
+        let hook = webhook(true, MockWebhookTransport::new());
+        let payload = hook.payload("base.en", "hello world", 2500, 1_700_000_000);
+        assert_eq!(
+            payload,
+            WebhookPayload {
+                text: Some("hello world".to_owned()),
+                profile: "base.en".to_owned(),
+                duration_ms: 2500,
+                timestamp: 1_700_000_000,
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"text":"hello world","profile":"base.en","duration_ms":2500,"timestamp":1700000000}"#
+        );
+    }
+
+    #[test]
+    fn test_payload_omits_text_without_opt_in() {
+        let hook = webhook(false, MockWebhookTransport::new());
+        let payload = hook.payload("base.en", "secret", 1000, 42);
+        assert_eq!(payload.text, None);
+        let json = serde_json::to_string(&payload).unwrap();
+        assert!(!json.contains("text"));
+        assert!(!json.contains("secret"));
+    }
+
+    #[test]
+    fn test_send_posts_json_to_url() {
+        let mut transport = MockWebhookTransport::new();
+        transport
+            .expect_post()
+            .withf(|url, body| {
+                url == "http://localhost/hook"
+                    && body == r#"{"profile":"medical","duration_ms":800,"timestamp":7}"#
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let hook = webhook(false, transport);
+        hook.send(&hook.payload("medical", "text", 800, 7)).unwrap();
+    }
+
+    #[test]
+    fn test_send_propagates_delivery_error() {
+        let mut transport = MockWebhookTransport::new();
+        transport
+            .expect_post()
+            .returning(|_, _| Err(anyhow!("connection refused")));
+        let hook = webhook(true, transport);
+        assert!(hook.send(&hook.payload("base.en", "hi", 1, 1)).is_err());
+    }
+
+    #[test]
+    fn test_notify_delivers_in_background() {
+        let (tx, rx) = mpsc::channel();
+        let tx = std::sync::Mutex::new(tx);
+        let mut transport = MockWebhookTransport::new();
+        transport.expect_post().times(1).returning(move |_, body| {
+            tx.lock().unwrap().send(body.to_owned()).unwrap();
+            Ok(())
+        });
+        let hook = Arc::new(webhook(true, transport));
+
+        hook.notify("base.en", "hello", 1500);
+
+        let body = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["text"], "hello");
+        assert_eq!(json["profile"], "base.en");
+        assert_eq!(json["duration_ms"], 1500);
+    }
+
+    #[test]
+    fn test_from_config() {
+        assert!(Webhook::from_config(&WebhookConfig::default())
+            .unwrap()
+            .is_none());
+
+        let config = WebhookConfig {
+            enabled: true,
+            ..WebhookConfig::default()
+        };
+        assert!(Webhook::from_config(&config).is_err());
+
+        let config = WebhookConfig {
+            enabled: true,
+            url: "http://localhost:8123/hook".to_owned(),
+            ..WebhookConfig::default()
+        };
+        assert!(Webhook::from_config(&config).unwrap().is_some());
+    }
+}
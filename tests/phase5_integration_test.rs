@@ -22,6 +22,13 @@
 #![allow(clippy::cast_precision_loss)]
 
 use std::path::PathBuf;
+use whisper_hotkey::input::cgevent;
+use whisper_hotkey::input::strategy::InsertionChain;
+
+/// Insert through the default chain (keystrokes only), without clearing the field
+fn insert_text(text: &str) -> bool {
+    cgevent::insert_text_replacing_safe(text, false, &InsertionChain::default())
+}
 
 fn get_test_model_path() -> Option<PathBuf> {
     let home = std::env::var("HOME").ok()?;
@@ -40,7 +47,6 @@ fn get_test_model_path() -> Option<PathBuf> {
 #[test]
 #[ignore = "Requires model file and Accessibility permissions with active text input"]
 fn test_transcribe_silence_to_text_insertion() {
-    use whisper_hotkey::input::cgevent;
     use whisper_hotkey::transcription::TranscriptionEngine;
 
     let Some(model_path) = get_test_model_path() else {
@@ -65,7 +71,7 @@ fn test_transcribe_silence_to_text_insertion() {
 
     // If text is non-empty, test insertion (requires Accessibility permissions)
     if !result.is_empty() {
-        let inserted = cgevent::insert_text_safe(&result);
+        let inserted = insert_text(&result);
         assert!(inserted, "Failed to insert transcribed text");
     }
 }
@@ -73,7 +79,6 @@ fn test_transcribe_silence_to_text_insertion() {
 #[test]
 #[ignore = "Requires model file and Accessibility permissions"]
 fn test_full_pipeline_with_tone() {
-    use whisper_hotkey::input::cgevent;
     use whisper_hotkey::transcription::TranscriptionEngine;
 
     let Some(model_path) = get_test_model_path() else {
@@ -99,7 +104,7 @@ fn test_full_pipeline_with_tone() {
 
     // Insert any resulting text (might be empty or gibberish)
     if !result.is_empty() {
-        let inserted = cgevent::insert_text_safe(&result);
+        let inserted = insert_text(&result);
         assert!(inserted, "Failed to insert text");
     }
 }
@@ -107,8 +112,6 @@ fn test_full_pipeline_with_tone() {
 #[test]
 #[ignore = "Requires Accessibility permissions and active text input"]
 fn test_text_insertion_various_apps() {
-    use whisper_hotkey::input::cgevent;
-
     // This test should be run manually with different apps:
     // 1. TextEdit
     // 2. VS Code
@@ -127,7 +130,7 @@ fn test_text_insertion_various_apps() {
 
     let test_text = "Hello from Whisper Hotkey! 👋";
 
-    let result = cgevent::insert_text_safe(test_text);
+    let result = insert_text(test_text);
     assert!(result, "Text insertion failed");
 
     println!("✓ Text inserted: '{}'", test_text);
@@ -137,14 +140,12 @@ fn test_text_insertion_various_apps() {
 #[test]
 #[ignore = "Requires Accessibility permissions and active text input"]
 fn test_unicode_insertion_polish() {
-    use whisper_hotkey::input::cgevent;
-
     println!("Focus a text input in 3 seconds...");
     std::thread::sleep(std::time::Duration::from_secs(3));
 
     let test_text = "Zażółć gęślą jaźń 🇵🇱";
 
-    let result = cgevent::insert_text_safe(test_text);
+    let result = insert_text(test_text);
     assert!(result, "Polish text insertion failed");
 
     println!("✓ Polish text inserted: '{}'", test_text);
@@ -153,14 +154,12 @@ fn test_unicode_insertion_polish() {
 #[test]
 #[ignore = "Requires Accessibility permissions and active text input"]
 fn test_multiline_insertion() {
-    use whisper_hotkey::input::cgevent;
-
     println!("Focus a text input in 3 seconds...");
     std::thread::sleep(std::time::Duration::from_secs(3));
 
     let test_text = "Line 1: Testing multiline\nLine 2: Text insertion\nLine 3: Via CGEvent";
 
-    let result = cgevent::insert_text_safe(test_text);
+    let result = insert_text(test_text);
     assert!(result, "Multiline text insertion failed");
 
     println!("✓ Multiline text inserted");
@@ -169,15 +168,13 @@ fn test_multiline_insertion() {
 #[test]
 #[ignore = "Requires Accessibility permissions and active text input"]
 fn test_long_text_insertion() {
-    use whisper_hotkey::input::cgevent;
-
     println!("Focus a text input in 3 seconds...");
     std::thread::sleep(std::time::Duration::from_secs(3));
 
     // Simulate a long transcription (~500 words)
     let test_text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(63);
 
-    let result = cgevent::insert_text_safe(&test_text);
+    let result = insert_text(&test_text);
     assert!(result, "Long text insertion failed");
 
     println!("✓ Long text inserted ({} chars)", test_text.len());
@@ -186,14 +183,12 @@ fn test_long_text_insertion() {
 #[test]
 #[ignore = "Requires Accessibility permissions"]
 fn test_text_insertion_error_handling() {
-    use whisper_hotkey::input::cgevent;
-
     // Empty text should fail gracefully
-    let result = cgevent::insert_text_safe("");
+    let result = insert_text("");
     assert!(!result, "Empty text should return false");
 
     // Valid text should succeed (if permissions granted)
-    let result = cgevent::insert_text_safe("test");
+    let result = insert_text("test");
     // Can't assert success without knowing if app is focused
     println!("Text insertion result: {}", result);
 }
@@ -278,11 +273,10 @@ fn test_phase5_integration_module_exports() {
     fn assert_send_sync<T: Send + Sync>() {}
 
     // Verify all Phase 5 modules are accessible
-    use whisper_hotkey::input::cgevent;
     use whisper_hotkey::transcription::TranscriptionEngine;
 
     // Type checks (compile-time verification)
-    let _: fn(&str) -> bool = cgevent::insert_text_safe;
+    let _: fn(&str, bool, &InsertionChain) -> bool = cgevent::insert_text_replacing_safe;
 
     // Ensure TranscriptionEngine types are available
     assert_send_sync::<TranscriptionEngine>();